@@ -0,0 +1,65 @@
+-- Elections and the frozen per-election voter roll.
+
+CREATE TABLE IF NOT EXISTS elections (
+    id               UUID PRIMARY KEY,
+    name             TEXT        NOT NULL,
+    phase            TEXT        NOT NULL DEFAULT 'draft'
+                     CHECK (phase IN ('draft', 'registration', 'voting', 'closed', 'published')),
+    voting_starts_at TIMESTAMPTZ,
+    voting_ends_at   TIMESTAMPTZ,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at       TIMESTAMPTZ
+);
+
+-- Live voter registry, kept in sync by the registrar.
+CREATE TABLE IF NOT EXISTS voters (
+    id         UUID PRIMARY KEY,
+    nim        TEXT        NOT NULL UNIQUE,
+    name       TEXT        NOT NULL,
+    email      TEXT        NOT NULL,
+    faculty    TEXT        NOT NULL,
+    cohort     INTEGER     NOT NULL,
+    eligible   BOOLEAN     NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at TIMESTAMPTZ
+);
+
+-- Snapshot of the eligible voters taken when an election enters voting.
+CREATE TABLE IF NOT EXISTS voter_rolls (
+    election_id UUID        NOT NULL REFERENCES elections (id),
+    voter_id    UUID        NOT NULL,
+    nim         TEXT        NOT NULL,
+    name        TEXT        NOT NULL,
+    email       TEXT        NOT NULL,
+    faculty     TEXT        NOT NULL,
+    cohort      INTEGER     NOT NULL,
+    frozen_at   TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (election_id, voter_id)
+);
+
+-- A roll is written once, in the same transaction that opens voting, and never changes.
+CREATE OR REPLACE FUNCTION voter_rolls_immutable() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'voter_rolls is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS voter_rolls_no_update ON voter_rolls;
+CREATE TRIGGER voter_rolls_no_update
+    BEFORE UPDATE OR DELETE ON voter_rolls
+    FOR EACH ROW EXECUTE FUNCTION voter_rolls_immutable();
+
+-- Inserting into an election's roll is only allowed while it has none yet.
+CREATE OR REPLACE FUNCTION voter_rolls_frozen() RETURNS trigger AS $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM voter_rolls WHERE election_id = NEW.election_id AND frozen_at <> NEW.frozen_at) THEN
+        RAISE EXCEPTION 'voter roll for election % is already frozen', NEW.election_id;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS voter_rolls_no_late_insert ON voter_rolls;
+CREATE TRIGGER voter_rolls_no_late_insert
+    BEFORE INSERT ON voter_rolls
+    FOR EACH ROW EXECUTE FUNCTION voter_rolls_frozen();
@@ -0,0 +1,23 @@
+-- One-time voting codes for voters on a frozen roll, and the sessions they redeem into.
+
+CREATE TABLE IF NOT EXISTS voting_credentials (
+    election_id UUID        NOT NULL REFERENCES elections (id),
+    voter_id    UUID        NOT NULL,
+    -- SHA-256 of the normalised code; the code itself is never stored.
+    code_hash   TEXT        NOT NULL UNIQUE,
+    issued_at   TIMESTAMPTZ NOT NULL DEFAULT now(),
+    redeemed_at TIMESTAMPTZ,
+    PRIMARY KEY (election_id, voter_id),
+    FOREIGN KEY (election_id, voter_id) REFERENCES voter_rolls (election_id, voter_id)
+);
+
+CREATE TABLE IF NOT EXISTS voting_sessions (
+    -- SHA-256 of the bearer token handed to the voter.
+    token_hash  TEXT PRIMARY KEY,
+    election_id UUID        NOT NULL REFERENCES elections (id),
+    voter_id    UUID        NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    expires_at  TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS voting_sessions_expires_at_idx ON voting_sessions (expires_at);
@@ -0,0 +1,28 @@
+-- Voter PII is encrypted by the application. `nim`, `name` and `email` now hold
+-- envelopes (`v1.<key id>.…`); `nim_bidx` is a keyed HMAC of the NIM for exact lookups.
+-- Rows written before this migration stay readable as plaintext until
+-- `POST /voters/pii/rotate` encrypts them and fills in their blind index.
+
+ALTER TABLE voters ALTER COLUMN id SET DEFAULT gen_random_uuid();
+ALTER TABLE voters DROP CONSTRAINT IF EXISTS voters_nim_key;
+ALTER TABLE voters ADD COLUMN IF NOT EXISTS nim_bidx TEXT;
+CREATE UNIQUE INDEX IF NOT EXISTS voters_nim_bidx_key ON voters (nim_bidx);
+
+ALTER TABLE voter_rolls ADD COLUMN IF NOT EXISTS nim_bidx TEXT;
+CREATE INDEX IF NOT EXISTS voter_rolls_nim_bidx_idx ON voter_rolls (election_id, nim_bidx);
+
+-- Membership of a frozen roll stays immutable; only the encrypted identity columns may be
+-- rewritten, which key rotation needs.
+CREATE OR REPLACE FUNCTION voter_rolls_immutable() RETURNS trigger AS $$
+BEGIN
+    IF TG_OP = 'UPDATE'
+        AND NEW.election_id = OLD.election_id
+        AND NEW.voter_id = OLD.voter_id
+        AND NEW.faculty = OLD.faculty
+        AND NEW.cohort = OLD.cohort
+        AND NEW.frozen_at = OLD.frozen_at THEN
+        RETURN NEW;
+    END IF;
+    RAISE EXCEPTION 'voter_rolls is append-only';
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,3 @@
+-- Voters erased on request keep their row and id, so roll sizes, credential counts and
+-- anything else referring to the id stay intact; only the identity columns are replaced.
+ALTER TABLE voters ADD COLUMN IF NOT EXISTS erased_at TIMESTAMPTZ;
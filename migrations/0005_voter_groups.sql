@@ -0,0 +1,18 @@
+-- Named groups of voters ("student council members", "residence hall A", ...) for
+-- eligibility rules, reminder campaigns and turnout breakdowns.
+
+CREATE TABLE IF NOT EXISTS voter_groups (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name        TEXT        NOT NULL UNIQUE,
+    description TEXT        NOT NULL DEFAULT '',
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE TABLE IF NOT EXISTS voter_group_members (
+    group_id UUID        NOT NULL REFERENCES voter_groups (id) ON DELETE CASCADE,
+    voter_id UUID        NOT NULL REFERENCES voters (id),
+    added_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (group_id, voter_id)
+);
+
+CREATE INDEX IF NOT EXISTS voter_group_members_voter_id_idx ON voter_group_members (voter_id);
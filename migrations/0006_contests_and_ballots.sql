@@ -0,0 +1,29 @@
+-- Contests group the candidates voted on in an election; ballots record one choice per contest.
+
+CREATE TABLE IF NOT EXISTS contests (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    election_id UUID        NOT NULL REFERENCES elections (id),
+    name        TEXT        NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    UNIQUE (election_id, name)
+);
+
+ALTER TABLE candidates ADD COLUMN IF NOT EXISTS contest_id UUID REFERENCES contests (id);
+CREATE INDEX IF NOT EXISTS candidates_contest_id_idx ON candidates (contest_id);
+
+CREATE TABLE IF NOT EXISTS ballots (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    election_id UUID        NOT NULL REFERENCES elections (id),
+    voter_id    UUID        NOT NULL REFERENCES voters (id),
+    cast_at     TIMESTAMPTZ NOT NULL DEFAULT now(),
+    -- One ballot per voter per election. Enforced here rather than by a prior SELECT, so two
+    -- racing submissions cannot both get in: the second insert fails on this constraint.
+    CONSTRAINT ballots_one_per_voter UNIQUE (election_id, voter_id)
+);
+
+CREATE TABLE IF NOT EXISTS ballot_choices (
+    ballot_id    UUID NOT NULL REFERENCES ballots (id),
+    contest_id   UUID NOT NULL REFERENCES contests (id),
+    candidate_id UUID NOT NULL REFERENCES candidates (id),
+    PRIMARY KEY (ballot_id, contest_id)
+);
@@ -0,0 +1,10 @@
+-- Idempotency keys sent with ballot submissions, so a retried submit returns the stored ballot.
+
+CREATE TABLE IF NOT EXISTS ballot_idempotency_keys (
+    voter_id        UUID        NOT NULL REFERENCES voters (id),
+    idempotency_key TEXT        NOT NULL,
+    request_hash    TEXT        NOT NULL,
+    ballot_id       UUID        NOT NULL REFERENCES ballots (id),
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT ballot_idempotency_keys_pkey PRIMARY KEY (voter_id, idempotency_key)
+);
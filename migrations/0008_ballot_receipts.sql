@@ -0,0 +1,8 @@
+-- Receipt codes handed to voters after casting. The receipt is a salted hash of the ballot;
+-- the salt is kept so an auditor can recompute every receipt from the stored ballots.
+-- Ballots cast before this migration have no receipt.
+
+ALTER TABLE ballots ADD COLUMN IF NOT EXISTS receipt_nonce TEXT;
+ALTER TABLE ballots ADD COLUMN IF NOT EXISTS receipt TEXT;
+
+CREATE UNIQUE INDEX IF NOT EXISTS ballots_election_receipt_key ON ballots (election_id, receipt);
@@ -0,0 +1,52 @@
+-- Splits "who voted" from "what was voted". Participations name the voter; ballots do not,
+-- and keep their time only to the hour. The two tables share no column besides the election
+-- and no foreign key, and are written in separate transactions so their rows do not share a
+-- transaction id either.
+
+CREATE TABLE IF NOT EXISTS participations (
+    election_id UUID        NOT NULL REFERENCES elections (id),
+    voter_id    UUID        NOT NULL REFERENCES voters (id),
+    voted_at    TIMESTAMPTZ NOT NULL,
+    -- One participation per voter per election; this is now what stops a second ballot.
+    CONSTRAINT participations_one_per_voter UNIQUE (election_id, voter_id)
+);
+
+-- The backfill and the rebuild below only apply to the old layout, so running this file again
+-- leaves participations and live idempotency records alone.
+DO $$
+BEGIN
+    IF EXISTS (
+        SELECT 1 FROM information_schema.columns
+        WHERE table_schema = current_schema() AND table_name = 'ballots' AND column_name = 'voter_id'
+    ) THEN
+        INSERT INTO participations (election_id, voter_id, voted_at)
+        SELECT election_id, voter_id, cast_at FROM ballots
+        ON CONFLICT DO NOTHING;
+    END IF;
+
+    -- Idempotency records linked a voter to a ballot id. They are replaced by results sealed
+    -- under the client's key; existing records are only useful for retries and are dropped.
+    IF EXISTS (
+        SELECT 1 FROM information_schema.columns
+        WHERE table_schema = current_schema()
+          AND table_name = 'ballot_idempotency_keys'
+          AND column_name = 'idempotency_key'
+    ) THEN
+        DROP TABLE ballot_idempotency_keys;
+    END IF;
+END
+$$;
+
+CREATE TABLE IF NOT EXISTS ballot_idempotency_keys (
+    voter_id      UUID        NOT NULL REFERENCES voters (id),
+    key_hash      TEXT        NOT NULL,
+    election_id   UUID        NOT NULL REFERENCES elections (id),
+    sealed_result TEXT        NOT NULL,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT now(),
+    CONSTRAINT ballot_idempotency_keys_pkey PRIMARY KEY (voter_id, key_hash)
+);
+
+ALTER TABLE ballots DROP CONSTRAINT IF EXISTS ballots_one_per_voter;
+ALTER TABLE ballots DROP COLUMN IF EXISTS voter_id;
+ALTER TABLE ballots ALTER COLUMN cast_at SET DEFAULT date_trunc('hour', now());
+UPDATE ballots SET cast_at = date_trunc('hour', cast_at);
@@ -0,0 +1,14 @@
+-- Re-voting: per-election setting, reusable codes, and ballots retired by a later ballot
+-- cast with the same credential.
+
+ALTER TABLE elections ADD COLUMN IF NOT EXISTS allow_revote BOOLEAN NOT NULL DEFAULT false;
+
+-- Sealed under the session token; only readable while the voter presents it.
+ALTER TABLE voting_sessions ADD COLUMN IF NOT EXISTS revote_tag_sealed TEXT;
+
+ALTER TABLE ballots ADD COLUMN IF NOT EXISTS revote_tag TEXT;
+ALTER TABLE ballots ADD COLUMN IF NOT EXISTS counted BOOLEAN NOT NULL DEFAULT true;
+
+-- At most one counted ballot per credential, whatever happens to the advisory lock.
+CREATE UNIQUE INDEX IF NOT EXISTS ballots_one_counted_per_tag
+    ON ballots (election_id, revote_tag) WHERE counted AND revote_tag IS NOT NULL;
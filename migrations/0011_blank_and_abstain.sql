@@ -0,0 +1,9 @@
+-- Blank ballots and explicit abstentions, stored apart from votes for a candidate.
+
+ALTER TABLE ballots ADD COLUMN IF NOT EXISTS blank BOOLEAN NOT NULL DEFAULT false;
+
+ALTER TABLE ballot_choices ALTER COLUMN candidate_id DROP NOT NULL;
+ALTER TABLE ballot_choices ADD COLUMN IF NOT EXISTS abstain BOOLEAN NOT NULL DEFAULT false;
+ALTER TABLE ballot_choices DROP CONSTRAINT IF EXISTS ballot_choices_candidate_or_abstain;
+ALTER TABLE ballot_choices ADD CONSTRAINT ballot_choices_candidate_or_abstain
+    CHECK (abstain = (candidate_id IS NULL));
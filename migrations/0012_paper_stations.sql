@@ -0,0 +1,41 @@
+-- Paper polling stations: codes issued at each station, the two committee entries of its
+-- totals, and the point at which the agreed totals join the electronic tally.
+
+CREATE TABLE IF NOT EXISTS polling_stations (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    election_id UUID        NOT NULL REFERENCES elections (id),
+    name        TEXT        NOT NULL,
+    -- Voters of this faculty are issued their codes at the station.
+    faculty     TEXT        NOT NULL,
+    merged_at   TIMESTAMPTZ,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT now(),
+    UNIQUE (election_id, name)
+);
+
+ALTER TABLE voting_credentials ADD COLUMN IF NOT EXISTS station_id UUID REFERENCES polling_stations (id);
+CREATE INDEX IF NOT EXISTS voting_credentials_station_id_idx ON voting_credentials (station_id);
+
+-- Each station's totals are keyed in twice, by two different committee members.
+CREATE TABLE IF NOT EXISTS paper_entries (
+    station_id UUID        NOT NULL REFERENCES polling_stations (id),
+    slot       SMALLINT    NOT NULL CHECK (slot IN (1, 2)),
+    entered_by TEXT        NOT NULL,
+    ballots    BIGINT      NOT NULL CHECK (ballots >= 0),
+    blank      BIGINT      NOT NULL CHECK (blank >= 0),
+    invalid    BIGINT      NOT NULL CHECK (invalid >= 0),
+    entered_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (station_id, slot)
+);
+
+-- Per-contest counts of an entry; a NULL candidate is the contest's abstentions.
+CREATE TABLE IF NOT EXISTS paper_entry_counts (
+    station_id   UUID   NOT NULL,
+    slot         SMALLINT NOT NULL,
+    contest_id   UUID   NOT NULL REFERENCES contests (id),
+    candidate_id UUID   REFERENCES candidates (id),
+    votes        BIGINT NOT NULL CHECK (votes >= 0),
+    FOREIGN KEY (station_id, slot) REFERENCES paper_entries (station_id, slot) ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS paper_entry_counts_one_per_choice
+    ON paper_entry_counts (station_id, slot, contest_id, COALESCE(candidate_id, '00000000-0000-0000-0000-000000000000'));
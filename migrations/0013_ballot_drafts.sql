@@ -0,0 +1,15 @@
+-- Ballots held for review: a voter submits their selections, checks the summary, and only
+-- then confirms, which casts the ballot.
+
+CREATE TABLE IF NOT EXISTS ballot_drafts (
+    id               UUID        PRIMARY KEY DEFAULT gen_random_uuid(),
+    election_id      UUID        NOT NULL REFERENCES elections (id),
+    -- The voting session the draft belongs to; see voting_sessions.token_hash.
+    session_hash     TEXT        NOT NULL,
+    -- Selections sealed under the session token, so they cannot be read without it.
+    sealed_selection TEXT        NOT NULL,
+    expires_at       TIMESTAMPTZ NOT NULL,
+    confirmed        BOOLEAN     NOT NULL DEFAULT false
+);
+
+CREATE INDEX IF NOT EXISTS ballot_drafts_expires_at_idx ON ballot_drafts (expires_at);
@@ -0,0 +1,13 @@
+-- Ballots refused because voting had closed, kept for the audit report. Nothing about what
+-- the ballot said is recorded.
+
+CREATE TABLE IF NOT EXISTS late_ballot_rejections (
+    id                 UUID        PRIMARY KEY DEFAULT gen_random_uuid(),
+    election_id        UUID        NOT NULL REFERENCES elections (id),
+    voter_id           UUID        NOT NULL,
+    session_started_at TIMESTAMPTZ NOT NULL,
+    voting_ends_at     TIMESTAMPTZ,
+    rejected_at        TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS late_ballot_rejections_election_idx ON late_ballot_rejections (election_id, rejected_at);
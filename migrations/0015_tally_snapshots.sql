@@ -0,0 +1,26 @@
+-- Signed result snapshots. Each tally is kept as the exact JSON that was signed; a later
+-- tally adds a snapshot rather than replacing one.
+
+CREATE TABLE IF NOT EXISTS tally_snapshots (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    election_id UUID        NOT NULL REFERENCES elections (id),
+    results     TEXT        NOT NULL,
+    -- SHA-256 of results, hex.
+    digest      TEXT        NOT NULL,
+    key_id      TEXT        NOT NULL,
+    signature   TEXT        NOT NULL,
+    computed_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS tally_snapshots_election_id_idx ON tally_snapshots (election_id, computed_at DESC);
+
+CREATE OR REPLACE FUNCTION tally_snapshots_immutable() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'tally_snapshots is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS tally_snapshots_no_update ON tally_snapshots;
+CREATE TRIGGER tally_snapshots_no_update
+    BEFORE UPDATE OR DELETE ON tally_snapshots
+    FOR EACH ROW EXECUTE FUNCTION tally_snapshots_immutable();
@@ -0,0 +1,42 @@
+-- Results embargo: two-person overrides to see results before voting closes, an audit trail
+-- of early reads, and snapshots counted under an override kept apart from the results.
+
+CREATE TABLE IF NOT EXISTS embargo_overrides (
+    id           UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    election_id  UUID        NOT NULL REFERENCES elections (id),
+    requested_by TEXT        NOT NULL,
+    reason       TEXT        NOT NULL,
+    requested_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    approved_by  TEXT,
+    approved_at  TIMESTAMPTZ,
+    expires_at   TIMESTAMPTZ,
+    CONSTRAINT embargo_overrides_two_people CHECK (lower(approved_by) <> lower(requested_by))
+);
+
+CREATE INDEX IF NOT EXISTS embargo_overrides_election_id_idx ON embargo_overrides (election_id, expires_at);
+
+CREATE TABLE IF NOT EXISTS audit_log (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    election_id UUID        NOT NULL REFERENCES elections (id),
+    actor       TEXT,
+    action      TEXT        NOT NULL,
+    outcome     TEXT        NOT NULL CHECK (outcome IN ('allowed', 'denied')),
+    detail      TEXT        NOT NULL,
+    at          TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS audit_log_election_id_idx ON audit_log (election_id, at);
+
+CREATE OR REPLACE FUNCTION audit_log_immutable() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'audit_log is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS audit_log_no_update ON audit_log;
+CREATE TRIGGER audit_log_no_update
+    BEFORE UPDATE OR DELETE ON audit_log
+    FOR EACH ROW EXECUTE FUNCTION audit_log_immutable();
+
+-- Counted before voting closed, under an override: kept as evidence, never served as results.
+ALTER TABLE tally_snapshots ADD COLUMN IF NOT EXISTS provisional BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,10 @@
+-- How each contest settles a tie for the most votes. A lottery draws from a seed that the
+-- server sets when voting opens (see 0021), so a lottery contest has none before then.
+
+ALTER TABLE contests ADD COLUMN IF NOT EXISTS tie_break TEXT NOT NULL DEFAULT 'runoff'
+    CHECK (tie_break IN ('runoff', 'lottery', 'registration'));
+ALTER TABLE contests ADD COLUMN IF NOT EXISTS tie_break_seed TEXT;
+
+ALTER TABLE contests DROP CONSTRAINT IF EXISTS contests_tie_break_seed;
+ALTER TABLE contests ADD CONSTRAINT contests_tie_break_seed
+    CHECK (tie_break = 'lottery' OR tie_break_seed IS NULL);
@@ -0,0 +1,28 @@
+-- Signed recount reports: a fresh count from the stored ballots set against the snapshot it
+-- checks, kept as the exact JSON that was signed. Like snapshots, they are only ever added.
+
+CREATE TABLE IF NOT EXISTS recount_reports (
+    id           UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    election_id  UUID        NOT NULL REFERENCES elections (id),
+    snapshot_id  UUID        NOT NULL REFERENCES tally_snapshots (id),
+    report       TEXT        NOT NULL,
+    -- SHA-256 of report, hex.
+    digest       TEXT        NOT NULL,
+    key_id       TEXT        NOT NULL,
+    signature    TEXT        NOT NULL,
+    matches      BOOLEAN     NOT NULL,
+    recounted_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS recount_reports_election_id_idx ON recount_reports (election_id, recounted_at DESC);
+
+CREATE OR REPLACE FUNCTION recount_reports_immutable() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'recount_reports is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS recount_reports_no_update ON recount_reports;
+CREATE TRIGGER recount_reports_no_update
+    BEFORE UPDATE OR DELETE ON recount_reports
+    FOR EACH ROW EXECUTE FUNCTION recount_reports_immutable();
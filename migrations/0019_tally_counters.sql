@@ -0,0 +1,34 @@
+-- Running counts kept up to date by the transactions that write participations and ballots,
+-- so live turnout does not count every row on each look. Counters are keyed per election:
+--   voted                               participations
+--   ballots, blank                      counted ballots, and those of them left blank
+--   contest:<contest>                   counted ballots with a choice in the contest
+--   abstain:<contest>                   of those, explicit abstentions
+--   candidate:<contest>:<candidate>     votes for a candidate
+-- The reconciler recomputes them from the raw rows and reports any drift.
+
+CREATE TABLE IF NOT EXISTS tally_counters (
+    election_id UUID   NOT NULL REFERENCES elections (id),
+    counter     TEXT   NOT NULL,
+    value       BIGINT NOT NULL,
+    PRIMARY KEY (election_id, counter)
+);
+
+INSERT INTO tally_counters (election_id, counter, value)
+SELECT election_id, counter, value FROM (
+    SELECT election_id, 'voted' AS counter, COUNT(*) AS value FROM participations GROUP BY 1
+    UNION ALL
+    SELECT election_id, 'ballots', COUNT(*) FROM ballots WHERE counted GROUP BY 1
+    UNION ALL
+    SELECT election_id, 'blank', COUNT(*) FROM ballots WHERE counted AND blank GROUP BY 1
+    UNION ALL
+    SELECT b.election_id, 'contest:' || c.contest_id, COUNT(*)
+    FROM ballot_choices c JOIN ballots b ON b.id = c.ballot_id WHERE b.counted GROUP BY 1, 2
+    UNION ALL
+    SELECT b.election_id, 'abstain:' || c.contest_id, COUNT(*)
+    FROM ballot_choices c JOIN ballots b ON b.id = c.ballot_id WHERE b.counted AND c.abstain GROUP BY 1, 2
+    UNION ALL
+    SELECT b.election_id, 'candidate:' || c.contest_id || ':' || c.candidate_id, COUNT(*)
+    FROM ballot_choices c JOIN ballots b ON b.id = c.ballot_id WHERE b.counted AND NOT c.abstain GROUP BY 1, 2
+) AS counts
+ON CONFLICT (election_id, counter) DO UPDATE SET value = EXCLUDED.value;
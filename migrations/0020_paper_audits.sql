@@ -0,0 +1,44 @@
+-- Ballot-polling risk-limiting audits of paper contests. An audit fixes the reported paper
+-- outcome and the stations' ballot counts when it starts; the ballots to pull follow from its
+-- seed, and the hand interpretations of those ballots are kept as entered.
+
+CREATE TABLE IF NOT EXISTS paper_audits (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    election_id UUID             NOT NULL REFERENCES elections (id),
+    contest_id  UUID             NOT NULL REFERENCES contests (id),
+    risk_limit  DOUBLE PRECISION NOT NULL CHECK (risk_limit > 0 AND risk_limit < 1),
+    seed        TEXT             NOT NULL CHECK (length(seed) > 0),
+    -- Only ever grows: an escalated audit keeps the draws it already has.
+    sample_size BIGINT           NOT NULL CHECK (sample_size > 0),
+    -- JSON: the reported votes, winner and sampling frame.
+    reported    TEXT             NOT NULL,
+    created_by  TEXT             NOT NULL,
+    created_at  TIMESTAMPTZ      NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS paper_audits_election_id_idx ON paper_audits (election_id, created_at);
+
+CREATE TABLE IF NOT EXISTS paper_audit_interpretations (
+    audit_id       UUID        NOT NULL REFERENCES paper_audits (id),
+    station_id     UUID        NOT NULL REFERENCES polling_stations (id),
+    -- 1-based place of the ballot in the station's box.
+    position       BIGINT      NOT NULL CHECK (position > 0),
+    mark           TEXT        NOT NULL CHECK (mark IN ('candidate', 'abstain', 'blank', 'invalid')),
+    candidate_id   UUID        REFERENCES candidates (id),
+    interpreted_by TEXT        NOT NULL,
+    interpreted_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (audit_id, station_id, position),
+    CONSTRAINT paper_audit_interpretations_candidate CHECK ((mark = 'candidate') = (candidate_id IS NOT NULL))
+);
+
+-- An interpretation is final once entered.
+CREATE OR REPLACE FUNCTION paper_audit_interpretations_immutable() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'paper_audit_interpretations is append-only';
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS paper_audit_interpretations_no_update ON paper_audit_interpretations;
+CREATE TRIGGER paper_audit_interpretations_no_update
+    BEFORE UPDATE OR DELETE ON paper_audit_interpretations
+    FOR EACH ROW EXECUTE FUNCTION paper_audit_interpretations_immutable();
@@ -0,0 +1,11 @@
+-- A lottery seed is no longer chosen by an admin but drawn by the server when voting opens, so a
+-- lottery contest has no seed until then. Seeds chosen for elections that have not opened yet
+-- are cleared; they are drawn afresh when those elections open.
+
+ALTER TABLE contests DROP CONSTRAINT IF EXISTS contests_tie_break_seed;
+ALTER TABLE contests ADD CONSTRAINT contests_tie_break_seed
+    CHECK (tie_break = 'lottery' OR tie_break_seed IS NULL);
+
+UPDATE contests c SET tie_break_seed = NULL
+FROM elections e
+WHERE e.id = c.election_id AND e.phase IN ('draft', 'registration');
@@ -0,0 +1,4 @@
+-- Who merged each paper station into the count, by their name on the staff roster. Stations
+-- merged before this was recorded keep no name.
+
+ALTER TABLE polling_stations ADD COLUMN IF NOT EXISTS merged_by TEXT;
@@ -0,0 +1,4 @@
+-- Who erased a voter, by their name on the staff roster. Voters erased before this was
+-- recorded keep no name.
+
+ALTER TABLE voters ADD COLUMN IF NOT EXISTS erased_by TEXT;
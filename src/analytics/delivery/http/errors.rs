@@ -0,0 +1,24 @@
+use crate::analytics::domain::AnalyticsError;
+use crate::utils::response;
+use actix_web::HttpResponse;
+
+pub fn error_response(e: &AnalyticsError) -> HttpResponse {
+    println!("Error: {}", e);
+    match e {
+        AnalyticsError::NotFound(msg) => HttpResponse::NotFound().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "ANALYTICS_NOT_FOUND".into(),
+        )),
+        AnalyticsError::InvalidInput(msg) => HttpResponse::BadRequest().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "ANALYTICS_INVALID_INPUT".into(),
+        )),
+        AnalyticsError::UnknownError(_) => HttpResponse::InternalServerError().json(response::error::<()>(
+            None,
+            "failed process data".into(),
+            "-1".into(),
+        )),
+    }
+}
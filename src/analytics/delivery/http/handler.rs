@@ -0,0 +1,7 @@
+use actix_web::web;
+use crate::analytics::delivery::http::turnout_breakdown::turnout_breakdown;
+
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/elections/{id}/turnout/{dimension}", web::get().to(turnout_breakdown));
+}
@@ -0,0 +1,6 @@
+mod handler;
+mod errors;
+mod turnout_breakdown;
+
+pub use turnout_breakdown::*;
+pub use handler::*;
@@ -0,0 +1,47 @@
+use crate::analytics::delivery::http::errors::error_response;
+use crate::analytics::usecase::breakdown::*;
+use crate::utils::{app, response};
+use actix_web::{HttpResponse, web};
+
+pub async fn turnout_breakdown(
+    handler: web::Data<app::AppHandlerData>,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+
+    let (election_id, dimension) = path.into_inner();
+    let request = Request { election_id, dimension };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.analytics_uc.breakdown.handle(request).await {
+        Ok(breakdown) => HttpResponse::Ok().json(response::success(
+            Some(breakdown),
+            "Successfully processed turnout".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::turnout_breakdown;
+    use actix_web::{App, http::StatusCode, test, web};
+    use crate::utils::app;
+
+    #[actix_rt::test]
+    async fn test_turnout_breakdown_rejects_an_unknown_dimension() {
+        let app_data = web::Data::new(app::AppHandlerData::mocked());
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_data)
+                .route("/elections/{id}/turnout/{dimension}", web::get().to(turnout_breakdown)),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/elections/e1/turnout/email").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Dimension {
+    Faculty,
+    Cohort,
+    /// Hour in which the voter voted online or signed in at a paper station, in UTC.
+    Hour,
+}
+
+impl Dimension {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Dimension::Faculty => "faculty",
+            Dimension::Cohort => "cohort",
+            Dimension::Hour => "hour",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Dimension> {
+        match value {
+            "faculty" => Some(Dimension::Faculty),
+            "cohort" => Some(Dimension::Cohort),
+            "hour" => Some(Dimension::Hour),
+            _ => None,
+        }
+    }
+}
+
+/// One group's counts as read from the roll, the participations and the station sign-ins, before
+/// anything is hidden.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GroupCount {
+    pub group: String,
+    /// Voters of the group on the frozen roll; hours have no roll of their own.
+    pub roll_size: Option<i64>,
+    pub voted: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TurnoutGroup {
+    pub group: String,
+    pub roll_size: Option<i64>,
+    pub voted: i64,
+    pub turnout_percent: Option<f64>,
+}
+
+/// The hidden groups, added up.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SuppressedGroups {
+    pub groups: usize,
+    pub roll_size: Option<i64>,
+    pub voted: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TurnoutBreakdown {
+    pub election_id: String,
+    pub dimension: Dimension,
+    pub min_group_size: i64,
+    /// In group order: faculty name, cohort year or hour.
+    pub groups: Vec<TurnoutGroup>,
+    pub suppressed: Option<SuppressedGroups>,
+}
@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+
+#[derive(Debug, Error)]
+#[derive(Clone)]
+pub enum AnalyticsError {
+    #[error("AnalyticsError::NotFound: {0}")]
+    NotFound(String),
+    #[error("AnalyticsError::InvalidInput: {0}")]
+    InvalidInput(String),
+    #[error("AnalyticsError::UnknownError: {0}")]
+    UnknownError(String),
+}
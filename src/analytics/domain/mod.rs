@@ -0,0 +1,9 @@
+mod entities;
+mod errors;
+mod repository;
+mod suppression;
+
+pub use entities::*;
+pub use errors::*;
+pub use repository::*;
+pub use suppression::*;
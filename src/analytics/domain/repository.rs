@@ -0,0 +1,11 @@
+use crate::analytics::domain::entities::{Dimension, GroupCount};
+use crate::analytics::domain::errors::AnalyticsError;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait Repository: Send + Sync {
+    /// Roll sizes and voters who voted online or at a station per group, in group order; `NotFound` for an unknown election.
+    async fn find_group_counts(&self, election_id: String, dimension: Dimension) -> Result<Vec<GroupCount>, AnalyticsError>;
+}
@@ -0,0 +1,129 @@
+use crate::analytics::domain::entities::{Dimension, GroupCount, SuppressedGroups, TurnoutBreakdown, TurnoutGroup};
+
+/// A group is too small when either side of its turnout is: few who voted names them, and few
+/// who did not names everyone else on the roll.
+fn too_small(roll_size: Option<i64>, voted: i64, min_group_size: i64) -> bool {
+    voted < min_group_size || roll_size.is_some_and(|r| r - voted < min_group_size)
+}
+
+fn added_up(groups: &[GroupCount]) -> (Option<i64>, i64) {
+    let roll_size = groups.iter().map(|g| g.roll_size).sum::<Option<i64>>();
+    (roll_size, groups.iter().map(|g| g.voted).sum())
+}
+
+fn turnout_percent(roll_size: Option<i64>, voted: i64) -> Option<f64> {
+    roll_size
+        .filter(|&r| r > 0)
+        .map(|r| (voted as f64 * 10_000.0 / r as f64).round() / 100.0)
+}
+
+/// Hides every group with fewer than `min_group_size` voters who voted, or fewer than that many
+/// on its roll who did not. Overall turnout is public, so the hidden groups are only reported added up,
+/// and when that sum is itself too small the smallest shown groups are hidden along with them.
+pub fn breakdown(election_id: String, dimension: Dimension, counts: Vec<GroupCount>, min_group_size: i64) -> TurnoutBreakdown {
+    let (mut hidden, mut shown): (Vec<GroupCount>, Vec<GroupCount>) = counts
+        .into_iter()
+        .partition(|g| too_small(g.roll_size, g.voted, min_group_size));
+
+    while !hidden.is_empty() && !shown.is_empty() {
+        let (roll_size, voted) = added_up(&hidden);
+        if !too_small(roll_size, voted, min_group_size) {
+            break;
+        }
+        let smallest = shown
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, g)| (g.voted, g.roll_size))
+            .map(|(i, _)| i)
+            .unwrap_or_default();
+        hidden.push(shown.remove(smallest));
+    }
+
+    let suppressed = (!hidden.is_empty()).then(|| {
+        let (roll_size, voted) = added_up(&hidden);
+        SuppressedGroups { groups: hidden.len(), roll_size, voted }
+    });
+
+    TurnoutBreakdown {
+        election_id,
+        dimension,
+        min_group_size,
+        groups: shown
+            .into_iter()
+            .map(|g| TurnoutGroup {
+                turnout_percent: turnout_percent(g.roll_size, g.voted),
+                group: g.group,
+                roll_size: g.roll_size,
+                voted: g.voted,
+            })
+            .collect(),
+        suppressed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn faculty(group: &str, roll_size: i64, voted: i64) -> GroupCount {
+        GroupCount { group: group.to_string(), roll_size: Some(roll_size), voted }
+    }
+
+    fn shown(b: &TurnoutBreakdown) -> Vec<&str> {
+        b.groups.iter().map(|g| g.group.as_str()).collect()
+    }
+
+    #[test]
+    fn test_small_groups_are_hidden_with_enough_company() {
+        let counts = vec![
+            faculty("Engineering", 400, 250),
+            faculty("Law", 120, 30),
+            faculty("Medicine", 200, 90),
+            faculty("Music", 4, 3),
+        ];
+
+        let b = breakdown("e1".to_string(), Dimension::Faculty, counts, 10);
+
+        // Music alone could be read off the overall turnout, so Law goes with it.
+        assert_eq!(shown(&b), vec!["Engineering", "Medicine"]);
+        assert_eq!(b.suppressed, Some(SuppressedGroups { groups: 2, roll_size: Some(124), voted: 33 }));
+        assert_eq!(b.groups[0].turnout_percent, Some(62.5));
+    }
+
+    #[test]
+    fn test_large_groups_are_all_shown() {
+        let b = breakdown("e1".to_string(), Dimension::Cohort, vec![faculty("2023", 50, 20), faculty("2024", 60, 10)], 10);
+
+        assert_eq!(shown(&b), vec!["2023", "2024"]);
+        assert_eq!(b.suppressed, None);
+    }
+
+    #[test]
+    fn test_groups_where_nearly_everyone_voted_are_hidden() {
+        let counts = vec![
+            faculty("Engineering", 400, 250),
+            faculty("Law", 120, 30),
+            faculty("Medicine", 200, 90),
+            faculty("Nursing", 60, 55),
+        ];
+
+        let b = breakdown("e1".to_string(), Dimension::Faculty, counts, 10);
+
+        // Only five nurses stayed away, so 55 of 60 would single them out. Alone, Nursing could
+        // be read off the overall turnout, so Law goes with it.
+        assert_eq!(shown(&b), vec!["Engineering", "Medicine"]);
+        assert_eq!(b.suppressed, Some(SuppressedGroups { groups: 2, roll_size: Some(180), voted: 85 }));
+    }
+
+    #[test]
+    fn test_quiet_hours_are_hidden_together() {
+        let hour = |group: &str, voted: i64| GroupCount { group: group.to_string(), roll_size: None, voted };
+        let counts = vec![hour("07:00", 6), hour("08:00", 40), hour("09:00", 5), hour("10:00", 35)];
+
+        let b = breakdown("e1".to_string(), Dimension::Hour, counts, 10);
+
+        assert_eq!(shown(&b), vec!["08:00", "10:00"]);
+        assert_eq!(b.suppressed, Some(SuppressedGroups { groups: 2, roll_size: None, voted: 11 }));
+        assert_eq!(b.groups[0].turnout_percent, None);
+    }
+}
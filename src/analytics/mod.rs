@@ -0,0 +1,4 @@
+pub mod delivery;
+pub mod domain;
+pub mod repository;
+pub mod usecase;
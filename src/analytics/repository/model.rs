@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct GroupCount {
+    pub grp: String,
+    pub roll_size: Option<i64>,
+    pub voted: i64,
+}
@@ -0,0 +1,102 @@
+use crate::analytics::domain;
+use crate::analytics::domain::{AnalyticsError, Dimension};
+use crate::analytics::domain::Repository;
+use crate::analytics::repository::model::GroupCount;
+use crate::infrastructure::database::postgres::Postgres;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+// Faculty and cohort come from the roll frozen when voting opened, so a voter's group does
+// not move with later registry edits.
+//
+// A voter counts as having voted once they have a participation or have signed in at a paper
+// station with their code. A voter holds one code per election, so the two should not overlap;
+// each voter is still counted once, in the hour of the earlier of the two.
+const ROLL_GROUPS_SQL: &str = r#"
+    WITH voted AS (
+        SELECT voter_id FROM participations WHERE election_id = $1
+        UNION
+        SELECT voter_id FROM voting_credentials
+        WHERE election_id = $1 AND station_id IS NOT NULL AND redeemed_at IS NOT NULL
+    )
+    SELECT {group}::TEXT AS grp
+        , COUNT(*)::BIGINT AS roll_size
+        , COUNT(v.voter_id)::BIGINT AS voted
+    FROM voter_rolls r
+    LEFT JOIN voted v ON v.voter_id = r.voter_id
+    WHERE r.election_id = $1
+    GROUP BY {group}
+    ORDER BY {group}
+    "#;
+
+const HOURS_SQL: &str = r#"
+    WITH voted AS (
+        SELECT voter_id, MIN(voted_at) AS voted_at
+        FROM (
+            SELECT voter_id, voted_at FROM participations WHERE election_id = $1
+            UNION ALL
+            SELECT voter_id, redeemed_at FROM voting_credentials
+            WHERE election_id = $1 AND station_id IS NOT NULL AND redeemed_at IS NOT NULL
+        ) t
+        GROUP BY voter_id
+    )
+    SELECT to_char(date_trunc('hour', v.voted_at AT TIME ZONE 'UTC'), 'YYYY-MM-DD"T"HH24:00:00"Z"') AS grp
+        , NULL::BIGINT AS roll_size
+        , COUNT(*)::BIGINT AS voted
+    FROM voted v
+    GROUP BY 1
+    ORDER BY 1
+    "#;
+
+pub struct PostgresRepo {
+    postgres: sqlx::PgPool,
+}
+impl PostgresRepo {
+    pub async fn new(postgres: Arc<Mutex<Postgres>>) -> anyhow::Result<Self> {
+        let guard = postgres.lock().await;
+        let pool = guard
+            .pool()
+            .ok_or_else(|| anyhow!("DB pool is not initialized"))?;
+        Ok(PostgresRepo { postgres: pool })
+    }
+
+    fn parse_id(id: &str) -> Result<Uuid, AnalyticsError> {
+        Uuid::parse_str(id).map_err(|_| AnalyticsError::NotFound(format!("election {} not found", id)))
+    }
+}
+
+#[async_trait]
+impl Repository for PostgresRepo {
+    async fn find_group_counts(&self, election_id: String, dimension: Dimension) -> Result<Vec<domain::GroupCount>, AnalyticsError> {
+        let uuid = Self::parse_id(&election_id)?;
+
+        let exists: bool = sqlx::query_scalar(r#"SELECT EXISTS (SELECT 1 FROM elections WHERE id = $1)"#)
+            .bind(uuid)
+            .fetch_one(&self.postgres)
+            .await
+            .map_err(|e| AnalyticsError::UnknownError(e.to_string()))?;
+        if !exists {
+            return Err(AnalyticsError::NotFound(format!("election {} not found", election_id)));
+        }
+
+        let sql = match dimension {
+            Dimension::Faculty => ROLL_GROUPS_SQL.replace("{group}", "r.faculty"),
+            Dimension::Cohort => ROLL_GROUPS_SQL.replace("{group}", "r.cohort"),
+            Dimension::Hour => HOURS_SQL.to_string(),
+        };
+
+        let counts = sqlx::query_as::<_, GroupCount>(&sql)
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| AnalyticsError::UnknownError(e.to_string()))?;
+
+        Ok(counts
+            .into_iter()
+            .map(|c| domain::GroupCount { group: c.grp, roll_size: c.roll_size, voted: c.voted })
+            .collect())
+    }
+}
@@ -0,0 +1,86 @@
+use crate::analytics::domain::{self, AnalyticsError, Dimension, Repository, TurnoutBreakdown};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<TurnoutBreakdown, AnalyticsError>;
+}
+
+/// Turnout of an election split by faculty, cohort or hour, with small groups hidden.
+pub struct BreakdownUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    min_group_size: i64,
+}
+
+#[derive(Debug)]
+pub struct Request {
+    pub election_id: String,
+    pub dimension: String,
+}
+
+impl<R: ?Sized + Send + Sync> BreakdownUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, min_group_size: i64) -> Self {
+        Self { repository, min_group_size }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for BreakdownUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<TurnoutBreakdown, AnalyticsError> {
+        let dimension = Dimension::parse(&req.dimension).ok_or_else(|| {
+            AnalyticsError::InvalidInput(format!("turnout cannot be broken down by {}; use faculty, cohort or hour", req.dimension))
+        })?;
+
+        let counts = self.repository.find_group_counts(req.election_id.clone(), dimension).await?;
+
+        Ok(domain::breakdown(req.election_id, dimension, counts, self.min_group_size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analytics::domain::{self, AnalyticsError, Dimension, GroupCount};
+    use crate::analytics::usecase::breakdown;
+    use crate::analytics::usecase::breakdown::Interactor;
+    use std::sync::Arc;
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_breakdown_hides_groups_below_the_configured_size() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_group_counts()
+            .times(1)
+            .withf(|id, dimension| id == "e1" && *dimension == Dimension::Cohort)
+            .returning(|_, _| Ok(vec![
+                GroupCount { group: "2022".to_string(), roll_size: Some(30), voted: 24 },
+                GroupCount { group: "2023".to_string(), roll_size: Some(40), voted: 22 },
+                GroupCount { group: "2024".to_string(), roll_size: Some(45), voted: 21 },
+            ]));
+
+        let uc = breakdown::BreakdownUseCase::new(Arc::new(repo_mock), 25);
+        let result = uc.handle(breakdown::Request { election_id: "e1".to_string(), dimension: "cohort".to_string() }).await.unwrap();
+
+        assert!(result.groups.is_empty());
+        assert_eq!(result.suppressed.map(|s| (s.groups, s.voted)), Some((3, 67)));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_unknown_dimension_is_rejected() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_group_counts().never();
+
+        let uc = breakdown::BreakdownUseCase::new(Arc::new(repo_mock), 10);
+        let result = uc.handle(breakdown::Request { election_id: "e1".to_string(), dimension: "name".to_string() }).await;
+
+        assert!(matches!(result, Err(AnalyticsError::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,22 @@
+use std::sync::Arc;
+use crate::analytics::domain::Repository;
+use crate::analytics::usecase::breakdown;
+use crate::analytics::usecase::breakdown::BreakdownUseCase;
+
+
+#[derive(Clone)]
+pub struct UseCase
+{
+    pub breakdown: Arc<dyn breakdown::Interactor>,
+}
+
+impl UseCase {
+
+    pub fn new(analytics_repo: Arc<dyn Repository + Send + Sync>, min_group_size: i64) -> Self {
+
+        Self {
+            breakdown: Arc::new(BreakdownUseCase::new(analytics_repo, min_group_size)),
+        }
+    }
+
+}
@@ -0,0 +1,4 @@
+mod init;
+pub mod breakdown;
+
+pub use init::UseCase;
@@ -4,6 +4,19 @@ use std::sync::Arc;
 use tokio::signal;
 use tokio::sync::{oneshot, Mutex};
 use crate::candidate;
+use crate::credential;
+use crate::election;
+use crate::group;
+use crate::paper;
+use crate::privacy;
+use crate::tally;
+use crate::embargo;
+use crate::analytics;
+use crate::rla;
+use crate::vote;
+use crate::voter;
+use crate::infrastructure::batch;
+use crate::infrastructure::crypto;
 use crate::infrastructure::database::postgres;
 use crate::infrastructure::http::server;
 use crate::infrastructure::config;
@@ -44,6 +57,47 @@ pub async fn run() {
 
     let mut postgres_conn = postgres::Postgres::new(postgres_config);
 
+    let pii_config = crypto::PiiCipherConfig {
+        active_key_id: cfg.pii.active_key_id.clone(),
+        keys: cfg.pii.keys.clone(),
+        blind_index_key: cfg.pii.blind_index_key.clone(),
+    };
+
+    let pii_cipher = match crypto::PiiCipher::from_config(&pii_config) {
+        Ok(cipher) => Arc::new(cipher),
+        Err(e) => panic!("Failed to load PII encryption keys: {}", e),
+    };
+
+    let signer_config = crypto::SignerConfig {
+        active_key_id: cfg.tally.active_key_id.clone(),
+        keys: cfg.tally.keys.clone(),
+    };
+
+    let signer = match crypto::Signer::from_config(&signer_config) {
+        Ok(signer) => Arc::new(signer),
+        Err(e) => panic!("Failed to load tally signing keys: {}", e),
+    };
+
+    let certifier_config = crypto::CertifierConfig {
+        active_key_id: cfg.certificate.active_key_id.clone(),
+        keys: cfg.certificate.keys.clone(),
+    };
+
+    let certifier = match crypto::Certifier::from_config(&certifier_config) {
+        Ok(certifier) => Arc::new(certifier),
+        Err(e) => panic!("Failed to load certificate signing keys: {}", e),
+    };
+
+    let pepper = match crypto::Pepper::from_base64(&cfg.seal.key) {
+        Ok(pepper) => Arc::new(pepper),
+        Err(e) => panic!("Failed to load seal key: {}", e),
+    };
+
+    let staff_roster = match embargo::domain::StaffRoster::parse(&cfg.embargo.staff) {
+        Ok(roster) => Arc::new(roster),
+        Err(e) => panic!("Failed to load embargo staff roster: {}", e),
+    };
+
     println!("Trying to run database instance...");
     match postgres_conn.connect().await {
         Ok(_) => println!("Successfully connected to database..."),
@@ -51,10 +105,10 @@ pub async fn run() {
     }
 
     let postgres_arc = Arc::new(Mutex::new(postgres_conn));
-    let mut postgres_for_shutdown = Arc::clone(&postgres_arc);
+    let postgres_for_shutdown = Arc::clone(&postgres_arc);
 
     //candidate repo
-    let candidate_repo = match candidate::repository::PostgresRepo::new(postgres_arc).await {
+    let candidate_repo = match candidate::repository::PostgresRepo::new(postgres_arc.clone()).await {
         Ok(repo) => repo,
         Err(e) => panic!("Database connection failed to establish: {}", e),
     };
@@ -64,9 +118,166 @@ pub async fn run() {
     // candidate usecase
     let candidate_uc = candidate::usecase::UseCase::new(candidate_repo_arc);
 
-    let app_data = app::AppHandlerData { candidate_uc };
+    //election repo
+    let election_repo = match election::repository::PostgresRepo::new(postgres_arc.clone()).await {
+        Ok(repo) => repo,
+        Err(e) => panic!("Database connection failed to establish: {}", e),
+    };
+
+    let election_repo_arc = Arc::new(election_repo);
+
+    // election usecase
+    let election_uc = election::usecase::UseCase::new(election_repo_arc.clone(), staff_roster.clone());
+
+    //voter repo
+    let voter_repo = match voter::repository::PostgresRepo::new(postgres_arc.clone(), pii_cipher.clone()).await {
+        Ok(repo) => repo,
+        Err(e) => panic!("Database connection failed to establish: {}", e),
+    };
+
+    // voter usecase
+    let voter_uc = voter::usecase::UseCase::new(Arc::new(voter_repo), staff_roster.clone());
+
+    //credential repo
+    let credential_repo = match credential::repository::PostgresRepo::new(postgres_arc.clone(), pii_cipher.clone()).await {
+        Ok(repo) => repo,
+        Err(e) => panic!("Database connection failed to establish: {}", e),
+    };
+
+    let credential_repo_arc = Arc::new(credential_repo);
+
+    // credential usecase
+    let credential_uc = credential::usecase::UseCase::new(
+        credential_repo_arc.clone(),
+        election_repo_arc.clone(),
+        staff_roster.clone(),
+        chrono::Duration::seconds(cfg.voting.session_ttl_secs),
+    );
+
+    //privacy repo
+    let privacy_repo = match privacy::repository::PostgresRepo::new(postgres_arc.clone(), pii_cipher.clone()).await {
+        Ok(repo) => repo,
+        Err(e) => panic!("Database connection failed to establish: {}", e),
+    };
+
+    // privacy usecase
+    let privacy_uc = privacy::usecase::UseCase::new(
+        Arc::new(privacy_repo),
+        staff_roster.clone(),
+        chrono::Duration::days(cfg.privacy.retention_days),
+    );
+
+    //group repo
+    let group_repo = match group::repository::PostgresRepo::new(postgres_arc.clone(), pii_cipher).await {
+        Ok(repo) => repo,
+        Err(e) => panic!("Database connection failed to establish: {}", e),
+    };
+
+    // group usecase
+    let group_uc = group::usecase::UseCase::new(Arc::new(group_repo), staff_roster.clone());
+
+    //paper repo
+    let paper_repo = match paper::repository::PostgresRepo::new(postgres_arc.clone()).await {
+        Ok(repo) => repo,
+        Err(e) => panic!("Database connection failed to establish: {}", e),
+    };
+
+    // paper usecase
+    let paper_uc = paper::usecase::UseCase::new(Arc::new(paper_repo), election_repo_arc.clone(), staff_roster.clone());
+
+    //embargo repo
+    let embargo_repo = match embargo::repository::PostgresRepo::new(postgres_arc.clone()).await {
+        Ok(repo) => repo,
+        Err(e) => panic!("Database connection failed to establish: {}", e),
+    };
+
+    // embargo usecase
+    let embargo_uc = embargo::usecase::UseCase::new(
+        Arc::new(embargo_repo),
+        election_repo_arc.clone(),
+        staff_roster.clone(),
+        chrono::Duration::seconds(cfg.embargo.override_ttl_secs),
+    );
+
+    //tally repo
+    let tally_repo = match tally::repository::PostgresRepo::new(postgres_arc.clone()).await {
+        Ok(repo) => repo,
+        Err(e) => panic!("Database connection failed to establish: {}", e),
+    };
+
+    // tally usecase
+    let tally_uc = tally::usecase::UseCase::new(
+        Arc::new(tally_repo),
+        embargo_uc.authorize.clone(),
+        signer,
+        certifier,
+        std::time::Duration::from_millis(cfg.live.debounce_ms),
+        std::time::Duration::from_secs(cfg.live.keepalive_secs),
+    );
+
+    if cfg.counters.reconcile_secs > 0 {
+        tally::usecase::reconcile::spawn(
+            tally_uc.reconcile.clone(),
+            std::time::Duration::from_secs(cfg.counters.reconcile_secs),
+        );
+    }
+
+    //analytics repo
+    let analytics_repo = match analytics::repository::PostgresRepo::new(postgres_arc.clone()).await {
+        Ok(repo) => repo,
+        Err(e) => panic!("Database connection failed to establish: {}", e),
+    };
+
+    // analytics usecase
+    let analytics_uc = analytics::usecase::UseCase::new(Arc::new(analytics_repo), cfg.analytics.min_group_size);
+
+    //rla repo
+    let rla_repo = match rla::repository::PostgresRepo::new(postgres_arc.clone()).await {
+        Ok(repo) => repo,
+        Err(e) => panic!("Database connection failed to establish: {}", e),
+    };
+
+    // rla usecase
+    let rla_uc = rla::usecase::UseCase::new(Arc::new(rla_repo), embargo_uc.authorize.clone());
+
+    //vote repo
+    let vote_batch = batch::BatchConfig {
+        max_batch: cfg.voting.write_batch_max,
+        queue_capacity: cfg.voting.write_queue_capacity,
+        hold: std::time::Duration::ZERO,
+    };
+    let ballot_mix = std::time::Duration::from_millis(cfg.voting.ballot_mix_ms);
+
+    let vote_repo = match vote::repository::PostgresRepo::new(postgres_arc, vote_batch, ballot_mix).await {
+        Ok(repo) => repo,
+        Err(e) => panic!("Database connection failed to establish: {}", e),
+    };
+
+    // vote usecase
+    let vote_uc = vote::usecase::UseCase::new(
+        Arc::new(vote_repo),
+        credential_repo_arc,
+        election_repo_arc,
+        chrono::Duration::seconds(cfg.voting.review_ttl_secs),
+        chrono::Duration::seconds(cfg.voting.close_grace_secs),
+        pepper,
+        staff_roster,
+    );
+
+    let app_data = app::AppHandlerData { candidate_uc, election_uc, voter_uc, credential_uc, privacy_uc, group_uc, vote_uc, paper_uc, tally_uc, embargo_uc, analytics_uc, rla_uc };
 
     server.add_routers(candidate::delivery::http::routes);
+    server.add_routers(election::delivery::http::routes);
+    server.add_routers(voter::delivery::http::routes);
+    server.add_routers(credential::delivery::http::routes);
+    server.add_routers(privacy::delivery::http::routes);
+    server.add_routers(group::delivery::http::routes);
+    server.add_routers(vote::delivery::http::routes);
+    server.add_routers(paper::delivery::http::routes);
+    server.add_routers(tally::delivery::http::routes);
+    server.add_routers(embargo::delivery::http::routes);
+    server.add_routers(analytics::delivery::http::routes);
+    server.add_routers(rla::delivery::http::routes);
     let mut server = server; // keep `server` as owned value
 
     // Create a oneshot channel to signal shutdown
@@ -0,0 +1,271 @@
+//! Sustained ballot write throughput against a local database.
+//!
+//! ```text
+//! cargo run --release --bin cast_bench -- --ballots 20000 --concurrency 500
+//! ```
+//!
+//! Reads the same `.env` as the service. Seeds a throwaway election with one contest, two
+//! candidates and a voter per ballot, then has `--concurrency` clients cast through the
+//! vote repository the way the cast usecase does: participation first, ballot second. A
+//! cast turned away with `Busy` is retried after a short pause, like a client honouring
+//! `Retry-After`. Run it against a scratch database: frozen rolls and ballots are
+//! append-only, so seeded rows are left behind.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use vote_svc::infrastructure::batch::BatchConfig;
+use vote_svc::infrastructure::config::AppConfig;
+use vote_svc::infrastructure::database::postgres::{Postgres, PostgresConfig};
+use vote_svc::vote;
+use vote_svc::vote::domain::{Choice, NewBallot, Participation, Repository, VoteError};
+
+struct Args {
+    ballots: usize,
+    concurrency: usize,
+    batch_max: usize,
+    queue_capacity: usize,
+    mix_ms: usize,
+    max_conn: u32,
+}
+
+impl Args {
+    fn parse(cfg: &AppConfig) -> Args {
+        let mut args = Args {
+            ballots: 20_000,
+            concurrency: 500,
+            batch_max: cfg.voting.write_batch_max,
+            queue_capacity: cfg.voting.write_queue_capacity,
+            mix_ms: cfg.voting.ballot_mix_ms as usize,
+            max_conn: cfg.database.max_conn,
+        };
+
+        let raw: Vec<String> = std::env::args().skip(1).collect();
+        for pair in raw.chunks(2) {
+            let value = || pair.get(1).and_then(|v| v.parse::<usize>().ok()).unwrap_or_else(|| usage());
+            match pair[0].as_str() {
+                "--ballots" => args.ballots = value(),
+                "--concurrency" => args.concurrency = value().max(1),
+                "--batch-max" => args.batch_max = value(),
+                "--queue-capacity" => args.queue_capacity = value(),
+                "--mix-ms" => args.mix_ms = value(),
+                "--max-conn" => args.max_conn = value() as u32,
+                _ => usage(),
+            }
+        }
+        args
+    }
+}
+
+fn usage() -> ! {
+    eprintln!(
+        "usage: cast_bench [--ballots N] [--concurrency N] [--batch-max N] [--queue-capacity N] [--mix-ms N] [--max-conn N]\n\
+         --batch-max 1 writes every cast in its own transaction, for comparison."
+    );
+    std::process::exit(2);
+}
+
+struct Seed {
+    election_id: String,
+    contest_id: String,
+    candidate_ids: Vec<String>,
+    voter_ids: Vec<String>,
+}
+
+async fn seed(pool: &sqlx::PgPool, voters: usize) -> Result<Seed, sqlx::Error> {
+    let election_id: Uuid = sqlx::query_scalar(
+        r#"
+        INSERT INTO elections (id, name, phase, voting_starts_at)
+        VALUES (gen_random_uuid(), 'cast_bench ' || now(), 'voting', now())
+        RETURNING id
+        "#,
+    )
+        .fetch_one(pool)
+        .await?;
+
+    let contest_id: Uuid = sqlx::query_scalar(
+        r#"INSERT INTO contests (election_id, name) VALUES ($1, 'President') RETURNING id"#,
+    )
+        .bind(election_id)
+        .fetch_one(pool)
+        .await?;
+
+    let candidate_ids: Vec<Uuid> = sqlx::query_scalar(
+        r#"
+        INSERT INTO candidates (id, vote_number, president_name, vice_president_name, president_nim,
+            vice_president_nim, president_photo, vice_president_photo, status, created_by, created_at, contest_id)
+        SELECT gen_random_uuid(), n, 'Bench ' || n, 'Bench ' || n, '', '', '', '', true, 'cast_bench', now(), $1
+        FROM generate_series(1, 2) AS n
+        RETURNING id
+        "#,
+    )
+        .bind(contest_id)
+        .fetch_all(pool)
+        .await?;
+
+    // Voters only need to exist; their identity columns are never read here.
+    let voter_ids: Vec<Uuid> = sqlx::query_scalar(
+        r#"
+        INSERT INTO voters (id, nim, name, email, faculty, cohort)
+        SELECT gen_random_uuid(), 'cast_bench', 'cast_bench', 'cast_bench', 'Bench', 2026
+        FROM generate_series(1, $1)
+        RETURNING id
+        "#,
+    )
+        .bind(voters as i32)
+        .fetch_all(pool)
+        .await?;
+
+    Ok(Seed {
+        election_id: election_id.to_string(),
+        contest_id: contest_id.to_string(),
+        candidate_ids: candidate_ids.iter().map(Uuid::to_string).collect(),
+        voter_ids: voter_ids.iter().map(Uuid::to_string).collect(),
+    })
+}
+
+/// One cast as the usecase performs it, retried while the write queue is full.
+async fn cast(repo: &vote::repository::PostgresRepo, seed: &Seed, n: usize, busy: &mut u64) -> Result<(), VoteError> {
+    let now = chrono::Utc::now();
+    let choices = vec![Choice::candidate(&seed.contest_id, &seed.candidate_ids[n % seed.candidate_ids.len()])];
+    let nonce = vote::domain::generate_receipt_nonce();
+    let receipt = vote::domain::receipt_code(&nonce, &seed.election_id, false, &choices);
+    let ballot = NewBallot {
+        id: vote::domain::generate_ballot_id(),
+        election_id: seed.election_id.clone(),
+        blank: false,
+        choices,
+        cast_at: vote::domain::coarsen_cast_time(now),
+        receipt_nonce: nonce,
+        receipt,
+        revote_tag: None,
+    };
+    let participation = Participation {
+        election_id: seed.election_id.clone(),
+        voter_id: seed.voter_ids[n].clone(),
+        voted_at: now,
+        idempotency: None,
+        revote: false,
+    };
+
+    loop {
+        match repo.record_participation(participation.clone()).await {
+            Err(VoteError::Busy(_)) => {
+                *busy += 1;
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+            other => {
+                other?;
+                break;
+            }
+        }
+    }
+    loop {
+        match repo.store_ballot(ballot.clone()).await {
+            Err(VoteError::Busy(_)) => {
+                *busy += 1;
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+            other => return other,
+        }
+    }
+}
+
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let index = ((sorted.len() as f64 - 1.0) * p).round() as usize;
+    sorted[index]
+}
+
+#[tokio::main]
+async fn main() {
+    dotenvy::dotenv().ok();
+    if let Err(e) = AppConfig::init() {
+        panic!("Failed to load configuration: {}", e);
+    }
+    let cfg = AppConfig::global();
+    let args = Args::parse(cfg);
+
+    let mut postgres = Postgres::new(PostgresConfig {
+        address: cfg.database.addr.clone(),
+        port: cfg.database.port,
+        dbname: cfg.database.name.clone(),
+        username: cfg.database.username.clone(),
+        password: cfg.database.password.clone(),
+        max_conn: args.max_conn,
+        min_conn: cfg.database.min_conn.min(args.max_conn),
+    });
+    if let Err(e) = postgres.connect().await {
+        panic!("Database connection failed to establish: {}", e);
+    }
+    let pool = postgres.pool().expect("pool is connected");
+
+    println!("Seeding {} voters...", args.ballots);
+    let seed = match seed(&pool, args.ballots).await {
+        Ok(seed) => Arc::new(seed),
+        Err(e) => panic!("Failed to seed the benchmark election: {}", e),
+    };
+
+    let batch = BatchConfig { max_batch: args.batch_max, queue_capacity: args.queue_capacity, hold: Duration::ZERO };
+    let mix = Duration::from_millis(args.mix_ms as u64);
+    let repo = match vote::repository::PostgresRepo::new(Arc::new(Mutex::new(postgres)), batch, mix).await {
+        Ok(repo) => Arc::new(repo),
+        Err(e) => panic!("Failed to set up the vote repository: {}", e),
+    };
+
+    println!(
+        "Casting {} ballots from {} clients (batch max {}, queue {}, mix {}ms, {} connections)...",
+        args.ballots, args.concurrency, args.batch_max, args.queue_capacity, args.mix_ms, args.max_conn
+    );
+
+    let started = Instant::now();
+    let clients: Vec<_> = (0..args.concurrency)
+        .map(|client| {
+            let (repo, seed) = (repo.clone(), seed.clone());
+            let (ballots, concurrency) = (args.ballots, args.concurrency);
+            tokio::spawn(async move {
+                let mut latencies = vec![];
+                let (mut busy, mut failed) = (0u64, 0u64);
+                for n in (client..ballots).step_by(concurrency) {
+                    let at = Instant::now();
+                    match cast(&repo, &seed, n, &mut busy).await {
+                        Ok(()) => latencies.push(at.elapsed()),
+                        Err(e) => {
+                            failed += 1;
+                            eprintln!("cast {} failed: {}", n, e);
+                        }
+                    }
+                }
+                (latencies, busy, failed)
+            })
+        })
+        .collect();
+
+    let mut latencies = Vec::with_capacity(args.ballots);
+    let (mut busy, mut failed) = (0u64, 0u64);
+    for client in clients {
+        let (l, b, f) = client.await.expect("client task panicked");
+        latencies.extend(l);
+        busy += b;
+        failed += f;
+    }
+    let elapsed = started.elapsed();
+    latencies.sort();
+
+    println!("Election:        {}", seed.election_id);
+    println!("Ballots stored:  {}", latencies.len());
+    println!("Failed:          {}", failed);
+    println!("Busy retries:    {}", busy);
+    println!("Elapsed:         {:.2?}", elapsed);
+    println!("Throughput:      {:.0} ballots/s", latencies.len() as f64 / elapsed.as_secs_f64());
+    println!(
+        "Latency:         p50 {:.2?}, p95 {:.2?}, p99 {:.2?}, max {:.2?}",
+        percentile(&latencies, 0.50),
+        percentile(&latencies, 0.95),
+        percentile(&latencies, 0.99),
+        latencies.last().copied().unwrap_or_default(),
+    );
+}
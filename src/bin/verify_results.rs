@@ -0,0 +1,100 @@
+//! Offline check of a results certificate.
+//!
+//! ```text
+//! cargo run --release --bin verify_results -- results-<id>.certificate.json --keys certificate-keys.json
+//! cargo run --release --bin verify_results -- results-<id>.certificate.json --public-key <base64url>
+//! ```
+//!
+//! `--keys` takes the saved response of `GET /certificate-keys`, and the key the certificate
+//! names is picked from it; `--public-key` takes one key as published. Nothing is fetched and
+//! no configuration is read, so the check needs neither the service nor its database. Exits 0
+//! when the certificate verifies and 1 when it does not.
+
+use serde_json::Value;
+use std::process::ExitCode;
+use vote_svc::infrastructure::crypto::PublicKey;
+use vote_svc::tally::domain::verify_certificate;
+
+enum KeySource {
+    File(String),
+    Key(String),
+}
+
+fn usage() -> ! {
+    eprintln!("usage: verify_results <certificate.json> (--keys <certificate-keys.json> | --public-key <base64url>)");
+    std::process::exit(2);
+}
+
+fn parse_args() -> (String, KeySource) {
+    let raw: Vec<String> = std::env::args().skip(1).collect();
+    let mut certificate = None;
+    let mut keys = None;
+
+    let mut args = raw.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--keys" => keys = Some(KeySource::File(args.next().unwrap_or_else(|| usage()))),
+            "--public-key" => keys = Some(KeySource::Key(args.next().unwrap_or_else(|| usage()))),
+            _ if arg.starts_with("--") || certificate.is_some() => usage(),
+            _ => certificate = Some(arg),
+        }
+    }
+
+    match (certificate, keys) {
+        (Some(certificate), Some(keys)) => (certificate, keys),
+        _ => usage(),
+    }
+}
+
+fn read_json(path: &str) -> Result<Value, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("cannot read {}: {}", path, e))?;
+    serde_json::from_str(&text).map_err(|e| format!("{} is not JSON: {}", path, e))
+}
+
+/// The saved key list may still be in its response envelope.
+fn public_key(source: KeySource, key_id: &str) -> Result<String, String> {
+    let path = match source {
+        KeySource::Key(key) => return Ok(key),
+        KeySource::File(path) => path,
+    };
+    let document = read_json(&path)?;
+    let list = document.get("data").cloned().unwrap_or(document);
+    let keys: Vec<PublicKey> = serde_json::from_value(list).map_err(|e| format!("{} is not a key list: {}", path, e))?;
+
+    keys.into_iter()
+        .find(|k| k.key_id == key_id)
+        .map(|k| k.public_key)
+        .ok_or_else(|| format!("{} has no key {}", path, key_id))
+}
+
+fn run() -> Result<String, String> {
+    let (path, keys) = parse_args();
+    let document = read_json(&path)?;
+    let payload = document.get("payload").cloned().unwrap_or(Value::Null);
+    let field = |pointer: &str| payload.pointer(pointer).and_then(Value::as_str).unwrap_or("?").to_string();
+
+    let key_id = field("/key_id");
+    let key = public_key(keys, &key_id)?;
+    verify_certificate(&document, &key).map_err(|e| format!("{} does not verify: {}", path, e))?;
+
+    Ok(format!(
+        "OK: {} results of {} (election {}), signed with key {}",
+        field("/results/status"),
+        field("/results/election_name"),
+        field("/election_id"),
+        key_id,
+    ))
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(summary) => {
+            println!("{}", summary);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("FAILED: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
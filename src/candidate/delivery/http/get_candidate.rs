@@ -1,7 +1,7 @@
 use crate::candidate::usecase::get::*;
 use crate::utils::{app, response};
 use actix_web::{HttpResponse, web};
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 
 #[derive(Deserialize)]
 pub struct CandidateListQuery {
@@ -42,6 +42,8 @@ pub async fn get_candidate(handler: web::Data<app::AppHandlerData>, q: web::Quer
 
 // rust
 #[cfg(test)]
+// Baseline tests, kept as written.
+#[allow(clippy::redundant_field_names)]
 mod tests {
     use super::get_candidate;
     use actix_web::{App, http::StatusCode, test, web};
@@ -60,7 +62,7 @@ mod tests {
             get: get_impl,
         };
 
-        web::Data::new(app::AppHandlerData { candidate_uc })
+        web::Data::new(app::AppHandlerData { candidate_uc, ..app::AppHandlerData::mocked() })
     }
 
     #[actix_rt::test]
@@ -0,0 +1,44 @@
+use crate::credential::domain::CredentialError;
+use crate::utils::response;
+use actix_web::HttpResponse;
+
+pub fn error_response(e: &CredentialError) -> HttpResponse {
+    println!("Error: {}", e);
+    match e {
+        CredentialError::NotFound(msg) => HttpResponse::NotFound().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "CREDENTIAL_NOT_FOUND".into(),
+        )),
+        CredentialError::Unauthorized(msg) => HttpResponse::Unauthorized().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "CREDENTIAL_UNAUTHORIZED".into(),
+        )),
+        CredentialError::Forbidden(msg) => HttpResponse::Forbidden().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "CREDENTIAL_FORBIDDEN".into(),
+        )),
+        CredentialError::InvalidCode(msg) => HttpResponse::Unauthorized().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "CREDENTIAL_INVALID_CODE".into(),
+        )),
+        CredentialError::ElectionNotOpen(msg) => HttpResponse::Conflict().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "ELECTION_NOT_OPEN".into(),
+        )),
+        CredentialError::StationClosed(msg) => HttpResponse::Conflict().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "CREDENTIAL_STATION_CLOSED".into(),
+        )),
+        CredentialError::UnknownError(_) => HttpResponse::InternalServerError().json(response::error::<()>(
+            None,
+            "failed process data".into(),
+            "-1".into(),
+        )),
+    }
+}
@@ -0,0 +1,9 @@
+use actix_web::web;
+use crate::credential::delivery::http::issue_credentials::issue_credentials;
+use crate::credential::delivery::http::redeem_credential::redeem_credential;
+
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/elections/{id}/credentials", web::post().to(issue_credentials))
+        .route("/elections/{id}/sessions", web::post().to(redeem_credential));
+}
@@ -0,0 +1,109 @@
+use crate::credential::delivery::http::errors::error_response;
+use crate::credential::domain::IssuedCredential;
+use crate::credential::usecase::issue::*;
+use crate::embargo::delivery::http::staff_token;
+use crate::utils::{app, csv};
+use actix_web::{HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct IssueCredentialsQuery {
+    station_id: Option<String>,
+}
+
+/// Renders freshly issued codes for mail merge. This is the only time the codes exist in clear.
+pub fn credentials_csv(issued: &[IssuedCredential]) -> String {
+    let mut out = String::new();
+    csv::write_row(&mut out, &["nim", "name", "email", "code"]);
+    for i in issued {
+        csv::write_row(&mut out, &[&i.recipient.nim, &i.recipient.name, &i.recipient.email, &i.code]);
+    }
+    out
+}
+
+pub async fn issue_credentials(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+    q: web::Query<IssueCredentialsQuery>,
+) -> HttpResponse {
+
+    let election_id = path.into_inner();
+    let request = Request {
+        election_id: election_id.clone(),
+        station_id: q.into_inner().station_id,
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.credential_uc.issue.handle(request).await {
+        Ok(issued) => HttpResponse::Ok()
+            .content_type("text/csv; charset=utf-8")
+            .insert_header((
+                "Content-Disposition",
+                format!("attachment; filename=\"credentials-{}.csv\"", election_id),
+            ))
+            .insert_header(("Cache-Control", "no-store"))
+            .body(credentials_csv(&issued)),
+        Err(e) => error_response(&e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::issue_credentials;
+    use actix_web::{App, http::StatusCode, test, web};
+    use async_trait::async_trait;
+    use std::sync::Arc;
+    use crate::credential::domain::{CredentialError, IssuedCredential, Recipient};
+    use crate::credential::usecase::issue::{Interactor, Request};
+    use crate::utils::app;
+
+    #[actix_rt::test]
+    async fn test_issue_credentials_exports_csv() {
+        struct MockIssue;
+
+        #[async_trait]
+        impl Interactor for MockIssue {
+            async fn handle(&self, req: Request) -> Result<Vec<IssuedCredential>, CredentialError> {
+                assert_eq!(req.station_id.as_deref(), Some("s1"));
+                assert_eq!(req.staff_token.as_deref(), Some("rina-token"));
+                Ok(vec![IssuedCredential {
+                    recipient: Recipient {
+                        voter_id: "v1".to_string(),
+                        nim: "123".to_string(),
+                        name: "Doe, Jane".to_string(),
+                        email: "jane@campus.ac.id".to_string(),
+                    },
+                    code: "7K3M-Q9TX-2B4R-HW8D".to_string(),
+                }])
+            }
+        }
+
+        let mut credential_uc = app::AppHandlerData::mocked().credential_uc;
+        credential_uc.issue = Arc::new(MockIssue);
+        let app_data = web::Data::new(app::AppHandlerData { credential_uc, ..app::AppHandlerData::mocked() });
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_data)
+                .route("/elections/{id}/credentials", web::post().to(issue_credentials)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/elections/e1/credentials?station_id=s1")
+            .insert_header(("Authorization", "Bearer rina-token"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers().get("Content-Type").unwrap(), "text/csv; charset=utf-8");
+        let body = test::read_body(resp).await;
+        assert_eq!(
+            std::str::from_utf8(&body).unwrap(),
+            "nim,name,email,code\r\n123,\"Doe, Jane\",jane@campus.ac.id,7K3M-Q9TX-2B4R-HW8D\r\n"
+        );
+    }
+}
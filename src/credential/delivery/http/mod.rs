@@ -0,0 +1,8 @@
+mod handler;
+mod errors;
+mod issue_credentials;
+mod redeem_credential;
+
+pub use issue_credentials::*;
+pub use redeem_credential::*;
+pub use handler::*;
@@ -0,0 +1,34 @@
+use crate::credential::delivery::http::errors::error_response;
+use crate::credential::usecase::redeem::*;
+use crate::utils::{app, response};
+use actix_web::{HttpResponse, web};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct RedeemCredentialBody {
+    code: String,
+}
+
+pub async fn redeem_credential(
+    handler: web::Data<app::AppHandlerData>,
+    path: web::Path<String>,
+    body: web::Json<RedeemCredentialBody>,
+) -> HttpResponse {
+
+    let request = Request {
+        election_id: path.into_inner(),
+        code: body.into_inner().code,
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.credential_uc.redeem.handle(request).await {
+        Ok(session) => HttpResponse::Ok()
+            .insert_header(("Cache-Control", "no-store"))
+            .json(response::success(
+                Some(session),
+                "Successfully processed voting session".into(),
+            )),
+        Err(e) => error_response(&e),
+    }
+}
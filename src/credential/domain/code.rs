@@ -0,0 +1,111 @@
+use crate::credential::domain::errors::CredentialError;
+use crate::infrastructure::crypto;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+/// Crockford base32: no I, L, O or U, so printed codes survive being read aloud or retyped.
+const ALPHABET: &[u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const CODE_LEN: usize = 16;
+const GROUP_LEN: usize = 4;
+const TOKEN_BYTES: usize = 32;
+const REVOTE_TAG_PURPOSE: &str = "session-revote-tag-v1";
+
+/// Generates a printable voting code with 80 bits of entropy, e.g. `7K3M-Q9TX-2B4R-HW8D`.
+pub fn generate_code() -> String {
+    let mut rng = rand::rng();
+    let mut code = String::with_capacity(CODE_LEN + CODE_LEN / GROUP_LEN);
+
+    for i in 0..CODE_LEN {
+        if i > 0 && i % GROUP_LEN == 0 {
+            code.push('-');
+        }
+        code.push(ALPHABET[rng.random_range(0..ALPHABET.len())] as char);
+    }
+
+    code
+}
+
+/// Upper-cases the code, drops separators and maps look-alike letters onto their digits.
+pub fn normalize_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .map(|c| match c.to_ascii_uppercase() {
+            'O' => '0',
+            'I' | 'L' => '1',
+            other => other,
+        })
+        .collect()
+}
+
+pub fn hash_code(code: &str) -> String {
+    sha256_hex(&normalize_code(code))
+}
+
+/// Generates an opaque bearer token for a voting session.
+pub fn generate_session_token() -> String {
+    let mut bytes = [0u8; TOKEN_BYTES];
+    rand::rng().fill(&mut bytes[..]);
+    hex::encode(bytes)
+}
+
+pub fn hash_session_token(token: &str) -> String {
+    sha256_hex(token)
+}
+
+/// Same for every ballot cast with one code, so a re-vote can replace the earlier ballot.
+/// Derived from the code itself, which the server never stores, so the tag cannot be
+/// traced back to the voter the code was issued to.
+pub fn revote_tag(election_id: &str, code: &str) -> String {
+    sha256_hex(&format!("revote-tag-v1\0{}\0{}", election_id, normalize_code(code)))
+}
+
+/// Seals the re-vote tag onto a session under its bearer token, which is only stored hashed.
+pub fn seal_revote_tag(token: &str, tag: &str) -> Result<String, CredentialError> {
+    crypto::seal_with_secret(REVOTE_TAG_PURPOSE, token, tag.as_bytes())
+        .map_err(|e| CredentialError::UnknownError(e.to_string()))
+}
+
+pub fn open_revote_tag(token: &str, sealed: &str) -> Result<String, CredentialError> {
+    crypto::open_with_secret(REVOTE_TAG_PURPOSE, token, sealed)
+        .map_err(|e| CredentialError::UnknownError(e.to_string()))
+        .and_then(|tag| String::from_utf8(tag).map_err(|e| CredentialError::UnknownError(e.to_string())))
+}
+
+fn sha256_hex(value: &str) -> String {
+    hex::encode(Sha256::digest(value.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_code_shape() {
+        let code = generate_code();
+
+        assert_eq!(code.len(), 19);
+        assert_eq!(code.split('-').count(), 4);
+        assert!(code.chars().all(|c| c == '-' || ALPHABET.contains(&(c as u8))));
+        assert_ne!(code, generate_code());
+    }
+
+    #[test]
+    fn test_hash_ignores_formatting() {
+        assert_eq!(hash_code("7K3M-Q9TX-2B4R-HW8D"), hash_code("7k3m q9tx 2b4r hw8d"));
+        assert_eq!(hash_code("0O1I-L000-0000-0000"), hash_code("0011-1000-0000-0000"));
+        assert_ne!(hash_code("7K3M-Q9TX-2B4R-HW8D"), hash_code("7K3M-Q9TX-2B4R-HW8E"));
+    }
+
+    #[test]
+    fn test_revote_tag_is_per_code_and_unrelated_to_its_hash() {
+        let tag = revote_tag("e1", "7K3M-Q9TX-2B4R-HW8D");
+
+        assert_eq!(tag, revote_tag("e1", "7k3m q9tx 2b4r hw8d"));
+        assert_ne!(tag, revote_tag("e2", "7K3M-Q9TX-2B4R-HW8D"));
+        assert_ne!(tag, hash_code("7K3M-Q9TX-2B4R-HW8D"));
+
+        let sealed = seal_revote_tag("token", &tag).unwrap();
+        assert_eq!(open_revote_tag("token", &sealed).unwrap(), tag);
+        assert!(open_revote_tag("other", &sealed).is_err());
+    }
+}
@@ -0,0 +1,56 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+
+/// A voter on the frozen roll who has not been issued a voting code yet.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Recipient {
+    pub voter_id: String,
+    pub nim: String,
+    pub name: String,
+    pub email: String,
+}
+
+/// A paper polling station codes can be issued at; only its faculty's voters get them there.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct IssueStation {
+    pub id: String,
+    pub faculty: String,
+    /// Its totals are in the tally; no more codes can be counted against it.
+    pub merged: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct NewCredential {
+    pub voter_id: String,
+    pub code_hash: String,
+}
+
+/// A freshly generated code, handed out once for printing and never stored in clear.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IssuedCredential {
+    pub recipient: Recipient,
+    pub code: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct NewSession {
+    pub election_id: String,
+    pub code_hash: String,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+    /// Redeeming does not use the code up; set for elections that allow re-voting.
+    pub reusable: bool,
+    pub revote_tag_sealed: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VotingSession {
+    pub election_id: String,
+    pub voter_id: String,
+    /// When the code was redeemed; decides whether the session gets the grace period at close.
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    /// Only opens with the session token; see `seal_revote_tag`.
+    pub revote_tag_sealed: Option<String>,
+}
@@ -0,0 +1,41 @@
+use crate::election::domain::ElectionError;
+use crate::embargo::domain::StaffDenied;
+use thiserror::Error;
+
+
+#[derive(Debug, Error)]
+#[derive(Clone)]
+pub enum CredentialError {
+    #[error("CredentialError::NotFound: {0}")]
+    NotFound(String),
+    #[error("CredentialError::Unauthorized: {0}")]
+    Unauthorized(String),
+    #[error("CredentialError::Forbidden: {0}")]
+    Forbidden(String),
+    #[error("CredentialError::InvalidCode: {0}")]
+    InvalidCode(String),
+    #[error("CredentialError::ElectionNotOpen: {0}")]
+    ElectionNotOpen(String),
+    #[error("CredentialError::StationClosed: {0}")]
+    StationClosed(String),
+    #[error("CredentialError::UnknownError: {0}")]
+    UnknownError(String),
+}
+
+impl From<ElectionError> for CredentialError {
+    fn from(e: ElectionError) -> Self {
+        match e {
+            ElectionError::NotFound(msg) => CredentialError::NotFound(msg),
+            other => CredentialError::UnknownError(other.to_string()),
+        }
+    }
+}
+
+impl From<StaffDenied> for CredentialError {
+    fn from(e: StaffDenied) -> Self {
+        match e {
+            StaffDenied::Unauthenticated(msg) => CredentialError::Unauthorized(msg),
+            StaffDenied::NotAllowed(msg) => CredentialError::Forbidden(msg),
+        }
+    }
+}
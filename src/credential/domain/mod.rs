@@ -0,0 +1,9 @@
+mod code;
+mod entities;
+mod errors;
+mod repository;
+
+pub use code::*;
+pub use entities::*;
+pub use repository::*;
+pub use errors::*;
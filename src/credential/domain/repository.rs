@@ -0,0 +1,32 @@
+use crate::credential::domain::entities::{IssueStation, NewCredential, NewSession, Recipient, VotingSession};
+use crate::credential::domain::errors::CredentialError;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait Repository: Send + Sync {
+    /// A polling station of this election; `NotFound` for a station of another election.
+    async fn find_station(&self, election_id: String, station_id: String) -> Result<IssueStation, CredentialError>;
+
+    /// Voters on the election's frozen roll that hold no credential yet, optionally only
+    /// those of one faculty.
+    async fn find_unissued(&self, election_id: String, faculty: Option<String>) -> Result<Vec<Recipient>, CredentialError>;
+
+    /// Stores all credential hashes in one transaction; either every code is usable or none is.
+    /// Codes issued at a station count towards its paper reconciliation, so this fails with
+    /// `StationClosed` once the station has been merged.
+    async fn insert_credentials(&self, election_id: String, station_id: Option<String>, credentials: Vec<NewCredential>) -> Result<(), CredentialError>;
+
+    /// Whether `code_hash` is a code issued at a paper station. Those are used up at sign-in
+    /// and never open an online session.
+    async fn is_station_code(&self, election_id: String, code_hash: String) -> Result<bool, CredentialError>;
+
+    /// Burns the credential matching `code_hash` and opens a session for its voter, atomically.
+    /// Fails with `InvalidCode` when the code is unknown, issued at a paper station, or already
+    /// redeemed and not `reusable`.
+    async fn redeem(&self, session: NewSession) -> Result<VotingSession, CredentialError>;
+
+    /// Looks up an unexpired session by token hash.
+    async fn find_session(&self, token_hash: String) -> Result<VotingSession, CredentialError>;
+}
@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct Recipient {
+    pub voter_id: Uuid,
+    pub nim: String,
+    pub name: String,
+    pub email: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct VotingSession {
+    pub election_id: Uuid,
+    pub voter_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub revote_tag_sealed: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct IssueStation {
+    pub id: Uuid,
+    pub faculty: String,
+    pub merged: bool,
+}
@@ -0,0 +1,249 @@
+use crate::credential::domain;
+use crate::credential::domain::CredentialError;
+use crate::credential::domain::Repository;
+use crate::infrastructure::crypto::PiiCipher;
+use crate::infrastructure::database::postgres::Postgres;
+use crate::voter::repository::{FIELD_EMAIL, FIELD_NAME, FIELD_NIM};
+use anyhow::anyhow;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::credential::repository::model::{IssueStation, Recipient, VotingSession};
+
+pub struct PostgresRepo {
+    postgres: sqlx::PgPool,
+    cipher: Arc<PiiCipher>,
+}
+impl PostgresRepo {
+    pub async fn new(postgres: Arc<Mutex<Postgres>>, cipher: Arc<PiiCipher>) -> anyhow::Result<Self> {
+        let guard = postgres.lock().await;
+        let pool = guard
+            .pool()
+            .ok_or_else(|| anyhow!("DB pool is not initialized"))?;
+        Ok(PostgresRepo { postgres: pool, cipher })
+    }
+
+    fn transform_recipient(&self, r: Recipient) -> Result<domain::Recipient, CredentialError> {
+        let reveal = |field: &str, value: &str| self.cipher
+            .reveal(field, value)
+            .map_err(|e| CredentialError::UnknownError(e.to_string()));
+
+        Ok(domain::Recipient {
+            voter_id: r.voter_id.to_string(),
+            nim: reveal(FIELD_NIM, &r.nim)?,
+            name: reveal(FIELD_NAME, &r.name)?,
+            email: reveal(FIELD_EMAIL, &r.email)?,
+        })
+    }
+
+    fn transform_session(&self, s: VotingSession) -> domain::VotingSession {
+        domain::VotingSession {
+            election_id: s.election_id.to_string(),
+            voter_id: s.voter_id.to_string(),
+            created_at: s.created_at,
+            expires_at: s.expires_at,
+            revote_tag_sealed: s.revote_tag_sealed,
+        }
+    }
+
+    fn parse_id(id: &str) -> Result<Uuid, CredentialError> {
+        Uuid::parse_str(id).map_err(|_| CredentialError::NotFound(format!("{} not found", id)))
+    }
+}
+
+#[async_trait]
+impl Repository for PostgresRepo {
+    async fn find_station(&self, election_id: String, station_id: String) -> Result<domain::IssueStation, CredentialError> {
+        let election_uuid = Self::parse_id(&election_id)?;
+        let station_uuid = Self::parse_id(&station_id)?;
+
+        let station = sqlx::query_as::<_, IssueStation>(
+            r#"
+            SELECT id, faculty, merged_at IS NOT NULL AS merged
+            FROM polling_stations WHERE id = $1 AND election_id = $2
+            "#,
+        )
+            .bind(station_uuid)
+            .bind(election_uuid)
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| CredentialError::UnknownError(e.to_string()))?
+            .ok_or_else(|| CredentialError::NotFound(format!("station {} not found", station_id)))?;
+
+        Ok(domain::IssueStation {
+            id: station.id.to_string(),
+            faculty: station.faculty,
+            merged: station.merged,
+        })
+    }
+
+    async fn find_unissued(&self, election_id: String, faculty: Option<String>) -> Result<Vec<domain::Recipient>, CredentialError> {
+        let uuid = Self::parse_id(&election_id)?;
+
+        let recipients = sqlx::query_as::<_, Recipient>(
+            r#"
+        SELECT r.voter_id
+            , r.nim
+            , r.name
+            , r.email
+        FROM voter_rolls r
+        LEFT JOIN voting_credentials c
+            ON c.election_id = r.election_id AND c.voter_id = r.voter_id
+        WHERE r.election_id = $1 AND c.voter_id IS NULL
+            AND ($2::TEXT IS NULL OR r.faculty = $2)
+        "#,
+        )
+            .bind(uuid)
+            .bind(faculty)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| CredentialError::UnknownError(e.to_string()))?;
+
+        let mut recipients = recipients
+            .into_iter()
+            .map(|r| self.transform_recipient(r))
+            .collect::<Result<Vec<_>, _>>()?;
+        recipients.sort_by(|a, b| a.nim.cmp(&b.nim));
+
+        Ok(recipients)
+    }
+
+    async fn insert_credentials(
+        &self,
+        election_id: String,
+        station_id: Option<String>,
+        credentials: Vec<domain::NewCredential>,
+    ) -> Result<(), CredentialError> {
+        let uuid = Self::parse_id(&election_id)?;
+        let station_uuid = station_id.as_deref().map(Self::parse_id).transpose()?;
+        let voter_ids = credentials
+            .iter()
+            .map(|c| Self::parse_id(&c.voter_id))
+            .collect::<Result<Vec<Uuid>, CredentialError>>()?;
+        let hashes: Vec<String> = credentials.into_iter().map(|c| c.code_hash).collect();
+
+        let mut tx = self.postgres.begin().await
+            .map_err(|e| CredentialError::UnknownError(e.to_string()))?;
+
+        // Waits out a merge holding the station row, then sees whether it went through.
+        if let Some(station_uuid) = station_uuid {
+            let merged: bool = sqlx::query_scalar(
+                r#"SELECT merged_at IS NOT NULL FROM polling_stations WHERE id = $1 FOR SHARE"#,
+            )
+                .bind(station_uuid)
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(|e| CredentialError::UnknownError(e.to_string()))?;
+
+            if merged {
+                return Err(CredentialError::StationClosed(format!(
+                    "station {} has already been merged into the tally", station_uuid
+                )));
+            }
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO voting_credentials (election_id, voter_id, code_hash, station_id)
+            SELECT $1, voter_id, code_hash, $4
+            FROM UNNEST($2::UUID[], $3::TEXT[]) AS t (voter_id, code_hash)
+            "#,
+        )
+            .bind(uuid)
+            .bind(voter_ids)
+            .bind(hashes)
+            .bind(station_uuid)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| CredentialError::UnknownError(e.to_string()))?;
+
+        tx.commit().await
+            .map_err(|e| CredentialError::UnknownError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn is_station_code(&self, election_id: String, code_hash: String) -> Result<bool, CredentialError> {
+        let uuid = Self::parse_id(&election_id)?;
+
+        sqlx::query_scalar(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM voting_credentials
+                WHERE election_id = $1 AND code_hash = $2 AND station_id IS NOT NULL
+            )
+            "#,
+        )
+            .bind(uuid)
+            .bind(code_hash)
+            .fetch_one(&self.postgres)
+            .await
+            .map_err(|e| CredentialError::UnknownError(e.to_string()))
+    }
+
+    async fn redeem(&self, session: domain::NewSession) -> Result<domain::VotingSession, CredentialError> {
+        let uuid = Self::parse_id(&session.election_id)?;
+
+        let mut tx = self.postgres.begin().await
+            .map_err(|e| CredentialError::UnknownError(e.to_string()))?;
+
+        // The conditional update is the burn: of two concurrent redemptions only one sees a row.
+        // Reusable codes are stamped with the latest redemption instead.
+        let voter_id: Option<Uuid> = sqlx::query_scalar(
+            r#"
+            UPDATE voting_credentials SET redeemed_at = now()
+            WHERE election_id = $1 AND code_hash = $2 AND station_id IS NULL AND (redeemed_at IS NULL OR $3)
+            RETURNING voter_id
+            "#,
+        )
+            .bind(uuid)
+            .bind(&session.code_hash)
+            .bind(session.reusable)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| CredentialError::UnknownError(e.to_string()))?;
+
+        let voter_id = voter_id
+            .ok_or_else(|| CredentialError::InvalidCode("voting code is unknown or already used".into()))?;
+
+        let created = sqlx::query_as::<_, VotingSession>(
+            r#"
+            INSERT INTO voting_sessions (token_hash, election_id, voter_id, expires_at, revote_tag_sealed)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING election_id, voter_id, created_at, expires_at, revote_tag_sealed
+            "#,
+        )
+            .bind(&session.token_hash)
+            .bind(uuid)
+            .bind(voter_id)
+            .bind(session.expires_at)
+            .bind(&session.revote_tag_sealed)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| CredentialError::UnknownError(e.to_string()))?;
+
+        tx.commit().await
+            .map_err(|e| CredentialError::UnknownError(e.to_string()))?;
+
+        Ok(self.transform_session(created))
+    }
+
+    async fn find_session(&self, token_hash: String) -> Result<domain::VotingSession, CredentialError> {
+        let session = sqlx::query_as::<_, VotingSession>(
+            r#"
+            SELECT election_id, voter_id, created_at, expires_at, revote_tag_sealed
+            FROM voting_sessions
+            WHERE token_hash = $1 AND expires_at > now()
+            "#,
+        )
+            .bind(token_hash)
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| CredentialError::UnknownError(e.to_string()))?;
+
+        session
+            .map(|s| self.transform_session(s))
+            .ok_or_else(|| CredentialError::NotFound("voting session not found or expired".into()))
+    }
+}
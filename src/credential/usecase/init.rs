@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use crate::credential::domain::Repository;
+use crate::credential::usecase::{issue, redeem};
+use crate::credential::usecase::issue::IssueCredentialUseCase;
+use crate::credential::usecase::redeem::RedeemCredentialUseCase;
+use crate::election;
+use crate::embargo::domain::StaffRoster;
+
+
+#[derive(Clone)]
+pub struct UseCase
+{
+    pub issue: Arc<dyn issue::Interactor>,
+    pub redeem: Arc<dyn redeem::Interactor>,
+}
+
+impl UseCase {
+
+    pub fn new(
+        credential_repo: Arc<dyn Repository + Send + Sync>,
+        election_repo: Arc<dyn election::domain::Repository + Send + Sync>,
+        roster: Arc<StaffRoster>,
+        session_ttl: chrono::Duration,
+    ) -> Self {
+
+        let issue_uc = IssueCredentialUseCase::new(credential_repo.clone(), election_repo.clone(), roster);
+        let redeem_uc = RedeemCredentialUseCase::new(credential_repo, election_repo, session_ttl);
+
+        Self {
+            issue: Arc::new(issue_uc),
+            redeem: Arc::new(redeem_uc),
+        }
+    }
+
+}
@@ -0,0 +1,247 @@
+use crate::credential::domain::{self, CredentialError, IssuedCredential, NewCredential, Repository};
+use crate::election;
+use crate::election::domain::ElectionPhase;
+use crate::embargo::domain::StaffRoster;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Vec<IssuedCredential>, CredentialError>;
+}
+
+pub struct IssueCredentialUseCase<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    repository: Arc<R>,
+    election_repository: Arc<E>,
+    roster: Arc<StaffRoster>,
+}
+
+pub struct Request {
+    pub election_id: String,
+    /// Issue at a paper polling station, to that station's faculty only.
+    pub station_id: Option<String>,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("election_id", &self.election_id)
+            .field("station_id", &self.station_id)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync> IssueCredentialUseCase<R, E>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    pub fn new(repository: Arc<R>, election_repository: Arc<E>, roster: Arc<StaffRoster>) -> Self {
+        Self { repository, election_repository, roster }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync> Interactor for IssueCredentialUseCase<R, E>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Vec<IssuedCredential>, CredentialError> {
+        // The response is the only place the codes exist in clear, next to who they are for.
+        self.roster.acting(req.staff_token.as_deref(), "issue voting codes")?;
+
+        let election = self.election_repository
+            .find_by_id(req.election_id.clone())
+            .await?;
+
+        // Codes are only issued against a frozen roll.
+        if election.phase != ElectionPhase::Voting {
+            return Err(CredentialError::ElectionNotOpen(format!(
+                "election {} is in phase {}",
+                election.id,
+                election.phase.as_str()
+            )));
+        }
+
+        let station = match &req.station_id {
+            Some(station_id) => {
+                let station = self.repository.find_station(req.election_id.clone(), station_id.clone()).await?;
+                if station.merged {
+                    return Err(CredentialError::StationClosed(format!(
+                        "station {} has already been merged into the tally", station.id
+                    )));
+                }
+                Some(station)
+            }
+            None => None,
+        };
+
+        let faculty = station.as_ref().map(|s| s.faculty.clone());
+        let recipients = self.repository.find_unissued(req.election_id.clone(), faculty).await?;
+        if recipients.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let issued: Vec<IssuedCredential> = recipients
+            .into_iter()
+            .map(|recipient| IssuedCredential { recipient, code: domain::generate_code() })
+            .collect();
+
+        let credentials = issued
+            .iter()
+            .map(|i| NewCredential {
+                voter_id: i.recipient.voter_id.clone(),
+                code_hash: domain::hash_code(&i.code),
+            })
+            .collect();
+
+        self.repository.insert_credentials(req.election_id, req.station_id, credentials).await?;
+
+        Ok(issued)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::credential::domain::{self, CredentialError};
+    use crate::credential::usecase::issue;
+    use crate::credential::usecase::issue::Interactor;
+    use crate::election;
+    use crate::election::domain::ElectionPhase;
+    use crate::embargo::domain::{StaffRoster, hash_staff_token};
+    use std::sync::Arc;
+
+    fn election_repo(phase: ElectionPhase) -> election::domain::MockRepository {
+        let mut repo_mock = election::domain::MockRepository::new();
+        repo_mock.expect_find_by_id().returning(move |id| Ok(election::domain::Election {
+            id,
+            name: "Student Council 2026".to_string(),
+            phase,
+            voting_starts_at: None,
+            voting_ends_at: None,
+            allow_revote: false,
+            created_at: chrono::Utc::now(),
+            updated_at: None,
+        }));
+        repo_mock
+    }
+
+    fn roster() -> Arc<StaffRoster> {
+        Arc::new(StaffRoster::parse(&format!(
+            "rina:committee:{},budi:observer:{}",
+            hash_staff_token("rina-token"),
+            hash_staff_token("budi-token")
+        )).unwrap())
+    }
+
+    fn request(station_id: Option<&str>, staff_token: Option<&str>) -> issue::Request {
+        issue::Request {
+            election_id: "e1".to_string(),
+            station_id: station_id.map(str::to_string),
+            staff_token: staff_token.map(str::to_string),
+        }
+    }
+
+    fn recipient(id: &str) -> domain::Recipient {
+        domain::Recipient {
+            voter_id: id.to_string(),
+            nim: format!("nim-{}", id),
+            name: format!("Voter {}", id),
+            email: format!("{}@campus.ac.id", id),
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_issue_stores_only_hashes() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_unissued()
+            .times(1)
+            .returning(|_, _| Ok(vec![recipient("1"), recipient("2")]));
+        repo_mock.expect_insert_credentials()
+            .times(1)
+            .withf(|election_id, station_id, credentials| {
+                election_id == "e1"
+                    && station_id.is_none()
+                    && credentials.len() == 2
+                    && credentials.iter().all(|c| c.code_hash.len() == 64)
+            })
+            .returning(|_, _, _| Ok(()));
+
+        let uc = issue::IssueCredentialUseCase::new(Arc::new(repo_mock), Arc::new(election_repo(ElectionPhase::Voting)), roster());
+        let issued = uc.handle(request(None, Some("rina-token"))).await.unwrap();
+
+        assert_eq!(issued.len(), 2);
+        assert_ne!(issued[0].code, issued[1].code);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_issue_requires_frozen_roll() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_unissued().times(0);
+
+        let uc = issue::IssueCredentialUseCase::new(Arc::new(repo_mock), Arc::new(election_repo(ElectionPhase::Registration)), roster());
+        let res = uc.handle(request(None, Some("rina-token"))).await;
+
+        assert!(matches!(res, Err(CredentialError::ElectionNotOpen(_))));
+    }
+
+    fn station(merged: bool) -> domain::IssueStation {
+        domain::IssueStation {
+            id: "s1".to_string(),
+            faculty: "Engineering".to_string(),
+            merged,
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_issue_at_station_is_limited_to_its_faculty() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_station().times(1).returning(|_, _| Ok(station(false)));
+        repo_mock.expect_find_unissued()
+            .times(1)
+            .withf(|_, faculty| faculty.as_deref() == Some("Engineering"))
+            .returning(|_, _| Ok(vec![recipient("1")]));
+        repo_mock.expect_insert_credentials()
+            .times(1)
+            .withf(|_, station_id, _| station_id.as_deref() == Some("s1"))
+            .returning(|_, _, _| Ok(()));
+
+        let uc = issue::IssueCredentialUseCase::new(Arc::new(repo_mock), Arc::new(election_repo(ElectionPhase::Voting)), roster());
+        let issued = uc.handle(request(Some("s1"), Some("rina-token"))).await.unwrap();
+
+        assert_eq!(issued.len(), 1);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_issue_refuses_merged_station() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_station().returning(|_, _| Ok(station(true)));
+        repo_mock.expect_find_unissued().times(0);
+
+        let uc = issue::IssueCredentialUseCase::new(Arc::new(repo_mock), Arc::new(election_repo(ElectionPhase::Voting)), roster());
+        let res = uc.handle(request(Some("s1"), Some("rina-token"))).await;
+
+        assert!(matches!(res, Err(CredentialError::StationClosed(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_issue_needs_an_acting_staff_member() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_unissued().times(0);
+        repo_mock.expect_insert_credentials().times(0);
+
+        let uc = issue::IssueCredentialUseCase::new(Arc::new(repo_mock), Arc::new(election_repo(ElectionPhase::Voting)), roster());
+
+        assert!(matches!(uc.handle(request(None, None)).await, Err(CredentialError::Unauthorized(_))));
+        assert!(matches!(uc.handle(request(None, Some("guess"))).await, Err(CredentialError::Unauthorized(_))));
+        assert!(matches!(uc.handle(request(None, Some("budi-token"))).await, Err(CredentialError::Forbidden(_))));
+    }
+}
@@ -0,0 +1,5 @@
+mod init;
+pub mod issue;
+pub mod redeem;
+
+pub use init::UseCase;
@@ -0,0 +1,244 @@
+use crate::credential::domain::{self, CredentialError, NewSession, Repository};
+use crate::election;
+use crate::election::domain::ElectionPhase;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Response, CredentialError>;
+}
+
+pub struct RedeemCredentialUseCase<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    repository: Arc<R>,
+    election_repository: Arc<E>,
+    session_ttl: chrono::Duration,
+}
+
+pub struct Request {
+    pub election_id: String,
+    pub code: String,
+}
+
+// Keep the voting code out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("election_id", &self.election_id)
+            .field("code", &"<redacted>")
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Response {
+    pub election_id: String,
+    pub token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync> RedeemCredentialUseCase<R, E>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    pub fn new(repository: Arc<R>, election_repository: Arc<E>, session_ttl: chrono::Duration) -> Self {
+        Self { repository, election_repository, session_ttl }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync> Interactor for RedeemCredentialUseCase<R, E>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Response, CredentialError> {
+        let election = self.election_repository
+            .find_by_id(req.election_id.clone())
+            .await?;
+
+        // Checked before burning, so a code is not wasted on a closed election.
+        if election.phase != ElectionPhase::Voting {
+            return Err(CredentialError::ElectionNotOpen(format!(
+                "election {} is in phase {}",
+                election.id,
+                election.phase.as_str()
+            )));
+        }
+
+        let code_hash = domain::hash_code(&req.code);
+        if self.repository.is_station_code(req.election_id.clone(), code_hash.clone()).await? {
+            return Err(CredentialError::InvalidCode("this code is for signing in at a paper station".into()));
+        }
+
+        let token = domain::generate_session_token();
+        // With re-voting the code opens a new session for each change of mind.
+        let revote_tag_sealed = if election.allow_revote {
+            Some(domain::seal_revote_tag(&token, &domain::revote_tag(&req.election_id, &req.code))?)
+        } else {
+            None
+        };
+
+        let session = self.repository.redeem(NewSession {
+            code_hash,
+            token_hash: domain::hash_session_token(&token),
+            expires_at: Utc::now() + self.session_ttl,
+            reusable: election.allow_revote,
+            revote_tag_sealed,
+            election_id: req.election_id,
+        }).await?;
+
+        Ok(Response {
+            election_id: session.election_id,
+            token,
+            expires_at: session.expires_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::credential::domain::{self, CredentialError};
+    use crate::credential::usecase::redeem;
+    use crate::credential::usecase::redeem::Interactor;
+    use crate::election;
+    use crate::election::domain::ElectionPhase;
+    use std::sync::Arc;
+
+    fn election_repo(phase: ElectionPhase) -> election::domain::MockRepository {
+        election_repo_with_revote(phase, false)
+    }
+
+    fn election_repo_with_revote(phase: ElectionPhase, allow_revote: bool) -> election::domain::MockRepository {
+        let mut repo_mock = election::domain::MockRepository::new();
+        repo_mock.expect_find_by_id().returning(move |id| Ok(election::domain::Election {
+            id,
+            name: "Student Council 2026".to_string(),
+            phase,
+            voting_starts_at: None,
+            voting_ends_at: None,
+            allow_revote,
+            created_at: chrono::Utc::now(),
+            updated_at: None,
+        }));
+        repo_mock
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_redeem_opens_short_lived_session() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_is_station_code().returning(|_, _| Ok(false));
+        repo_mock.expect_redeem()
+            .times(1)
+            .withf(|s| {
+                s.election_id == "e1" && s.code_hash == domain::hash_code("7K3M-Q9TX-2B4R-HW8D")
+                    && !s.reusable && s.revote_tag_sealed.is_none()
+            })
+            .returning(|s| Ok(domain::VotingSession {
+                election_id: s.election_id,
+                voter_id: "v1".to_string(),
+                created_at: chrono::Utc::now(),
+                expires_at: s.expires_at,
+                revote_tag_sealed: s.revote_tag_sealed,
+            }));
+
+        let uc = redeem::RedeemCredentialUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(election_repo(ElectionPhase::Voting)),
+            chrono::Duration::minutes(15),
+        );
+        let res = uc.handle(redeem::Request {
+            election_id: "e1".to_string(),
+            code: "7k3m-q9tx-2b4r-hw8d".to_string(),
+        }).await.unwrap();
+
+        assert_eq!(res.token.len(), 64);
+        assert!(res.expires_at <= chrono::Utc::now() + chrono::Duration::minutes(15));
+        assert!(res.expires_at > chrono::Utc::now() + chrono::Duration::minutes(14));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_redeem_outside_voting_keeps_code() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_is_station_code().returning(|_, _| Ok(false));
+        repo_mock.expect_redeem().times(0);
+
+        let uc = redeem::RedeemCredentialUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(election_repo(ElectionPhase::Closed)),
+            chrono::Duration::minutes(15),
+        );
+        let res = uc.handle(redeem::Request {
+            election_id: "e1".to_string(),
+            code: "7K3M-Q9TX-2B4R-HW8D".to_string(),
+        }).await;
+
+        assert!(matches!(res, Err(CredentialError::ElectionNotOpen(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_redeem_with_revote_keeps_code_and_seals_tag() {
+        let sealed = Arc::new(std::sync::Mutex::new(None));
+        let seen = sealed.clone();
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_is_station_code().returning(|_, _| Ok(false));
+        repo_mock.expect_redeem()
+            .times(1)
+            .withf(|s| s.reusable)
+            .returning(move |s| {
+                *seen.lock().unwrap() = s.revote_tag_sealed.clone();
+                Ok(domain::VotingSession {
+                    election_id: s.election_id,
+                    voter_id: "v1".to_string(),
+                    created_at: chrono::Utc::now(),
+                    expires_at: s.expires_at,
+                    revote_tag_sealed: s.revote_tag_sealed,
+                })
+            });
+
+        let uc = redeem::RedeemCredentialUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(election_repo_with_revote(ElectionPhase::Voting, true)),
+            chrono::Duration::minutes(15),
+        );
+        let res = uc.handle(redeem::Request {
+            election_id: "e1".to_string(),
+            code: "7K3M-Q9TX-2B4R-HW8D".to_string(),
+        }).await.unwrap();
+
+        let sealed = sealed.lock().unwrap().clone().unwrap();
+        assert_eq!(
+            domain::open_revote_tag(&res.token, &sealed).unwrap(),
+            domain::revote_tag("e1", "7K3M-Q9TX-2B4R-HW8D")
+        );
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_redeem_refuses_a_paper_station_code() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_is_station_code()
+            .times(1)
+            .withf(|election_id, code_hash| election_id == "e1" && code_hash == &domain::hash_code("7K3M-Q9TX-2B4R-HW8D"))
+            .returning(|_, _| Ok(true));
+        repo_mock.expect_redeem().times(0);
+
+        let uc = redeem::RedeemCredentialUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(election_repo_with_revote(ElectionPhase::Voting, true)),
+            chrono::Duration::minutes(15),
+        );
+        let res = uc.handle(redeem::Request {
+            election_id: "e1".to_string(),
+            code: "7K3M-Q9TX-2B4R-HW8D".to_string(),
+        }).await;
+
+        assert!(matches!(res, Err(CredentialError::InvalidCode(_))));
+    }
+}
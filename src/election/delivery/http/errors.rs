@@ -0,0 +1,44 @@
+use crate::election::domain::ElectionError;
+use crate::utils::response;
+use actix_web::HttpResponse;
+
+pub fn error_response(e: &ElectionError) -> HttpResponse {
+    println!("Error: {}", e);
+    match e {
+        ElectionError::NotFound(_) => HttpResponse::NotFound().json(response::error::<()>(
+            None,
+            "election not found".into(),
+            "ELECTION_NOT_FOUND".into(),
+        )),
+        ElectionError::Unauthorized(msg) => HttpResponse::Unauthorized().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "ELECTION_UNAUTHORIZED".into(),
+        )),
+        ElectionError::Forbidden(msg) => HttpResponse::Forbidden().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "ELECTION_FORBIDDEN".into(),
+        )),
+        ElectionError::InvalidTransition(msg) => HttpResponse::Conflict().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "ELECTION_INVALID_TRANSITION".into(),
+        )),
+        ElectionError::SettingsLocked(msg) => HttpResponse::Conflict().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "ELECTION_SETTINGS_LOCKED".into(),
+        )),
+        ElectionError::InvalidInput(msg) => HttpResponse::BadRequest().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "ELECTION_INVALID_INPUT".into(),
+        )),
+        ElectionError::UnknownError(_) => HttpResponse::InternalServerError().json(response::error::<()>(
+            None,
+            "failed process data".into(),
+            "-1".into(),
+        )),
+    }
+}
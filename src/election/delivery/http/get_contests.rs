@@ -0,0 +1,19 @@
+use crate::election::delivery::http::errors::error_response;
+use crate::election::usecase::get_contests::*;
+use crate::utils::{app, response};
+use actix_web::{HttpResponse, web};
+
+pub async fn get_contests(handler: web::Data<app::AppHandlerData>, path: web::Path<String>) -> HttpResponse {
+
+    let request = Request { election_id: path.into_inner() };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.election_uc.get_contests.handle(request).await {
+        Ok(contests) => HttpResponse::Ok().json(response::success(
+            Some(contests),
+            "Successfully processed election".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
@@ -0,0 +1,19 @@
+use crate::election::delivery::http::errors::error_response;
+use crate::election::usecase::get::*;
+use crate::utils::{app, response};
+use actix_web::{HttpResponse, web};
+
+pub async fn get_election(handler: web::Data<app::AppHandlerData>, path: web::Path<String>) -> HttpResponse {
+
+    let request = Request { id: path.into_inner() };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.election_uc.get.handle(request).await {
+        Ok(election) => HttpResponse::Ok().json(response::success(
+            Some(election),
+            "Successfully processed election".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
@@ -0,0 +1,17 @@
+use actix_web::web;
+use crate::election::delivery::http::get_contests::get_contests;
+use crate::election::delivery::http::get_election::get_election;
+use crate::election::delivery::http::set_revote::set_revote;
+use crate::election::delivery::http::set_tie_break::set_tie_break;
+use crate::election::delivery::http::set_window::set_window;
+use crate::election::delivery::http::transition_election::transition_election;
+
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/elections/{id}", web::get().to(get_election))
+        .route("/elections/{id}/contests", web::get().to(get_contests))
+        .route("/elections/{id}/contests/{contest_id}/tie-break", web::put().to(set_tie_break))
+        .route("/elections/{id}/phase", web::put().to(transition_election))
+        .route("/elections/{id}/revote", web::put().to(set_revote))
+        .route("/elections/{id}/window", web::put().to(set_window));
+}
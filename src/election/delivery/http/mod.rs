@@ -0,0 +1,16 @@
+mod handler;
+mod errors;
+mod get_contests;
+mod get_election;
+mod set_revote;
+mod set_tie_break;
+mod set_window;
+mod transition_election;
+
+pub use get_contests::*;
+pub use get_election::*;
+pub use set_revote::*;
+pub use set_tie_break::*;
+pub use set_window::*;
+pub use transition_election::*;
+pub use handler::*;
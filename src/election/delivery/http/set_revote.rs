@@ -0,0 +1,35 @@
+use crate::election::delivery::http::errors::error_response;
+use crate::election::usecase::set_revote::*;
+use crate::embargo::delivery::http::staff_token;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct SetRevoteBody {
+    allow_revote: bool,
+}
+
+pub async fn set_revote(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<SetRevoteBody>,
+) -> HttpResponse {
+
+    let request = Request {
+        id: path.into_inner(),
+        allow_revote: body.allow_revote,
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.election_uc.set_revote.handle(request).await {
+        Ok(election) => HttpResponse::Ok().json(response::success(
+            Some(election),
+            "Successfully processed election".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
@@ -0,0 +1,42 @@
+use crate::election::delivery::http::errors::error_response;
+use crate::election::domain::TieBreak;
+use crate::election::usecase::set_tie_break::*;
+use crate::embargo::delivery::http::staff_token;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct SetTieBreakBody {
+    tie_break: TieBreak,
+    /// Refused: a lottery seed is drawn when voting opens.
+    seed: Option<String>,
+}
+
+pub async fn set_tie_break(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<(String, String)>,
+    body: web::Json<SetTieBreakBody>,
+) -> HttpResponse {
+
+    let (election_id, contest_id) = path.into_inner();
+    let body = body.into_inner();
+    let request = Request {
+        election_id,
+        contest_id,
+        tie_break: body.tie_break,
+        seed: body.seed,
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.election_uc.set_tie_break.handle(request).await {
+        Ok(contest) => HttpResponse::Ok().json(response::success(
+            Some(contest),
+            "Successfully processed contest".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
@@ -0,0 +1,38 @@
+use crate::election::delivery::http::errors::error_response;
+use crate::election::usecase::set_window::*;
+use crate::embargo::delivery::http::staff_token;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct SetWindowBody {
+    voting_starts_at: Option<DateTime<Utc>>,
+    voting_ends_at: Option<DateTime<Utc>>,
+}
+
+pub async fn set_window(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<SetWindowBody>,
+) -> HttpResponse {
+
+    let request = Request {
+        id: path.into_inner(),
+        voting_starts_at: body.voting_starts_at,
+        voting_ends_at: body.voting_ends_at,
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.election_uc.set_window.handle(request).await {
+        Ok(election) => HttpResponse::Ok().json(response::success(
+            Some(election),
+            "Successfully processed election".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
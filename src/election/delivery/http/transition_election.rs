@@ -0,0 +1,128 @@
+use crate::election::delivery::http::errors::error_response;
+use crate::election::domain::ElectionPhase;
+use crate::election::usecase::transition::*;
+use crate::embargo::delivery::http::staff_token;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct TransitionElectionBody {
+    phase: ElectionPhase,
+}
+
+pub async fn transition_election(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<TransitionElectionBody>,
+) -> HttpResponse {
+
+    let request = Request {
+        id: path.into_inner(),
+        phase: body.phase,
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.election_uc.transition.handle(request).await {
+        Ok(response) => HttpResponse::Ok().json(response::success(
+            Some(response),
+            "Successfully processed election".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::transition_election;
+    use actix_web::{App, http::StatusCode, test, web};
+    use async_trait::async_trait;
+    use serde_json::Value;
+    use std::sync::Arc;
+    use crate::election;
+    use crate::election::domain::{ElectionError, ElectionPhase};
+    use crate::election::usecase::transition::{Interactor, Request, Response};
+    use crate::utils::app;
+
+    fn init_app_data(transition_impl: Arc<dyn Interactor>) -> web::Data<app::AppHandlerData> {
+        let mut election_uc = app::AppHandlerData::mocked().election_uc;
+        election_uc.transition = transition_impl;
+
+        web::Data::new(app::AppHandlerData { election_uc, ..app::AppHandlerData::mocked() })
+    }
+
+    #[actix_rt::test]
+    async fn test_transition_election_success() {
+        struct MockTransition;
+
+        #[async_trait]
+        impl Interactor for MockTransition {
+            async fn handle(&self, req: Request) -> Result<Response, ElectionError> {
+                Ok(Response {
+                    election: election::domain::Election {
+                        id: req.id,
+                        name: "Student Council 2026".to_string(),
+                        phase: req.phase,
+                        voting_starts_at: None,
+                        voting_ends_at: None,
+                        allow_revote: false,
+                        created_at: chrono::Utc::now(),
+                        updated_at: None,
+                    },
+                    roll_size: Some(3),
+                })
+            }
+        }
+
+        let app = test::init_service(
+            App::new()
+                .app_data(init_app_data(Arc::new(MockTransition)))
+                .route("/elections/{id}/phase", web::put().to(transition_election)),
+        )
+        .await;
+
+        let req = test::TestRequest::put()
+            .uri("/elections/e1/phase")
+            .set_json(serde_json::json!({ "phase": "voting" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["election"]["phase"], "voting");
+        assert_eq!(body["data"]["roll_size"], 3);
+    }
+
+    #[actix_rt::test]
+    async fn test_transition_election_conflict() {
+        struct MockTransition;
+
+        #[async_trait]
+        impl Interactor for MockTransition {
+            async fn handle(&self, req: Request) -> Result<Response, ElectionError> {
+                assert_eq!(req.phase, ElectionPhase::Voting);
+                Err(ElectionError::InvalidTransition("cannot move election".into()))
+            }
+        }
+
+        let app = test::init_service(
+            App::new()
+                .app_data(init_app_data(Arc::new(MockTransition)))
+                .route("/elections/{id}/phase", web::put().to(transition_election)),
+        )
+        .await;
+
+        let req = test::TestRequest::put()
+            .uri("/elections/e1/phase")
+            .set_json(serde_json::json!({ "phase": "voting" }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["error_code"], "ELECTION_INVALID_TRANSITION");
+    }
+}
@@ -0,0 +1,118 @@
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+
+/// Lifecycle of an election. Phases only ever move forward, one step at a time.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ElectionPhase {
+    Draft,
+    Registration,
+    Voting,
+    Closed,
+    Published,
+}
+
+impl ElectionPhase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ElectionPhase::Draft => "draft",
+            ElectionPhase::Registration => "registration",
+            ElectionPhase::Voting => "voting",
+            ElectionPhase::Closed => "closed",
+            ElectionPhase::Published => "published",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<ElectionPhase> {
+        match value {
+            "draft" => Some(ElectionPhase::Draft),
+            "registration" => Some(ElectionPhase::Registration),
+            "voting" => Some(ElectionPhase::Voting),
+            "closed" => Some(ElectionPhase::Closed),
+            "published" => Some(ElectionPhase::Published),
+            _ => None,
+        }
+    }
+
+    pub fn next(&self) -> Option<ElectionPhase> {
+        match self {
+            ElectionPhase::Draft => Some(ElectionPhase::Registration),
+            ElectionPhase::Registration => Some(ElectionPhase::Voting),
+            ElectionPhase::Voting => Some(ElectionPhase::Closed),
+            ElectionPhase::Closed => Some(ElectionPhase::Published),
+            ElectionPhase::Published => None,
+        }
+    }
+
+    pub fn can_transition_to(&self, to: ElectionPhase) -> bool {
+        self.next() == Some(to)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Election {
+    pub id: String,
+    pub name: String,
+    pub phase: ElectionPhase,
+    pub voting_starts_at: Option<DateTime<Utc>>,
+    pub voting_ends_at: Option<DateTime<Utc>>,
+    /// Voters may cast again until voting closes; only their last ballot is counted.
+    pub allow_revote: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// How a contest picks between candidates level on the most votes.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum TieBreak {
+    /// Nobody wins; the tied candidates go to a new vote.
+    #[default]
+    Runoff,
+    /// Drawn from the contest's seed, which the server draws when voting opens.
+    Lottery,
+    /// The candidate registered first wins.
+    Registration,
+}
+
+impl TieBreak {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TieBreak::Runoff => "runoff",
+            TieBreak::Lottery => "lottery",
+            TieBreak::Registration => "registration",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<TieBreak> {
+        match value {
+            "runoff" => Some(TieBreak::Runoff),
+            "lottery" => Some(TieBreak::Lottery),
+            "registration" => Some(TieBreak::Registration),
+            _ => None,
+        }
+    }
+}
+
+/// Draws a lottery seed: 32 random bytes in hex. It is drawn when voting opens, once the
+/// candidates and rules are locked, so nobody can try seeds until one favours a candidate.
+pub fn draw_lottery_seed() -> String {
+    let mut bytes = [0u8; 32];
+    rand::rng().fill(&mut bytes[..]);
+    hex::encode(bytes)
+}
+
+/// A race within an election, e.g. president and vice president of the student council.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Contest {
+    pub id: String,
+    pub election_id: String,
+    pub name: String,
+    /// Candidates in this contest whose `status` is active.
+    pub candidate_ids: Vec<String>,
+    pub tie_break: TieBreak,
+    /// Set for a lottery once voting opens, and never for the other policies.
+    pub tie_break_seed: Option<String>,
+}
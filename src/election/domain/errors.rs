@@ -0,0 +1,31 @@
+use crate::embargo::domain::StaffDenied;
+use thiserror::Error;
+
+
+#[derive(Debug, Error)]
+#[derive(Clone)]
+pub enum ElectionError {
+    #[error("ElectionError::NotFound: {0}")]
+    NotFound(String),
+    #[error("ElectionError::Unauthorized: {0}")]
+    Unauthorized(String),
+    #[error("ElectionError::Forbidden: {0}")]
+    Forbidden(String),
+    #[error("ElectionError::InvalidTransition: {0}")]
+    InvalidTransition(String),
+    #[error("ElectionError::SettingsLocked: {0}")]
+    SettingsLocked(String),
+    #[error("ElectionError::InvalidInput: {0}")]
+    InvalidInput(String),
+    #[error("ElectionError::UnknownError: {0}")]
+    UnknownError(String),
+}
+
+impl From<StaffDenied> for ElectionError {
+    fn from(e: StaffDenied) -> Self {
+        match e {
+            StaffDenied::Unauthenticated(msg) => ElectionError::Unauthorized(msg),
+            StaffDenied::NotAllowed(msg) => ElectionError::Forbidden(msg),
+        }
+    }
+}
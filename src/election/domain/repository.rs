@@ -0,0 +1,46 @@
+use crate::election::domain::entities::{Contest, Election, ElectionPhase, TieBreak};
+use crate::election::domain::errors::ElectionError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait Repository: Send + Sync {
+    async fn find_by_id(&self, id: String) -> Result<Election, ElectionError>;
+
+    async fn find_contests(&self, election_id: String) -> Result<Vec<Contest>, ElectionError>;
+
+    /// Moves the election from `from` to `to`. Fails with `InvalidTransition` when the
+    /// stored phase is no longer `from`, so two concurrent transitions cannot both win.
+    async fn update_phase(&self, id: String, from: ElectionPhase, to: ElectionPhase) -> Result<Election, ElectionError>;
+
+    /// Turns re-voting on or off. Fails with `SettingsLocked` when the stored phase is no
+    /// longer `phase`, so the setting cannot slip in after voting has opened.
+    async fn update_allow_revote(&self, id: String, phase: ElectionPhase, allow_revote: bool) -> Result<Election, ElectionError>;
+
+    /// Sets when ballots may be cast. Fails with `SettingsLocked` when the stored phase is no
+    /// longer `phase`, like `update_allow_revote`.
+    async fn update_voting_window(
+        &self,
+        id: String,
+        phase: ElectionPhase,
+        starts_at: Option<DateTime<Utc>>,
+        ends_at: Option<DateTime<Utc>>,
+    ) -> Result<Election, ElectionError>;
+
+    /// Sets how a contest breaks ties and clears any lottery seed. Fails with `SettingsLocked` when
+    /// the stored phase of its election is no longer `phase`, like `update_allow_revote`.
+    async fn update_tie_break(
+        &self,
+        election_id: String,
+        contest_id: String,
+        phase: ElectionPhase,
+        tie_break: TieBreak,
+    ) -> Result<Contest, ElectionError>;
+
+    /// Moves the election into `Voting`, freezes the eligible voter set into its roll and draws a
+    /// seed for every lottery contest, atomically. Returns the election and the number of voters
+    /// on the frozen roll.
+    async fn open_voting(&self, id: String) -> Result<(Election, i64), ElectionError>;
+}
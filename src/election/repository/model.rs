@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct Election {
+    pub id: Uuid,
+    pub name: String,
+    pub phase: String,
+    pub voting_starts_at: Option<DateTime<Utc>>,
+    pub voting_ends_at: Option<DateTime<Utc>>,
+    pub allow_revote: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct Contest {
+    pub id: Uuid,
+    pub election_id: Uuid,
+    pub name: String,
+    pub candidate_ids: Vec<Uuid>,
+    pub tie_break: String,
+    pub tie_break_seed: Option<String>,
+}
@@ -0,0 +1,309 @@
+use crate::election::domain;
+use crate::election::domain::{ElectionError, ElectionPhase, TieBreak};
+use crate::election::domain::Repository;
+use crate::infrastructure::database::postgres::Postgres;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::election::repository::model::{Contest, Election};
+
+const ELECTION_COLUMNS: &str = r#"
+        id
+        , name
+        , phase
+        , voting_starts_at
+        , voting_ends_at
+        , allow_revote
+        , created_at
+        , updated_at
+"#;
+
+pub struct PostgresRepo {
+    postgres: sqlx::PgPool,
+}
+impl PostgresRepo {
+    pub async fn new(postgres: Arc<Mutex<Postgres>>) -> anyhow::Result<Self> {
+        let guard = postgres.lock().await;
+        let pool = guard
+            .pool()
+            .ok_or_else(|| anyhow!("DB pool is not initialized"))?;
+        Ok(PostgresRepo { postgres: pool })
+    }
+
+    fn transform_election(&self, e: Election) -> Result<domain::Election, ElectionError> {
+        let phase = ElectionPhase::parse(&e.phase)
+            .ok_or_else(|| ElectionError::UnknownError(format!("unknown election phase: {}", e.phase)))?;
+
+        Ok(domain::Election {
+            id: e.id.to_string(),
+            name: e.name,
+            phase,
+            voting_starts_at: e.voting_starts_at,
+            voting_ends_at: e.voting_ends_at,
+            allow_revote: e.allow_revote,
+            created_at: e.created_at,
+            updated_at: e.updated_at,
+        })
+    }
+
+    fn transform_contest(&self, c: Contest) -> Result<domain::Contest, ElectionError> {
+        let tie_break = TieBreak::parse(&c.tie_break)
+            .ok_or_else(|| ElectionError::UnknownError(format!("unknown tie-break policy: {}", c.tie_break)))?;
+
+        Ok(domain::Contest {
+            id: c.id.to_string(),
+            election_id: c.election_id.to_string(),
+            name: c.name,
+            candidate_ids: c.candidate_ids.iter().map(|id| id.to_string()).collect(),
+            tie_break,
+            tie_break_seed: c.tie_break_seed,
+        })
+    }
+
+    fn parse_id(id: &str) -> Result<Uuid, ElectionError> {
+        Uuid::parse_str(id).map_err(|_| ElectionError::NotFound(format!("election {} not found", id)))
+    }
+
+    async fn update_phase_tx(
+        &self,
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        id: Uuid,
+        from: ElectionPhase,
+        to: ElectionPhase,
+    ) -> Result<Election, ElectionError> {
+        let sql = format!(
+            "UPDATE elections SET phase = $1, updated_at = now() WHERE id = $2 AND phase = $3 RETURNING {}",
+            ELECTION_COLUMNS
+        );
+
+        let updated = sqlx::query_as::<_, Election>(&sql)
+            .bind(to.as_str())
+            .bind(id)
+            .bind(from.as_str())
+            .fetch_optional(&mut **tx)
+            .await
+            .map_err(|e| ElectionError::UnknownError(e.to_string()))?;
+
+        updated.ok_or_else(|| ElectionError::InvalidTransition(format!(
+            "election {} is no longer in phase {}",
+            id,
+            from.as_str()
+        )))
+    }
+}
+
+#[async_trait]
+impl Repository for PostgresRepo {
+    async fn find_by_id(&self, id: String) -> Result<domain::Election, ElectionError> {
+        let uuid = Self::parse_id(&id)?;
+        let sql = format!("SELECT {} FROM elections WHERE id = $1", ELECTION_COLUMNS);
+
+        let election = sqlx::query_as::<_, Election>(&sql)
+            .bind(uuid)
+            .fetch_one(&self.postgres)
+            .await
+            .map_err(|e| match e {
+                sqlx::Error::RowNotFound => ElectionError::NotFound(format!("election {} not found", id)),
+                _ => ElectionError::UnknownError(e.to_string()),
+            })?;
+
+        self.transform_election(election)
+    }
+
+    async fn find_contests(&self, election_id: String) -> Result<Vec<domain::Contest>, ElectionError> {
+        let uuid = Self::parse_id(&election_id)?;
+
+        let contests = sqlx::query_as::<_, Contest>(
+            r#"
+        SELECT c.id
+            , c.election_id
+            , c.name
+            , COALESCE(ARRAY_AGG(k.id ORDER BY k.vote_number) FILTER (WHERE k.id IS NOT NULL), '{}') AS candidate_ids
+            , c.tie_break
+            , c.tie_break_seed
+        FROM contests c
+        LEFT JOIN candidates k ON k.contest_id = c.id AND k.status
+        WHERE c.election_id = $1
+        GROUP BY c.id, c.election_id, c.name, c.tie_break, c.tie_break_seed
+        ORDER BY c.name
+        "#,
+        )
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| ElectionError::UnknownError(e.to_string()))?;
+
+        contests
+            .into_iter()
+            .map(|c| self.transform_contest(c))
+            .collect()
+    }
+
+    async fn update_phase(&self, id: String, from: ElectionPhase, to: ElectionPhase) -> Result<domain::Election, ElectionError> {
+        let uuid = Self::parse_id(&id)?;
+
+        let mut tx = self.postgres.begin().await
+            .map_err(|e| ElectionError::UnknownError(e.to_string()))?;
+        let election = self.update_phase_tx(&mut tx, uuid, from, to).await?;
+        tx.commit().await
+            .map_err(|e| ElectionError::UnknownError(e.to_string()))?;
+
+        self.transform_election(election)
+    }
+
+    async fn update_allow_revote(&self, id: String, phase: ElectionPhase, allow_revote: bool) -> Result<domain::Election, ElectionError> {
+        let uuid = Self::parse_id(&id)?;
+        let sql = format!(
+            "UPDATE elections SET allow_revote = $1, updated_at = now() WHERE id = $2 AND phase = $3 RETURNING {}",
+            ELECTION_COLUMNS
+        );
+
+        let updated = sqlx::query_as::<_, Election>(&sql)
+            .bind(allow_revote)
+            .bind(uuid)
+            .bind(phase.as_str())
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| ElectionError::UnknownError(e.to_string()))?;
+
+        let election = updated.ok_or_else(|| ElectionError::SettingsLocked(format!(
+            "election {} is no longer in phase {}",
+            id,
+            phase.as_str()
+        )))?;
+
+        self.transform_election(election)
+    }
+
+    async fn update_tie_break(
+        &self,
+        election_id: String,
+        contest_id: String,
+        phase: ElectionPhase,
+        tie_break: TieBreak,
+    ) -> Result<domain::Contest, ElectionError> {
+        let election_uuid = Self::parse_id(&election_id)?;
+        let contest_uuid = Uuid::parse_str(&contest_id)
+            .map_err(|_| ElectionError::NotFound(format!("contest {} not found", contest_id)))?;
+
+        let updated = sqlx::query_as::<_, Contest>(
+            r#"
+        WITH updated AS (
+            UPDATE contests c SET tie_break = $1, tie_break_seed = NULL
+            FROM elections e
+            WHERE c.id = $2 AND c.election_id = $3 AND e.id = c.election_id AND e.phase = $4
+            RETURNING c.id, c.election_id, c.name, c.tie_break, c.tie_break_seed
+        )
+        SELECT u.id
+            , u.election_id
+            , u.name
+            , COALESCE(ARRAY_AGG(k.id ORDER BY k.vote_number) FILTER (WHERE k.id IS NOT NULL), '{}') AS candidate_ids
+            , u.tie_break
+            , u.tie_break_seed
+        FROM updated u
+        LEFT JOIN candidates k ON k.contest_id = u.id AND k.status
+        GROUP BY u.id, u.election_id, u.name, u.tie_break, u.tie_break_seed
+        "#,
+        )
+            .bind(tie_break.as_str())
+            .bind(contest_uuid)
+            .bind(election_uuid)
+            .bind(phase.as_str())
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| ElectionError::UnknownError(e.to_string()))?;
+
+        let contest = updated.ok_or_else(|| ElectionError::SettingsLocked(format!(
+            "election {} is no longer in phase {}",
+            election_id,
+            phase.as_str()
+        )))?;
+
+        self.transform_contest(contest)
+    }
+
+    async fn update_voting_window(
+        &self,
+        id: String,
+        phase: ElectionPhase,
+        starts_at: Option<chrono::DateTime<chrono::Utc>>,
+        ends_at: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<domain::Election, ElectionError> {
+        let uuid = Self::parse_id(&id)?;
+        let sql = format!(
+            "UPDATE elections SET voting_starts_at = $1, voting_ends_at = $2, updated_at = now() WHERE id = $3 AND phase = $4 RETURNING {}",
+            ELECTION_COLUMNS
+        );
+
+        let updated = sqlx::query_as::<_, Election>(&sql)
+            .bind(starts_at)
+            .bind(ends_at)
+            .bind(uuid)
+            .bind(phase.as_str())
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| ElectionError::UnknownError(e.to_string()))?;
+
+        let election = updated.ok_or_else(|| ElectionError::SettingsLocked(format!(
+            "election {} is no longer in phase {}",
+            id,
+            phase.as_str()
+        )))?;
+
+        self.transform_election(election)
+    }
+
+    async fn open_voting(&self, id: String) -> Result<(domain::Election, i64), ElectionError> {
+        let uuid = Self::parse_id(&id)?;
+
+        let mut tx = self.postgres.begin().await
+            .map_err(|e| ElectionError::UnknownError(e.to_string()))?;
+
+        let election = self
+            .update_phase_tx(&mut tx, uuid, ElectionPhase::Registration, ElectionPhase::Voting)
+            .await?;
+
+        // Registrar syncs that commit after this point no longer change who may vote.
+        let frozen = sqlx::query(
+            r#"
+            INSERT INTO voter_rolls (election_id, voter_id, nim, nim_bidx, name, email, faculty, cohort, frozen_at)
+            SELECT $1, id, nim, nim_bidx, name, email, faculty, cohort, now()
+            FROM voters
+            WHERE eligible
+            "#,
+        )
+            .bind(uuid)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| ElectionError::UnknownError(e.to_string()))?;
+
+        // Lottery seeds are drawn only now that the candidates and rules are locked.
+        let lotteries: Vec<Uuid> = sqlx::query_scalar(
+            "SELECT id FROM contests WHERE election_id = $1 AND tie_break = 'lottery' FOR UPDATE",
+        )
+            .bind(uuid)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| ElectionError::UnknownError(e.to_string()))?;
+        let seeds: Vec<String> = lotteries.iter().map(|_| domain::draw_lottery_seed()).collect();
+        sqlx::query(
+            r#"
+            UPDATE contests c SET tie_break_seed = s.seed
+            FROM UNNEST($1::uuid[], $2::text[]) AS s(id, seed)
+            WHERE c.id = s.id
+            "#,
+        )
+            .bind(&lotteries)
+            .bind(&seeds)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| ElectionError::UnknownError(e.to_string()))?;
+
+        tx.commit().await
+            .map_err(|e| ElectionError::UnknownError(e.to_string()))?;
+
+        Ok((self.transform_election(election)?, frozen.rows_affected() as i64))
+    }
+}
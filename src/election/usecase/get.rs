@@ -0,0 +1,39 @@
+use crate::election::domain::{Election, ElectionError, Repository};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Election, ElectionError>;
+}
+
+pub struct GetElectionUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+}
+
+#[derive(Debug)]
+pub struct Request {
+    pub id: String,
+}
+
+impl<R: ?Sized + Send + Sync> GetElectionUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for GetElectionUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Election, ElectionError> {
+        self.repository.find_by_id(req.id).await
+    }
+}
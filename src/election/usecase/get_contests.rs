@@ -0,0 +1,41 @@
+use crate::election::domain::{Contest, ElectionError, Repository};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Vec<Contest>, ElectionError>;
+}
+
+pub struct GetContestsUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+}
+
+#[derive(Debug)]
+pub struct Request {
+    pub election_id: String,
+}
+
+impl<R: ?Sized + Send + Sync> GetContestsUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for GetContestsUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Vec<Contest>, ElectionError> {
+        // Surfaces NotFound for an unknown election instead of an empty list.
+        self.repository.find_by_id(req.election_id.clone()).await?;
+        self.repository.find_contests(req.election_id).await
+    }
+}
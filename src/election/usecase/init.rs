@@ -0,0 +1,45 @@
+use std::sync::Arc;
+use crate::election::domain::Repository;
+use crate::embargo::domain::StaffRoster;
+use crate::election::usecase::{get, get_contests, set_revote, set_tie_break, set_window, transition};
+use crate::election::usecase::get::GetElectionUseCase;
+use crate::election::usecase::get_contests::GetContestsUseCase;
+use crate::election::usecase::set_revote::SetRevoteUseCase;
+use crate::election::usecase::set_tie_break::SetTieBreakUseCase;
+use crate::election::usecase::set_window::SetWindowUseCase;
+use crate::election::usecase::transition::TransitionElectionUseCase;
+
+
+#[derive(Clone)]
+pub struct UseCase
+{
+    pub get: Arc<dyn get::Interactor>,
+    pub get_contests: Arc<dyn get_contests::Interactor>,
+    pub transition: Arc<dyn transition::Interactor>,
+    pub set_revote: Arc<dyn set_revote::Interactor>,
+    pub set_window: Arc<dyn set_window::Interactor>,
+    pub set_tie_break: Arc<dyn set_tie_break::Interactor>,
+}
+
+impl UseCase {
+
+    pub fn new(election_repo: Arc<dyn Repository + Send + Sync>, roster: Arc<StaffRoster>) -> Self {
+
+        let get_uc = GetElectionUseCase::new(election_repo.clone());
+        let get_contests_uc = GetContestsUseCase::new(election_repo.clone());
+        let transition_uc = TransitionElectionUseCase::new(election_repo.clone(), roster.clone());
+        let set_revote_uc = SetRevoteUseCase::new(election_repo.clone(), roster.clone());
+        let set_window_uc = SetWindowUseCase::new(election_repo.clone(), roster.clone());
+        let set_tie_break_uc = SetTieBreakUseCase::new(election_repo, roster);
+
+        Self {
+            get: Arc::new(get_uc),
+            get_contests: Arc::new(get_contests_uc),
+            transition: Arc::new(transition_uc),
+            set_revote: Arc::new(set_revote_uc),
+            set_window: Arc::new(set_window_uc),
+            set_tie_break: Arc::new(set_tie_break_uc),
+        }
+    }
+
+}
@@ -0,0 +1,9 @@
+mod init;
+pub mod get;
+pub mod get_contests;
+pub mod set_revote;
+pub mod set_tie_break;
+pub mod set_window;
+pub mod transition;
+
+pub use init::UseCase;
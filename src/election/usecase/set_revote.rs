@@ -0,0 +1,148 @@
+use crate::election::domain::{Election, ElectionError, ElectionPhase, Repository};
+use crate::embargo::domain::StaffRoster;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Election, ElectionError>;
+}
+
+pub struct SetRevoteUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    roster: Arc<StaffRoster>,
+}
+
+pub struct Request {
+    pub id: String,
+    pub allow_revote: bool,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("id", &self.id)
+            .field("allow_revote", &self.allow_revote)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> SetRevoteUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, roster: Arc<StaffRoster>) -> Self {
+        Self { repository, roster }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for SetRevoteUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Election, ElectionError> {
+        self.roster.acting(req.staff_token.as_deref(), "change election settings")?;
+
+        let election = self.repository.find_by_id(req.id.clone()).await?;
+
+        // Voters must know the rules before the first ballot is cast.
+        if !matches!(election.phase, ElectionPhase::Draft | ElectionPhase::Registration) {
+            return Err(ElectionError::SettingsLocked(format!(
+                "re-voting cannot be changed once election {} is in phase {}",
+                election.id,
+                election.phase.as_str()
+            )));
+        }
+
+        self.repository.update_allow_revote(req.id, election.phase, req.allow_revote).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+    use crate::election::domain::{self, ElectionError, ElectionPhase};
+    use crate::election::usecase::set_revote;
+    use crate::election::usecase::set_revote::Interactor;
+    use std::sync::Arc;
+    use crate::embargo::domain::{StaffRoster, hash_staff_token};
+
+    fn roster() -> Arc<StaffRoster> {
+        Arc::new(StaffRoster::parse(&format!(
+            "rina:committee:{},budi:observer:{}",
+            hash_staff_token("rina-token"),
+            hash_staff_token("budi-token")
+        )).unwrap())
+    }
+
+    fn election(phase: ElectionPhase, allow_revote: bool) -> domain::Election {
+        domain::Election {
+            id: "e1".to_string(),
+            name: "Student Council 2026".to_string(),
+            phase,
+            voting_starts_at: None,
+            voting_ends_at: None,
+            allow_revote,
+            created_at: chrono::Utc::now(),
+            updated_at: None,
+        }
+    }
+
+    fn request(token: Option<&str>) -> set_revote::Request {
+        set_revote::Request {
+            id: "e1".to_string(),
+            allow_revote: true,
+            staff_token: token.map(str::to_string),
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_set_revote_before_voting() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_by_id()
+            .returning(|_| Ok(election(ElectionPhase::Registration, false)));
+        repo_mock.expect_update_allow_revote()
+            .times(1)
+            .with(eq("e1".to_string()), eq(ElectionPhase::Registration), eq(true))
+            .returning(|_, phase, allow| Ok(election(phase, allow)));
+
+        let uc = set_revote::SetRevoteUseCase::new(Arc::new(repo_mock), roster());
+        let updated = uc.handle(request(Some("rina-token"))).await.unwrap();
+
+        assert!(updated.allow_revote);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_set_revote_locked_once_voting() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_by_id()
+            .returning(|_| Ok(election(ElectionPhase::Voting, false)));
+        repo_mock.expect_update_allow_revote().never();
+
+        let uc = set_revote::SetRevoteUseCase::new(Arc::new(repo_mock), roster());
+        let result = uc.handle(request(Some("rina-token"))).await;
+
+        assert!(matches!(result, Err(ElectionError::SettingsLocked(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_set_revote_needs_an_acting_staff_member() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_by_id().never();
+        repo_mock.expect_update_allow_revote().never();
+        let uc = set_revote::SetRevoteUseCase::new(Arc::new(repo_mock), roster());
+
+        let anonymous = uc.handle(request(None)).await;
+        assert!(matches!(anonymous, Err(ElectionError::Unauthorized(_))));
+
+        let observer = uc.handle(request(Some("budi-token"))).await;
+        assert!(matches!(observer, Err(ElectionError::Forbidden(_))));
+    }
+}
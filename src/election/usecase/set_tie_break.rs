@@ -0,0 +1,197 @@
+use crate::election::domain::{Contest, ElectionError, ElectionPhase, Repository, TieBreak};
+use crate::embargo::domain::StaffRoster;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Contest, ElectionError>;
+}
+
+pub struct SetTieBreakUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    roster: Arc<StaffRoster>,
+}
+
+pub struct Request {
+    pub election_id: String,
+    pub contest_id: String,
+    pub tie_break: TieBreak,
+    pub seed: Option<String>,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("election_id", &self.election_id)
+            .field("contest_id", &self.contest_id)
+            .field("tie_break", &self.tie_break)
+            .field("seed", &self.seed)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> SetTieBreakUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, roster: Arc<StaffRoster>) -> Self {
+        Self { repository, roster }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for SetTieBreakUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Contest, ElectionError> {
+        self.roster.acting(req.staff_token.as_deref(), "change election settings")?;
+
+        // The lottery seed is drawn by the server when voting opens, after the candidates and the
+        // rules are fixed, so nobody can pick one that favours a candidate.
+        if req.seed.is_some() {
+            return Err(ElectionError::InvalidInput(format!(
+                "a {} tie-break takes no seed; a lottery seed is drawn when voting opens",
+                req.tie_break.as_str()
+            )));
+        }
+
+        let election = self.repository.find_by_id(req.election_id.clone()).await?;
+
+        // Like the other rules, the tie-break is public before the first ballot.
+        if !matches!(election.phase, ElectionPhase::Draft | ElectionPhase::Registration) {
+            return Err(ElectionError::SettingsLocked(format!(
+                "tie-breaks cannot be changed once election {} is in phase {}",
+                election.id,
+                election.phase.as_str()
+            )));
+        }
+
+        let contests = self.repository.find_contests(req.election_id.clone()).await?;
+        if !contests.iter().any(|c| c.id == req.contest_id) {
+            return Err(ElectionError::NotFound(format!(
+                "contest {} not found in election {}", req.contest_id, req.election_id
+            )));
+        }
+
+        self.repository
+            .update_tie_break(req.election_id, req.contest_id, election.phase, req.tie_break)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+    use crate::election::domain::{self, ElectionError, ElectionPhase, TieBreak};
+    use crate::election::usecase::set_tie_break;
+    use crate::election::usecase::set_tie_break::Interactor;
+    use std::sync::Arc;
+    use crate::embargo::domain::{StaffRoster, hash_staff_token};
+
+    fn roster() -> Arc<StaffRoster> {
+        Arc::new(StaffRoster::parse(&format!(
+            "rina:committee:{},budi:observer:{}",
+            hash_staff_token("rina-token"),
+            hash_staff_token("budi-token")
+        )).unwrap())
+    }
+
+    fn election(phase: ElectionPhase) -> domain::Election {
+        domain::Election {
+            id: "e1".to_string(),
+            name: "Student Council 2026".to_string(),
+            phase,
+            voting_starts_at: None,
+            voting_ends_at: None,
+            allow_revote: false,
+            created_at: chrono::Utc::now(),
+            updated_at: None,
+        }
+    }
+
+    fn contest(tie_break: TieBreak, tie_break_seed: Option<String>) -> domain::Contest {
+        domain::Contest {
+            id: "president".to_string(),
+            election_id: "e1".to_string(),
+            name: "President".to_string(),
+            candidate_ids: vec![],
+            tie_break,
+            tie_break_seed,
+        }
+    }
+
+    fn request(tie_break: TieBreak, seed: Option<&str>) -> set_tie_break::Request {
+        set_tie_break::Request {
+            election_id: "e1".to_string(),
+            contest_id: "president".to_string(),
+            tie_break,
+            seed: seed.map(str::to_string),
+            staff_token: Some("rina-token".to_string()),
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_set_lottery_leaves_the_seed_to_be_drawn() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_by_id().returning(|_| Ok(election(ElectionPhase::Registration)));
+        repo_mock.expect_find_contests().returning(|_| Ok(vec![contest(TieBreak::Runoff, None)]));
+        repo_mock.expect_update_tie_break()
+            .times(1)
+            .with(
+                eq("e1".to_string()),
+                eq("president".to_string()),
+                eq(ElectionPhase::Registration),
+                eq(TieBreak::Lottery),
+            )
+            .returning(|_, _, _, tie_break| Ok(contest(tie_break, None)));
+
+        let uc = set_tie_break::SetTieBreakUseCase::new(Arc::new(repo_mock), roster());
+        let updated = uc.handle(request(TieBreak::Lottery, None)).await.unwrap();
+
+        assert_eq!(updated.tie_break, TieBreak::Lottery);
+        assert_eq!(updated.tie_break_seed, None);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_set_tie_break_refuses_a_chosen_seed_and_locks_once_voting() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_by_id().returning(|_| Ok(election(ElectionPhase::Voting)));
+        repo_mock.expect_update_tie_break().never();
+
+        let uc = set_tie_break::SetTieBreakUseCase::new(Arc::new(repo_mock), roster());
+
+        let result = uc.handle(request(TieBreak::Lottery, Some("council-2026"))).await;
+        assert!(matches!(result, Err(ElectionError::InvalidInput(_))));
+
+        let result = uc.handle(request(TieBreak::Registration, None)).await;
+        assert!(matches!(result, Err(ElectionError::SettingsLocked(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_set_tie_break_needs_an_acting_staff_member() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_by_id().never();
+        repo_mock.expect_update_tie_break().never();
+        let uc = set_tie_break::SetTieBreakUseCase::new(Arc::new(repo_mock), roster());
+
+        let anonymous = uc.handle(set_tie_break::Request {
+            staff_token: None,
+            ..request(TieBreak::Runoff, None)
+        }).await;
+        assert!(matches!(anonymous, Err(ElectionError::Unauthorized(_))));
+
+        let observer = uc.handle(set_tie_break::Request {
+            staff_token: Some("budi-token".to_string()),
+            ..request(TieBreak::Runoff, None)
+        }).await;
+        assert!(matches!(observer, Err(ElectionError::Forbidden(_))));
+    }
+}
@@ -0,0 +1,165 @@
+use crate::election::domain::{Election, ElectionError, ElectionPhase, Repository};
+use crate::embargo::domain::StaffRoster;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Election, ElectionError>;
+}
+
+pub struct SetWindowUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    roster: Arc<StaffRoster>,
+}
+
+pub struct Request {
+    pub id: String,
+    /// Left open, voting starts as soon as the election enters the voting phase.
+    pub voting_starts_at: Option<DateTime<Utc>>,
+    /// Left open, voting runs until the election is closed.
+    pub voting_ends_at: Option<DateTime<Utc>>,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("id", &self.id)
+            .field("voting_starts_at", &self.voting_starts_at)
+            .field("voting_ends_at", &self.voting_ends_at)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> SetWindowUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, roster: Arc<StaffRoster>) -> Self {
+        Self { repository, roster }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for SetWindowUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Election, ElectionError> {
+        self.roster.acting(req.staff_token.as_deref(), "change election settings")?;
+
+        if let (Some(starts_at), Some(ends_at)) = (req.voting_starts_at, req.voting_ends_at)
+            && starts_at >= ends_at
+        {
+            return Err(ElectionError::InvalidInput("voting must start before it ends".into()));
+        }
+
+        let election = self.repository.find_by_id(req.id.clone()).await?;
+
+        // Like re-voting, the window is part of the rules voters are told before voting opens.
+        if !matches!(election.phase, ElectionPhase::Draft | ElectionPhase::Registration) {
+            return Err(ElectionError::SettingsLocked(format!(
+                "the voting window cannot be changed once election {} is in phase {}",
+                election.id,
+                election.phase.as_str()
+            )));
+        }
+
+        self.repository
+            .update_voting_window(req.id, election.phase, req.voting_starts_at, req.voting_ends_at)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::election::domain::{self, ElectionError, ElectionPhase};
+    use crate::election::usecase::set_window;
+    use crate::election::usecase::set_window::Interactor;
+    use std::sync::Arc;
+    use crate::embargo::domain::{StaffRoster, hash_staff_token};
+
+    fn roster() -> Arc<StaffRoster> {
+        Arc::new(StaffRoster::parse(&format!(
+            "rina:committee:{},budi:observer:{}",
+            hash_staff_token("rina-token"),
+            hash_staff_token("budi-token")
+        )).unwrap())
+    }
+
+    fn election(phase: ElectionPhase) -> domain::Election {
+        domain::Election {
+            id: "e1".to_string(),
+            name: "Student Council 2026".to_string(),
+            phase,
+            voting_starts_at: None,
+            voting_ends_at: None,
+            allow_revote: false,
+            created_at: chrono::Utc::now(),
+            updated_at: None,
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_set_window_refuses_an_end_before_the_start() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_by_id().never();
+        repo_mock.expect_update_voting_window().never();
+
+        let uc = set_window::SetWindowUseCase::new(Arc::new(repo_mock), roster());
+        let starts_at = chrono::Utc::now();
+        let result = uc.handle(set_window::Request {
+            id: "e1".to_string(),
+            voting_starts_at: Some(starts_at),
+            voting_ends_at: Some(starts_at - chrono::Duration::hours(1)),
+            staff_token: Some("rina-token".to_string()),
+        }).await;
+
+        assert!(matches!(result, Err(ElectionError::InvalidInput(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_set_window_locked_once_voting() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_by_id().returning(|_| Ok(election(ElectionPhase::Voting)));
+        repo_mock.expect_update_voting_window().never();
+
+        let uc = set_window::SetWindowUseCase::new(Arc::new(repo_mock), roster());
+        let result = uc.handle(set_window::Request {
+            id: "e1".to_string(),
+            voting_starts_at: None,
+            voting_ends_at: Some(chrono::Utc::now()),
+            staff_token: Some("rina-token".to_string()),
+        }).await;
+
+        assert!(matches!(result, Err(ElectionError::SettingsLocked(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_set_window_needs_an_acting_staff_member() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_by_id().never();
+        repo_mock.expect_update_voting_window().never();
+        let uc = set_window::SetWindowUseCase::new(Arc::new(repo_mock), roster());
+
+        let request = |token: Option<&str>| set_window::Request {
+            id: "e1".to_string(),
+            voting_starts_at: None,
+            voting_ends_at: Some(chrono::Utc::now()),
+            staff_token: token.map(str::to_string),
+        };
+
+        let anonymous = uc.handle(request(None)).await;
+        assert!(matches!(anonymous, Err(ElectionError::Unauthorized(_))));
+
+        let observer = uc.handle(request(Some("budi-token"))).await;
+        assert!(matches!(observer, Err(ElectionError::Forbidden(_))));
+    }
+}
@@ -0,0 +1,182 @@
+use crate::election::domain::{Election, ElectionError, ElectionPhase, Repository};
+use crate::embargo::domain::StaffRoster;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Response, ElectionError>;
+}
+
+pub struct TransitionElectionUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    roster: Arc<StaffRoster>,
+}
+
+pub struct Request {
+    pub id: String,
+    pub phase: ElectionPhase,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("id", &self.id)
+            .field("phase", &self.phase)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Response {
+    pub election: Election,
+    /// Number of voters frozen onto the roll; only set when the election enters voting.
+    pub roll_size: Option<i64>,
+}
+
+impl<R: ?Sized + Send + Sync> TransitionElectionUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, roster: Arc<StaffRoster>) -> Self {
+        Self { repository, roster }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for TransitionElectionUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Response, ElectionError> {
+        self.roster.acting(req.staff_token.as_deref(), "move elections between phases")?;
+
+        let election = self.repository.find_by_id(req.id.clone()).await?;
+
+        if !election.phase.can_transition_to(req.phase) {
+            return Err(ElectionError::InvalidTransition(format!(
+                "cannot move election {} from {} to {}",
+                election.id,
+                election.phase.as_str(),
+                req.phase.as_str()
+            )));
+        }
+
+        if req.phase == ElectionPhase::Voting {
+            let (election, roll_size) = self.repository.open_voting(req.id).await?;
+            return Ok(Response { election, roll_size: Some(roll_size) });
+        }
+
+        let election = self.repository.update_phase(req.id, election.phase, req.phase).await?;
+        Ok(Response { election, roll_size: None })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+    use crate::election::domain::{self, ElectionError, ElectionPhase};
+    use crate::election::usecase::transition;
+    use crate::election::usecase::transition::Interactor;
+    use crate::embargo::domain::{StaffRoster, hash_staff_token};
+    use std::sync::Arc;
+
+    fn roster() -> Arc<StaffRoster> {
+        Arc::new(StaffRoster::parse(&format!(
+            "rina:admin:{},budi:observer:{}",
+            hash_staff_token("rina-token"),
+            hash_staff_token("budi-token")
+        )).unwrap())
+    }
+
+    fn request(phase: ElectionPhase, staff_token: Option<&str>) -> transition::Request {
+        transition::Request { id: "e1".to_string(), phase, staff_token: staff_token.map(str::to_string) }
+    }
+
+    fn election(phase: ElectionPhase) -> domain::Election {
+        domain::Election {
+            id: "e1".to_string(),
+            name: "Student Council 2026".to_string(),
+            phase,
+            voting_starts_at: None,
+            voting_ends_at: None,
+            allow_revote: false,
+            created_at: chrono::Utc::now(),
+            updated_at: None,
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_entering_voting_freezes_roll() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_by_id()
+            .with(eq("e1".to_string()))
+            .times(1)
+            .returning(|_| Ok(election(ElectionPhase::Registration)));
+        repo_mock.expect_open_voting()
+            .with(eq("e1".to_string()))
+            .times(1)
+            .returning(|_| Ok((election(ElectionPhase::Voting), 42)));
+        repo_mock.expect_update_phase().times(0);
+
+        let uc = transition::TransitionElectionUseCase::new(Arc::new(repo_mock), roster());
+        let res = uc.handle(request(ElectionPhase::Voting, Some("rina-token"))).await.unwrap();
+
+        assert_eq!(res.election.phase, ElectionPhase::Voting);
+        assert_eq!(res.roll_size, Some(42));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_other_transitions_do_not_touch_roll() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_by_id()
+            .times(1)
+            .returning(|_| Ok(election(ElectionPhase::Voting)));
+        repo_mock.expect_update_phase()
+            .with(eq("e1".to_string()), eq(ElectionPhase::Voting), eq(ElectionPhase::Closed))
+            .times(1)
+            .returning(|_, _, _| Ok(election(ElectionPhase::Closed)));
+        repo_mock.expect_open_voting().times(0);
+
+        let uc = transition::TransitionElectionUseCase::new(Arc::new(repo_mock), roster());
+        let res = uc.handle(request(ElectionPhase::Closed, Some("rina-token"))).await.unwrap();
+
+        assert_eq!(res.election.phase, ElectionPhase::Closed);
+        assert_eq!(res.roll_size, None);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_skipping_a_phase_is_rejected() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_by_id()
+            .times(1)
+            .returning(|_| Ok(election(ElectionPhase::Draft)));
+        repo_mock.expect_open_voting().times(0);
+
+        let uc = transition::TransitionElectionUseCase::new(Arc::new(repo_mock), roster());
+        let res = uc.handle(request(ElectionPhase::Voting, Some("rina-token"))).await;
+
+        assert!(matches!(res, Err(ElectionError::InvalidTransition(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_transition_needs_an_acting_staff_member() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_by_id().times(0);
+        repo_mock.expect_open_voting().times(0);
+
+        let uc = transition::TransitionElectionUseCase::new(Arc::new(repo_mock), roster());
+
+        let res = uc.handle(request(ElectionPhase::Voting, None)).await;
+        assert!(matches!(res, Err(ElectionError::Unauthorized(_))));
+        let res = uc.handle(request(ElectionPhase::Voting, Some("budi-token"))).await;
+        assert!(matches!(res, Err(ElectionError::Forbidden(_))));
+    }
+}
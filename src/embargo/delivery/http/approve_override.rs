@@ -0,0 +1,73 @@
+use crate::embargo::delivery::http::errors::error_response;
+use crate::embargo::delivery::http::request_override::staff_token;
+use crate::embargo::usecase::approve_override::*;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+
+pub async fn approve_override(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+
+    let request = Request {
+        override_id: path.into_inner(),
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.embargo_uc.approve_override.handle(request).await {
+        Ok(approved) => HttpResponse::Ok().json(response::success(
+            Some(approved),
+            "Successfully processed embargo override".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::approve_override;
+    use actix_web::{App, http::StatusCode, test, web};
+    use async_trait::async_trait;
+    use std::sync::Arc;
+    use crate::embargo::domain::{EmbargoError, Override};
+    use crate::embargo::usecase::approve_override::{Interactor, Request};
+    use crate::utils::app;
+
+    #[actix_rt::test]
+    async fn test_approve_override_rejects_the_requester() {
+        struct MockApprove;
+
+        #[async_trait]
+        impl Interactor for MockApprove {
+            async fn handle(&self, req: Request) -> Result<Override, EmbargoError> {
+                assert_eq!(req.override_id, "o1");
+                assert_eq!(req.staff_token.as_deref(), Some("rina-token"));
+                Err(EmbargoError::SamePerson("override o1 must be approved by someone other than rina".into()))
+            }
+        }
+
+        let mut embargo_uc = app::AppHandlerData::mocked().embargo_uc;
+        embargo_uc.approve_override = Arc::new(MockApprove);
+        let app_data = web::Data::new(app::AppHandlerData { embargo_uc, ..app::AppHandlerData::mocked() });
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_data)
+                .route("/embargo-overrides/{id}/approve", web::post().to(approve_override)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/embargo-overrides/o1/approve")
+            .insert_header(("Authorization", "Bearer rina-token"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error_code"], "EMBARGO_SAME_PERSON");
+    }
+}
@@ -0,0 +1,54 @@
+use crate::embargo::domain::EmbargoError;
+use crate::utils::response;
+use actix_web::HttpResponse;
+
+pub fn error_response(e: &EmbargoError) -> HttpResponse {
+    println!("Error: {}", e);
+    match e {
+        EmbargoError::NotFound(msg) => HttpResponse::NotFound().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "EMBARGO_NOT_FOUND".into(),
+        )),
+        EmbargoError::InvalidInput(msg) => HttpResponse::BadRequest().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "EMBARGO_INVALID_INPUT".into(),
+        )),
+        EmbargoError::Unauthorized(msg) => HttpResponse::Unauthorized().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "EMBARGO_UNAUTHORIZED".into(),
+        )),
+        EmbargoError::Forbidden(msg) => HttpResponse::Forbidden().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "EMBARGO_FORBIDDEN".into(),
+        )),
+        EmbargoError::Embargoed(msg) => HttpResponse::Forbidden().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "RESULTS_EMBARGOED".into(),
+        )),
+        EmbargoError::SamePerson(msg) => HttpResponse::Conflict().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "EMBARGO_SAME_PERSON".into(),
+        )),
+        EmbargoError::AlreadyApproved(msg) => HttpResponse::Conflict().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "EMBARGO_ALREADY_APPROVED".into(),
+        )),
+        EmbargoError::OverrideExpired(msg) => HttpResponse::Gone().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "EMBARGO_OVERRIDE_EXPIRED".into(),
+        )),
+        EmbargoError::UnknownError(_) => HttpResponse::InternalServerError().json(response::error::<()>(
+            None,
+            "failed process data".into(),
+            "-1".into(),
+        )),
+    }
+}
@@ -0,0 +1,11 @@
+use actix_web::web;
+use crate::embargo::delivery::http::approve_override::approve_override;
+use crate::embargo::delivery::http::list_audit::list_audit;
+use crate::embargo::delivery::http::request_override::request_override;
+
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/elections/{id}/embargo-overrides", web::post().to(request_override))
+        .route("/embargo-overrides/{id}/approve", web::post().to(approve_override))
+        .route("/elections/{id}/audit", web::get().to(list_audit));
+}
@@ -0,0 +1,27 @@
+use crate::embargo::delivery::http::errors::error_response;
+use crate::embargo::delivery::http::request_override::staff_token;
+use crate::embargo::usecase::list_audit::*;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+
+pub async fn list_audit(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+
+    let request = Request {
+        election_id: path.into_inner(),
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.embargo_uc.list_audit.handle(request).await {
+        Ok(entries) => HttpResponse::Ok().json(response::success(
+            Some(entries),
+            "Successfully processed audit trail".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
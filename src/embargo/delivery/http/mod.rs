@@ -0,0 +1,10 @@
+mod handler;
+mod errors;
+mod approve_override;
+mod list_audit;
+mod request_override;
+
+pub use approve_override::*;
+pub use list_audit::*;
+pub use request_override::*;
+pub use handler::*;
@@ -0,0 +1,44 @@
+use crate::embargo::delivery::http::errors::error_response;
+use crate::embargo::usecase::request_override::*;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+pub struct RequestOverrideBody {
+    pub reason: String,
+}
+
+/// Reads the staff token from `Authorization: Bearer`, if any. Results endpoints take it too.
+pub(crate) fn staff_token(req: &HttpRequest) -> Option<String> {
+    req.headers()
+        .get("Authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+}
+
+pub async fn request_override(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<RequestOverrideBody>,
+) -> HttpResponse {
+
+    let request = Request {
+        election_id: path.into_inner(),
+        staff_token: staff_token(&http_request),
+        reason: body.into_inner().reason,
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.embargo_uc.request_override.handle(request).await {
+        Ok(created) => HttpResponse::Created().json(response::success(
+            Some(created),
+            "Successfully processed embargo override".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
@@ -0,0 +1,135 @@
+use crate::election::domain::ElectionPhase;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    Admin,
+    Committee,
+    /// May look once results are out to staff, but never acts.
+    Observer,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Admin => "admin",
+            Role::Committee => "committee",
+            Role::Observer => "observer",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Role> {
+        match value {
+            "admin" => Some(Role::Admin),
+            "committee" => Some(Role::Committee),
+            "observer" => Some(Role::Observer),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct StaffMember {
+    pub name: String,
+    pub role: Role,
+}
+
+impl StaffMember {
+    /// Admins and committee members may count, and request or approve overrides.
+    pub fn can_act(&self) -> bool {
+        matches!(self.role, Role::Admin | Role::Committee)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ResultsAction {
+    Read,
+    Compute,
+}
+
+impl ResultsAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ResultsAction::Read => "results.read",
+            ResultsAction::Compute => "results.compute",
+        }
+    }
+}
+
+/// What the embargo let through.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Access {
+    pub phase: ElectionPhase,
+    pub caller: Option<StaffMember>,
+    /// Granted before voting closed, under an override.
+    pub early: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct NewOverride {
+    pub election_id: String,
+    pub requested_by: String,
+    pub reason: String,
+}
+
+/// Lets two staff members see one election's results before voting closes. One requests it,
+/// a different one approves it, and it then holds for a limited time.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Override {
+    pub id: String,
+    pub election_id: String,
+    pub requested_by: String,
+    pub reason: String,
+    pub requested_at: DateTime<Utc>,
+    pub approved_by: Option<String>,
+    pub approved_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOutcome {
+    Allowed,
+    Denied,
+}
+
+impl AuditOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditOutcome::Allowed => "allowed",
+            AuditOutcome::Denied => "denied",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<AuditOutcome> {
+        match value {
+            "allowed" => Some(AuditOutcome::Allowed),
+            "denied" => Some(AuditOutcome::Denied),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct NewAuditEntry {
+    pub election_id: String,
+    /// Staff name, or unset for an anonymous caller.
+    pub actor: Option<String>,
+    /// e.g. `results.read` or `override.approve`.
+    pub action: String,
+    pub outcome: AuditOutcome,
+    pub detail: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AuditEntry {
+    pub election_id: String,
+    pub actor: Option<String>,
+    pub action: String,
+    pub outcome: AuditOutcome,
+    pub detail: String,
+    pub at: DateTime<Utc>,
+}
@@ -0,0 +1,38 @@
+use crate::election::domain::ElectionError;
+use thiserror::Error;
+
+
+#[derive(Debug, Error)]
+#[derive(Clone)]
+pub enum EmbargoError {
+    #[error("EmbargoError::NotFound: {0}")]
+    NotFound(String),
+    #[error("EmbargoError::InvalidInput: {0}")]
+    InvalidInput(String),
+    /// No staff token, or one that is not on the roster.
+    #[error("EmbargoError::Unauthorized: {0}")]
+    Unauthorized(String),
+    /// The caller's role does not allow this.
+    #[error("EmbargoError::Forbidden: {0}")]
+    Forbidden(String),
+    /// Results are not out yet for this caller.
+    #[error("EmbargoError::Embargoed: {0}")]
+    Embargoed(String),
+    #[error("EmbargoError::SamePerson: {0}")]
+    SamePerson(String),
+    #[error("EmbargoError::AlreadyApproved: {0}")]
+    AlreadyApproved(String),
+    #[error("EmbargoError::OverrideExpired: {0}")]
+    OverrideExpired(String),
+    #[error("EmbargoError::UnknownError: {0}")]
+    UnknownError(String),
+}
+
+impl From<ElectionError> for EmbargoError {
+    fn from(e: ElectionError) -> Self {
+        match e {
+            ElectionError::NotFound(msg) => EmbargoError::NotFound(msg),
+            other => EmbargoError::UnknownError(other.to_string()),
+        }
+    }
+}
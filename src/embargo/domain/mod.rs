@@ -0,0 +1,11 @@
+mod entities;
+mod errors;
+mod policy;
+mod repository;
+mod roster;
+
+pub use entities::*;
+pub use errors::*;
+pub use policy::*;
+pub use repository::*;
+pub use roster::*;
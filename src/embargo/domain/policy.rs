@@ -0,0 +1,76 @@
+use crate::election::domain::ElectionPhase;
+use crate::embargo::domain::entities::{ResultsAction, StaffMember};
+use crate::embargo::domain::errors::EmbargoError;
+
+/// Who may see results, and when:
+///
+/// - counting always takes an admin or committee member;
+/// - once published, anyone may read them;
+/// - once closed, any staff member may;
+/// - before that nobody may, admins included, unless they are party to an approved override.
+pub fn results_access(
+    phase: ElectionPhase,
+    caller: Option<&StaffMember>,
+    action: ResultsAction,
+    has_override: bool,
+) -> Result<(), EmbargoError> {
+    if action == ResultsAction::Compute {
+        match caller {
+            None => return Err(EmbargoError::Unauthorized("counting needs a staff token".into())),
+            Some(member) if !member.can_act() => {
+                return Err(EmbargoError::Forbidden(format!("{} may not count results", member.role.as_str())));
+            }
+            Some(_) => {}
+        }
+    }
+
+    match phase {
+        ElectionPhase::Published => Ok(()),
+        ElectionPhase::Closed if caller.is_some() => Ok(()),
+        ElectionPhase::Closed => Err(EmbargoError::Embargoed("results are not published yet".into())),
+        _ if caller.is_some() && has_override => Ok(()),
+        _ => Err(EmbargoError::Embargoed(format!("voting has not closed; election is in phase {}", phase.as_str()))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::embargo::domain::Role;
+
+    fn member(role: Role) -> StaffMember {
+        StaffMember { name: "rina".to_string(), role }
+    }
+
+    #[test]
+    fn test_nobody_reads_before_close_without_an_override() {
+        let admin = member(Role::Admin);
+
+        assert!(matches!(
+            results_access(ElectionPhase::Voting, Some(&admin), ResultsAction::Read, false),
+            Err(EmbargoError::Embargoed(_))
+        ));
+        assert!(results_access(ElectionPhase::Voting, Some(&admin), ResultsAction::Read, true).is_ok());
+        assert!(results_access(ElectionPhase::Voting, None, ResultsAction::Read, true).is_err());
+
+        assert!(matches!(
+            results_access(ElectionPhase::Closed, None, ResultsAction::Read, false),
+            Err(EmbargoError::Embargoed(_))
+        ));
+        assert!(results_access(ElectionPhase::Closed, Some(&member(Role::Observer)), ResultsAction::Read, false).is_ok());
+        assert!(results_access(ElectionPhase::Published, None, ResultsAction::Read, false).is_ok());
+    }
+
+    #[test]
+    fn test_counting_takes_an_acting_role() {
+        assert!(matches!(
+            results_access(ElectionPhase::Published, None, ResultsAction::Compute, false),
+            Err(EmbargoError::Unauthorized(_))
+        ));
+        assert!(matches!(
+            results_access(ElectionPhase::Closed, Some(&member(Role::Observer)), ResultsAction::Compute, false),
+            Err(EmbargoError::Forbidden(_))
+        ));
+        assert!(results_access(ElectionPhase::Closed, Some(&member(Role::Committee)), ResultsAction::Compute, false).is_ok());
+    }
+}
@@ -0,0 +1,28 @@
+use crate::embargo::domain::entities::{AuditEntry, NewAuditEntry, NewOverride, Override};
+use crate::embargo::domain::errors::EmbargoError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait Repository: Send + Sync {
+    async fn create_override(&self, request: NewOverride) -> Result<Override, EmbargoError>;
+
+    async fn find_override(&self, id: String) -> Result<Override, EmbargoError>;
+
+    /// Fails with `AlreadyApproved` when someone approved it first.
+    async fn approve_override(
+        &self,
+        id: String,
+        approved_by: String,
+        expires_at: DateTime<Utc>,
+    ) -> Result<Override, EmbargoError>;
+
+    /// An approved, unexpired override of the election that `staff_name` requested or approved.
+    async fn find_active_override(&self, election_id: String, staff_name: String) -> Result<Option<Override>, EmbargoError>;
+
+    async fn record_audit(&self, entry: NewAuditEntry) -> Result<(), EmbargoError>;
+
+    async fn find_audit(&self, election_id: String) -> Result<Vec<AuditEntry>, EmbargoError>;
+}
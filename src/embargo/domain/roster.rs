@@ -0,0 +1,103 @@
+use crate::embargo::domain::entities::{Role, StaffMember};
+use crate::embargo::domain::errors::EmbargoError;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+pub fn hash_staff_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.trim().as_bytes()))
+}
+
+/// Staff allowed past the embargo, from config as `name:role:sha256,...`. Only hashes of
+/// the tokens are configured, so the config alone does not let anyone in.
+#[derive(Debug, Clone, Default)]
+pub struct StaffRoster {
+    members: HashMap<String, StaffMember>,
+}
+
+impl StaffRoster {
+    pub fn parse(config: &str) -> Result<Self, EmbargoError> {
+        let mut members = HashMap::new();
+
+        for entry in config.split(',').filter(|e| !e.trim().is_empty()) {
+            let mut parts = entry.trim().split(':');
+            let (Some(name), Some(role), Some(hash), None) = (parts.next(), parts.next(), parts.next(), parts.next()) else {
+                return Err(EmbargoError::InvalidInput("staff entries must look like name:role:sha256".into()));
+            };
+            let role = Role::parse(role)
+                .ok_or_else(|| EmbargoError::InvalidInput(format!("unknown role {:?} for {}", role, name)))?;
+            if name.is_empty() || hash.len() != 64 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(EmbargoError::InvalidInput(format!("invalid staff entry for {:?}", name)));
+            }
+
+            members.insert(hash.to_lowercase(), StaffMember { name: name.to_string(), role });
+        }
+
+        Ok(StaffRoster { members })
+    }
+
+    pub fn resolve(&self, token: &str) -> Option<StaffMember> {
+        self.members.get(&hash_staff_token(token)).cloned()
+    }
+
+    /// The admin or committee member behind `token`, for a usecase that only they may run.
+    /// `action` completes "cannot …" in the refusal, e.g. `"issue voting codes"`.
+    pub fn acting(&self, token: Option<&str>, action: &str) -> Result<StaffMember, StaffDenied> {
+        let member = token
+            .and_then(|token| self.resolve(token))
+            .ok_or_else(|| StaffDenied::Unauthenticated(format!("a staff token is needed to {}", action)))?;
+        if !member.can_act() {
+            return Err(StaffDenied::NotAllowed(format!(
+                "{} is an {} and cannot {}", member.name, member.role.as_str(), action
+            )));
+        }
+        Ok(member)
+    }
+}
+
+/// Why a caller was refused by `StaffRoster::acting`. Each module maps it onto its own
+/// unauthorized (401) and forbidden (403) errors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StaffDenied {
+    /// No token, or one that is not on the roster.
+    Unauthenticated(String),
+    /// A member whose role does not let them act.
+    NotAllowed(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roster_resolves_tokens_by_hash() {
+        let roster = StaffRoster::parse(&format!(
+            "rina:admin:{},budi:observer:{}",
+            hash_staff_token("rina-token"),
+            hash_staff_token("budi-token").to_uppercase()
+        )).unwrap();
+
+        assert_eq!(roster.resolve("rina-token"), Some(StaffMember { name: "rina".to_string(), role: Role::Admin }));
+        assert_eq!(roster.resolve("budi-token").map(|m| m.role), Some(Role::Observer));
+        assert_eq!(roster.resolve("guess"), None);
+
+        assert!(StaffRoster::parse("rina:root:abc").is_err());
+        assert!(StaffRoster::parse("rina:admin").is_err());
+    }
+
+    #[test]
+    fn test_only_admins_and_committee_act() {
+        let roster = StaffRoster::parse(&format!(
+            "rina:committee:{},budi:observer:{}",
+            hash_staff_token("rina-token"),
+            hash_staff_token("budi-token")
+        )).unwrap();
+
+        assert_eq!(roster.acting(Some("rina-token"), "merge stations").map(|m| m.name), Ok("rina".to_string()));
+        assert_eq!(
+            roster.acting(Some("budi-token"), "merge stations"),
+            Err(StaffDenied::NotAllowed("budi is an observer and cannot merge stations".to_string()))
+        );
+        assert!(matches!(roster.acting(Some("guess"), "merge stations"), Err(StaffDenied::Unauthenticated(_))));
+        assert!(matches!(roster.acting(None, "merge stations"), Err(StaffDenied::Unauthenticated(_))));
+    }
+}
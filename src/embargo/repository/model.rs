@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct Override {
+    pub id: Uuid,
+    pub election_id: Uuid,
+    pub requested_by: String,
+    pub reason: String,
+    pub requested_at: DateTime<Utc>,
+    pub approved_by: Option<String>,
+    pub approved_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct AuditEntry {
+    pub election_id: Uuid,
+    pub actor: Option<String>,
+    pub action: String,
+    pub outcome: String,
+    pub detail: String,
+    pub at: DateTime<Utc>,
+}
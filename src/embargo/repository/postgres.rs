@@ -0,0 +1,192 @@
+use crate::embargo::domain;
+use crate::embargo::domain::EmbargoError;
+use crate::embargo::domain::Repository;
+use crate::embargo::repository::model::{AuditEntry, Override};
+use crate::infrastructure::database::postgres::Postgres;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+const OVERRIDE_COLUMNS: &str =
+    "id, election_id, requested_by, reason, requested_at, approved_by, approved_at, expires_at";
+
+pub struct PostgresRepo {
+    postgres: sqlx::PgPool,
+}
+impl PostgresRepo {
+    pub async fn new(postgres: Arc<Mutex<Postgres>>) -> anyhow::Result<Self> {
+        let guard = postgres.lock().await;
+        let pool = guard
+            .pool()
+            .ok_or_else(|| anyhow!("DB pool is not initialized"))?;
+        Ok(PostgresRepo { postgres: pool })
+    }
+
+    fn transform_override(&self, o: Override) -> domain::Override {
+        domain::Override {
+            id: o.id.to_string(),
+            election_id: o.election_id.to_string(),
+            requested_by: o.requested_by,
+            reason: o.reason,
+            requested_at: o.requested_at,
+            approved_by: o.approved_by,
+            approved_at: o.approved_at,
+            expires_at: o.expires_at,
+        }
+    }
+
+    fn parse_id(id: &str) -> Result<Uuid, EmbargoError> {
+        Uuid::parse_str(id).map_err(|_| EmbargoError::NotFound(format!("{} not found", id)))
+    }
+}
+
+#[async_trait]
+impl Repository for PostgresRepo {
+    async fn create_override(&self, request: domain::NewOverride) -> Result<domain::Override, EmbargoError> {
+        let election_id = Self::parse_id(&request.election_id)?;
+        let sql = format!(
+            r#"
+            INSERT INTO embargo_overrides (election_id, requested_by, reason) VALUES ($1, $2, $3)
+            RETURNING {}
+            "#,
+            OVERRIDE_COLUMNS
+        );
+
+        let created = sqlx::query_as::<_, Override>(&sql)
+            .bind(election_id)
+            .bind(&request.requested_by)
+            .bind(&request.reason)
+            .fetch_one(&self.postgres)
+            .await
+            .map_err(|e| match e {
+                sqlx::Error::Database(db) if db.is_foreign_key_violation() => {
+                    EmbargoError::NotFound(format!("election {} not found", request.election_id))
+                }
+                _ => EmbargoError::UnknownError(e.to_string()),
+            })?;
+
+        Ok(self.transform_override(created))
+    }
+
+    async fn find_override(&self, id: String) -> Result<domain::Override, EmbargoError> {
+        let uuid = Self::parse_id(&id)?;
+        let sql = format!(r#"SELECT {} FROM embargo_overrides WHERE id = $1"#, OVERRIDE_COLUMNS);
+
+        let found = sqlx::query_as::<_, Override>(&sql)
+            .bind(uuid)
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| EmbargoError::UnknownError(e.to_string()))?
+            .ok_or_else(|| EmbargoError::NotFound(format!("override {} not found", id)))?;
+
+        Ok(self.transform_override(found))
+    }
+
+    async fn approve_override(
+        &self,
+        id: String,
+        approved_by: String,
+        expires_at: DateTime<Utc>,
+    ) -> Result<domain::Override, EmbargoError> {
+        let uuid = Self::parse_id(&id)?;
+        let sql = format!(
+            r#"
+            UPDATE embargo_overrides SET approved_by = $2, approved_at = now(), expires_at = $3
+            WHERE id = $1 AND approved_by IS NULL
+            RETURNING {}
+            "#,
+            OVERRIDE_COLUMNS
+        );
+
+        let approved = sqlx::query_as::<_, Override>(&sql)
+            .bind(uuid)
+            .bind(&approved_by)
+            .bind(expires_at)
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| match e {
+                sqlx::Error::Database(db) if db.is_check_violation() => {
+                    EmbargoError::SamePerson(format!("{} requested override {} themselves", approved_by, id))
+                }
+                _ => EmbargoError::UnknownError(e.to_string()),
+            })?
+            .ok_or_else(|| EmbargoError::AlreadyApproved(format!("override {} is already approved", id)))?;
+
+        Ok(self.transform_override(approved))
+    }
+
+    async fn find_active_override(&self, election_id: String, staff_name: String) -> Result<Option<domain::Override>, EmbargoError> {
+        let uuid = Self::parse_id(&election_id)?;
+        let sql = format!(
+            r#"
+            SELECT {} FROM embargo_overrides
+            WHERE election_id = $1
+              AND expires_at > now()
+              AND (lower(requested_by) = lower($2) OR lower(approved_by) = lower($2))
+            ORDER BY expires_at DESC
+            LIMIT 1
+            "#,
+            OVERRIDE_COLUMNS
+        );
+
+        let found = sqlx::query_as::<_, Override>(&sql)
+            .bind(uuid)
+            .bind(&staff_name)
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| EmbargoError::UnknownError(e.to_string()))?;
+
+        Ok(found.map(|o| self.transform_override(o)))
+    }
+
+    async fn record_audit(&self, entry: domain::NewAuditEntry) -> Result<(), EmbargoError> {
+        let election_id = Self::parse_id(&entry.election_id)?;
+
+        sqlx::query(
+            r#"INSERT INTO audit_log (election_id, actor, action, outcome, detail) VALUES ($1, $2, $3, $4, $5)"#,
+        )
+            .bind(election_id)
+            .bind(&entry.actor)
+            .bind(&entry.action)
+            .bind(entry.outcome.as_str())
+            .bind(&entry.detail)
+            .execute(&self.postgres)
+            .await
+            .map_err(|e| EmbargoError::UnknownError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_audit(&self, election_id: String) -> Result<Vec<domain::AuditEntry>, EmbargoError> {
+        let uuid = Self::parse_id(&election_id)?;
+
+        let entries = sqlx::query_as::<_, AuditEntry>(
+            r#"
+            SELECT election_id, actor, action, outcome, detail, at
+            FROM audit_log WHERE election_id = $1 ORDER BY at, id
+            "#,
+        )
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| EmbargoError::UnknownError(e.to_string()))?;
+
+        entries
+            .into_iter()
+            .map(|e| {
+                Ok(domain::AuditEntry {
+                    election_id: e.election_id.to_string(),
+                    actor: e.actor,
+                    action: e.action,
+                    outcome: domain::AuditOutcome::parse(&e.outcome)
+                        .ok_or_else(|| EmbargoError::UnknownError(format!("unknown outcome {}", e.outcome)))?,
+                    detail: e.detail,
+                    at: e.at,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,171 @@
+use crate::embargo::domain::{AuditOutcome, EmbargoError, NewAuditEntry, Override, Repository, StaffRoster};
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Override, EmbargoError>;
+}
+
+/// Second half of an override: a different staff member approves it, and it holds for `ttl`.
+/// A request nobody approves within `ttl` lapses.
+pub struct ApproveOverrideUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    roster: Arc<StaffRoster>,
+    ttl: Duration,
+}
+
+pub struct Request {
+    pub override_id: String,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("override_id", &self.override_id)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> ApproveOverrideUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, roster: Arc<StaffRoster>, ttl: Duration) -> Self {
+        Self { repository, roster, ttl }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for ApproveOverrideUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Override, EmbargoError> {
+        let member = req.staff_token
+            .as_deref()
+            .and_then(|token| self.roster.resolve(token))
+            .ok_or_else(|| EmbargoError::Unauthorized("approving an override needs a staff token".into()))?;
+        if !member.can_act() {
+            return Err(EmbargoError::Forbidden(format!("{} may not approve overrides", member.role.as_str())));
+        }
+
+        let pending = self.repository.find_override(req.override_id.clone()).await?;
+        if pending.approved_by.is_some() {
+            return Err(EmbargoError::AlreadyApproved(format!("override {} is already approved", pending.id)));
+        }
+        if pending.requested_by.eq_ignore_ascii_case(&member.name) {
+            return Err(EmbargoError::SamePerson(format!(
+                "override {} must be approved by someone other than {}", pending.id, member.name
+            )));
+        }
+
+        let now = Utc::now();
+        if pending.requested_at + self.ttl < now {
+            return Err(EmbargoError::OverrideExpired(format!("override {} was not approved in time", pending.id)));
+        }
+
+        let approved = self.repository
+            .approve_override(pending.id.clone(), member.name.clone(), now + self.ttl)
+            .await?;
+
+        self.repository.record_audit(NewAuditEntry {
+            election_id: approved.election_id.clone(),
+            actor: Some(member.name),
+            action: "override.approve".to_string(),
+            outcome: AuditOutcome::Allowed,
+            detail: format!("override {} requested by {}", approved.id, approved.requested_by),
+        }).await?;
+
+        Ok(approved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::embargo::domain::{self, EmbargoError, StaffRoster};
+    use crate::embargo::usecase::approve_override;
+    use crate::embargo::usecase::approve_override::Interactor;
+    use chrono::{Duration, Utc};
+    use std::sync::Arc;
+
+    fn roster() -> Arc<StaffRoster> {
+        Arc::new(StaffRoster::parse(&format!(
+            "rina:admin:{},budi:committee:{}",
+            domain::hash_staff_token("rina-token"),
+            domain::hash_staff_token("budi-token")
+        )).unwrap())
+    }
+
+    fn pending(requested_by: &str, age: Duration) -> domain::Override {
+        domain::Override {
+            id: "o1".to_string(),
+            election_id: "e1".to_string(),
+            requested_by: requested_by.to_string(),
+            reason: "dispute".to_string(),
+            requested_at: Utc::now() - age,
+            approved_by: None,
+            approved_at: None,
+            expires_at: None,
+        }
+    }
+
+    fn request(token: &str) -> approve_override::Request {
+        approve_override::Request { override_id: "o1".to_string(), staff_token: Some(token.to_string()) }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_requester_cannot_approve_their_own_override() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_override().returning(|_| Ok(pending("Rina", Duration::minutes(1))));
+        repo_mock.expect_approve_override().never();
+
+        let uc = approve_override::ApproveOverrideUseCase::new(Arc::new(repo_mock), roster(), Duration::minutes(15));
+        let result = uc.handle(request("rina-token")).await;
+
+        assert!(matches!(result, Err(EmbargoError::SamePerson(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_second_person_approves_for_a_limited_time() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_override().returning(|_| Ok(pending("rina", Duration::minutes(1))));
+        repo_mock.expect_approve_override()
+            .times(1)
+            .withf(|id, by, expires_at| id == "o1" && by == "budi" && *expires_at <= Utc::now() + Duration::minutes(15))
+            .returning(|_, by, expires_at| Ok(domain::Override {
+                approved_by: Some(by),
+                approved_at: Some(Utc::now()),
+                expires_at: Some(expires_at),
+                ..pending("rina", Duration::minutes(1))
+            }));
+        repo_mock.expect_record_audit()
+            .times(1)
+            .withf(|e| e.action == "override.approve" && e.actor.as_deref() == Some("budi"))
+            .returning(|_| Ok(()));
+
+        let uc = approve_override::ApproveOverrideUseCase::new(Arc::new(repo_mock), roster(), Duration::minutes(15));
+        let approved = uc.handle(request("budi-token")).await.unwrap();
+
+        assert_eq!(approved.approved_by.as_deref(), Some("budi"));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_stale_request_lapses() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_override().returning(|_| Ok(pending("rina", Duration::minutes(20))));
+        repo_mock.expect_approve_override().never();
+
+        let uc = approve_override::ApproveOverrideUseCase::new(Arc::new(repo_mock), roster(), Duration::minutes(15));
+        let result = uc.handle(request("budi-token")).await;
+
+        assert!(matches!(result, Err(EmbargoError::OverrideExpired(_))));
+    }
+}
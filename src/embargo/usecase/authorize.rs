@@ -0,0 +1,205 @@
+use crate::election;
+use crate::election::domain::ElectionPhase;
+use crate::embargo::domain::{self, Access, AuditOutcome, EmbargoError, NewAuditEntry, Repository, ResultsAction, StaffRoster};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Access, EmbargoError>;
+}
+
+/// Decides whether a caller may see or count an election's results, and writes early
+/// attempts, allowed or not, to the audit trail.
+pub struct AuthorizeUseCase<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    repository: Arc<R>,
+    election_repository: Arc<E>,
+    roster: Arc<StaffRoster>,
+}
+
+pub struct Request {
+    pub election_id: String,
+    pub staff_token: Option<String>,
+    pub action: ResultsAction,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("election_id", &self.election_id)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .field("action", &self.action)
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync> AuthorizeUseCase<R, E>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    pub fn new(repository: Arc<R>, election_repository: Arc<E>, roster: Arc<StaffRoster>) -> Self {
+        Self { repository, election_repository, roster }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync> Interactor for AuthorizeUseCase<R, E>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Access, EmbargoError> {
+        let caller = match &req.staff_token {
+            Some(token) => Some(
+                self.roster
+                    .resolve(token)
+                    .ok_or_else(|| EmbargoError::Unauthorized("unknown staff token".into()))?,
+            ),
+            None => None,
+        };
+
+        let election = self.election_repository.find_by_id(req.election_id.clone()).await?;
+        let early = !matches!(election.phase, ElectionPhase::Closed | ElectionPhase::Published);
+
+        let active = match (&caller, early) {
+            (Some(member), true) => {
+                self.repository
+                    .find_active_override(election.id.clone(), member.name.clone())
+                    .await?
+            }
+            _ => None,
+        };
+
+        let decision = domain::results_access(election.phase, caller.as_ref(), req.action, active.is_some());
+
+        match (&decision, early) {
+            (Ok(()), true) => {
+                // An early look that cannot be recorded does not happen.
+                let detail = match &active {
+                    Some(o) => format!("phase {}; override {}", election.phase.as_str(), o.id),
+                    None => format!("phase {}", election.phase.as_str()),
+                };
+                self.repository.record_audit(NewAuditEntry {
+                    election_id: election.id.clone(),
+                    actor: caller.as_ref().map(|m| m.name.clone()),
+                    action: req.action.as_str().to_string(),
+                    outcome: AuditOutcome::Allowed,
+                    detail,
+                }).await?;
+            }
+            (Err(e @ EmbargoError::Embargoed(_)), _) => {
+                let entry = NewAuditEntry {
+                    election_id: election.id.clone(),
+                    actor: caller.as_ref().map(|m| m.name.clone()),
+                    action: req.action.as_str().to_string(),
+                    outcome: AuditOutcome::Denied,
+                    detail: e.to_string(),
+                };
+                if let Err(audit_err) = self.repository.record_audit(entry).await {
+                    log::error!("failed to audit embargoed read: {}", audit_err);
+                }
+            }
+            _ => {}
+        }
+
+        decision?;
+        Ok(Access { phase: election.phase, caller, early })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::election;
+    use crate::election::domain::ElectionPhase;
+    use crate::embargo::domain::{self, AuditOutcome, EmbargoError, ResultsAction, StaffRoster};
+    use crate::embargo::usecase::authorize;
+    use crate::embargo::usecase::authorize::Interactor;
+    use std::sync::Arc;
+
+    fn roster() -> Arc<StaffRoster> {
+        Arc::new(StaffRoster::parse(&format!(
+            "rina:admin:{}",
+            domain::hash_staff_token("rina-token")
+        )).unwrap())
+    }
+
+    fn election_repo(phase: ElectionPhase) -> election::domain::MockRepository {
+        let mut repo_mock = election::domain::MockRepository::new();
+        repo_mock.expect_find_by_id().returning(move |id| Ok(election::domain::Election {
+            id,
+            name: "Student Council 2026".to_string(),
+            phase,
+            voting_starts_at: None,
+            voting_ends_at: None,
+            allow_revote: false,
+            created_at: chrono::Utc::now(),
+            updated_at: None,
+        }));
+        repo_mock
+    }
+
+    fn request(token: Option<&str>) -> authorize::Request {
+        authorize::Request {
+            election_id: "e1".to_string(),
+            staff_token: token.map(str::to_string),
+            action: ResultsAction::Read,
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_admin_is_embargoed_while_voting_and_the_attempt_is_audited() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_active_override().returning(|_, _| Ok(None));
+        repo_mock.expect_record_audit()
+            .times(1)
+            .withf(|e| e.outcome == AuditOutcome::Denied && e.actor.as_deref() == Some("rina") && e.action == "results.read")
+            .returning(|_| Ok(()));
+
+        let uc = authorize::AuthorizeUseCase::new(Arc::new(repo_mock), Arc::new(election_repo(ElectionPhase::Voting)), roster());
+        let result = uc.handle(request(Some("rina-token"))).await;
+
+        assert!(matches!(result, Err(EmbargoError::Embargoed(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_override_lets_an_early_read_through_on_the_record() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_active_override().returning(|election_id, requested_by| Ok(Some(domain::Override {
+            id: "o1".to_string(),
+            election_id,
+            requested_by,
+            reason: "dispute".to_string(),
+            requested_at: chrono::Utc::now(),
+            approved_by: Some("budi".to_string()),
+            approved_at: Some(chrono::Utc::now()),
+            expires_at: Some(chrono::Utc::now() + chrono::Duration::minutes(15)),
+        })));
+        repo_mock.expect_record_audit()
+            .times(1)
+            .withf(|e| e.outcome == AuditOutcome::Allowed && e.detail.contains("override o1"))
+            .returning(|_| Ok(()));
+
+        let uc = authorize::AuthorizeUseCase::new(Arc::new(repo_mock), Arc::new(election_repo(ElectionPhase::Voting)), roster());
+        let access = uc.handle(request(Some("rina-token"))).await.unwrap();
+
+        assert!(access.early);
+        assert_eq!(access.caller.map(|m| m.name), Some("rina".to_string()));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_unknown_token_is_rejected() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_record_audit().never();
+
+        let uc = authorize::AuthorizeUseCase::new(Arc::new(repo_mock), Arc::new(election_repo(ElectionPhase::Published)), roster());
+        let result = uc.handle(request(Some("guess"))).await;
+
+        assert!(matches!(result, Err(EmbargoError::Unauthorized(_))));
+    }
+}
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+use chrono::Duration;
+use crate::election;
+use crate::embargo::domain::{Repository, StaffRoster};
+use crate::embargo::usecase::{approve_override, authorize, list_audit, request_override};
+use crate::embargo::usecase::approve_override::ApproveOverrideUseCase;
+use crate::embargo::usecase::authorize::AuthorizeUseCase;
+use crate::embargo::usecase::list_audit::ListAuditUseCase;
+use crate::embargo::usecase::request_override::RequestOverrideUseCase;
+
+
+#[derive(Clone)]
+pub struct UseCase
+{
+    pub authorize: Arc<dyn authorize::Interactor>,
+    pub request_override: Arc<dyn request_override::Interactor>,
+    pub approve_override: Arc<dyn approve_override::Interactor>,
+    pub list_audit: Arc<dyn list_audit::Interactor>,
+}
+
+impl UseCase {
+
+    pub fn new(
+        embargo_repo: Arc<dyn Repository + Send + Sync>,
+        election_repo: Arc<dyn election::domain::Repository + Send + Sync>,
+        roster: Arc<StaffRoster>,
+        override_ttl: Duration,
+    ) -> Self {
+
+        Self {
+            authorize: Arc::new(AuthorizeUseCase::new(embargo_repo.clone(), election_repo.clone(), roster.clone())),
+            request_override: Arc::new(RequestOverrideUseCase::new(embargo_repo.clone(), election_repo, roster.clone())),
+            approve_override: Arc::new(ApproveOverrideUseCase::new(embargo_repo.clone(), roster.clone(), override_ttl)),
+            list_audit: Arc::new(ListAuditUseCase::new(embargo_repo, roster)),
+        }
+    }
+
+}
@@ -0,0 +1,55 @@
+use crate::embargo::domain::{AuditEntry, EmbargoError, Repository, StaffRoster};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Vec<AuditEntry>, EmbargoError>;
+}
+
+pub struct ListAuditUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    roster: Arc<StaffRoster>,
+}
+
+pub struct Request {
+    pub election_id: String,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("election_id", &self.election_id)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> ListAuditUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, roster: Arc<StaffRoster>) -> Self {
+        Self { repository, roster }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for ListAuditUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Vec<AuditEntry>, EmbargoError> {
+        req.staff_token
+            .as_deref()
+            .and_then(|token| self.roster.resolve(token))
+            .ok_or_else(|| EmbargoError::Unauthorized("the audit trail needs a staff token".into()))?;
+
+        self.repository.find_audit(req.election_id).await
+    }
+}
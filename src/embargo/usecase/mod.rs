@@ -0,0 +1,7 @@
+mod init;
+pub mod approve_override;
+pub mod authorize;
+pub mod list_audit;
+pub mod request_override;
+
+pub use init::UseCase;
@@ -0,0 +1,87 @@
+use crate::election;
+use crate::embargo::domain::{AuditOutcome, EmbargoError, NewAuditEntry, NewOverride, Override, Repository, StaffRoster};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Override, EmbargoError>;
+}
+
+/// First half of an override: one staff member asks, with a reason, to see results early.
+pub struct RequestOverrideUseCase<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    repository: Arc<R>,
+    election_repository: Arc<E>,
+    roster: Arc<StaffRoster>,
+}
+
+pub struct Request {
+    pub election_id: String,
+    pub staff_token: Option<String>,
+    pub reason: String,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("election_id", &self.election_id)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .field("reason", &self.reason)
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync> RequestOverrideUseCase<R, E>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    pub fn new(repository: Arc<R>, election_repository: Arc<E>, roster: Arc<StaffRoster>) -> Self {
+        Self { repository, election_repository, roster }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync> Interactor for RequestOverrideUseCase<R, E>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Override, EmbargoError> {
+        let member = req.staff_token
+            .as_deref()
+            .and_then(|token| self.roster.resolve(token))
+            .ok_or_else(|| EmbargoError::Unauthorized("requesting an override needs a staff token".into()))?;
+        if !member.can_act() {
+            return Err(EmbargoError::Forbidden(format!("{} may not request overrides", member.role.as_str())));
+        }
+
+        let reason = req.reason.trim();
+        if reason.is_empty() {
+            return Err(EmbargoError::InvalidInput("an override needs a reason".into()));
+        }
+
+        let election = self.election_repository.find_by_id(req.election_id).await?;
+
+        let created = self.repository.create_override(NewOverride {
+            election_id: election.id.clone(),
+            requested_by: member.name.clone(),
+            reason: reason.to_string(),
+        }).await?;
+
+        self.repository.record_audit(NewAuditEntry {
+            election_id: election.id,
+            actor: Some(member.name),
+            action: "override.request".to_string(),
+            outcome: AuditOutcome::Allowed,
+            detail: format!("override {}: {}", created.id, created.reason),
+        }).await?;
+
+        Ok(created)
+    }
+}
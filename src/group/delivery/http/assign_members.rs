@@ -0,0 +1,103 @@
+use crate::group::delivery::http::errors::error_response;
+use crate::group::domain::MemberFilter;
+use crate::group::usecase::assign::*;
+use crate::embargo::delivery::http::staff_token;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+
+async fn assign(handler: web::Data<app::AppHandlerData>, request: Request) -> HttpResponse {
+    match handler.group_uc.assign.handle(request).await {
+        Ok(report) => HttpResponse::Ok().json(response::success(
+            Some(report),
+            "Successfully processed group".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
+
+pub async fn assign_members_csv(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+    body: String,
+) -> HttpResponse {
+
+    let group_id = path.into_inner();
+
+    println!("-> Received CSV assignment for group {} ({} bytes)", group_id, body.len());
+
+    let request = Request { group_id, source: Source::Csv(body), staff_token: staff_token(&http_request) };
+
+    assign(handler, request).await
+}
+
+pub async fn assign_members_filter(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<MemberFilter>,
+) -> HttpResponse {
+
+    let request = Request {
+        group_id: path.into_inner(),
+        source: Source::Filter(body.into_inner()),
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    assign(handler, request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::assign_members_csv;
+    use actix_web::{App, http::StatusCode, test, web};
+    use async_trait::async_trait;
+    use serde_json::Value;
+    use std::sync::Arc;
+    use crate::group::domain::{AssignmentReport, GroupError};
+    use crate::group::usecase::assign::{Interactor, Request, Source};
+    use crate::utils::app;
+
+    #[actix_rt::test]
+    async fn test_assign_members_csv() {
+        struct MockAssign;
+
+        #[async_trait]
+        impl Interactor for MockAssign {
+            async fn handle(&self, req: Request) -> Result<AssignmentReport, GroupError> {
+                match req.source {
+                    Source::Csv(body) if body == "nim\n2023010001\n" => Ok(AssignmentReport {
+                        assigned: 1,
+                        already_members: 0,
+                        unknown_nims: vec![],
+                    }),
+                    _ => Err(GroupError::InvalidInput("unexpected body".into())),
+                }
+            }
+        }
+
+        let mut group_uc = app::AppHandlerData::mocked().group_uc;
+        group_uc.assign = Arc::new(MockAssign);
+        let app_data = web::Data::new(app::AppHandlerData { group_uc, ..app::AppHandlerData::mocked() });
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_data)
+                .route("/groups/{id}/members/csv", web::post().to(assign_members_csv)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/groups/g1/members/csv")
+            .insert_header(("Content-Type", "text/csv"))
+            .set_payload("nim\n2023010001\n")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["assigned"], 1);
+    }
+}
@@ -0,0 +1,36 @@
+use crate::group::delivery::http::errors::error_response;
+use crate::group::usecase::create::*;
+use crate::embargo::delivery::http::staff_token;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct CreateGroupBody {
+    name: String,
+    description: Option<String>,
+}
+
+pub async fn create_group(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    body: web::Json<CreateGroupBody>,
+) -> HttpResponse {
+
+    let body = body.into_inner();
+    let request = Request {
+        name: body.name,
+        description: body.description,
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.group_uc.create.handle(request).await {
+        Ok(group) => HttpResponse::Created().json(response::success(
+            Some(group),
+            "Successfully processed group".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
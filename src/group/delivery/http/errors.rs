@@ -0,0 +1,39 @@
+use crate::group::domain::GroupError;
+use crate::utils::response;
+use actix_web::HttpResponse;
+
+pub fn error_response(e: &GroupError) -> HttpResponse {
+    println!("Error: {}", e);
+    match e {
+        GroupError::NotFound(msg) => HttpResponse::NotFound().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "GROUP_NOT_FOUND".into(),
+        )),
+        GroupError::Unauthorized(msg) => HttpResponse::Unauthorized().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "GROUP_UNAUTHORIZED".into(),
+        )),
+        GroupError::Forbidden(msg) => HttpResponse::Forbidden().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "GROUP_FORBIDDEN".into(),
+        )),
+        GroupError::AlreadyExists(msg) => HttpResponse::Conflict().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "GROUP_ALREADY_EXISTS".into(),
+        )),
+        GroupError::InvalidInput(msg) => HttpResponse::BadRequest().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "GROUP_INVALID_INPUT".into(),
+        )),
+        GroupError::UnknownError(_) => HttpResponse::InternalServerError().json(response::error::<()>(
+            None,
+            "failed process data".into(),
+            "-1".into(),
+        )),
+    }
+}
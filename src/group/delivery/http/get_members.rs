@@ -0,0 +1,37 @@
+use crate::group::delivery::http::errors::error_response;
+use crate::group::usecase::get_members::*;
+use crate::embargo::delivery::http::staff_token;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct GroupMembersQuery {
+    page: Option<u32>,
+    limit: Option<u32>,
+}
+
+pub async fn get_members(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+    q: web::Query<GroupMembersQuery>,
+) -> HttpResponse {
+
+    let request = Request {
+        group_id: path.into_inner(),
+        page: Some(q.page.unwrap_or(1)),
+        limit: Some(q.limit.unwrap_or(10)),
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.group_uc.get_members.handle(request).await {
+        Ok(page) => HttpResponse::Ok().json(response::success(
+            Some(page),
+            "Successfully processed group".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
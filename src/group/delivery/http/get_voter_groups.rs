@@ -0,0 +1,24 @@
+use crate::group::delivery::http::errors::error_response;
+use crate::group::usecase::get_voter_groups::*;
+use crate::embargo::delivery::http::staff_token;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+
+pub async fn get_voter_groups(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+
+    let request = Request { voter_id: path.into_inner(), staff_token: staff_token(&http_request) };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.group_uc.get_voter_groups.handle(request).await {
+        Ok(groups) => HttpResponse::Ok().json(response::success(
+            Some(groups),
+            "Successfully processed group".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
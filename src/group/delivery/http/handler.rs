@@ -0,0 +1,23 @@
+use actix_web::web;
+use crate::group::delivery::http::assign_members::{assign_members_csv, assign_members_filter};
+use crate::group::delivery::http::create_group::create_group;
+use crate::group::delivery::http::get_members::get_members;
+use crate::group::delivery::http::get_voter_groups::get_voter_groups;
+use crate::group::delivery::http::list_groups::list_groups;
+use crate::group::delivery::http::remove_member::remove_member;
+
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/groups", web::get().to(list_groups))
+        .route("/groups", web::post().to(create_group))
+        .route("/groups/{id}/members", web::get().to(get_members))
+        .service(
+            web::resource("/groups/{id}/members/csv")
+                // Registrar exports of a whole faculty go well past the 256 KiB default.
+                .app_data(web::PayloadConfig::new(8 * 1024 * 1024))
+                .route(web::post().to(assign_members_csv)),
+        )
+        .route("/groups/{id}/members/filter", web::post().to(assign_members_filter))
+        .route("/groups/{id}/members/{voter_id}", web::delete().to(remove_member))
+        .route("/voters/{id}/groups", web::get().to(get_voter_groups));
+}
@@ -0,0 +1,18 @@
+use crate::group::delivery::http::errors::error_response;
+use crate::group::usecase::list::*;
+use crate::embargo::delivery::http::staff_token;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+
+pub async fn list_groups(handler: web::Data<app::AppHandlerData>, http_request: HttpRequest) -> HttpResponse {
+
+    let request = Request { staff_token: staff_token(&http_request) };
+
+    match handler.group_uc.list.handle(request).await {
+        Ok(groups) => HttpResponse::Ok().json(response::success(
+            Some(groups),
+            "Successfully processed group".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
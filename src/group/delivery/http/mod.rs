@@ -0,0 +1,16 @@
+mod handler;
+mod errors;
+mod assign_members;
+mod create_group;
+mod get_members;
+mod get_voter_groups;
+mod list_groups;
+mod remove_member;
+
+pub use assign_members::*;
+pub use create_group::*;
+pub use get_members::*;
+pub use get_voter_groups::*;
+pub use list_groups::*;
+pub use remove_member::*;
+pub use handler::*;
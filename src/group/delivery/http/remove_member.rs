@@ -0,0 +1,25 @@
+use crate::group::delivery::http::errors::error_response;
+use crate::group::usecase::remove_member::*;
+use crate::embargo::delivery::http::staff_token;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+
+pub async fn remove_member(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+
+    let (group_id, voter_id) = path.into_inner();
+    let request = Request { group_id, voter_id, staff_token: staff_token(&http_request) };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.group_uc.remove_member.handle(request).await {
+        Ok(()) => HttpResponse::Ok().json(response::success::<()>(
+            None,
+            "Successfully processed group".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
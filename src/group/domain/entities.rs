@@ -0,0 +1,43 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::voter;
+
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Group {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub member_count: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct NewGroup {
+    pub name: String,
+    pub description: String,
+}
+
+/// Registry filter for bulk assignment; unset fields match every voter.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct MemberFilter {
+    pub faculty: Option<String>,
+    pub cohort: Option<i32>,
+    pub eligible: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct AssignmentReport {
+    /// Voters newly added to the group.
+    pub assigned: u64,
+    /// Matched voters that were already members.
+    pub already_members: u64,
+    /// NIMs from the upload that match no voter in the registry.
+    pub unknown_nims: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GroupMembersPage {
+    pub total: usize,
+    pub members: Vec<voter::domain::Voter>,
+}
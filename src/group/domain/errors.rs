@@ -0,0 +1,29 @@
+use crate::embargo::domain::StaffDenied;
+use thiserror::Error;
+
+
+#[derive(Debug, Error)]
+#[derive(Clone)]
+pub enum GroupError {
+    #[error("GroupError::NotFound: {0}")]
+    NotFound(String),
+    #[error("GroupError::Unauthorized: {0}")]
+    Unauthorized(String),
+    #[error("GroupError::Forbidden: {0}")]
+    Forbidden(String),
+    #[error("GroupError::AlreadyExists: {0}")]
+    AlreadyExists(String),
+    #[error("GroupError::InvalidInput: {0}")]
+    InvalidInput(String),
+    #[error("GroupError::UnknownError: {0}")]
+    UnknownError(String),
+}
+
+impl From<StaffDenied> for GroupError {
+    fn from(e: StaffDenied) -> Self {
+        match e {
+            StaffDenied::Unauthenticated(msg) => GroupError::Unauthorized(msg),
+            StaffDenied::NotAllowed(msg) => GroupError::Forbidden(msg),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use crate::group::domain::entities::{AssignmentReport, Group, GroupMembersPage, MemberFilter, NewGroup};
+use crate::group::domain::errors::GroupError;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait Repository: Send + Sync {
+    async fn create(&self, group: NewGroup) -> Result<Group, GroupError>;
+
+    async fn find_all(&self) -> Result<Vec<Group>, GroupError>;
+
+    async fn find_by_id(&self, id: String) -> Result<Group, GroupError>;
+
+    async fn find_members(&self, group_id: String, page: u32, limit: u32) -> Result<GroupMembersPage, GroupError>;
+
+    /// Groups the voter belongs to.
+    async fn find_by_voter(&self, voter_id: String) -> Result<Vec<Group>, GroupError>;
+
+    /// Adds the voters with these NIMs, matched through the blind index.
+    async fn assign_by_nims(&self, group_id: String, nims: Vec<String>) -> Result<AssignmentReport, GroupError>;
+
+    /// Adds every registry voter matching `filter`.
+    async fn assign_by_filter(&self, group_id: String, filter: MemberFilter) -> Result<AssignmentReport, GroupError>;
+
+    async fn remove_member(&self, group_id: String, voter_id: String) -> Result<(), GroupError>;
+}
@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct Group {
+    pub id: Uuid,
+    pub name: String,
+    pub description: String,
+    pub member_count: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct Member {
+    pub id: Uuid,
+    pub nim: String,
+    pub name: String,
+    pub email: String,
+    pub faculty: String,
+    pub cohort: i32,
+    pub eligible: bool,
+    pub updated_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,283 @@
+use crate::group::domain;
+use crate::group::domain::GroupError;
+use crate::group::domain::Repository;
+use crate::infrastructure::crypto::PiiCipher;
+use crate::infrastructure::database::postgres::Postgres;
+use crate::voter;
+use crate::voter::repository::{FIELD_EMAIL, FIELD_NAME, FIELD_NIM};
+use anyhow::anyhow;
+use async_trait::async_trait;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::group::repository::model::{Group, Member};
+
+const GROUP_SELECT: &str = r#"
+        SELECT g.id
+            , g.name
+            , g.description
+            , (SELECT COUNT(*) FROM voter_group_members m WHERE m.group_id = g.id)::BIGINT AS member_count
+            , g.created_at
+        FROM voter_groups g
+"#;
+
+pub struct PostgresRepo {
+    postgres: sqlx::PgPool,
+    cipher: Arc<PiiCipher>,
+}
+impl PostgresRepo {
+    pub async fn new(postgres: Arc<Mutex<Postgres>>, cipher: Arc<PiiCipher>) -> anyhow::Result<Self> {
+        let guard = postgres.lock().await;
+        let pool = guard
+            .pool()
+            .ok_or_else(|| anyhow!("DB pool is not initialized"))?;
+        Ok(PostgresRepo { postgres: pool, cipher })
+    }
+
+    fn transform_group(&self, g: Group) -> domain::Group {
+        domain::Group {
+            id: g.id.to_string(),
+            name: g.name,
+            description: g.description,
+            member_count: g.member_count,
+            created_at: g.created_at,
+        }
+    }
+
+    fn transform_member(&self, m: Member) -> Result<voter::domain::Voter, GroupError> {
+        let reveal = |field: &str, value: &str| self.cipher
+            .reveal(field, value)
+            .map_err(|e| GroupError::UnknownError(e.to_string()));
+
+        Ok(voter::domain::Voter {
+            id: m.id.to_string(),
+            nim: reveal(FIELD_NIM, &m.nim)?,
+            name: reveal(FIELD_NAME, &m.name)?,
+            email: reveal(FIELD_EMAIL, &m.email)?,
+            faculty: m.faculty,
+            cohort: m.cohort,
+            eligible: m.eligible,
+            updated_at: m.updated_at,
+        })
+    }
+
+    fn parse_id(id: &str) -> Result<Uuid, GroupError> {
+        Uuid::parse_str(id).map_err(|_| GroupError::NotFound(format!("{} not found", id)))
+    }
+
+    async fn ensure_group(&self, group_id: Uuid) -> Result<(), GroupError> {
+        let exists: Option<Uuid> = sqlx::query_scalar(r#"SELECT id FROM voter_groups WHERE id = $1"#)
+            .bind(group_id)
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| GroupError::UnknownError(e.to_string()))?;
+
+        exists
+            .map(|_| ())
+            .ok_or_else(|| GroupError::NotFound(format!("group {} not found", group_id)))
+    }
+}
+
+#[async_trait]
+impl Repository for PostgresRepo {
+    async fn create(&self, group: domain::NewGroup) -> Result<domain::Group, GroupError> {
+        let created = sqlx::query_as::<_, Group>(
+            r#"
+            INSERT INTO voter_groups (name, description) VALUES ($1, $2)
+            RETURNING id, name, description, 0::BIGINT AS member_count, created_at
+            "#,
+        )
+            .bind(&group.name)
+            .bind(&group.description)
+            .fetch_one(&self.postgres)
+            .await
+            .map_err(|e| match e {
+                sqlx::Error::Database(db) if db.is_unique_violation() => {
+                    GroupError::AlreadyExists(format!("group {} already exists", group.name))
+                }
+                _ => GroupError::UnknownError(e.to_string()),
+            })?;
+
+        Ok(self.transform_group(created))
+    }
+
+    async fn find_all(&self) -> Result<Vec<domain::Group>, GroupError> {
+        let sql = format!("{} ORDER BY g.name", GROUP_SELECT);
+        let groups = sqlx::query_as::<_, Group>(&sql)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| GroupError::UnknownError(e.to_string()))?;
+
+        Ok(groups.into_iter().map(|g| self.transform_group(g)).collect())
+    }
+
+    async fn find_by_id(&self, id: String) -> Result<domain::Group, GroupError> {
+        let uuid = Self::parse_id(&id)?;
+        let sql = format!("{} WHERE g.id = $1", GROUP_SELECT);
+
+        let group = sqlx::query_as::<_, Group>(&sql)
+            .bind(uuid)
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| GroupError::UnknownError(e.to_string()))?
+            .ok_or_else(|| GroupError::NotFound(format!("group {} not found", id)))?;
+
+        Ok(self.transform_group(group))
+    }
+
+    async fn find_members(&self, group_id: String, page: u32, limit: u32) -> Result<domain::GroupMembersPage, GroupError> {
+        let uuid = Self::parse_id(&group_id)?;
+        self.ensure_group(uuid).await?;
+
+        let offset = (page.max(1) - 1) * limit;
+
+        let members = sqlx::query_as::<_, Member>(
+            r#"
+        SELECT v.id
+            , v.nim
+            , v.name
+            , v.email
+            , v.faculty
+            , v.cohort
+            , v.eligible
+            , v.updated_at
+        FROM voter_group_members m
+        JOIN voters v ON v.id = m.voter_id
+        WHERE m.group_id = $1
+        ORDER BY m.added_at, v.id
+        LIMIT $2 OFFSET $3
+        "#,
+        )
+            .bind(uuid)
+            .bind(limit as i64)
+            .bind(offset as i64)
+            .fetch_all(&self.postgres);
+
+        let total: i64 = sqlx::query_scalar(r#"SELECT COUNT(*)::BIGINT FROM voter_group_members WHERE group_id = $1"#)
+            .bind(uuid)
+            .fetch_one(&self.postgres)
+            .await
+            .map_err(|e| GroupError::UnknownError(e.to_string()))?;
+
+        let members = members
+            .await
+            .map_err(|e| GroupError::UnknownError(e.to_string()))?
+            .into_iter()
+            .map(|m| self.transform_member(m))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(domain::GroupMembersPage { total: total as usize, members })
+    }
+
+    async fn find_by_voter(&self, voter_id: String) -> Result<Vec<domain::Group>, GroupError> {
+        let uuid = Self::parse_id(&voter_id)?;
+        let sql = format!(
+            "{} WHERE g.id IN (SELECT group_id FROM voter_group_members WHERE voter_id = $1) ORDER BY g.name",
+            GROUP_SELECT
+        );
+
+        let groups = sqlx::query_as::<_, Group>(&sql)
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| GroupError::UnknownError(e.to_string()))?;
+
+        Ok(groups.into_iter().map(|g| self.transform_group(g)).collect())
+    }
+
+    async fn assign_by_nims(&self, group_id: String, nims: Vec<String>) -> Result<domain::AssignmentReport, GroupError> {
+        let uuid = Self::parse_id(&group_id)?;
+        self.ensure_group(uuid).await?;
+
+        let bidxs: Vec<String> = nims.iter().map(|n| self.cipher.blind_index(FIELD_NIM, n)).collect();
+
+        let known: Vec<String> = sqlx::query_scalar(r#"SELECT nim_bidx FROM voters WHERE nim_bidx = ANY($1)"#)
+            .bind(&bidxs)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| GroupError::UnknownError(e.to_string()))?;
+        let known: HashSet<String> = known.into_iter().collect();
+
+        let assigned = sqlx::query(
+            r#"
+            INSERT INTO voter_group_members (group_id, voter_id)
+            SELECT $1, id FROM voters WHERE nim_bidx = ANY($2)
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+            .bind(uuid)
+            .bind(&bidxs)
+            .execute(&self.postgres)
+            .await
+            .map_err(|e| GroupError::UnknownError(e.to_string()))?
+            .rows_affected();
+
+        let unknown_nims: Vec<String> = nims
+            .into_iter()
+            .zip(bidxs.iter())
+            .filter(|(_, bidx)| !known.contains(*bidx))
+            .map(|(nim, _)| nim)
+            .collect();
+
+        Ok(domain::AssignmentReport {
+            assigned,
+            already_members: (known.len() as u64).saturating_sub(assigned),
+            unknown_nims,
+        })
+    }
+
+    async fn assign_by_filter(&self, group_id: String, filter: domain::MemberFilter) -> Result<domain::AssignmentReport, GroupError> {
+        let uuid = Self::parse_id(&group_id)?;
+        self.ensure_group(uuid).await?;
+
+        let (matched, assigned): (i64, i64) = sqlx::query_as(
+            r#"
+            WITH matched AS (
+                SELECT id FROM voters
+                WHERE ($2::TEXT IS NULL OR faculty = $2)
+                    AND ($3::INTEGER IS NULL OR cohort = $3)
+                    AND ($4::BOOLEAN IS NULL OR eligible = $4)
+            ), inserted AS (
+                INSERT INTO voter_group_members (group_id, voter_id)
+                SELECT $1, id FROM matched
+                ON CONFLICT DO NOTHING
+                RETURNING voter_id
+            )
+            SELECT (SELECT COUNT(*) FROM matched)::BIGINT, (SELECT COUNT(*) FROM inserted)::BIGINT
+            "#,
+        )
+            .bind(uuid)
+            .bind(filter.faculty)
+            .bind(filter.cohort)
+            .bind(filter.eligible)
+            .fetch_one(&self.postgres)
+            .await
+            .map_err(|e| GroupError::UnknownError(e.to_string()))?;
+
+        Ok(domain::AssignmentReport {
+            assigned: assigned as u64,
+            already_members: (matched - assigned) as u64,
+            unknown_nims: vec![],
+        })
+    }
+
+    async fn remove_member(&self, group_id: String, voter_id: String) -> Result<(), GroupError> {
+        let group_uuid = Self::parse_id(&group_id)?;
+        let voter_uuid = Self::parse_id(&voter_id)?;
+
+        let removed = sqlx::query(r#"DELETE FROM voter_group_members WHERE group_id = $1 AND voter_id = $2"#)
+            .bind(group_uuid)
+            .bind(voter_uuid)
+            .execute(&self.postgres)
+            .await
+            .map_err(|e| GroupError::UnknownError(e.to_string()))?
+            .rows_affected();
+
+        if removed == 0 {
+            return Err(GroupError::NotFound(format!("voter {} is not a member of group {}", voter_id, group_id)));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+use crate::group::domain::{AssignmentReport, GroupError, MemberFilter, Repository};
+use crate::utils::csv;
+use crate::embargo::domain::StaffRoster;
+use async_trait::async_trait;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<AssignmentReport, GroupError>;
+}
+
+pub struct AssignMembersUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    roster: Arc<StaffRoster>,
+}
+
+#[derive(Debug)]
+pub enum Source {
+    /// CSV upload with a header row that has a `nim` column.
+    Csv(String),
+    Filter(MemberFilter),
+}
+
+pub struct Request {
+    pub group_id: String,
+    pub source: Source,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("group_id", &self.group_id)
+            .field("source", &self.source)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> AssignMembersUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, roster: Arc<StaffRoster>) -> Self {
+        Self { repository, roster }
+    }
+}
+
+/// Reads the `nim` column of an uploaded CSV, trimmed and de-duplicated in upload order.
+pub fn nims_from_csv(input: &str) -> Result<Vec<String>, GroupError> {
+    let rows = csv::parse(input).map_err(GroupError::InvalidInput)?;
+    let mut rows = rows.into_iter();
+
+    let header = rows
+        .next()
+        .ok_or_else(|| GroupError::InvalidInput("CSV is empty".into()))?;
+    let column = header
+        .iter()
+        .position(|h| h.trim().eq_ignore_ascii_case("nim"))
+        .ok_or_else(|| GroupError::InvalidInput("CSV has no nim column".into()))?;
+
+    let mut seen = HashSet::new();
+    let mut nims = Vec::new();
+    for (i, row) in rows.enumerate() {
+        let nim = row.get(column).map(|n| n.trim()).unwrap_or_default();
+        if nim.is_empty() {
+            return Err(GroupError::InvalidInput(format!("row {} has no nim", i + 2)));
+        }
+        if seen.insert(nim.to_string()) {
+            nims.push(nim.to_string());
+        }
+    }
+
+    if nims.is_empty() {
+        return Err(GroupError::InvalidInput("CSV has no rows".into()));
+    }
+
+    Ok(nims)
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for AssignMembersUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<AssignmentReport, GroupError> {
+        self.roster.acting(req.staff_token.as_deref(), "manage voter groups")?;
+
+        match req.source {
+            Source::Csv(input) => {
+                let nims = nims_from_csv(&input)?;
+                self.repository.assign_by_nims(req.group_id, nims).await
+            }
+            Source::Filter(filter) => {
+                // An empty filter would silently add the whole registry.
+                if filter == MemberFilter::default() {
+                    return Err(GroupError::InvalidInput("filter needs at least one criterion".into()));
+                }
+                self.repository.assign_by_filter(req.group_id, filter).await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+    use crate::group::domain::{self, AssignmentReport, GroupError, MemberFilter};
+    use crate::group::usecase::assign;
+    use crate::group::usecase::assign::Interactor;
+    use std::sync::Arc;
+    use crate::embargo::domain::{StaffRoster, hash_staff_token};
+
+    fn roster() -> Arc<StaffRoster> {
+        Arc::new(StaffRoster::parse(&format!(
+            "rina:committee:{},budi:observer:{}",
+            hash_staff_token("rina-token"),
+            hash_staff_token("budi-token")
+        )).unwrap())
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_assign_from_csv() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_assign_by_nims()
+            .with(eq("g1".to_string()), eq(vec!["2023010001".to_string(), "2023010002".to_string()]))
+            .times(1)
+            .returning(|_, _| Ok(AssignmentReport {
+                assigned: 1,
+                already_members: 0,
+                unknown_nims: vec!["2023010002".to_string()],
+            }));
+
+        let uc = assign::AssignMembersUseCase::new(Arc::new(repo_mock), roster());
+        let report = uc.handle(assign::Request {
+            group_id: "g1".to_string(),
+            source: assign::Source::Csv("name,NIM\nAlice, 2023010001\nBob,2023010002\nAlice again,2023010001\n".to_string()),
+            staff_token: Some("rina-token".to_string()),
+        }).await.unwrap();
+
+        assert_eq!(report.assigned, 1);
+        assert_eq!(report.unknown_nims, vec!["2023010002".to_string()]);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_assign_csv_without_nim_column() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_assign_by_nims().times(0);
+
+        let uc = assign::AssignMembersUseCase::new(Arc::new(repo_mock), roster());
+        let res = uc.handle(assign::Request {
+            group_id: "g1".to_string(),
+            source: assign::Source::Csv("name,email\nAlice,alice@campus.ac.id\n".to_string()),
+            staff_token: Some("rina-token".to_string()),
+        }).await;
+
+        assert!(matches!(res, Err(GroupError::InvalidInput(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_assign_from_filter() {
+        let filter = MemberFilter { faculty: Some("Engineering".to_string()), cohort: Some(2023), eligible: None };
+
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_assign_by_filter()
+            .with(eq("g1".to_string()), eq(filter.clone()))
+            .times(1)
+            .returning(|_, _| Ok(AssignmentReport { assigned: 40, already_members: 2, unknown_nims: vec![] }));
+
+        let uc = assign::AssignMembersUseCase::new(Arc::new(repo_mock), roster());
+        let report = uc.handle(assign::Request {
+            group_id: "g1".to_string(),
+            source: assign::Source::Filter(filter),
+            staff_token: Some("rina-token".to_string()),
+        }).await.unwrap();
+
+        assert_eq!(report.assigned, 40);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_assign_rejects_empty_filter() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_assign_by_filter().times(0);
+
+        let uc = assign::AssignMembersUseCase::new(Arc::new(repo_mock), roster());
+        let res = uc.handle(assign::Request {
+            group_id: "g1".to_string(),
+            source: assign::Source::Filter(MemberFilter::default()),
+            staff_token: Some("rina-token".to_string()),
+        }).await;
+
+        assert!(matches!(res, Err(GroupError::InvalidInput(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_assign_needs_an_acting_staff_member() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_assign_by_nims().times(0);
+        let uc = assign::AssignMembersUseCase::new(Arc::new(repo_mock), roster());
+
+        let request = |token: Option<&str>| assign::Request {
+            group_id: "g1".to_string(),
+            source: assign::Source::Csv("nim\n2023010001\n".to_string()),
+            staff_token: token.map(str::to_string),
+        };
+
+        let anonymous = uc.handle(request(None)).await;
+        assert!(matches!(anonymous, Err(GroupError::Unauthorized(_))));
+
+        let observer = uc.handle(request(Some("budi-token"))).await;
+        assert!(matches!(observer, Err(GroupError::Forbidden(_))));
+    }
+}
@@ -0,0 +1,63 @@
+use crate::group::domain::{Group, GroupError, NewGroup, Repository};
+use crate::embargo::domain::StaffRoster;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Group, GroupError>;
+}
+
+pub struct CreateGroupUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    roster: Arc<StaffRoster>,
+}
+
+pub struct Request {
+    pub name: String,
+    pub description: Option<String>,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("name", &self.name)
+            .field("description", &self.description)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> CreateGroupUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, roster: Arc<StaffRoster>) -> Self {
+        Self { repository, roster }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for CreateGroupUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Group, GroupError> {
+        self.roster.acting(req.staff_token.as_deref(), "manage voter groups")?;
+
+        let name = req.name.trim().to_string();
+        if name.is_empty() {
+            return Err(GroupError::InvalidInput("group name is required".into()));
+        }
+
+        self.repository.create(NewGroup {
+            name,
+            description: req.description.unwrap_or_default().trim().to_string(),
+        }).await
+    }
+}
@@ -0,0 +1,59 @@
+use crate::group::domain::{GroupError, GroupMembersPage, Repository};
+use crate::embargo::domain::StaffRoster;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<GroupMembersPage, GroupError>;
+}
+
+pub struct GetMembersUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    roster: Arc<StaffRoster>,
+}
+
+pub struct Request {
+    pub group_id: String,
+    pub page: Option<u32>,
+    pub limit: Option<u32>,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("group_id", &self.group_id)
+            .field("page", &self.page)
+            .field("limit", &self.limit)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> GetMembersUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, roster: Arc<StaffRoster>) -> Self {
+        Self { repository, roster }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for GetMembersUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<GroupMembersPage, GroupError> {
+        self.roster.acting(req.staff_token.as_deref(), "view voter groups")?;
+
+        self.repository
+            .find_members(req.group_id, req.page.unwrap_or(1), req.limit.unwrap_or(10))
+            .await
+    }
+}
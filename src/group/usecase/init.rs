@@ -0,0 +1,38 @@
+use std::sync::Arc;
+use crate::group::domain::Repository;
+use crate::embargo::domain::StaffRoster;
+use crate::group::usecase::{assign, create, get_members, get_voter_groups, list, remove_member};
+use crate::group::usecase::assign::AssignMembersUseCase;
+use crate::group::usecase::create::CreateGroupUseCase;
+use crate::group::usecase::get_members::GetMembersUseCase;
+use crate::group::usecase::get_voter_groups::GetVoterGroupsUseCase;
+use crate::group::usecase::list::ListGroupsUseCase;
+use crate::group::usecase::remove_member::RemoveMemberUseCase;
+
+
+#[derive(Clone)]
+pub struct UseCase
+{
+    pub create: Arc<dyn create::Interactor>,
+    pub list: Arc<dyn list::Interactor>,
+    pub get_members: Arc<dyn get_members::Interactor>,
+    pub get_voter_groups: Arc<dyn get_voter_groups::Interactor>,
+    pub assign: Arc<dyn assign::Interactor>,
+    pub remove_member: Arc<dyn remove_member::Interactor>,
+}
+
+impl UseCase {
+
+    pub fn new(group_repo: Arc<dyn Repository + Send + Sync>, roster: Arc<StaffRoster>) -> Self {
+
+        Self {
+            create: Arc::new(CreateGroupUseCase::new(group_repo.clone(), roster.clone())),
+            list: Arc::new(ListGroupsUseCase::new(group_repo.clone(), roster.clone())),
+            get_members: Arc::new(GetMembersUseCase::new(group_repo.clone(), roster.clone())),
+            get_voter_groups: Arc::new(GetVoterGroupsUseCase::new(group_repo.clone(), roster.clone())),
+            assign: Arc::new(AssignMembersUseCase::new(group_repo.clone(), roster.clone())),
+            remove_member: Arc::new(RemoveMemberUseCase::new(group_repo, roster)),
+        }
+    }
+
+}
@@ -0,0 +1,9 @@
+mod init;
+pub mod assign;
+pub mod create;
+pub mod get_members;
+pub mod get_voter_groups;
+pub mod list;
+pub mod remove_member;
+
+pub use init::UseCase;
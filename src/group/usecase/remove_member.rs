@@ -0,0 +1,55 @@
+use crate::group::domain::{GroupError, Repository};
+use crate::embargo::domain::StaffRoster;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<(), GroupError>;
+}
+
+pub struct RemoveMemberUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    roster: Arc<StaffRoster>,
+}
+
+pub struct Request {
+    pub group_id: String,
+    pub voter_id: String,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("group_id", &self.group_id)
+            .field("voter_id", &self.voter_id)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> RemoveMemberUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, roster: Arc<StaffRoster>) -> Self {
+        Self { repository, roster }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for RemoveMemberUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<(), GroupError> {
+        self.roster.acting(req.staff_token.as_deref(), "manage voter groups")?;
+
+        self.repository.remove_member(req.group_id, req.voter_id).await
+    }
+}
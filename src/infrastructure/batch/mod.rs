@@ -0,0 +1,3 @@
+mod writer;
+
+pub use writer::*;
@@ -0,0 +1,170 @@
+use std::future::Future;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio::sync::{mpsc, oneshot};
+
+#[derive(Debug, Clone, Copy)]
+pub struct BatchConfig {
+    /// Most items written by one flush.
+    pub max_batch: usize,
+    /// Items that may wait for a flush before `submit` turns new ones away.
+    pub queue_capacity: usize,
+    /// How long to keep gathering items once the first one arrives, so that items submitted
+    /// close together are written together. Zero flushes as soon as anything is queued.
+    pub hold: Duration,
+}
+
+#[derive(Debug, Error)]
+#[derive(Clone, PartialEq)]
+pub enum BatchError {
+    #[error("BatchError::Full: {0}")]
+    Full(String),
+    #[error("BatchError::Closed: {0}")]
+    Closed(String),
+}
+
+type Job<T, O> = (T, oneshot::Sender<O>);
+
+/// Group commit: callers queue an item and wait for its result, while a single task writes
+/// whatever has queued up since its last flush in one go. Under light load every batch holds
+/// one item and adds no delay unless `hold` asks for it; under heavy load batches grow up to
+/// `max_batch`.
+pub struct BatchWriter<T, O> {
+    queue: mpsc::Sender<Job<T, O>>,
+}
+
+impl<T: Send + 'static, O: Send + 'static> BatchWriter<T, O> {
+    /// Starts the flushing task. `flush` must return one result per item, in order. The task
+    /// drains the queue and stops once the writer is dropped.
+    pub fn spawn<F, Fut>(config: BatchConfig, flush: F) -> Self
+    where
+        F: Fn(Vec<T>) -> Fut + Send + 'static,
+        Fut: Future<Output = Vec<O>> + Send + 'static,
+    {
+        let (queue, mut pending) = mpsc::channel::<Job<T, O>>(config.queue_capacity.max(1));
+        let max_batch = config.max_batch.max(1);
+        let hold = config.hold;
+
+        tokio::spawn(async move {
+            let mut jobs = Vec::with_capacity(max_batch);
+            while pending.recv_many(&mut jobs, max_batch).await > 0 {
+                if !hold.is_zero() {
+                    let deadline = tokio::time::Instant::now() + hold;
+                    while jobs.len() < max_batch {
+                        let room = max_batch - jobs.len();
+                        let more = pending.recv_many(&mut jobs, room);
+                        if !matches!(tokio::time::timeout_at(deadline, more).await, Ok(n) if n > 0) {
+                            break;
+                        }
+                    }
+                }
+                let (items, waiters): (Vec<T>, Vec<oneshot::Sender<O>>) = jobs.drain(..).unzip();
+                let results = flush(items).await;
+                for (waiter, result) in waiters.into_iter().zip(results) {
+                    let _ = waiter.send(result);
+                }
+            }
+        });
+
+        Self { queue }
+    }
+
+    /// Queues `item` and waits until the batch holding it has been written. Fails with `Full`
+    /// straight away when the queue is at capacity, so callers can shed load.
+    pub async fn submit(&self, item: T) -> Result<O, BatchError> {
+        let (done, result) = oneshot::channel();
+        self.queue.try_send((item, done)).map_err(|e| match e {
+            TrySendError::Full(_) => BatchError::Full("write queue is full".into()),
+            TrySendError::Closed(_) => BatchError::Closed("batch writer has stopped".into()),
+        })?;
+
+        result.await.map_err(|_| BatchError::Closed("batch writer dropped the item".into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use tokio::sync::Semaphore;
+
+    type Batches = Arc<Mutex<Vec<Vec<u32>>>>;
+
+    /// A writer whose flushes wait for a permit, recording the batches it was handed.
+    fn gated(capacity: usize, hold: Duration) -> (Arc<BatchWriter<u32, u32>>, Arc<Semaphore>, Batches) {
+        let gate = Arc::new(Semaphore::new(0));
+        let batches = Arc::new(Mutex::new(vec![]));
+
+        let (flush_gate, flush_batches) = (gate.clone(), batches.clone());
+        let config = BatchConfig { max_batch: 8, queue_capacity: capacity, hold };
+        let writer = BatchWriter::spawn(config, move |items: Vec<u32>| {
+            let (gate, batches) = (flush_gate.clone(), flush_batches.clone());
+            async move {
+                gate.acquire().await.unwrap().forget();
+                batches.lock().unwrap().push(items.clone());
+                items.into_iter().map(|i| i * 10).collect()
+            }
+        });
+
+        (Arc::new(writer), gate, batches)
+    }
+
+    async fn settle() {
+        for _ in 0..10 {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_items_queued_during_a_flush_are_written_together() {
+        let (writer, gate, batches) = gated(16, Duration::ZERO);
+
+        let first = tokio::spawn({ let w = writer.clone(); async move { w.submit(1).await } });
+        settle().await;
+        let rest: Vec<_> = (2..=4)
+            .map(|i| tokio::spawn({ let w = writer.clone(); async move { w.submit(i).await } }))
+            .collect();
+        settle().await;
+
+        gate.add_permits(2);
+        assert_eq!(first.await.unwrap(), Ok(10));
+        for (i, handle) in rest.into_iter().enumerate() {
+            assert_eq!(handle.await.unwrap(), Ok((i as u32 + 2) * 10));
+        }
+        assert_eq!(*batches.lock().unwrap(), vec![vec![1], vec![2, 3, 4]]);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_full_queue_is_refused_immediately() {
+        let (writer, gate, _) = gated(1, Duration::ZERO);
+
+        let in_flight = tokio::spawn({ let w = writer.clone(); async move { w.submit(1).await } });
+        settle().await;
+        let queued = tokio::spawn({ let w = writer.clone(); async move { w.submit(2).await } });
+        settle().await;
+
+        assert!(matches!(writer.submit(3).await, Err(BatchError::Full(_))));
+
+        gate.add_permits(2);
+        assert_eq!(in_flight.await.unwrap(), Ok(10));
+        assert_eq!(queued.await.unwrap(), Ok(20));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_hold_gathers_items_submitted_apart_into_one_batch() {
+        let (writer, gate, batches) = gated(16, Duration::from_millis(200));
+        gate.add_permits(1);
+
+        let mut handles = vec![];
+        for i in 1..=3 {
+            handles.push(tokio::spawn({ let w = writer.clone(); async move { w.submit(i).await } }));
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+
+        for (i, handle) in handles.into_iter().enumerate() {
+            assert_eq!(handle.await.unwrap(), Ok((i as u32 + 1) * 10));
+        }
+        assert_eq!(*batches.lock().unwrap(), vec![vec![1, 2, 3]]);
+    }
+}
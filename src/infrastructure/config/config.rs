@@ -22,6 +22,123 @@ pub struct DatabaseConfig {
     pub min_conn: u32,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct VotingConfig {
+    pub session_ttl_secs: i64,
+    /// How long a ballot held for review can still be confirmed.
+    pub review_ttl_secs: i64,
+    /// How long after voting closes a session opened before the close may still cast.
+    pub close_grace_secs: i64,
+    /// Most participations or ballots written in one transaction.
+    pub write_batch_max: usize,
+    /// Casts waiting to be written before new ones are turned away with 503.
+    pub write_queue_capacity: usize,
+    /// How long ballots are gathered before they are written together, in shuffled order.
+    pub ballot_mix_ms: u64,
+}
+
+impl Default for VotingConfig {
+    fn default() -> Self {
+        VotingConfig { session_ttl_secs: 900, review_ttl_secs: 300, close_grace_secs: 120, write_batch_max: 256, write_queue_capacity: 2048, ballot_mix_ms: 1000 }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PrivacyConfig {
+    pub retention_days: i64,
+}
+
+impl Default for PrivacyConfig {
+    fn default() -> Self {
+        PrivacyConfig { retention_days: 365 }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct PiiConfig {
+    pub active_key_id: String,
+    pub keys: String,
+    pub blind_index_key: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct LiveConfig {
+    /// Shortest gap between two pushes to a live results stream.
+    pub debounce_ms: u64,
+    /// How long a stream may stay silent before a keep-alive comment is sent.
+    pub keepalive_secs: u64,
+}
+
+impl Default for LiveConfig {
+    fn default() -> Self {
+        LiveConfig { debounce_ms: 2000, keepalive_secs: 15 }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CountersConfig {
+    /// How often the running tally counters are checked against the ballots; 0 turns it off.
+    pub reconcile_secs: u64,
+}
+
+impl Default for CountersConfig {
+    fn default() -> Self {
+        CountersConfig { reconcile_secs: 300 }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct EmbargoConfig {
+    /// Staff who may see results once voting closes, as `name:role:sha256,...` over their tokens.
+    pub staff: String,
+    /// How long an override waits for its second approver, and then how long it holds.
+    pub override_ttl_secs: i64,
+}
+
+impl Default for EmbargoConfig {
+    fn default() -> Self {
+        EmbargoConfig { staff: String::new(), override_ttl_secs: 900 }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct AnalyticsConfig {
+    /// Turnout groups with fewer voters on the roll, or fewer who voted, are hidden.
+    pub min_group_size: i64,
+}
+
+impl Default for AnalyticsConfig {
+    fn default() -> Self {
+        AnalyticsConfig { min_group_size: 10 }
+    }
+}
+
+/// Keys that sign result snapshots, as `id:base64,id:base64`.
+#[derive(Debug, Deserialize, Clone)]
+pub struct TallyConfig {
+    pub active_key_id: String,
+    pub keys: String,
+}
+
+/// Server-side key mixed into `Idempotency-Key` hashes and sealed cast results, as base64.
+#[derive(Debug, Deserialize, Clone)]
+pub struct SealConfig {
+    pub key: String,
+}
+
+/// Ed25519 key seeds that sign results certificates, as `id:base64,id:base64`.
+#[derive(Debug, Deserialize, Clone)]
+pub struct CertificateConfig {
+    pub active_key_id: String,
+    pub keys: String,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct AppMeta {
     pub env: String,
@@ -34,6 +151,22 @@ pub struct AppConfig {
     pub app: AppMeta,
     pub server: ServerConfig,
     pub database: DatabaseConfig,
+    #[serde(default)]
+    pub voting: VotingConfig,
+    pub pii: PiiConfig,
+    pub tally: TallyConfig,
+    pub certificate: CertificateConfig,
+    pub seal: SealConfig,
+    #[serde(default)]
+    pub live: LiveConfig,
+    #[serde(default)]
+    pub counters: CountersConfig,
+    #[serde(default)]
+    pub embargo: EmbargoConfig,
+    #[serde(default)]
+    pub analytics: AnalyticsConfig,
+    #[serde(default)]
+    pub privacy: PrivacyConfig,
 }
 
 impl AppConfig {
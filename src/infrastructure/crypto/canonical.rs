@@ -0,0 +1,55 @@
+use serde_json::Value;
+
+/// The one text a JSON value signs as: no whitespace, object members sorted by the bytes of
+/// their keys, arrays in order, and strings and numbers as `serde_json` writes them. Parsing
+/// the text and writing it again gives the same bytes, so a verifier can rebuild it from a file.
+pub fn canonical_json(value: &Value) -> String {
+    let mut out = String::new();
+    write_value(&mut out, value);
+    out
+}
+
+fn write_value(out: &mut String, value: &Value) {
+    match value {
+        Value::Object(members) => {
+            let mut sorted: Vec<_> = members.iter().collect();
+            sorted.sort_by(|a, b| a.0.as_bytes().cmp(b.0.as_bytes()));
+            out.push('{');
+            for (i, (key, member)) in sorted.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_value(out, member);
+            }
+            out.push('}');
+        }
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_value(out, item);
+            }
+            out.push(']');
+        }
+        scalar => out.push_str(&scalar.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_canonical_json_ignores_member_order_and_whitespace() {
+        let parsed: Value = serde_json::from_str(r#"{ "b": [1, {"z": null, "a": "é\n"}], "a": 0.5, "B": true }"#).unwrap();
+        let built = json!({"B": true, "a": 0.5, "b": [1, {"a": "é\n", "z": null}]});
+
+        assert_eq!(canonical_json(&parsed), r#"{"B":true,"a":0.5,"b":[1,{"a":"é\n","z":null}]}"#);
+        assert_eq!(canonical_json(&parsed), canonical_json(&built));
+    }
+}
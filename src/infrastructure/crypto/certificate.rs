@@ -0,0 +1,122 @@
+use crate::infrastructure::crypto::{CryptoError, Signature, parse_keys};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use ring::signature::{ED25519, Ed25519KeyPair, KeyPair, UnparsedPublicKey};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+pub const CERTIFICATE_ALGORITHM: &str = "ed25519";
+
+/// `keys` holds Ed25519 private key seeds as `id:base64,id:base64`. Certificates are always
+/// signed with `active_key_id`; the others stay listed so their public keys stay published.
+#[derive(Debug, Clone)]
+pub struct CertifierConfig {
+    pub active_key_id: String,
+    pub keys: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PublicKey {
+    pub key_id: String,
+    pub algorithm: String,
+    /// The raw 32-byte Ed25519 public key, unpadded base64url.
+    pub public_key: String,
+    pub active: bool,
+}
+
+/// Ed25519 signatures over documents that leave the service, such as results certificates.
+/// Unlike `Signer`, anyone holding a public key can check them without the service.
+pub struct Certifier {
+    keys: BTreeMap<String, Ed25519KeyPair>,
+    active_key_id: String,
+}
+
+impl std::fmt::Debug for Certifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Certifier")
+            .field("active_key_id", &self.active_key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Certifier {
+    pub fn from_config(cfg: &CertifierConfig) -> Result<Self, CryptoError> {
+        let mut keys = BTreeMap::new();
+        for (id, seed) in parse_keys(&cfg.keys)? {
+            let pair = Ed25519KeyPair::from_seed_unchecked(&seed)
+                .map_err(|_| CryptoError::InvalidKey(format!("key {} is not a valid Ed25519 seed", id)))?;
+            keys.insert(id, pair);
+        }
+
+        if !keys.contains_key(&cfg.active_key_id) {
+            return Err(CryptoError::UnknownKey(format!(
+                "active key {} is not listed in keys",
+                cfg.active_key_id
+            )));
+        }
+
+        Ok(Certifier { keys, active_key_id: cfg.active_key_id.clone() })
+    }
+
+    pub fn active_key_id(&self) -> &str {
+        &self.active_key_id
+    }
+
+    pub fn sign(&self, message: &[u8]) -> Signature {
+        let pair = &self.keys[&self.active_key_id];
+        Signature {
+            key_id: self.active_key_id.clone(),
+            value: URL_SAFE_NO_PAD.encode(pair.sign(message).as_ref()),
+        }
+    }
+
+    pub fn public_keys(&self) -> Vec<PublicKey> {
+        self.keys
+            .iter()
+            .map(|(id, pair)| PublicKey {
+                key_id: id.clone(),
+                algorithm: CERTIFICATE_ALGORITHM.to_string(),
+                public_key: URL_SAFE_NO_PAD.encode(pair.public_key().as_ref()),
+                active: *id == self.active_key_id,
+            })
+            .collect()
+    }
+}
+
+/// Checks an Ed25519 signature with nothing but the signer's public key, both unpadded base64url.
+pub fn verify_certificate_signature(public_key: &str, message: &[u8], signature: &str) -> Result<(), CryptoError> {
+    let key = URL_SAFE_NO_PAD
+        .decode(public_key.trim())
+        .map_err(|_| CryptoError::InvalidKey("public key is not valid base64url".into()))?;
+    let signature = URL_SAFE_NO_PAD
+        .decode(signature.trim())
+        .map_err(|_| CryptoError::Malformed("invalid base64".into()))?;
+
+    UnparsedPublicKey::new(&ED25519, key)
+        .verify(message, &signature)
+        .map_err(|_| CryptoError::Failed("signature does not match".into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const C1: &str = "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=";
+    const C2: &str = "HyAhIiMkJSYnKCkqKywtLi8wMTIzNDU2Nzg5Ojs8PT4=";
+
+    #[test]
+    fn test_signature_verifies_with_the_published_key_only() {
+        let certifier = Certifier::from_config(&CertifierConfig {
+            active_key_id: "c2".to_string(),
+            keys: format!("c1:{},c2:{}", C1, C2),
+        }).unwrap();
+        let signature = certifier.sign(b"results");
+        let keys = certifier.public_keys();
+
+        assert_eq!(signature.key_id, "c2");
+        assert_eq!(keys.iter().map(|k| (k.key_id.as_str(), k.active)).collect::<Vec<_>>(), vec![("c1", false), ("c2", true)]);
+        assert!(verify_certificate_signature(&keys[1].public_key, b"results", &signature.value).is_ok());
+        assert!(verify_certificate_signature(&keys[1].public_key, b"result", &signature.value).is_err());
+        assert!(verify_certificate_signature(&keys[0].public_key, b"results", &signature.value).is_err());
+    }
+}
@@ -0,0 +1,13 @@
+mod canonical;
+mod certificate;
+mod pepper;
+mod pii;
+mod secret_box;
+mod signing;
+
+pub use canonical::*;
+pub use certificate::*;
+pub use pepper::*;
+pub use pii::*;
+pub use secret_box::*;
+pub use signing::*;
@@ -0,0 +1,343 @@
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+use ring::aead::{Aad, AES_256_GCM, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+use ring::hmac;
+use ring::rand::{SecureRandom, SystemRandom};
+use std::collections::HashMap;
+use thiserror::Error;
+
+const VERSION: &str = "v1";
+const KEY_LEN: usize = 32;
+
+#[derive(Debug, Error, Clone)]
+pub enum CryptoError {
+    #[error("CryptoError::InvalidKey: {0}")]
+    InvalidKey(String),
+    #[error("CryptoError::UnknownKey: {0}")]
+    UnknownKey(String),
+    #[error("CryptoError::Malformed: {0}")]
+    Malformed(String),
+    #[error("CryptoError::Failed: {0}")]
+    Failed(String),
+}
+
+/// `keys` holds every key encryption key still needed for decryption, as
+/// `id:base64,id:base64`. New values are always wrapped with `active_key_id`.
+#[derive(Debug, Clone)]
+pub struct PiiCipherConfig {
+    pub active_key_id: String,
+    pub keys: String,
+    pub blind_index_key: String,
+}
+
+/// Envelope encryption for voter PII columns.
+///
+/// Every value gets its own random data key. The value is sealed with AES-256-GCM under
+/// that data key, and the data key is sealed under a key encryption key from config:
+///
+/// `v1.<kek id>.<nonce ‖ wrapped data key>.<nonce ‖ ciphertext>`
+///
+/// Rotating keys only rewraps the data key; the ciphertext itself is left untouched.
+/// The field name is bound in as associated data, so ciphertexts cannot be swapped
+/// between columns.
+pub struct PiiCipher {
+    keys: HashMap<String, LessSafeKey>,
+    active_key_id: String,
+    blind_index_key: hmac::Key,
+    rng: SystemRandom,
+}
+
+impl std::fmt::Debug for PiiCipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PiiCipher")
+            .field("active_key_id", &self.active_key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+pub(crate) fn decode_key(id: &str, value: &str) -> Result<Vec<u8>, CryptoError> {
+    let bytes = STANDARD
+        .decode(value.trim())
+        .map_err(|_| CryptoError::InvalidKey(format!("key {} is not valid base64", id)))?;
+    if bytes.len() != KEY_LEN {
+        return Err(CryptoError::InvalidKey(format!("key {} must be {} bytes", id, KEY_LEN)));
+    }
+    Ok(bytes)
+}
+
+/// Splits an `id:base64,id:base64` key list into ids and decoded keys.
+pub(crate) fn parse_keys(keys: &str) -> Result<Vec<(String, Vec<u8>)>, CryptoError> {
+    let mut parsed = Vec::new();
+
+    for entry in keys.split(',').filter(|e| !e.trim().is_empty()) {
+        let (id, value) = entry
+            .split_once(':')
+            .ok_or_else(|| CryptoError::InvalidKey("key entries must look like id:base64".into()))?;
+        let id = id.trim();
+        if id.is_empty() || !id.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+            return Err(CryptoError::InvalidKey(format!("invalid key id {:?}", id)));
+        }
+        parsed.push((id.to_string(), decode_key(id, value)?));
+    }
+
+    Ok(parsed)
+}
+
+impl PiiCipher {
+    pub fn from_config(cfg: &PiiCipherConfig) -> Result<Self, CryptoError> {
+        let mut keys = HashMap::new();
+
+        for (id, bytes) in parse_keys(&cfg.keys)? {
+            let key = UnboundKey::new(&AES_256_GCM, &bytes)
+                .map_err(|_| CryptoError::InvalidKey(format!("key {} is not a valid AES-256 key", id)))?;
+            keys.insert(id, LessSafeKey::new(key));
+        }
+
+        if !keys.contains_key(&cfg.active_key_id) {
+            return Err(CryptoError::UnknownKey(format!(
+                "active key {} is not listed in keys",
+                cfg.active_key_id
+            )));
+        }
+
+        let blind_index_key = hmac::Key::new(
+            hmac::HMAC_SHA256,
+            &decode_key("blind_index_key", &cfg.blind_index_key)?,
+        );
+
+        Ok(PiiCipher {
+            keys,
+            active_key_id: cfg.active_key_id.clone(),
+            blind_index_key,
+            rng: SystemRandom::new(),
+        })
+    }
+
+    pub fn encrypt(&self, field: &str, plaintext: &str) -> Result<String, CryptoError> {
+        let mut dek = [0u8; KEY_LEN];
+        self.rng.fill(&mut dek).map_err(|_| CryptoError::Failed("rng failure".into()))?;
+
+        let data_key = LessSafeKey::new(
+            UnboundKey::new(&AES_256_GCM, &dek).map_err(|_| CryptoError::Failed("invalid data key".into()))?,
+        );
+        let sealed_value = self.seal(&data_key, field.as_bytes(), plaintext.as_bytes())?;
+        let wrapped_key = self.wrap(&self.active_key_id, &dek)?;
+
+        Ok(format!(
+            "{}.{}.{}.{}",
+            VERSION,
+            self.active_key_id,
+            URL_SAFE_NO_PAD.encode(wrapped_key),
+            URL_SAFE_NO_PAD.encode(sealed_value)
+        ))
+    }
+
+    pub fn decrypt(&self, field: &str, stored: &str) -> Result<String, CryptoError> {
+        let envelope = Envelope::parse(stored)?;
+        let dek = self.unwrap(envelope.key_id, envelope.wrapped_key)?;
+
+        let data_key = LessSafeKey::new(
+            UnboundKey::new(&AES_256_GCM, &dek).map_err(|_| CryptoError::Failed("invalid data key".into()))?,
+        );
+        let plaintext = Self::open(&data_key, field.as_bytes(), envelope.sealed_value)?;
+
+        String::from_utf8(plaintext).map_err(|_| CryptoError::Malformed("plaintext is not utf-8".into()))
+    }
+
+    /// Like `decrypt`, but passes through values that are not encrypted yet, so rows
+    /// written before encryption was introduced stay readable until they are rewrapped.
+    pub fn reveal(&self, field: &str, stored: &str) -> Result<String, CryptoError> {
+        match Envelope::parse(stored) {
+            Ok(_) => self.decrypt(field, stored),
+            Err(_) => Ok(stored.to_string()),
+        }
+    }
+
+    /// True when the value is still plaintext or wrapped with a key other than the active one.
+    pub fn needs_rewrap(&self, stored: &str) -> bool {
+        match Envelope::parse(stored) {
+            Ok(envelope) => envelope.key_id != self.active_key_id,
+            Err(_) => true,
+        }
+    }
+
+    /// Rewraps the data key under the active key. Values that are not encrypted yet,
+    /// e.g. rows written before encryption was introduced, are encrypted from scratch.
+    pub fn rewrap(&self, field: &str, stored: &str) -> Result<String, CryptoError> {
+        let envelope = match Envelope::parse(stored) {
+            Ok(envelope) => envelope,
+            Err(_) => return self.encrypt(field, stored),
+        };
+        if envelope.key_id == self.active_key_id {
+            return Ok(stored.to_string());
+        }
+
+        let dek = self.unwrap(envelope.key_id, envelope.wrapped_key)?;
+        let wrapped_key = self.wrap(&self.active_key_id, &dek)?;
+
+        Ok(format!(
+            "{}.{}.{}.{}",
+            VERSION,
+            self.active_key_id,
+            URL_SAFE_NO_PAD.encode(wrapped_key),
+            URL_SAFE_NO_PAD.encode(envelope.sealed_value)
+        ))
+    }
+
+    /// Keyed blind index for exact-match lookups on a value without decrypting it.
+    pub fn blind_index(&self, field: &str, value: &str) -> String {
+        let mut ctx = hmac::Context::with_key(&self.blind_index_key);
+        ctx.update(field.as_bytes());
+        ctx.update(&[0]);
+        ctx.update(value.trim().as_bytes());
+        hex::encode(ctx.sign().as_ref())
+    }
+
+    fn wrap(&self, key_id: &str, dek: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        let kek = self.keys.get(key_id).ok_or_else(|| CryptoError::UnknownKey(key_id.to_string()))?;
+        self.seal(kek, key_id.as_bytes(), dek)
+    }
+
+    fn unwrap(&self, key_id: &str, wrapped: Vec<u8>) -> Result<Vec<u8>, CryptoError> {
+        let kek = self.keys.get(key_id).ok_or_else(|| CryptoError::UnknownKey(key_id.to_string()))?;
+        Self::open(kek, key_id.as_bytes(), wrapped)
+    }
+
+    fn seal(&self, key: &LessSafeKey, aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, CryptoError> {
+        let mut nonce = [0u8; NONCE_LEN];
+        self.rng.fill(&mut nonce).map_err(|_| CryptoError::Failed("rng failure".into()))?;
+
+        let mut in_out = plaintext.to_vec();
+        key.seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::from(aad), &mut in_out)
+            .map_err(|_| CryptoError::Failed("seal failed".into()))?;
+
+        let mut out = nonce.to_vec();
+        out.extend_from_slice(&in_out);
+        Ok(out)
+    }
+
+    fn open(key: &LessSafeKey, aad: &[u8], sealed: Vec<u8>) -> Result<Vec<u8>, CryptoError> {
+        if sealed.len() < NONCE_LEN {
+            return Err(CryptoError::Malformed("sealed value too short".into()));
+        }
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce)
+            .map_err(|_| CryptoError::Malformed("bad nonce".into()))?;
+
+        let mut in_out = ciphertext.to_vec();
+        let plaintext = key.open_in_place(nonce, Aad::from(aad), &mut in_out)
+            .map_err(|_| CryptoError::Failed("authentication failed".into()))?;
+        Ok(plaintext.to_vec())
+    }
+}
+
+struct Envelope<'a> {
+    key_id: &'a str,
+    wrapped_key: Vec<u8>,
+    sealed_value: Vec<u8>,
+}
+
+impl<'a> Envelope<'a> {
+    fn parse(stored: &'a str) -> Result<Envelope<'a>, CryptoError> {
+        let mut parts = stored.split('.');
+        let (Some(VERSION), Some(key_id), Some(wrapped), Some(sealed), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(CryptoError::Malformed("not a v1 envelope".into()));
+        };
+
+        let decode = |v: &str| URL_SAFE_NO_PAD
+            .decode(v)
+            .map_err(|_| CryptoError::Malformed("invalid base64".into()));
+
+        Ok(Envelope {
+            key_id,
+            wrapped_key: decode(wrapped)?,
+            sealed_value: decode(sealed)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const K1: &str = "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=";
+    const K2: &str = "HyAhIiMkJSYnKCkqKywtLi8wMTIzNDU2Nzg5Ojs8PT4=";
+
+    fn cipher(active: &str, keys: &str) -> PiiCipher {
+        PiiCipher::from_config(&PiiCipherConfig {
+            active_key_id: active.to_string(),
+            keys: keys.to_string(),
+            blind_index_key: K2.to_string(),
+        }).unwrap()
+    }
+
+    #[test]
+    fn test_roundtrip_is_randomised() {
+        let c = cipher("k1", &format!("k1:{}", K1));
+
+        let a = c.encrypt("nim", "2023010001").unwrap();
+        let b = c.encrypt("nim", "2023010001").unwrap();
+
+        assert_ne!(a, b);
+        assert!(!a.contains("2023010001"));
+        assert_eq!(c.decrypt("nim", &a).unwrap(), "2023010001");
+    }
+
+    #[test]
+    fn test_field_is_bound() {
+        let c = cipher("k1", &format!("k1:{}", K1));
+        let stored = c.encrypt("name", "Alice").unwrap();
+
+        assert!(c.decrypt("email", &stored).is_err());
+    }
+
+    #[test]
+    fn test_rotation_rewraps_without_reencrypting() {
+        let old = cipher("k1", &format!("k1:{}", K1));
+        let stored = old.encrypt("email", "alice@campus.ac.id").unwrap();
+
+        let rotated = cipher("k2", &format!("k1:{},k2:{}", K1, K2));
+        assert!(rotated.needs_rewrap(&stored));
+
+        let rewrapped = rotated.rewrap("email", &stored).unwrap();
+        assert!(!rotated.needs_rewrap(&rewrapped));
+        assert!(rewrapped.starts_with("v1.k2."));
+        assert_eq!(stored.rsplit('.').next(), rewrapped.rsplit('.').next());
+
+        let retired = cipher("k2", &format!("k2:{}", K2));
+        assert_eq!(retired.decrypt("email", &rewrapped).unwrap(), "alice@campus.ac.id");
+        assert!(matches!(retired.decrypt("email", &stored), Err(CryptoError::UnknownKey(_))));
+    }
+
+    #[test]
+    fn test_rewrap_encrypts_legacy_plaintext() {
+        let c = cipher("k1", &format!("k1:{}", K1));
+
+        assert!(c.needs_rewrap("Alice"));
+        assert_eq!(c.reveal("name", "Alice").unwrap(), "Alice");
+        let stored = c.rewrap("name", "Alice").unwrap();
+        assert_eq!(c.decrypt("name", &stored).unwrap(), "Alice");
+    }
+
+    #[test]
+    fn test_blind_index_is_deterministic_and_keyed() {
+        let c = cipher("k1", &format!("k1:{}", K1));
+
+        assert_eq!(c.blind_index("nim", "2023010001"), c.blind_index("nim", " 2023010001 "));
+        assert_ne!(c.blind_index("nim", "2023010001"), c.blind_index("nim", "2023010002"));
+        assert_ne!(c.blind_index("nim", "2023010001"), c.blind_index("email", "2023010001"));
+    }
+
+    #[test]
+    fn test_active_key_must_be_configured() {
+        let res = PiiCipher::from_config(&PiiCipherConfig {
+            active_key_id: "k2".to_string(),
+            keys: format!("k1:{}", K1),
+            blind_index_key: K2.to_string(),
+        });
+
+        assert!(matches!(res, Err(CryptoError::UnknownKey(_))));
+    }
+}
@@ -0,0 +1,70 @@
+use crate::infrastructure::crypto::CryptoError;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use ring::aead::{Aad, AES_256_GCM, LessSafeKey, NONCE_LEN, Nonce, UnboundKey};
+use ring::rand::{SecureRandom, SystemRandom};
+use sha2::{Digest, Sha256};
+
+/// Seals a small value under a key derived from a secret only the client holds, such as a
+/// session token or an idempotency key. The server stores the result and a hash of the
+/// secret, so it can open the value again only while the client presents the secret.
+pub fn seal_with_secret(purpose: &str, secret: &str, plaintext: &[u8]) -> Result<String, CryptoError> {
+    let mut nonce = [0u8; NONCE_LEN];
+    SystemRandom::new()
+        .fill(&mut nonce)
+        .map_err(|_| CryptoError::Failed("rng failure".into()))?;
+
+    let mut in_out = plaintext.to_vec();
+    key(purpose, secret)?
+        .seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::from(purpose.as_bytes()), &mut in_out)
+        .map_err(|_| CryptoError::Failed("seal failed".into()))?;
+
+    let mut out = nonce.to_vec();
+    out.extend_from_slice(&in_out);
+    Ok(URL_SAFE_NO_PAD.encode(out))
+}
+
+pub fn open_with_secret(purpose: &str, secret: &str, sealed: &str) -> Result<Vec<u8>, CryptoError> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(sealed)
+        .map_err(|_| CryptoError::Malformed("invalid base64".into()))?;
+    if bytes.len() < NONCE_LEN {
+        return Err(CryptoError::Malformed("sealed value too short".into()));
+    }
+
+    let (nonce, ciphertext) = bytes.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce)
+        .map_err(|_| CryptoError::Malformed("bad nonce".into()))?;
+    let mut in_out = ciphertext.to_vec();
+    let plaintext = key(purpose, secret)?
+        .open_in_place(nonce, Aad::from(purpose.as_bytes()), &mut in_out)
+        .map_err(|_| CryptoError::Failed("authentication failed".into()))?;
+    Ok(plaintext.to_vec())
+}
+
+fn key(purpose: &str, secret: &str) -> Result<LessSafeKey, CryptoError> {
+    let mut hasher = Sha256::new();
+    hasher.update(purpose.as_bytes());
+    hasher.update([0]);
+    hasher.update(secret.as_bytes());
+    let bytes: [u8; 32] = hasher.finalize().into();
+
+    UnboundKey::new(&AES_256_GCM, &bytes)
+        .map(LessSafeKey::new)
+        .map_err(|_| CryptoError::InvalidKey("cannot derive key from secret".into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_opens_only_with_same_secret_and_purpose() {
+        let sealed = seal_with_secret("p1", "s1", b"hello").unwrap();
+
+        assert_ne!(sealed, seal_with_secret("p1", "s1", b"hello").unwrap());
+        assert_eq!(open_with_secret("p1", "s1", &sealed).unwrap(), b"hello");
+        assert!(open_with_secret("p1", "s2", &sealed).is_err());
+        assert!(open_with_secret("p2", "s1", &sealed).is_err());
+    }
+}
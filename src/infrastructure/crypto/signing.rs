@@ -0,0 +1,116 @@
+use crate::infrastructure::crypto::{CryptoError, parse_keys};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use ring::hmac;
+use std::collections::HashMap;
+
+/// `keys` holds every signing key still needed to verify stored records, as
+/// `id:base64,id:base64`. New records are always signed with `active_key_id`.
+#[derive(Debug, Clone)]
+pub struct SignerConfig {
+    pub active_key_id: String,
+    pub keys: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Signature {
+    pub key_id: String,
+    pub value: String,
+}
+
+/// HMAC-SHA256 signatures over records the service keeps, such as result snapshots, so
+/// a row edited in the database no longer verifies. The purpose is bound in, so a
+/// signature cannot be moved between kinds of record.
+pub struct Signer {
+    keys: HashMap<String, hmac::Key>,
+    active_key_id: String,
+}
+
+impl std::fmt::Debug for Signer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Signer")
+            .field("active_key_id", &self.active_key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Signer {
+    pub fn from_config(cfg: &SignerConfig) -> Result<Self, CryptoError> {
+        let keys: HashMap<String, hmac::Key> = parse_keys(&cfg.keys)?
+            .into_iter()
+            .map(|(id, bytes)| (id, hmac::Key::new(hmac::HMAC_SHA256, &bytes)))
+            .collect();
+
+        if !keys.contains_key(&cfg.active_key_id) {
+            return Err(CryptoError::UnknownKey(format!(
+                "active key {} is not listed in keys",
+                cfg.active_key_id
+            )));
+        }
+
+        Ok(Signer { keys, active_key_id: cfg.active_key_id.clone() })
+    }
+
+    pub fn sign(&self, purpose: &str, message: &[u8]) -> Signature {
+        let key = &self.keys[&self.active_key_id];
+        Signature {
+            key_id: self.active_key_id.clone(),
+            value: URL_SAFE_NO_PAD.encode(hmac::sign(key, &Self::framed(purpose, message)).as_ref()),
+        }
+    }
+
+    pub fn verify(&self, purpose: &str, message: &[u8], signature: &Signature) -> Result<(), CryptoError> {
+        let key = self.keys
+            .get(&signature.key_id)
+            .ok_or_else(|| CryptoError::UnknownKey(signature.key_id.clone()))?;
+        let tag = URL_SAFE_NO_PAD
+            .decode(&signature.value)
+            .map_err(|_| CryptoError::Malformed("invalid base64".into()))?;
+
+        hmac::verify(key, &Self::framed(purpose, message), &tag)
+            .map_err(|_| CryptoError::Failed("signature does not match".into()))
+    }
+
+    fn framed(purpose: &str, message: &[u8]) -> Vec<u8> {
+        let mut framed = Vec::with_capacity(purpose.len() + 1 + message.len());
+        framed.extend_from_slice(purpose.as_bytes());
+        framed.push(0);
+        framed.extend_from_slice(message);
+        framed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const K1: &str = "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=";
+    const K2: &str = "HyAhIiMkJSYnKCkqKywtLi8wMTIzNDU2Nzg5Ojs8PT4=";
+
+    fn signer(active: &str, keys: &str) -> Signer {
+        Signer::from_config(&SignerConfig { active_key_id: active.to_string(), keys: keys.to_string() }).unwrap()
+    }
+
+    #[test]
+    fn test_signature_binds_purpose_and_message() {
+        let s = signer("t1", &format!("t1:{}", K1));
+        let signature = s.sign("tally", b"results");
+
+        assert!(s.verify("tally", b"results", &signature).is_ok());
+        assert!(s.verify("tally", b"result", &signature).is_err());
+        assert!(s.verify("recount", b"results", &signature).is_err());
+    }
+
+    #[test]
+    fn test_retired_keys_still_verify() {
+        let old = signer("t1", &format!("t1:{}", K1));
+        let signature = old.sign("tally", b"results");
+
+        let rotated = signer("t2", &format!("t1:{},t2:{}", K1, K2));
+        assert!(rotated.verify("tally", b"results", &signature).is_ok());
+        assert_eq!(rotated.sign("tally", b"results").key_id, "t2");
+
+        let dropped = signer("t2", &format!("t2:{}", K2));
+        assert!(matches!(dropped.verify("tally", b"results", &signature), Err(CryptoError::UnknownKey(_))));
+    }
+}
@@ -1,10 +1,25 @@
 use std::sync::Arc;
 use actix_web::{App, HttpServer, web};
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, watch};
 
 
 type AppServiceConfig = Arc<dyn Fn(&mut web::ServiceConfig) + Send + Sync + 'static>;
 
+/// Registered as app data for every handler. Resolves once `Server::stop` is called, so
+/// long-lived responses such as event streams can end instead of holding up shutdown.
+#[derive(Clone)]
+pub struct Shutdown(watch::Receiver<bool>);
+
+impl Shutdown {
+    pub fn new(receiver: watch::Receiver<bool>) -> Self {
+        Shutdown(receiver)
+    }
+
+    pub async fn wait(mut self) {
+        let _ = self.0.wait_for(|stopping| *stopping).await;
+    }
+}
+
 pub struct Config {
     pub address: String,
     pub port: u16,
@@ -15,8 +30,7 @@ pub struct Server {
     config: Config,
     server_handle: Option<Arc<Mutex<actix_web::dev::ServerHandle>>>,
     routes: Vec<AppServiceConfig>,
-
-
+    shutdown: watch::Sender<bool>,
 }
 
 impl Server {
@@ -25,6 +39,7 @@ impl Server {
            config: cfg,
            server_handle: None,
            routes: Vec::new(),
+           shutdown: watch::channel(false).0,
        }
     }
 
@@ -42,10 +57,11 @@ impl Server {
 
         let routes = Arc::new(self.routes.clone());
         let web_data = web::Data::new(app_data);
+        let shutdown = web::Data::new(Shutdown::new(self.shutdown.subscribe()));
 
         let server = HttpServer::new(move || {
 
-            let mut app = App::new().app_data(web_data.clone());
+            let mut app = App::new().app_data(web_data.clone()).app_data(shutdown.clone());
 
             for route_fn in routes.iter() {
                 app = app.configure(|cfg| route_fn(cfg));
@@ -65,6 +81,9 @@ impl Server {
     pub async fn stop(&self) {
         println!("Server stopped listening on http://{}:{}", self.config.address, self.config.port);
 
+        // Open event streams end first; a graceful stop would otherwise wait them out.
+        self.shutdown.send_replace(true);
+
         if let Some(handle) = &self.server_handle {
             println!("Server stopped listening on http://{}", self.config.address);
 
@@ -1,4 +1,6 @@
 pub mod http;
 
 pub mod database;
-pub mod config;
\ No newline at end of file
+pub mod config;
+pub mod crypto;
+pub mod batch;
\ No newline at end of file
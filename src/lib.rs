@@ -1,4 +1,15 @@
 pub mod infrastructure;
 pub mod candidate;
+pub mod credential;
+pub mod election;
+pub mod voter;
+pub mod privacy;
+pub mod group;
+pub mod vote;
+pub mod paper;
+pub mod tally;
+pub mod embargo;
+pub mod analytics;
+pub mod rla;
 pub mod utils;
-pub mod app;
\ No newline at end of file
+pub mod app;
@@ -1,6 +1,6 @@
-
-
 #[tokio::main]
 async fn main() {
+    // Errors are logged even without RUST_LOG.
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("error")).init();
     vote_svc::app::run().await 
 }
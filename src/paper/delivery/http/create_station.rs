@@ -0,0 +1,31 @@
+use crate::paper::delivery::http::errors::error_response;
+use crate::paper::usecase::create_station::*;
+use crate::utils::{app, response};
+use actix_web::{HttpResponse, web};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct CreateStationBody {
+    name: String,
+    faculty: String,
+}
+
+pub async fn create_station(
+    handler: web::Data<app::AppHandlerData>,
+    path: web::Path<String>,
+    body: web::Json<CreateStationBody>,
+) -> HttpResponse {
+
+    let body = body.into_inner();
+    let request = Request { election_id: path.into_inner(), name: body.name, faculty: body.faculty };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.paper_uc.create_station.handle(request).await {
+        Ok(station) => HttpResponse::Created().json(response::success(
+            Some(station),
+            "Successfully processed station".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
@@ -0,0 +1,64 @@
+use crate::paper::domain::PaperError;
+use crate::utils::response;
+use actix_web::HttpResponse;
+
+pub fn error_response(e: &PaperError) -> HttpResponse {
+    println!("Error: {}", e);
+    match e {
+        PaperError::NotFound(msg) => HttpResponse::NotFound().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "PAPER_NOT_FOUND".into(),
+        )),
+        PaperError::AlreadyExists(msg) => HttpResponse::Conflict().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "PAPER_STATION_EXISTS".into(),
+        )),
+        PaperError::InvalidInput(msg) => HttpResponse::BadRequest().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "PAPER_INVALID_INPUT".into(),
+        )),
+        PaperError::Unauthorized(msg) => HttpResponse::Unauthorized().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "PAPER_UNAUTHORIZED".into(),
+        )),
+        PaperError::Forbidden(msg) => HttpResponse::Forbidden().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "PAPER_FORBIDDEN".into(),
+        )),
+        PaperError::InvalidCode(msg) => HttpResponse::UnprocessableEntity().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "PAPER_INVALID_CODE".into(),
+        )),
+        PaperError::InvalidPhase(msg) => HttpResponse::Conflict().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "PAPER_INVALID_PHASE".into(),
+        )),
+        PaperError::SameMember(msg) => HttpResponse::Conflict().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "PAPER_SAME_MEMBER".into(),
+        )),
+        PaperError::AlreadyMerged(msg) => HttpResponse::Conflict().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "PAPER_ALREADY_MERGED".into(),
+        )),
+        PaperError::NotReconciled(msg) => HttpResponse::Conflict().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "PAPER_NOT_RECONCILED".into(),
+        )),
+        PaperError::UnknownError(_) => HttpResponse::InternalServerError().json(response::error::<()>(
+            None,
+            "failed process data".into(),
+            "-1".into(),
+        )),
+    }
+}
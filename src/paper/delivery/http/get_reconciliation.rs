@@ -0,0 +1,19 @@
+use crate::paper::delivery::http::errors::error_response;
+use crate::paper::usecase::get_reconciliation::*;
+use crate::utils::{app, response};
+use actix_web::{HttpResponse, web};
+
+pub async fn get_reconciliation(handler: web::Data<app::AppHandlerData>, path: web::Path<String>) -> HttpResponse {
+
+    let request = Request { station_id: path.into_inner() };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.paper_uc.get_reconciliation.handle(request).await {
+        Ok(reconciliation) => HttpResponse::Ok().json(response::success(
+            Some(reconciliation),
+            "Successfully processed reconciliation".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
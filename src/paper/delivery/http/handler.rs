@@ -0,0 +1,17 @@
+use actix_web::web;
+use crate::paper::delivery::http::create_station::create_station;
+use crate::paper::delivery::http::get_reconciliation::get_reconciliation;
+use crate::paper::delivery::http::list_stations::list_stations;
+use crate::paper::delivery::http::merge_station::merge_station;
+use crate::paper::delivery::http::sign_in::sign_in;
+use crate::paper::delivery::http::submit_entry::submit_entry;
+
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/elections/{id}/stations", web::get().to(list_stations))
+        .route("/elections/{id}/stations", web::post().to(create_station))
+        .route("/stations/{id}/sign-ins", web::post().to(sign_in))
+        .route("/stations/{id}/entries/{slot}", web::put().to(submit_entry))
+        .route("/stations/{id}/reconciliation", web::get().to(get_reconciliation))
+        .route("/stations/{id}/merge", web::post().to(merge_station));
+}
@@ -0,0 +1,19 @@
+use crate::paper::delivery::http::errors::error_response;
+use crate::paper::usecase::list_stations::*;
+use crate::utils::{app, response};
+use actix_web::{HttpResponse, web};
+
+pub async fn list_stations(handler: web::Data<app::AppHandlerData>, path: web::Path<String>) -> HttpResponse {
+
+    let request = Request { election_id: path.into_inner() };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.paper_uc.list_stations.handle(request).await {
+        Ok(stations) => HttpResponse::Ok().json(response::success(
+            Some(stations),
+            "Successfully processed station".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
@@ -0,0 +1,27 @@
+use crate::embargo::delivery::http::staff_token;
+use crate::paper::delivery::http::errors::error_response;
+use crate::paper::usecase::merge::*;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+
+pub async fn merge_station(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+
+    let request = Request {
+        station_id: path.into_inner(),
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.paper_uc.merge.handle(request).await {
+        Ok(station) => HttpResponse::Ok().json(response::success(
+            Some(station),
+            "Successfully processed station".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
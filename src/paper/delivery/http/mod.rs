@@ -0,0 +1,16 @@
+mod handler;
+mod errors;
+mod create_station;
+mod get_reconciliation;
+mod list_stations;
+mod merge_station;
+mod sign_in;
+mod submit_entry;
+
+pub use create_station::*;
+pub use get_reconciliation::*;
+pub use list_stations::*;
+pub use merge_station::*;
+pub use sign_in::*;
+pub use submit_entry::*;
+pub use handler::*;
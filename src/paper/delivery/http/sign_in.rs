@@ -0,0 +1,83 @@
+use crate::embargo::delivery::http::staff_token;
+use crate::paper::delivery::http::errors::error_response;
+use crate::paper::usecase::sign_in::*;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct SignInBody {
+    code: String,
+}
+
+pub async fn sign_in(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<SignInBody>,
+) -> HttpResponse {
+
+    let request = Request {
+        station_id: path.into_inner(),
+        code: body.into_inner().code,
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.paper_uc.sign_in.handle(request).await {
+        Ok(station) => HttpResponse::Ok().json(response::success(
+            Some(station),
+            "Successfully processed sign-in".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sign_in;
+    use actix_web::{App, http::StatusCode, test, web};
+    use async_trait::async_trait;
+    use std::sync::Arc;
+    use crate::paper::domain::{PaperError, Station};
+    use crate::paper::usecase::sign_in::{Interactor, Request};
+    use crate::utils::app;
+
+    #[actix_rt::test]
+    async fn test_sign_in_maps_used_code_to_unprocessable() {
+        struct MockSignIn;
+
+        #[async_trait]
+        impl Interactor for MockSignIn {
+            async fn handle(&self, req: Request) -> Result<Station, PaperError> {
+                assert_eq!(req.station_id, "s1");
+                assert_eq!(req.code, "7K3M-Q9TX-2B4R-HW8D");
+                assert_eq!(req.staff_token.as_deref(), Some("rina-token"));
+                Err(PaperError::InvalidCode("code was not issued at this station or is already used".into()))
+            }
+        }
+
+        let mut paper_uc = app::AppHandlerData::mocked().paper_uc;
+        paper_uc.sign_in = Arc::new(MockSignIn);
+        let app_data = web::Data::new(app::AppHandlerData { paper_uc, ..app::AppHandlerData::mocked() });
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_data)
+                .route("/stations/{id}/sign-ins", web::post().to(sign_in)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/stations/s1/sign-ins")
+            .insert_header(("Authorization", "Bearer rina-token"))
+            .set_json(serde_json::json!({"code": "7K3M-Q9TX-2B4R-HW8D"}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error_code"], "PAPER_INVALID_CODE");
+    }
+}
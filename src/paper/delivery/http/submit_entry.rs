@@ -0,0 +1,98 @@
+use crate::embargo::delivery::http::staff_token;
+use crate::paper::delivery::http::errors::error_response;
+use crate::paper::domain::{ChoiceCount, StationTotals};
+use crate::paper::usecase::submit_entry::*;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct SubmitEntryBody {
+    ballots: i64,
+    #[serde(default)]
+    blank: i64,
+    #[serde(default)]
+    invalid: i64,
+    #[serde(default)]
+    counts: Vec<ChoiceCount>,
+}
+
+pub async fn submit_entry(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<(String, i16)>,
+    body: web::Json<SubmitEntryBody>,
+) -> HttpResponse {
+
+    let (station_id, slot) = path.into_inner();
+    let body = body.into_inner();
+    let request = Request {
+        station_id,
+        slot,
+        staff_token: staff_token(&http_request),
+        totals: StationTotals {
+            ballots: body.ballots,
+            blank: body.blank,
+            invalid: body.invalid,
+            counts: body.counts,
+        },
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.paper_uc.submit_entry.handle(request).await {
+        Ok(entry) => HttpResponse::Ok().json(response::success(
+            Some(entry),
+            "Successfully processed entry".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::submit_entry;
+    use actix_web::{App, http::StatusCode, test, web};
+    use async_trait::async_trait;
+    use std::sync::Arc;
+    use crate::paper::domain::{PaperEntry, PaperError};
+    use crate::paper::usecase::submit_entry::{Interactor, Request};
+    use crate::utils::app;
+
+    #[actix_rt::test]
+    async fn test_submit_entry_maps_same_member_to_conflict() {
+        struct MockSubmit;
+
+        #[async_trait]
+        impl Interactor for MockSubmit {
+            async fn handle(&self, req: Request) -> Result<PaperEntry, PaperError> {
+                assert_eq!(req.slot, 2);
+                assert_eq!(req.staff_token.as_deref(), Some("rina-token"));
+                assert_eq!(req.totals.blank, 0);
+                Err(PaperError::SameMember("Rina already keyed in the other entry".into()))
+            }
+        }
+
+        let mut paper_uc = app::AppHandlerData::mocked().paper_uc;
+        paper_uc.submit_entry = Arc::new(MockSubmit);
+        let app_data = web::Data::new(app::AppHandlerData { paper_uc, ..app::AppHandlerData::mocked() });
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_data)
+                .route("/stations/{id}/entries/{slot}", web::put().to(submit_entry)),
+        )
+        .await;
+
+        let req = test::TestRequest::put()
+            .uri("/stations/s1/entries/2")
+            .insert_header(("Authorization", "Bearer rina-token"))
+            .set_json(serde_json::json!({"ballots": 0}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error_code"], "PAPER_SAME_MEMBER");
+    }
+}
@@ -0,0 +1,107 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+
+/// A polling station where one faculty votes on paper.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Station {
+    pub id: String,
+    pub election_id: String,
+    pub name: String,
+    pub faculty: String,
+    /// Voting codes issued for this station's faculty to be handed out there.
+    pub credentials_issued: i64,
+    /// Codes used up at sign-in, i.e. voters handed a paper ballot at this station.
+    pub signed_in: i64,
+    /// Set once the agreed totals have joined the electronic tally.
+    pub merged_at: Option<DateTime<Utc>>,
+    /// The staff member who merged the station.
+    pub merged_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct NewStation {
+    pub election_id: String,
+    pub name: String,
+    pub faculty: String,
+}
+
+/// Votes for one candidate, or abstentions in the contest when `candidate_id` is unset.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ChoiceCount {
+    pub contest_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub candidate_id: Option<String>,
+    pub votes: i64,
+}
+
+/// What the committee counted out of a station's ballot box.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct StationTotals {
+    /// Every paper ballot in the box, whether valid, blank or invalid.
+    pub ballots: i64,
+    pub blank: i64,
+    /// Spoilt ballots: marked for more than one candidate, torn, unreadable.
+    pub invalid: i64,
+    pub counts: Vec<ChoiceCount>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct NewEntry {
+    pub station_id: String,
+    /// 1 or 2; each committee member keys the totals into their own slot.
+    pub slot: i16,
+    pub entered_by: String,
+    pub totals: StationTotals,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PaperEntry {
+    pub station_id: String,
+    pub slot: i16,
+    pub entered_by: String,
+    pub totals: StationTotals,
+    pub entered_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReconciliationStatus {
+    /// Fewer than two entries so far.
+    AwaitingEntries,
+    /// The two entries disagree; see `mismatches`.
+    EntriesDiffer,
+    /// The entries agree, but the ballots in the box do not match the voters signed in.
+    SignInMismatch,
+    Ready,
+    Merged,
+}
+
+/// A figure the two entries disagree on.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Mismatch {
+    /// `ballots`, `blank`, `invalid`, `<contest>:<candidate>` or `<contest>/abstain`.
+    pub field: String,
+    pub first: i64,
+    pub second: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Reconciliation {
+    pub station_id: String,
+    pub status: ReconciliationStatus,
+    pub credentials_issued: i64,
+    pub signed_in: i64,
+    /// Ballots in the box, once both entries agree on it.
+    pub ballots: Option<i64>,
+    pub mismatches: Vec<Mismatch>,
+}
+
+/// The state a merge was reconciled against; merging fails if any of it has moved on.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AgreedTotals {
+    pub station_id: String,
+    pub entered_at: Vec<DateTime<Utc>>,
+    pub signed_in: i64,
+}
@@ -0,0 +1,49 @@
+use crate::election::domain::ElectionError;
+use crate::embargo::domain::StaffDenied;
+use thiserror::Error;
+
+
+#[derive(Debug, Error)]
+#[derive(Clone)]
+pub enum PaperError {
+    #[error("PaperError::NotFound: {0}")]
+    NotFound(String),
+    #[error("PaperError::AlreadyExists: {0}")]
+    AlreadyExists(String),
+    #[error("PaperError::InvalidInput: {0}")]
+    InvalidInput(String),
+    #[error("PaperError::Unauthorized: {0}")]
+    Unauthorized(String),
+    #[error("PaperError::Forbidden: {0}")]
+    Forbidden(String),
+    #[error("PaperError::InvalidCode: {0}")]
+    InvalidCode(String),
+    #[error("PaperError::InvalidPhase: {0}")]
+    InvalidPhase(String),
+    #[error("PaperError::SameMember: {0}")]
+    SameMember(String),
+    #[error("PaperError::AlreadyMerged: {0}")]
+    AlreadyMerged(String),
+    #[error("PaperError::NotReconciled: {0}")]
+    NotReconciled(String),
+    #[error("PaperError::UnknownError: {0}")]
+    UnknownError(String),
+}
+
+impl From<ElectionError> for PaperError {
+    fn from(e: ElectionError) -> Self {
+        match e {
+            ElectionError::NotFound(msg) => PaperError::NotFound(msg),
+            other => PaperError::UnknownError(other.to_string()),
+        }
+    }
+}
+
+impl From<StaffDenied> for PaperError {
+    fn from(e: StaffDenied) -> Self {
+        match e {
+            StaffDenied::Unauthenticated(msg) => PaperError::Unauthorized(msg),
+            StaffDenied::NotAllowed(msg) => PaperError::Forbidden(msg),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod entities;
+mod errors;
+mod repository;
+mod reconcile;
+
+pub use entities::*;
+pub use repository::*;
+pub use errors::*;
+pub use reconcile::*;
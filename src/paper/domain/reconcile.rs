@@ -0,0 +1,266 @@
+use crate::election::domain::Contest;
+use crate::paper::domain::entities::{
+    ChoiceCount, Mismatch, PaperEntry, Reconciliation, ReconciliationStatus, Station, StationTotals,
+};
+use crate::paper::domain::errors::PaperError;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+/// Same line format as a canonical electronic ballot, so both read alike in reports.
+pub fn count_field(count: &ChoiceCount) -> String {
+    match &count.candidate_id {
+        Some(candidate_id) => format!("{}:{}", count.contest_id, candidate_id),
+        None => format!("{}/abstain", count.contest_id),
+    }
+}
+
+/// Checks one entry on its own: every count belongs to the election, and in every contest
+/// the votes and abstentions add up to the ballots that are neither blank nor invalid.
+pub fn check_totals(contests: &[Contest], totals: &StationTotals) -> Result<(), PaperError> {
+    if totals.ballots < 0 || totals.blank < 0 || totals.invalid < 0 {
+        return Err(PaperError::InvalidInput("totals cannot be negative".into()));
+    }
+    let marked = totals.ballots - totals.blank - totals.invalid;
+    if marked < 0 {
+        return Err(PaperError::InvalidInput(format!(
+            "{} blank and {} invalid ballots exceed the {} in the box",
+            totals.blank, totals.invalid, totals.ballots
+        )));
+    }
+
+    let by_id: HashMap<&str, &Contest> = contests.iter().map(|c| (c.id.as_str(), c)).collect();
+    let mut seen: HashSet<String> = HashSet::with_capacity(totals.counts.len());
+    let mut per_contest: HashMap<&str, i64> = HashMap::with_capacity(contests.len());
+
+    for count in &totals.counts {
+        let contest = by_id.get(count.contest_id.as_str()).ok_or_else(|| {
+            PaperError::InvalidInput(format!("contest {} is not part of this election", count.contest_id))
+        })?;
+        if let Some(candidate_id) = &count.candidate_id
+            && !contest.candidate_ids.contains(candidate_id)
+        {
+            return Err(PaperError::InvalidInput(format!(
+                "candidate {} is not running in contest {}", candidate_id, count.contest_id
+            )));
+        }
+        if count.votes < 0 {
+            return Err(PaperError::InvalidInput(format!("{} cannot be negative", count_field(count))));
+        }
+        if !seen.insert(count_field(count)) {
+            return Err(PaperError::InvalidInput(format!("{} is counted more than once", count_field(count))));
+        }
+        *per_contest.entry(contest.id.as_str()).or_default() += count.votes;
+    }
+
+    for contest in contests {
+        let counted = per_contest.get(contest.id.as_str()).copied().unwrap_or_default();
+        if counted != marked {
+            return Err(PaperError::InvalidInput(format!(
+                "contest {} adds up to {} but {} ballots were marked", contest.id, counted, marked
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+fn figures(totals: &StationTotals) -> BTreeMap<String, i64> {
+    let mut figures = BTreeMap::new();
+    figures.insert("ballots".to_string(), totals.ballots);
+    figures.insert("blank".to_string(), totals.blank);
+    figures.insert("invalid".to_string(), totals.invalid);
+    for count in &totals.counts {
+        figures.insert(count_field(count), count.votes);
+    }
+    figures
+}
+
+/// Every figure the two entries disagree on; a count left out of one entry reads as zero.
+pub fn compare_entries(first: &StationTotals, second: &StationTotals) -> Vec<Mismatch> {
+    let first = figures(first);
+    let second = figures(second);
+
+    let fields: BTreeSet<&String> = first.keys().chain(second.keys()).collect();
+    fields
+        .into_iter()
+        .filter_map(|field| {
+            let a = first.get(field).copied().unwrap_or_default();
+            let b = second.get(field).copied().unwrap_or_default();
+            (a != b).then(|| Mismatch { field: field.clone(), first: a, second: b })
+        })
+        .collect()
+}
+
+/// Where a station stands: both entries in and agreeing, and the ballots in the box matching
+/// the voters who signed in there, is `Ready`. Codes issued but never used at sign-in are
+/// voters who stayed home, so they do not count.
+pub fn reconcile(station: &Station, entries: &[PaperEntry]) -> Reconciliation {
+    let first = entries.iter().find(|e| e.slot == 1);
+    let second = entries.iter().find(|e| e.slot == 2);
+
+    let mut reconciliation = Reconciliation {
+        station_id: station.id.clone(),
+        status: ReconciliationStatus::AwaitingEntries,
+        credentials_issued: station.credentials_issued,
+        signed_in: station.signed_in,
+        ballots: None,
+        mismatches: vec![],
+    };
+
+    let (Some(first), Some(second)) = (first, second) else {
+        return reconciliation;
+    };
+
+    reconciliation.mismatches = compare_entries(&first.totals, &second.totals);
+    if first.totals.ballots == second.totals.ballots {
+        reconciliation.ballots = Some(first.totals.ballots);
+    }
+
+    reconciliation.status = if station.merged_at.is_some() {
+        ReconciliationStatus::Merged
+    } else if !reconciliation.mismatches.is_empty() {
+        ReconciliationStatus::EntriesDiffer
+    } else if first.totals.ballots != station.signed_in {
+        ReconciliationStatus::SignInMismatch
+    } else {
+        ReconciliationStatus::Ready
+    };
+
+    reconciliation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contests() -> Vec<Contest> {
+        vec![
+            Contest {
+                id: "president".to_string(),
+                election_id: "e1".to_string(),
+                name: "President".to_string(),
+                candidate_ids: vec!["c1".to_string(), "c2".to_string()],
+                tie_break: Default::default(),
+                tie_break_seed: None,
+            },
+            Contest {
+                id: "senate".to_string(),
+                election_id: "e1".to_string(),
+                name: "Senate".to_string(),
+                candidate_ids: vec!["c3".to_string()],
+                tie_break: Default::default(),
+                tie_break_seed: None,
+            },
+        ]
+    }
+
+    fn count(contest: &str, candidate: Option<&str>, votes: i64) -> ChoiceCount {
+        ChoiceCount {
+            contest_id: contest.to_string(),
+            candidate_id: candidate.map(str::to_string),
+            votes,
+        }
+    }
+
+    fn totals() -> StationTotals {
+        StationTotals {
+            ballots: 100,
+            blank: 4,
+            invalid: 6,
+            counts: vec![
+                count("president", Some("c1"), 50),
+                count("president", Some("c2"), 38),
+                count("president", None, 2),
+                count("senate", Some("c3"), 81),
+                count("senate", None, 9),
+            ],
+        }
+    }
+
+    fn station(issued: i64, signed_in: i64) -> Station {
+        Station {
+            id: "s1".to_string(),
+            election_id: "e1".to_string(),
+            name: "Engineering Hall".to_string(),
+            faculty: "Engineering".to_string(),
+            credentials_issued: issued,
+            signed_in,
+            merged_at: None,
+            merged_by: None,
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    fn entry(slot: i16, totals: StationTotals) -> PaperEntry {
+        PaperEntry {
+            station_id: "s1".to_string(),
+            slot,
+            entered_by: format!("member-{}", slot),
+            totals,
+            entered_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_check_totals_accepts_consistent_entry() {
+        assert!(check_totals(&contests(), &totals()).is_ok());
+    }
+
+    #[test]
+    fn test_check_totals_rejects_contest_that_does_not_add_up() {
+        let mut t = totals();
+        t.counts[0].votes = 51;
+
+        assert!(matches!(check_totals(&contests(), &t), Err(PaperError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_check_totals_rejects_unknown_candidate_and_duplicates() {
+        let mut t = totals();
+        t.counts.push(count("senate", Some("c1"), 0));
+        assert!(matches!(check_totals(&contests(), &t), Err(PaperError::InvalidInput(_))));
+
+        let mut t = totals();
+        t.counts.push(count("senate", None, 0));
+        assert!(matches!(check_totals(&contests(), &t), Err(PaperError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_reconcile_flags_every_differing_figure() {
+        let mut second = totals();
+        second.blank = 5;
+        second.invalid = 5;
+        second.counts[0].votes = 49;
+        second.counts.reverse();
+
+        let r = reconcile(&station(100, 100), &[entry(1, totals()), entry(2, second)]);
+
+        assert_eq!(r.status, ReconciliationStatus::EntriesDiffer);
+        assert_eq!(r.ballots, Some(100));
+        assert_eq!(r.mismatches, vec![
+            Mismatch { field: "blank".to_string(), first: 4, second: 5 },
+            Mismatch { field: "invalid".to_string(), first: 6, second: 5 },
+            Mismatch { field: "president:c1".to_string(), first: 50, second: 49 },
+        ]);
+    }
+
+    #[test]
+    fn test_reconcile_checks_ballots_against_voters_signed_in() {
+        let entries = [entry(1, totals()), entry(2, totals())];
+
+        assert_eq!(reconcile(&station(101, 101), &entries).status, ReconciliationStatus::SignInMismatch);
+        assert_eq!(reconcile(&station(100, 100), &entries).status, ReconciliationStatus::Ready);
+        assert_eq!(reconcile(&station(100, 100), &entries[..1]).status, ReconciliationStatus::AwaitingEntries);
+    }
+
+    #[test]
+    fn test_reconcile_ignores_codes_nobody_came_in_with() {
+        let entries = [entry(1, totals()), entry(2, totals())];
+
+        // 160 of the faculty's voters were issued codes; 100 turned up and signed in.
+        let r = reconcile(&station(160, 100), &entries);
+        assert_eq!(r.status, ReconciliationStatus::Ready);
+        assert_eq!((r.credentials_issued, r.signed_in), (160, 100));
+
+        assert_eq!(reconcile(&station(160, 99), &entries).status, ReconciliationStatus::SignInMismatch);
+    }
+}
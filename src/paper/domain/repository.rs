@@ -0,0 +1,29 @@
+use crate::paper::domain::entities::{AgreedTotals, NewEntry, NewStation, PaperEntry, Station};
+use crate::paper::domain::errors::PaperError;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait Repository: Send + Sync {
+    async fn create_station(&self, station: NewStation) -> Result<Station, PaperError>;
+
+    async fn find_stations(&self, election_id: String) -> Result<Vec<Station>, PaperError>;
+
+    async fn find_station(&self, id: String) -> Result<Station, PaperError>;
+
+    /// Replaces the entry in the given slot. Fails with `SameMember` when the other slot was
+    /// keyed in by the same person, and with `AlreadyMerged` once the station is merged.
+    async fn save_entry(&self, entry: NewEntry) -> Result<PaperEntry, PaperError>;
+
+    /// Uses up a code issued at this station as its voter signs in for a paper ballot. Fails
+    /// with `InvalidCode` for a code of another station or one already used, and with
+    /// `AlreadyMerged` once the station is merged.
+    async fn sign_in(&self, station_id: String, code_hash: String) -> Result<Station, PaperError>;
+
+    async fn find_entries(&self, station_id: String) -> Result<Vec<PaperEntry>, PaperError>;
+
+    /// Marks the station merged by `merged_by`. Fails with `NotReconciled` when either entry or
+    /// the number of voters signed in at the station changed since `agreed` was reconciled.
+    async fn merge(&self, agreed: AgreedTotals, merged_by: String) -> Result<Station, PaperError>;
+}
@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct Station {
+    pub id: Uuid,
+    pub election_id: Uuid,
+    pub name: String,
+    pub faculty: String,
+    pub credentials_issued: i64,
+    pub signed_in: i64,
+    pub merged_at: Option<DateTime<Utc>>,
+    pub merged_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct Entry {
+    pub station_id: Uuid,
+    pub slot: i16,
+    pub entered_by: String,
+    pub ballots: i64,
+    pub blank: i64,
+    pub invalid: i64,
+    pub entered_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct EntryCount {
+    pub slot: i16,
+    pub contest_id: Uuid,
+    pub candidate_id: Option<Uuid>,
+    pub votes: i64,
+}
@@ -0,0 +1,338 @@
+use crate::paper::domain;
+use crate::paper::domain::PaperError;
+use crate::paper::domain::Repository;
+use crate::infrastructure::database::postgres::Postgres;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::paper::repository::model::{Entry, EntryCount, Station};
+
+const STATION_SELECT: &str = r#"
+        SELECT s.id
+            , s.election_id
+            , s.name
+            , s.faculty
+            , (SELECT COUNT(*) FROM voting_credentials c WHERE c.station_id = s.id)::BIGINT AS credentials_issued
+            , (SELECT COUNT(*) FROM voting_credentials c
+                WHERE c.station_id = s.id AND c.redeemed_at IS NOT NULL)::BIGINT AS signed_in
+            , s.merged_at
+            , s.merged_by
+            , s.created_at
+        FROM polling_stations s
+"#;
+
+pub struct PostgresRepo {
+    postgres: sqlx::PgPool,
+}
+impl PostgresRepo {
+    pub async fn new(postgres: Arc<Mutex<Postgres>>) -> anyhow::Result<Self> {
+        let guard = postgres.lock().await;
+        let pool = guard
+            .pool()
+            .ok_or_else(|| anyhow!("DB pool is not initialized"))?;
+        Ok(PostgresRepo { postgres: pool })
+    }
+
+    fn transform_station(&self, s: Station) -> domain::Station {
+        domain::Station {
+            id: s.id.to_string(),
+            election_id: s.election_id.to_string(),
+            name: s.name,
+            faculty: s.faculty,
+            credentials_issued: s.credentials_issued,
+            signed_in: s.signed_in,
+            merged_at: s.merged_at,
+            merged_by: s.merged_by,
+            created_at: s.created_at,
+        }
+    }
+
+    fn transform_entry(&self, e: Entry, counts: &[EntryCount]) -> domain::PaperEntry {
+        domain::PaperEntry {
+            station_id: e.station_id.to_string(),
+            slot: e.slot,
+            entered_by: e.entered_by,
+            totals: domain::StationTotals {
+                ballots: e.ballots,
+                blank: e.blank,
+                invalid: e.invalid,
+                counts: counts
+                    .iter()
+                    .filter(|c| c.slot == e.slot)
+                    .map(|c| domain::ChoiceCount {
+                        contest_id: c.contest_id.to_string(),
+                        candidate_id: c.candidate_id.map(|id| id.to_string()),
+                        votes: c.votes,
+                    })
+                    .collect(),
+            },
+            entered_at: e.entered_at,
+        }
+    }
+
+    fn parse_id(id: &str) -> Result<Uuid, PaperError> {
+        Uuid::parse_str(id).map_err(|_| PaperError::NotFound(format!("{} not found", id)))
+    }
+
+    /// Locks the station row for the rest of the transaction, so entries, merging, sign-ins
+    /// and codes issued at the station are serialised against each other.
+    async fn lock_unmerged(tx: &mut sqlx::PgConnection, station_id: Uuid) -> Result<(), PaperError> {
+        let merged_at: Option<Option<chrono::DateTime<chrono::Utc>>> = sqlx::query_scalar(
+            r#"SELECT merged_at FROM polling_stations WHERE id = $1 FOR UPDATE"#,
+        )
+            .bind(station_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| PaperError::UnknownError(e.to_string()))?;
+
+        match merged_at {
+            None => Err(PaperError::NotFound(format!("station {} not found", station_id))),
+            Some(Some(_)) => Err(PaperError::AlreadyMerged(format!("station {} is already merged", station_id))),
+            Some(None) => Ok(()),
+        }
+    }
+}
+
+#[async_trait]
+impl Repository for PostgresRepo {
+    async fn create_station(&self, station: domain::NewStation) -> Result<domain::Station, PaperError> {
+        let election_id = Self::parse_id(&station.election_id)?;
+
+        let created = sqlx::query_as::<_, Station>(
+            r#"
+            INSERT INTO polling_stations (election_id, name, faculty) VALUES ($1, $2, $3)
+            RETURNING id, election_id, name, faculty, 0::BIGINT AS credentials_issued, 0::BIGINT AS signed_in, merged_at, merged_by, created_at
+            "#,
+        )
+            .bind(election_id)
+            .bind(&station.name)
+            .bind(&station.faculty)
+            .fetch_one(&self.postgres)
+            .await
+            .map_err(|e| match e {
+                sqlx::Error::Database(db) if db.is_unique_violation() => {
+                    PaperError::AlreadyExists(format!("station {} already exists", station.name))
+                }
+                _ => PaperError::UnknownError(e.to_string()),
+            })?;
+
+        Ok(self.transform_station(created))
+    }
+
+    async fn find_stations(&self, election_id: String) -> Result<Vec<domain::Station>, PaperError> {
+        let uuid = Self::parse_id(&election_id)?;
+        let sql = format!("{} WHERE s.election_id = $1 ORDER BY s.name", STATION_SELECT);
+
+        let stations = sqlx::query_as::<_, Station>(&sql)
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| PaperError::UnknownError(e.to_string()))?;
+
+        Ok(stations.into_iter().map(|s| self.transform_station(s)).collect())
+    }
+
+    async fn find_station(&self, id: String) -> Result<domain::Station, PaperError> {
+        let uuid = Self::parse_id(&id)?;
+        let sql = format!("{} WHERE s.id = $1", STATION_SELECT);
+
+        let station = sqlx::query_as::<_, Station>(&sql)
+            .bind(uuid)
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| PaperError::UnknownError(e.to_string()))?
+            .ok_or_else(|| PaperError::NotFound(format!("station {} not found", id)))?;
+
+        Ok(self.transform_station(station))
+    }
+
+    async fn save_entry(&self, entry: domain::NewEntry) -> Result<domain::PaperEntry, PaperError> {
+        let station_id = Self::parse_id(&entry.station_id)?;
+        let mut contest_ids = Vec::with_capacity(entry.totals.counts.len());
+        let mut candidate_ids = Vec::with_capacity(entry.totals.counts.len());
+        let mut votes = Vec::with_capacity(entry.totals.counts.len());
+        for count in &entry.totals.counts {
+            contest_ids.push(Self::parse_id(&count.contest_id)?);
+            candidate_ids.push(count.candidate_id.as_deref().map(Self::parse_id).transpose()?);
+            votes.push(count.votes);
+        }
+
+        let mut tx = self.postgres.begin().await
+            .map_err(|e| PaperError::UnknownError(e.to_string()))?;
+
+        Self::lock_unmerged(&mut tx, station_id).await?;
+
+        // The point of two entries is two pairs of eyes on the tally sheet.
+        let other: Option<String> = sqlx::query_scalar(
+            r#"SELECT entered_by FROM paper_entries WHERE station_id = $1 AND slot <> $2"#,
+        )
+            .bind(station_id)
+            .bind(entry.slot)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| PaperError::UnknownError(e.to_string()))?;
+
+        if other.is_some_and(|o| o.to_lowercase() == entry.entered_by.to_lowercase()) {
+            return Err(PaperError::SameMember(format!(
+                "{} already keyed in the other entry for this station", entry.entered_by
+            )));
+        }
+
+        sqlx::query(r#"DELETE FROM paper_entries WHERE station_id = $1 AND slot = $2"#)
+            .bind(station_id)
+            .bind(entry.slot)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| PaperError::UnknownError(e.to_string()))?;
+
+        let saved = sqlx::query_as::<_, Entry>(
+            r#"
+            INSERT INTO paper_entries (station_id, slot, entered_by, ballots, blank, invalid)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING station_id, slot, entered_by, ballots, blank, invalid, entered_at
+            "#,
+        )
+            .bind(station_id)
+            .bind(entry.slot)
+            .bind(&entry.entered_by)
+            .bind(entry.totals.ballots)
+            .bind(entry.totals.blank)
+            .bind(entry.totals.invalid)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| PaperError::UnknownError(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO paper_entry_counts (station_id, slot, contest_id, candidate_id, votes)
+            SELECT $1, $2, contest_id, candidate_id, votes
+            FROM UNNEST($3::UUID[], $4::UUID[], $5::BIGINT[]) AS t (contest_id, candidate_id, votes)
+            "#,
+        )
+            .bind(station_id)
+            .bind(entry.slot)
+            .bind(contest_ids)
+            .bind(candidate_ids)
+            .bind(votes)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| PaperError::UnknownError(e.to_string()))?;
+
+        tx.commit().await
+            .map_err(|e| PaperError::UnknownError(e.to_string()))?;
+
+        Ok(domain::PaperEntry {
+            station_id: saved.station_id.to_string(),
+            slot: saved.slot,
+            entered_by: saved.entered_by,
+            totals: entry.totals,
+            entered_at: saved.entered_at,
+        })
+    }
+
+    async fn sign_in(&self, station_id: String, code_hash: String) -> Result<domain::Station, PaperError> {
+        let uuid = Self::parse_id(&station_id)?;
+
+        let mut tx = self.postgres.begin().await
+            .map_err(|e| PaperError::UnknownError(e.to_string()))?;
+
+        Self::lock_unmerged(&mut tx, uuid).await?;
+
+        // Same burn as an online redemption: of two sign-ins with one code only one sees a row.
+        let burned: Option<Uuid> = sqlx::query_scalar(
+            r#"
+            UPDATE voting_credentials SET redeemed_at = now()
+            WHERE station_id = $1 AND code_hash = $2 AND redeemed_at IS NULL
+            RETURNING voter_id
+            "#,
+        )
+            .bind(uuid)
+            .bind(&code_hash)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| PaperError::UnknownError(e.to_string()))?;
+
+        if burned.is_none() {
+            return Err(PaperError::InvalidCode("code was not issued at this station or is already used".into()));
+        }
+
+        tx.commit().await
+            .map_err(|e| PaperError::UnknownError(e.to_string()))?;
+
+        self.find_station(station_id).await
+    }
+
+    async fn find_entries(&self, station_id: String) -> Result<Vec<domain::PaperEntry>, PaperError> {
+        let uuid = Self::parse_id(&station_id)?;
+
+        let entries = sqlx::query_as::<_, Entry>(
+            r#"
+            SELECT station_id, slot, entered_by, ballots, blank, invalid, entered_at
+            FROM paper_entries WHERE station_id = $1 ORDER BY slot
+            "#,
+        )
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| PaperError::UnknownError(e.to_string()))?;
+
+        let counts = sqlx::query_as::<_, EntryCount>(
+            r#"
+            SELECT slot, contest_id, candidate_id, votes
+            FROM paper_entry_counts WHERE station_id = $1
+            ORDER BY contest_id, candidate_id NULLS LAST
+            "#,
+        )
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| PaperError::UnknownError(e.to_string()))?;
+
+        Ok(entries.into_iter().map(|e| self.transform_entry(e, &counts)).collect())
+    }
+
+    async fn merge(&self, agreed: domain::AgreedTotals, merged_by: String) -> Result<domain::Station, PaperError> {
+        let station_id = Self::parse_id(&agreed.station_id)?;
+
+        let mut tx = self.postgres.begin().await
+            .map_err(|e| PaperError::UnknownError(e.to_string()))?;
+
+        Self::lock_unmerged(&mut tx, station_id).await?;
+
+        let entered_at: Vec<chrono::DateTime<chrono::Utc>> = sqlx::query_scalar(
+            r#"SELECT entered_at FROM paper_entries WHERE station_id = $1 ORDER BY slot"#,
+        )
+            .bind(station_id)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| PaperError::UnknownError(e.to_string()))?;
+
+        let signed_in: i64 = sqlx::query_scalar(
+            r#"SELECT COUNT(*)::BIGINT FROM voting_credentials WHERE station_id = $1 AND redeemed_at IS NOT NULL"#,
+        )
+            .bind(station_id)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| PaperError::UnknownError(e.to_string()))?;
+
+        if entered_at != agreed.entered_at || signed_in != agreed.signed_in {
+            return Err(PaperError::NotReconciled(format!(
+                "station {} changed while it was being merged", station_id
+            )));
+        }
+
+        sqlx::query(r#"UPDATE polling_stations SET merged_at = now(), merged_by = $2 WHERE id = $1"#)
+            .bind(station_id)
+            .bind(&merged_by)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| PaperError::UnknownError(e.to_string()))?;
+
+        tx.commit().await
+            .map_err(|e| PaperError::UnknownError(e.to_string()))?;
+
+        self.find_station(agreed.station_id).await
+    }
+}
@@ -0,0 +1,60 @@
+use crate::election;
+use crate::election::domain::ElectionPhase;
+use crate::paper::domain::{NewStation, PaperError, Repository, Station};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Station, PaperError>;
+}
+
+pub struct CreateStationUseCase<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    repository: Arc<R>,
+    election_repository: Arc<E>,
+}
+
+#[derive(Debug)]
+pub struct Request {
+    pub election_id: String,
+    pub name: String,
+    pub faculty: String,
+}
+
+impl<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync> CreateStationUseCase<R, E>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    pub fn new(repository: Arc<R>, election_repository: Arc<E>) -> Self {
+        Self { repository, election_repository }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync> Interactor for CreateStationUseCase<R, E>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Station, PaperError> {
+        let name = req.name.trim().to_string();
+        let faculty = req.faculty.trim().to_string();
+        if name.is_empty() || faculty.is_empty() {
+            return Err(PaperError::InvalidInput("station name and faculty are required".into()));
+        }
+
+        let election = self.election_repository.find_by_id(req.election_id.clone()).await?;
+        if matches!(election.phase, ElectionPhase::Closed | ElectionPhase::Published) {
+            return Err(PaperError::InvalidPhase(format!(
+                "election {} is in phase {}", election.id, election.phase.as_str()
+            )));
+        }
+
+        self.repository.create_station(NewStation { election_id: req.election_id, name, faculty }).await
+    }
+}
@@ -0,0 +1,42 @@
+use crate::paper::domain::{self, PaperError, Reconciliation, Repository};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Reconciliation, PaperError>;
+}
+
+pub struct GetReconciliationUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+}
+
+#[derive(Debug)]
+pub struct Request {
+    pub station_id: String,
+}
+
+impl<R: ?Sized + Send + Sync> GetReconciliationUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for GetReconciliationUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Reconciliation, PaperError> {
+        let station = self.repository.find_station(req.station_id.clone()).await?;
+        let entries = self.repository.find_entries(req.station_id).await?;
+
+        Ok(domain::reconcile(&station, &entries))
+    }
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+use crate::election;
+use crate::embargo::domain::StaffRoster;
+use crate::paper::domain::Repository;
+use crate::paper::usecase::{create_station, get_reconciliation, list_stations, merge, sign_in, submit_entry};
+use crate::paper::usecase::create_station::CreateStationUseCase;
+use crate::paper::usecase::get_reconciliation::GetReconciliationUseCase;
+use crate::paper::usecase::list_stations::ListStationsUseCase;
+use crate::paper::usecase::merge::MergeStationUseCase;
+use crate::paper::usecase::sign_in::SignInUseCase;
+use crate::paper::usecase::submit_entry::SubmitEntryUseCase;
+
+
+#[derive(Clone)]
+pub struct UseCase
+{
+    pub create_station: Arc<dyn create_station::Interactor>,
+    pub list_stations: Arc<dyn list_stations::Interactor>,
+    pub sign_in: Arc<dyn sign_in::Interactor>,
+    pub submit_entry: Arc<dyn submit_entry::Interactor>,
+    pub get_reconciliation: Arc<dyn get_reconciliation::Interactor>,
+    pub merge: Arc<dyn merge::Interactor>,
+}
+
+impl UseCase {
+
+    pub fn new(
+        paper_repo: Arc<dyn Repository + Send + Sync>,
+        election_repo: Arc<dyn election::domain::Repository + Send + Sync>,
+        roster: Arc<StaffRoster>,
+    ) -> Self {
+
+        Self {
+            create_station: Arc::new(CreateStationUseCase::new(paper_repo.clone(), election_repo.clone())),
+            list_stations: Arc::new(ListStationsUseCase::new(paper_repo.clone())),
+            sign_in: Arc::new(SignInUseCase::new(paper_repo.clone(), election_repo.clone(), roster.clone())),
+            submit_entry: Arc::new(SubmitEntryUseCase::new(paper_repo.clone(), election_repo.clone(), roster.clone())),
+            get_reconciliation: Arc::new(GetReconciliationUseCase::new(paper_repo.clone())),
+            merge: Arc::new(MergeStationUseCase::new(paper_repo, election_repo, roster)),
+        }
+    }
+
+}
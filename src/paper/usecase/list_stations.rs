@@ -0,0 +1,39 @@
+use crate::paper::domain::{PaperError, Repository, Station};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Vec<Station>, PaperError>;
+}
+
+pub struct ListStationsUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+}
+
+#[derive(Debug)]
+pub struct Request {
+    pub election_id: String,
+}
+
+impl<R: ?Sized + Send + Sync> ListStationsUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for ListStationsUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Vec<Station>, PaperError> {
+        self.repository.find_stations(req.election_id).await
+    }
+}
@@ -0,0 +1,214 @@
+use crate::election;
+use crate::election::domain::ElectionPhase;
+use crate::embargo::domain::StaffRoster;
+use crate::paper::domain::{self, AgreedTotals, PaperError, ReconciliationStatus, Repository, Station};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Station, PaperError>;
+}
+
+pub struct MergeStationUseCase<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    repository: Arc<R>,
+    election_repository: Arc<E>,
+    roster: Arc<StaffRoster>,
+}
+
+pub struct Request {
+    pub station_id: String,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("station_id", &self.station_id)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync> MergeStationUseCase<R, E>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    pub fn new(repository: Arc<R>, election_repository: Arc<E>, roster: Arc<StaffRoster>) -> Self {
+        Self { repository, election_repository, roster }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync> Interactor for MergeStationUseCase<R, E>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Station, PaperError> {
+        let member = self.roster.acting(req.staff_token.as_deref(), "merge stations")?;
+
+        let station = self.repository.find_station(req.station_id.clone()).await?;
+        let election = self.election_repository.find_by_id(station.election_id.clone()).await?;
+        if !matches!(election.phase, ElectionPhase::Voting | ElectionPhase::Closed) {
+            return Err(PaperError::InvalidPhase(format!(
+                "election {} is in phase {}", election.id, election.phase.as_str()
+            )));
+        }
+
+        let entries = self.repository.find_entries(req.station_id.clone()).await?;
+        let reconciliation = domain::reconcile(&station, &entries);
+
+        match reconciliation.status {
+            ReconciliationStatus::Ready => {}
+            ReconciliationStatus::Merged => {
+                return Err(PaperError::AlreadyMerged(format!("station {} is already merged", station.id)));
+            }
+            ReconciliationStatus::AwaitingEntries => {
+                return Err(PaperError::NotReconciled(format!("station {} needs two entries", station.id)));
+            }
+            ReconciliationStatus::EntriesDiffer => {
+                return Err(PaperError::NotReconciled(format!(
+                    "entries for station {} differ in {} figures", station.id, reconciliation.mismatches.len()
+                )));
+            }
+            ReconciliationStatus::SignInMismatch => {
+                return Err(PaperError::NotReconciled(format!(
+                    "station {} has {} ballots but {} voters signed in",
+                    station.id,
+                    reconciliation.ballots.unwrap_or_default(),
+                    reconciliation.signed_in
+                )));
+            }
+        }
+
+        self.repository.merge(AgreedTotals {
+            station_id: req.station_id,
+            entered_at: entries.iter().map(|e| e.entered_at).collect(),
+            signed_in: station.signed_in,
+        }, member.name).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::election;
+    use crate::election::domain::ElectionPhase;
+    use crate::embargo::domain::{StaffRoster, hash_staff_token};
+    use crate::paper::domain::{self, PaperError, StationTotals};
+    use crate::paper::usecase::merge;
+    use crate::paper::usecase::merge::Interactor;
+    use std::sync::Arc;
+
+    fn election_repo() -> election::domain::MockRepository {
+        let mut repo_mock = election::domain::MockRepository::new();
+        repo_mock.expect_find_by_id().returning(|id| Ok(election::domain::Election {
+            id,
+            name: "Student Council 2026".to_string(),
+            phase: ElectionPhase::Closed,
+            voting_starts_at: None,
+            voting_ends_at: None,
+            allow_revote: false,
+            created_at: chrono::Utc::now(),
+            updated_at: None,
+        }));
+        repo_mock
+    }
+
+    fn roster() -> Arc<StaffRoster> {
+        Arc::new(StaffRoster::parse(&format!(
+            "rina:committee:{},budi:observer:{}",
+            hash_staff_token("rina-token"),
+            hash_staff_token("budi-token")
+        )).unwrap())
+    }
+
+    fn request(staff_token: Option<&str>) -> merge::Request {
+        merge::Request { station_id: "s1".to_string(), staff_token: staff_token.map(str::to_string) }
+    }
+
+    fn entry(slot: i16, blank: i64) -> domain::PaperEntry {
+        domain::PaperEntry {
+            station_id: "s1".to_string(),
+            slot,
+            entered_by: format!("member-{}", slot),
+            totals: StationTotals { ballots: 10, blank, invalid: 0, counts: vec![] },
+            entered_at: chrono::Utc::now(),
+        }
+    }
+
+    fn repo(signed_in: i64, second_blank: i64) -> domain::MockRepository {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_station().returning(move |id| Ok(domain::Station {
+            id,
+            election_id: "e1".to_string(),
+            name: "Engineering Hall".to_string(),
+            faculty: "Engineering".to_string(),
+            credentials_issued: 12,
+            signed_in,
+            merged_at: None,
+            merged_by: None,
+            created_at: chrono::Utc::now(),
+        }));
+        repo_mock.expect_find_entries()
+            .returning(move |_| Ok(vec![entry(1, 10), entry(2, second_blank)]));
+        repo_mock
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_merge_refuses_differing_entries_and_unmatched_codes() {
+        let mut differing = repo(10, 9);
+        differing.expect_merge().times(0);
+        let uc = merge::MergeStationUseCase::new(Arc::new(differing), Arc::new(election_repo()), roster());
+        assert!(matches!(uc.handle(request(Some("rina-token"))).await, Err(PaperError::NotReconciled(_))));
+
+        let mut unmatched = repo(11, 10);
+        unmatched.expect_merge().times(0);
+        let uc = merge::MergeStationUseCase::new(Arc::new(unmatched), Arc::new(election_repo()), roster());
+        assert!(matches!(uc.handle(request(Some("rina-token"))).await, Err(PaperError::NotReconciled(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_merge_pins_the_reconciled_state() {
+        let mut repo_mock = repo(10, 10);
+        repo_mock.expect_merge()
+            .times(1)
+            .withf(|a, merged_by| {
+                a.station_id == "s1" && a.entered_at.len() == 2 && a.signed_in == 10 && merged_by == "rina"
+            })
+            .returning(|a, merged_by| Ok(domain::Station {
+                id: a.station_id,
+                election_id: "e1".to_string(),
+                name: "Engineering Hall".to_string(),
+                faculty: "Engineering".to_string(),
+                credentials_issued: 12,
+                signed_in: a.signed_in,
+                merged_at: Some(chrono::Utc::now()),
+                merged_by: Some(merged_by),
+                created_at: chrono::Utc::now(),
+            }));
+
+        let uc = merge::MergeStationUseCase::new(Arc::new(repo_mock), Arc::new(election_repo()), roster());
+        let station = uc.handle(request(Some("rina-token"))).await.unwrap();
+
+        assert!(station.merged_at.is_some());
+        assert_eq!(station.merged_by.as_deref(), Some("rina"));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_merge_needs_an_acting_staff_member() {
+        let mut repo_mock = repo(10, 10);
+        repo_mock.expect_merge().times(0);
+
+        let uc = merge::MergeStationUseCase::new(Arc::new(repo_mock), Arc::new(election_repo()), roster());
+
+        assert!(matches!(uc.handle(request(None)).await, Err(PaperError::Unauthorized(_))));
+        assert!(matches!(uc.handle(request(Some("budi-token"))).await, Err(PaperError::Forbidden(_))));
+    }
+}
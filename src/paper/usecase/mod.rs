@@ -0,0 +1,9 @@
+mod init;
+pub mod create_station;
+pub mod get_reconciliation;
+pub mod list_stations;
+pub mod merge;
+pub mod sign_in;
+pub mod submit_entry;
+
+pub use init::UseCase;
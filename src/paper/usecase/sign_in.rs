@@ -0,0 +1,152 @@
+use crate::credential;
+use crate::election;
+use crate::election::domain::ElectionPhase;
+use crate::embargo::domain::StaffRoster;
+use crate::paper::domain::{PaperError, Repository, Station};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Station, PaperError>;
+}
+
+pub struct SignInUseCase<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    repository: Arc<R>,
+    election_repository: Arc<E>,
+    roster: Arc<StaffRoster>,
+}
+
+pub struct Request {
+    pub station_id: String,
+    /// The code the voter brought to the station.
+    pub code: String,
+    pub staff_token: Option<String>,
+}
+
+// Keep the voting code and the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("station_id", &self.station_id)
+            .field("code", &"<redacted>")
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync> SignInUseCase<R, E>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    pub fn new(repository: Arc<R>, election_repository: Arc<E>, roster: Arc<StaffRoster>) -> Self {
+        Self { repository, election_repository, roster }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync> Interactor for SignInUseCase<R, E>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Station, PaperError> {
+        self.roster.acting(req.staff_token.as_deref(), "sign voters in")?;
+
+        let station = self.repository.find_station(req.station_id.clone()).await?;
+        let election = self.election_repository.find_by_id(station.election_id).await?;
+        // Voters are handed paper ballots only while the poll is open.
+        if election.phase != ElectionPhase::Voting {
+            return Err(PaperError::InvalidPhase(format!(
+                "election {} is in phase {}", election.id, election.phase.as_str()
+            )));
+        }
+
+        self.repository.sign_in(req.station_id, credential::domain::hash_code(&req.code)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::credential;
+    use crate::election;
+    use crate::election::domain::ElectionPhase;
+    use crate::embargo::domain::{StaffRoster, hash_staff_token};
+    use crate::paper::domain::{self, PaperError};
+    use crate::paper::usecase::sign_in;
+    use crate::paper::usecase::sign_in::Interactor;
+    use std::sync::Arc;
+
+    fn election_repo(phase: ElectionPhase) -> election::domain::MockRepository {
+        let mut repo_mock = election::domain::MockRepository::new();
+        repo_mock.expect_find_by_id().returning(move |id| Ok(election::domain::Election {
+            id,
+            name: "Student Council 2026".to_string(),
+            phase,
+            voting_starts_at: None,
+            voting_ends_at: None,
+            allow_revote: false,
+            created_at: chrono::Utc::now(),
+            updated_at: None,
+        }));
+        repo_mock
+    }
+
+    fn station(id: String, signed_in: i64) -> domain::Station {
+        domain::Station {
+            id,
+            election_id: "e1".to_string(),
+            name: "Engineering Hall".to_string(),
+            faculty: "Engineering".to_string(),
+            credentials_issued: 10,
+            signed_in,
+            merged_at: None,
+            merged_by: None,
+            created_at: chrono::Utc::now(),
+        }
+    }
+
+    fn roster() -> Arc<StaffRoster> {
+        Arc::new(StaffRoster::parse(&format!("rina:committee:{}", hash_staff_token("rina-token"))).unwrap())
+    }
+
+    fn request(staff_token: Option<&str>) -> sign_in::Request {
+        sign_in::Request {
+            station_id: "s1".to_string(),
+            code: "7k3m-q9tx-2b4r-hw8d".to_string(),
+            staff_token: staff_token.map(str::to_string),
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_sign_in_burns_the_code_by_hash() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_station().returning(|id| Ok(station(id, 3)));
+        repo_mock.expect_sign_in()
+            .times(1)
+            .withf(|station_id, code_hash| station_id == "s1" && code_hash == &credential::domain::hash_code("7K3M-Q9TX-2B4R-HW8D"))
+            .returning(|id, _| Ok(station(id, 4)));
+
+        let uc = sign_in::SignInUseCase::new(Arc::new(repo_mock), Arc::new(election_repo(ElectionPhase::Voting)), roster());
+        let station = uc.handle(request(Some("rina-token"))).await.unwrap();
+
+        assert_eq!(station.signed_in, 4);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_sign_in_needs_staff_and_an_open_poll() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_station().returning(|id| Ok(station(id, 3)));
+        repo_mock.expect_sign_in().times(0);
+
+        let uc = sign_in::SignInUseCase::new(Arc::new(repo_mock), Arc::new(election_repo(ElectionPhase::Closed)), roster());
+
+        assert!(matches!(uc.handle(request(None)).await, Err(PaperError::Unauthorized(_))));
+        assert!(matches!(uc.handle(request(Some("rina-token"))).await, Err(PaperError::InvalidPhase(_))));
+    }
+}
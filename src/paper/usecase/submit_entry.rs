@@ -0,0 +1,221 @@
+use crate::election;
+use crate::election::domain::ElectionPhase;
+use crate::embargo::domain::StaffRoster;
+use crate::paper::domain::{self, NewEntry, PaperEntry, PaperError, Repository, StationTotals};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<PaperEntry, PaperError>;
+}
+
+pub struct SubmitEntryUseCase<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    repository: Arc<R>,
+    election_repository: Arc<E>,
+    roster: Arc<StaffRoster>,
+}
+
+pub struct Request {
+    pub station_id: String,
+    pub slot: i16,
+    /// Identifies the committee member keying in the entry; it is recorded as `entered_by`.
+    pub staff_token: Option<String>,
+    pub totals: StationTotals,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("station_id", &self.station_id)
+            .field("slot", &self.slot)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .field("totals", &self.totals)
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync> SubmitEntryUseCase<R, E>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    pub fn new(repository: Arc<R>, election_repository: Arc<E>, roster: Arc<StaffRoster>) -> Self {
+        Self { repository, election_repository, roster }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync, E: ?Sized + Send + Sync> Interactor for SubmitEntryUseCase<R, E>
+where
+    R: Repository,
+    E: election::domain::Repository,
+{
+    async fn handle(&self, req: Request) -> Result<PaperEntry, PaperError> {
+        if req.slot != 1 && req.slot != 2 {
+            return Err(PaperError::InvalidInput("entry slot must be 1 or 2".into()));
+        }
+        let member = self.roster.acting(req.staff_token.as_deref(), "key in entries")?;
+
+        let station = self.repository.find_station(req.station_id.clone()).await?;
+        let election = self.election_repository.find_by_id(station.election_id.clone()).await?;
+        // Boxes are counted once the station's poll has started; tallies are final after that.
+        if !matches!(election.phase, ElectionPhase::Voting | ElectionPhase::Closed) {
+            return Err(PaperError::InvalidPhase(format!(
+                "election {} is in phase {}", election.id, election.phase.as_str()
+            )));
+        }
+
+        let contests = self.election_repository.find_contests(election.id).await?;
+        domain::check_totals(&contests, &req.totals)?;
+
+        self.repository.save_entry(NewEntry {
+            station_id: req.station_id,
+            slot: req.slot,
+            entered_by: member.name,
+            totals: req.totals,
+        }).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::election;
+    use crate::election::domain::ElectionPhase;
+    use crate::embargo::domain::{StaffRoster, hash_staff_token};
+    use crate::paper::domain::{self, ChoiceCount, PaperError, StationTotals};
+    use crate::paper::usecase::submit_entry;
+    use crate::paper::usecase::submit_entry::Interactor;
+    use std::sync::Arc;
+
+    fn election_repo(phase: ElectionPhase) -> election::domain::MockRepository {
+        let mut repo_mock = election::domain::MockRepository::new();
+        repo_mock.expect_find_by_id().returning(move |id| Ok(election::domain::Election {
+            id,
+            name: "Student Council 2026".to_string(),
+            phase,
+            voting_starts_at: None,
+            voting_ends_at: None,
+            allow_revote: false,
+            created_at: chrono::Utc::now(),
+            updated_at: None,
+        }));
+        repo_mock.expect_find_contests().returning(|election_id| Ok(vec![election::domain::Contest {
+            id: "president".to_string(),
+            election_id,
+            name: "President".to_string(),
+            candidate_ids: vec!["c1".to_string(), "c2".to_string()],
+            tie_break: Default::default(),
+            tie_break_seed: None,
+        }]));
+        repo_mock
+    }
+
+    fn station_repo() -> domain::MockRepository {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_station().returning(|id| Ok(domain::Station {
+            id,
+            election_id: "e1".to_string(),
+            name: "Engineering Hall".to_string(),
+            faculty: "Engineering".to_string(),
+            credentials_issued: 10,
+            signed_in: 10,
+            merged_at: None,
+            merged_by: None,
+            created_at: chrono::Utc::now(),
+        }));
+        repo_mock
+    }
+
+    fn roster() -> Arc<StaffRoster> {
+        Arc::new(StaffRoster::parse(&format!(
+            "rina:committee:{},budi:observer:{}",
+            hash_staff_token("rina-token"),
+            hash_staff_token("budi-token")
+        )).unwrap())
+    }
+
+    fn request(slot: i16, c1: i64) -> submit_entry::Request {
+        submit_entry::Request {
+            station_id: "s1".to_string(),
+            slot,
+            staff_token: Some("rina-token".to_string()),
+            totals: StationTotals {
+                ballots: 10,
+                blank: 1,
+                invalid: 1,
+                counts: vec![
+                    ChoiceCount { contest_id: "president".to_string(), candidate_id: Some("c1".to_string()), votes: c1 },
+                    ChoiceCount { contest_id: "president".to_string(), candidate_id: Some("c2".to_string()), votes: 3 },
+                ],
+            },
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_submit_entry_saves_checked_totals() {
+        let mut repo_mock = station_repo();
+        repo_mock.expect_save_entry()
+            .times(1)
+            .withf(|e| e.slot == 2 && e.entered_by == "rina")
+            .returning(|e| Ok(domain::PaperEntry {
+                station_id: e.station_id,
+                slot: e.slot,
+                entered_by: e.entered_by,
+                totals: e.totals,
+                entered_at: chrono::Utc::now(),
+            }));
+
+        let uc = submit_entry::SubmitEntryUseCase::new(Arc::new(repo_mock), Arc::new(election_repo(ElectionPhase::Closed)), roster());
+        let entry = uc.handle(request(2, 5)).await.unwrap();
+
+        assert_eq!(entry.slot, 2);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_submit_entry_rejects_totals_that_do_not_add_up() {
+        let mut repo_mock = station_repo();
+        repo_mock.expect_save_entry().times(0);
+
+        let uc = submit_entry::SubmitEntryUseCase::new(Arc::new(repo_mock), Arc::new(election_repo(ElectionPhase::Closed)), roster());
+        let res = uc.handle(request(1, 6)).await;
+
+        assert!(matches!(res, Err(PaperError::InvalidInput(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_submit_entry_requires_voting_to_have_started() {
+        let mut repo_mock = station_repo();
+        repo_mock.expect_save_entry().times(0);
+
+        let uc = submit_entry::SubmitEntryUseCase::new(Arc::new(repo_mock), Arc::new(election_repo(ElectionPhase::Registration)), roster());
+
+        assert!(matches!(uc.handle(request(1, 5)).await, Err(PaperError::InvalidPhase(_))));
+        assert!(matches!(uc.handle(request(3, 5)).await, Err(PaperError::InvalidInput(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_submit_entry_takes_the_member_from_the_staff_token() {
+        let mut repo_mock = station_repo();
+        repo_mock.expect_save_entry().times(0);
+
+        let uc = submit_entry::SubmitEntryUseCase::new(Arc::new(repo_mock), Arc::new(election_repo(ElectionPhase::Closed)), roster());
+
+        let mut anonymous = request(1, 5);
+        anonymous.staff_token = None;
+        assert!(matches!(uc.handle(anonymous).await, Err(PaperError::Unauthorized(_))));
+
+        let mut unknown = request(1, 5);
+        unknown.staff_token = Some("guess".to_string());
+        assert!(matches!(uc.handle(unknown).await, Err(PaperError::Unauthorized(_))));
+
+        let mut observer = request(1, 5);
+        observer.staff_token = Some("budi-token".to_string());
+        assert!(matches!(uc.handle(observer).await, Err(PaperError::Forbidden(_))));
+    }
+}
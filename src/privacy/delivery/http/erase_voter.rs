@@ -0,0 +1,66 @@
+use crate::embargo::delivery::http::staff_token;
+use crate::privacy::delivery::http::errors::error_response;
+use crate::privacy::usecase::erase::*;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+
+pub async fn erase_voter(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+
+    let request = Request { voter_id: path.into_inner(), staff_token: staff_token(&http_request) };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.privacy_uc.erase.handle(request).await {
+        Ok(report) => HttpResponse::Ok().json(response::success(
+            Some(report),
+            "Successfully processed voter erasure".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::erase_voter;
+    use actix_web::{App, http::StatusCode, test, web};
+    use async_trait::async_trait;
+    use serde_json::Value;
+    use std::sync::Arc;
+    use crate::privacy::domain::{ErasureReport, PrivacyError};
+    use crate::privacy::usecase::erase::{Interactor, Request};
+    use crate::utils::app;
+
+    #[actix_rt::test]
+    async fn test_erase_voter_retention_hold() {
+        struct MockErase;
+
+        #[async_trait]
+        impl Interactor for MockErase {
+            async fn handle(&self, _: Request) -> Result<ErasureReport, PrivacyError> {
+                Err(PrivacyError::RetentionHold("voter is still within the retention period of elections e1".into()))
+            }
+        }
+
+        let mut privacy_uc = app::AppHandlerData::mocked().privacy_uc;
+        privacy_uc.erase = Arc::new(MockErase);
+        let app_data = web::Data::new(app::AppHandlerData { privacy_uc, ..app::AppHandlerData::mocked() });
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_data)
+                .route("/voters/{id}/erase", web::post().to(erase_voter)),
+        )
+        .await;
+
+        let req = test::TestRequest::post().uri("/voters/v1/erase").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["error_code"], "PRIVACY_RETENTION_HOLD");
+    }
+}
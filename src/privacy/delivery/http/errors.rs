@@ -0,0 +1,34 @@
+use crate::privacy::domain::PrivacyError;
+use crate::utils::response;
+use actix_web::HttpResponse;
+
+pub fn error_response(e: &PrivacyError) -> HttpResponse {
+    println!("Error: {}", e);
+    match e {
+        PrivacyError::NotFound(msg) => HttpResponse::NotFound().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "VOTER_NOT_FOUND".into(),
+        )),
+        PrivacyError::Unauthorized(msg) => HttpResponse::Unauthorized().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "PRIVACY_UNAUTHORIZED".into(),
+        )),
+        PrivacyError::Forbidden(msg) => HttpResponse::Forbidden().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "PRIVACY_FORBIDDEN".into(),
+        )),
+        PrivacyError::RetentionHold(msg) => HttpResponse::Conflict().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "PRIVACY_RETENTION_HOLD".into(),
+        )),
+        PrivacyError::UnknownError(_) => HttpResponse::InternalServerError().json(response::error::<()>(
+            None,
+            "failed process data".into(),
+            "-1".into(),
+        )),
+    }
+}
@@ -0,0 +1,31 @@
+use crate::embargo::delivery::http::staff_token;
+use crate::privacy::delivery::http::errors::error_response;
+use crate::privacy::usecase::export::*;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+
+pub async fn export_voter(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+
+    let voter_id = path.into_inner();
+    let request = Request { voter_id: voter_id.clone(), staff_token: staff_token(&http_request) };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.privacy_uc.export.handle(request).await {
+        Ok(bundle) => HttpResponse::Ok()
+            .insert_header((
+                "Content-Disposition",
+                format!("attachment; filename=\"voter-{}.json\"", voter_id),
+            ))
+            .insert_header(("Cache-Control", "no-store"))
+            .json(response::success(
+                Some(bundle),
+                "Successfully processed voter export".into(),
+            )),
+        Err(e) => error_response(&e),
+    }
+}
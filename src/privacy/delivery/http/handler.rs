@@ -0,0 +1,9 @@
+use actix_web::web;
+use crate::privacy::delivery::http::erase_voter::erase_voter;
+use crate::privacy::delivery::http::export_voter::export_voter;
+
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/voters/{id}/export", web::get().to(export_voter))
+        .route("/voters/{id}/erase", web::post().to(erase_voter));
+}
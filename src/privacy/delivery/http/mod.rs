@@ -0,0 +1,8 @@
+mod handler;
+mod errors;
+mod erase_voter;
+mod export_voter;
+
+pub use erase_voter::*;
+pub use export_voter::*;
+pub use handler::*;
@@ -0,0 +1,110 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VoterRecord {
+    pub id: String,
+    pub nim: String,
+    pub name: String,
+    pub email: String,
+    pub faculty: String,
+    pub cohort: i32,
+    pub eligible: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub erased_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RollRecord {
+    pub election_id: String,
+    pub nim: String,
+    pub name: String,
+    pub email: String,
+    pub faculty: String,
+    pub cohort: i32,
+    pub frozen_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CredentialRecord {
+    pub election_id: String,
+    pub issued_at: DateTime<Utc>,
+    pub redeemed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SessionRecord {
+    pub election_id: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// That the voter took part in an election. Ballots carry no voter, so what they voted is not known.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ParticipationRecord {
+    pub election_id: String,
+    pub voted_at: DateTime<Utc>,
+}
+
+/// A ballot of the voter's refused because voting had closed; what it said is not recorded.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LateRejectionRecord {
+    pub election_id: String,
+    pub session_started_at: DateTime<Utc>,
+    pub voting_ends_at: Option<DateTime<Utc>>,
+    pub rejected_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GroupRecord {
+    pub group_id: String,
+    pub name: String,
+    pub added_at: DateTime<Utc>,
+}
+
+/// Everything the service holds about one voter, for subject access requests.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ExportBundle {
+    pub voter: VoterRecord,
+    pub roll_entries: Vec<RollRecord>,
+    pub credentials: Vec<CredentialRecord>,
+    pub sessions: Vec<SessionRecord>,
+    pub participations: Vec<ParticipationRecord>,
+    pub late_rejections: Vec<LateRejectionRecord>,
+    pub groups: Vec<GroupRecord>,
+    pub exported_at: DateTime<Utc>,
+}
+
+/// Replacement identity written over an erased voter's PII.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Pseudonym {
+    pub nim: String,
+    pub name: String,
+    pub email: String,
+}
+
+impl Pseudonym {
+    /// Derived from the voter id alone, so erasing twice writes the same identity and
+    /// nothing about the original person survives in it.
+    pub fn for_voter(voter_id: &str) -> Self {
+        Pseudonym {
+            nim: format!("erased-{}", voter_id),
+            name: "Erased voter".to_string(),
+            email: format!("erased-{}@invalid", voter_id),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ErasureReport {
+    pub voter_id: String,
+    pub roll_entries: u64,
+    pub sessions_deleted: u64,
+    pub group_memberships_deleted: u64,
+    pub late_rejections_deleted: u64,
+    pub erased_at: Option<DateTime<Utc>>,
+    /// The staff member who first erased the voter.
+    pub erased_by: Option<String>,
+}
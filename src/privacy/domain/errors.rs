@@ -0,0 +1,27 @@
+use crate::embargo::domain::StaffDenied;
+use thiserror::Error;
+
+
+#[derive(Debug, Error)]
+#[derive(Clone)]
+pub enum PrivacyError {
+    #[error("PrivacyError::NotFound: {0}")]
+    NotFound(String),
+    #[error("PrivacyError::Unauthorized: {0}")]
+    Unauthorized(String),
+    #[error("PrivacyError::Forbidden: {0}")]
+    Forbidden(String),
+    #[error("PrivacyError::RetentionHold: {0}")]
+    RetentionHold(String),
+    #[error("PrivacyError::UnknownError: {0}")]
+    UnknownError(String),
+}
+
+impl From<StaffDenied> for PrivacyError {
+    fn from(e: StaffDenied) -> Self {
+        match e {
+            StaffDenied::Unauthenticated(msg) => PrivacyError::Unauthorized(msg),
+            StaffDenied::NotAllowed(msg) => PrivacyError::Forbidden(msg),
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod entities;
+mod errors;
+mod repository;
+
+pub use entities::*;
+pub use repository::*;
+pub use errors::*;
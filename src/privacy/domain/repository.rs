@@ -0,0 +1,40 @@
+use crate::privacy::domain::entities::{ParticipationRecord, CredentialRecord, ErasureReport, GroupRecord, LateRejectionRecord, Pseudonym, RollRecord, SessionRecord, VoterRecord};
+use crate::privacy::domain::errors::PrivacyError;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use mockall::automock;
+
+/// Knows every table that holds data about a voter. Tables added later that refer to a
+/// voter must be covered here too, or exports and erasures silently miss them.
+#[automock]
+#[async_trait]
+pub trait Repository: Send + Sync {
+    async fn find_voter(&self, voter_id: String) -> Result<VoterRecord, PrivacyError>;
+
+    async fn find_roll_records(&self, voter_id: String) -> Result<Vec<RollRecord>, PrivacyError>;
+
+    async fn find_credentials(&self, voter_id: String) -> Result<Vec<CredentialRecord>, PrivacyError>;
+
+    async fn find_sessions(&self, voter_id: String) -> Result<Vec<SessionRecord>, PrivacyError>;
+
+    async fn find_participations(&self, voter_id: String) -> Result<Vec<ParticipationRecord>, PrivacyError>;
+
+    async fn find_late_rejections(&self, voter_id: String) -> Result<Vec<LateRejectionRecord>, PrivacyError>;
+
+    async fn find_groups(&self, voter_id: String) -> Result<Vec<GroupRecord>, PrivacyError>;
+
+    /// Overwrites the voter's identity in the registry and on every roll with `pseudonym`
+    /// and drops their sessions, group memberships and late ballot rejections, in one transaction. Row ids and counts are left as they are;
+    /// participations are kept so turnout does not change. The first erasure is recorded as
+    /// done by `erased_by`.
+    ///
+    /// Fails with `RetentionHold` while the voter is on the roll of, or had a ballot refused by,
+    /// an election that is not published yet, or was published after `retention_cutoff`.
+    async fn erase(
+        &self,
+        voter_id: String,
+        pseudonym: Pseudonym,
+        retention_cutoff: DateTime<Utc>,
+        erased_by: String,
+    ) -> Result<ErasureReport, PrivacyError>;
+}
@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct VoterRecord {
+    pub id: Uuid,
+    pub nim: String,
+    pub name: String,
+    pub email: String,
+    pub faculty: String,
+    pub cohort: i32,
+    pub eligible: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub erased_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct RollRecord {
+    pub election_id: Uuid,
+    pub nim: String,
+    pub name: String,
+    pub email: String,
+    pub faculty: String,
+    pub cohort: i32,
+    pub frozen_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct CredentialRecord {
+    pub election_id: Uuid,
+    pub issued_at: DateTime<Utc>,
+    pub redeemed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct SessionRecord {
+    pub election_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct ParticipationRecord {
+    pub election_id: Uuid,
+    pub voted_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct LateRejectionRecord {
+    pub election_id: Uuid,
+    pub session_started_at: DateTime<Utc>,
+    pub voting_ends_at: Option<DateTime<Utc>>,
+    pub rejected_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct GroupRecord {
+    pub group_id: Uuid,
+    pub name: String,
+    pub added_at: DateTime<Utc>,
+}
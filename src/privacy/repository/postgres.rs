@@ -0,0 +1,360 @@
+use crate::privacy::domain;
+use crate::privacy::domain::PrivacyError;
+use crate::privacy::domain::Repository;
+use crate::infrastructure::crypto::{CryptoError, PiiCipher};
+use crate::infrastructure::database::postgres::Postgres;
+use crate::voter::repository::{FIELD_EMAIL, FIELD_NAME, FIELD_NIM};
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::privacy::repository::model::{ParticipationRecord, CredentialRecord, GroupRecord, LateRejectionRecord, RollRecord, SessionRecord, VoterRecord};
+
+pub struct PostgresRepo {
+    postgres: sqlx::PgPool,
+    cipher: Arc<PiiCipher>,
+}
+impl PostgresRepo {
+    pub async fn new(postgres: Arc<Mutex<Postgres>>, cipher: Arc<PiiCipher>) -> anyhow::Result<Self> {
+        let guard = postgres.lock().await;
+        let pool = guard
+            .pool()
+            .ok_or_else(|| anyhow!("DB pool is not initialized"))?;
+        Ok(PostgresRepo { postgres: pool, cipher })
+    }
+
+    fn crypto_error(e: CryptoError) -> PrivacyError {
+        PrivacyError::UnknownError(e.to_string())
+    }
+
+    fn reveal(&self, field: &str, value: &str) -> Result<String, PrivacyError> {
+        self.cipher.reveal(field, value).map_err(Self::crypto_error)
+    }
+
+    fn parse_id(id: &str) -> Result<Uuid, PrivacyError> {
+        Uuid::parse_str(id).map_err(|_| PrivacyError::NotFound(format!("voter {} not found", id)))
+    }
+}
+
+#[async_trait]
+impl Repository for PostgresRepo {
+    async fn find_voter(&self, voter_id: String) -> Result<domain::VoterRecord, PrivacyError> {
+        let uuid = Self::parse_id(&voter_id)?;
+
+        let v = sqlx::query_as::<_, VoterRecord>(
+            r#"
+        SELECT id
+            , nim
+            , name
+            , email
+            , faculty
+            , cohort
+            , eligible
+            , created_at
+            , updated_at
+            , erased_at
+        FROM voters
+        WHERE id = $1
+        "#,
+        )
+            .bind(uuid)
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| PrivacyError::UnknownError(e.to_string()))?
+            .ok_or_else(|| PrivacyError::NotFound(format!("voter {} not found", voter_id)))?;
+
+        Ok(domain::VoterRecord {
+            id: v.id.to_string(),
+            nim: self.reveal(FIELD_NIM, &v.nim)?,
+            name: self.reveal(FIELD_NAME, &v.name)?,
+            email: self.reveal(FIELD_EMAIL, &v.email)?,
+            faculty: v.faculty,
+            cohort: v.cohort,
+            eligible: v.eligible,
+            created_at: v.created_at,
+            updated_at: v.updated_at,
+            erased_at: v.erased_at,
+        })
+    }
+
+    async fn find_roll_records(&self, voter_id: String) -> Result<Vec<domain::RollRecord>, PrivacyError> {
+        let uuid = Self::parse_id(&voter_id)?;
+
+        let records = sqlx::query_as::<_, RollRecord>(
+            r#"
+        SELECT election_id
+            , nim
+            , name
+            , email
+            , faculty
+            , cohort
+            , frozen_at
+        FROM voter_rolls
+        WHERE voter_id = $1
+        ORDER BY frozen_at
+        "#,
+        )
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| PrivacyError::UnknownError(e.to_string()))?;
+
+        records
+            .into_iter()
+            .map(|r| Ok(domain::RollRecord {
+                election_id: r.election_id.to_string(),
+                nim: self.reveal(FIELD_NIM, &r.nim)?,
+                name: self.reveal(FIELD_NAME, &r.name)?,
+                email: self.reveal(FIELD_EMAIL, &r.email)?,
+                faculty: r.faculty,
+                cohort: r.cohort,
+                frozen_at: r.frozen_at,
+            }))
+            .collect()
+    }
+
+    async fn find_credentials(&self, voter_id: String) -> Result<Vec<domain::CredentialRecord>, PrivacyError> {
+        let uuid = Self::parse_id(&voter_id)?;
+
+        // The code hash is deliberately left out: it is a secret, not data about the voter.
+        let records = sqlx::query_as::<_, CredentialRecord>(
+            r#"SELECT election_id, issued_at, redeemed_at FROM voting_credentials WHERE voter_id = $1 ORDER BY issued_at"#,
+        )
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| PrivacyError::UnknownError(e.to_string()))?;
+
+        Ok(records
+            .into_iter()
+            .map(|c| domain::CredentialRecord {
+                election_id: c.election_id.to_string(),
+                issued_at: c.issued_at,
+                redeemed_at: c.redeemed_at,
+            })
+            .collect())
+    }
+
+    async fn find_sessions(&self, voter_id: String) -> Result<Vec<domain::SessionRecord>, PrivacyError> {
+        let uuid = Self::parse_id(&voter_id)?;
+
+        let records = sqlx::query_as::<_, SessionRecord>(
+            r#"SELECT election_id, created_at, expires_at FROM voting_sessions WHERE voter_id = $1 ORDER BY created_at"#,
+        )
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| PrivacyError::UnknownError(e.to_string()))?;
+
+        Ok(records
+            .into_iter()
+            .map(|s| domain::SessionRecord {
+                election_id: s.election_id.to_string(),
+                created_at: s.created_at,
+                expires_at: s.expires_at,
+            })
+            .collect())
+    }
+
+    async fn find_participations(&self, voter_id: String) -> Result<Vec<domain::ParticipationRecord>, PrivacyError> {
+        let uuid = Self::parse_id(&voter_id)?;
+
+        let records = sqlx::query_as::<_, ParticipationRecord>(
+            r#"SELECT election_id, voted_at FROM participations WHERE voter_id = $1 ORDER BY voted_at"#,
+        )
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| PrivacyError::UnknownError(e.to_string()))?;
+
+        Ok(records
+            .into_iter()
+            .map(|p| domain::ParticipationRecord {
+                election_id: p.election_id.to_string(),
+                voted_at: p.voted_at,
+            })
+            .collect())
+    }
+
+    async fn find_late_rejections(&self, voter_id: String) -> Result<Vec<domain::LateRejectionRecord>, PrivacyError> {
+        let uuid = Self::parse_id(&voter_id)?;
+
+        let records = sqlx::query_as::<_, LateRejectionRecord>(
+            r#"
+            SELECT election_id, session_started_at, voting_ends_at, rejected_at
+            FROM late_ballot_rejections
+            WHERE voter_id = $1
+            ORDER BY rejected_at
+            "#,
+        )
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| PrivacyError::UnknownError(e.to_string()))?;
+
+        Ok(records
+            .into_iter()
+            .map(|r| domain::LateRejectionRecord {
+                election_id: r.election_id.to_string(),
+                session_started_at: r.session_started_at,
+                voting_ends_at: r.voting_ends_at,
+                rejected_at: r.rejected_at,
+            })
+            .collect())
+    }
+
+    async fn find_groups(&self, voter_id: String) -> Result<Vec<domain::GroupRecord>, PrivacyError> {
+        let uuid = Self::parse_id(&voter_id)?;
+
+        let records = sqlx::query_as::<_, GroupRecord>(
+            r#"
+            SELECT m.group_id, g.name, m.added_at
+            FROM voter_group_members m
+            JOIN voter_groups g ON g.id = m.group_id
+            WHERE m.voter_id = $1
+            ORDER BY m.added_at
+            "#,
+        )
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| PrivacyError::UnknownError(e.to_string()))?;
+
+        Ok(records
+            .into_iter()
+            .map(|g| domain::GroupRecord {
+                group_id: g.group_id.to_string(),
+                name: g.name,
+                added_at: g.added_at,
+            })
+            .collect())
+    }
+
+    async fn erase(
+        &self,
+        voter_id: String,
+        pseudonym: domain::Pseudonym,
+        retention_cutoff: DateTime<Utc>,
+        erased_by: String,
+    ) -> Result<domain::ErasureReport, PrivacyError> {
+        let uuid = Self::parse_id(&voter_id)?;
+
+        let mut tx = self.postgres.begin().await
+            .map_err(|e| PrivacyError::UnknownError(e.to_string()))?;
+
+        let locked: Option<Uuid> = sqlx::query_scalar(r#"SELECT id FROM voters WHERE id = $1 FOR UPDATE"#)
+            .bind(uuid)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| PrivacyError::UnknownError(e.to_string()))?;
+        if locked.is_none() {
+            return Err(PrivacyError::NotFound(format!("voter {} not found", voter_id)));
+        }
+
+        let holds: Vec<Uuid> = sqlx::query_scalar(
+            r#"
+            SELECT e.id
+            FROM elections e
+            WHERE e.id IN (
+                    SELECT election_id FROM voter_rolls WHERE voter_id = $1
+                    UNION
+                    SELECT election_id FROM late_ballot_rejections WHERE voter_id = $1
+                )
+                AND (e.phase <> 'published' OR COALESCE(e.updated_at, e.created_at) > $2)
+            ORDER BY e.id
+            "#,
+        )
+            .bind(uuid)
+            .bind(retention_cutoff)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| PrivacyError::UnknownError(e.to_string()))?;
+        if !holds.is_empty() {
+            let ids: Vec<String> = holds.iter().map(|id| id.to_string()).collect();
+            return Err(PrivacyError::RetentionHold(format!(
+                "voter is still within the retention period of elections {}",
+                ids.join(", ")
+            )));
+        }
+
+        let nim_bidx = self.cipher.blind_index(FIELD_NIM, &pseudonym.nim);
+        let nim = self.cipher.encrypt(FIELD_NIM, &pseudonym.nim).map_err(Self::crypto_error)?;
+        let name = self.cipher.encrypt(FIELD_NAME, &pseudonym.name).map_err(Self::crypto_error)?;
+        let email = self.cipher.encrypt(FIELD_EMAIL, &pseudonym.email).map_err(Self::crypto_error)?;
+
+        let (erased_at, erased_by): (DateTime<Utc>, Option<String>) = sqlx::query_as(
+            r#"
+            UPDATE voters SET nim = $2, nim_bidx = $3, name = $4, email = $5, eligible = FALSE
+                , erased_at = COALESCE(erased_at, now()), erased_by = COALESCE(erased_by, $6), updated_at = now()
+            WHERE id = $1
+            RETURNING erased_at, erased_by
+            "#,
+        )
+            .bind(uuid)
+            .bind(&nim)
+            .bind(&nim_bidx)
+            .bind(&name)
+            .bind(&email)
+            .bind(&erased_by)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| PrivacyError::UnknownError(e.to_string()))?;
+
+        let roll_entries = sqlx::query(
+            r#"UPDATE voter_rolls SET nim = $2, nim_bidx = $3, name = $4, email = $5 WHERE voter_id = $1"#,
+        )
+            .bind(uuid)
+            .bind(&nim)
+            .bind(&nim_bidx)
+            .bind(&name)
+            .bind(&email)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| PrivacyError::UnknownError(e.to_string()))?
+            .rows_affected();
+
+        let sessions_deleted = sqlx::query(r#"DELETE FROM voting_sessions WHERE voter_id = $1"#)
+            .bind(uuid)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| PrivacyError::UnknownError(e.to_string()))?
+            .rows_affected();
+
+        // Only needed to answer retries while voting is open; participations stay for turnout.
+        sqlx::query(r#"DELETE FROM ballot_idempotency_keys WHERE voter_id = $1"#)
+            .bind(uuid)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| PrivacyError::UnknownError(e.to_string()))?;
+
+        let group_memberships_deleted = sqlx::query(r#"DELETE FROM voter_group_members WHERE voter_id = $1"#)
+            .bind(uuid)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| PrivacyError::UnknownError(e.to_string()))?
+            .rows_affected();
+
+        // Every election these belong to is past retention, or the hold above would have refused.
+        let late_rejections_deleted = sqlx::query(r#"DELETE FROM late_ballot_rejections WHERE voter_id = $1"#)
+            .bind(uuid)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| PrivacyError::UnknownError(e.to_string()))?
+            .rows_affected();
+
+        tx.commit().await
+            .map_err(|e| PrivacyError::UnknownError(e.to_string()))?;
+
+        Ok(domain::ErasureReport {
+            voter_id,
+            roll_entries,
+            sessions_deleted,
+            group_memberships_deleted,
+            late_rejections_deleted,
+            erased_at: Some(erased_at),
+            erased_by,
+        })
+    }
+}
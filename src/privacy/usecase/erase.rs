@@ -0,0 +1,133 @@
+use crate::privacy::domain::{ErasureReport, PrivacyError, Pseudonym, Repository};
+use crate::embargo::domain::StaffRoster;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<ErasureReport, PrivacyError>;
+}
+
+pub struct EraseVoterUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    roster: Arc<StaffRoster>,
+    retention: chrono::Duration,
+}
+
+pub struct Request {
+    pub voter_id: String,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("voter_id", &self.voter_id)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> EraseVoterUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, roster: Arc<StaffRoster>, retention: chrono::Duration) -> Self {
+        Self { repository, roster, retention }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for EraseVoterUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<ErasureReport, PrivacyError> {
+        let member = self.roster.acting(req.staff_token.as_deref(), "erase a voter")?;
+
+        let pseudonym = Pseudonym::for_voter(&req.voter_id);
+        let cutoff = Utc::now() - self.retention;
+
+        self.repository.erase(req.voter_id, pseudonym, cutoff, member.name).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::embargo::domain::{StaffRoster, hash_staff_token};
+    use crate::privacy::domain::{self, PrivacyError};
+    use crate::privacy::usecase::erase;
+    use crate::privacy::usecase::erase::Interactor;
+    use std::sync::Arc;
+
+    fn roster() -> Arc<StaffRoster> {
+        Arc::new(StaffRoster::parse(&format!(
+            "rina:committee:{},budi:observer:{}",
+            hash_staff_token("rina-token"),
+            hash_staff_token("budi-token")
+        )).unwrap())
+    }
+
+    fn request(staff_token: Option<&str>) -> erase::Request {
+        erase::Request { voter_id: "v1".to_string(), staff_token: staff_token.map(str::to_string) }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_erase_writes_pseudonym_and_cutoff() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_erase()
+            .times(1)
+            .withf(|voter_id, pseudonym, cutoff, erased_by| {
+                let expected = chrono::Utc::now() - chrono::Duration::days(365);
+                voter_id == "v1"
+                    && pseudonym.nim == "erased-v1"
+                    && !pseudonym.email.contains("campus")
+                    && (*cutoff - expected).num_seconds().abs() < 5
+                    && erased_by == "rina"
+            })
+            .returning(|voter_id, _, _, erased_by| Ok(domain::ErasureReport {
+                voter_id,
+                roll_entries: 2,
+                sessions_deleted: 1,
+                group_memberships_deleted: 0,
+                late_rejections_deleted: 1,
+                erased_at: Some(chrono::Utc::now()),
+                erased_by: Some(erased_by),
+            }));
+
+        let uc = erase::EraseVoterUseCase::new(Arc::new(repo_mock), roster(), chrono::Duration::days(365));
+        let report = uc.handle(request(Some("rina-token"))).await.unwrap();
+
+        assert_eq!(report.roll_entries, 2);
+        assert_eq!(report.erased_by.as_deref(), Some("rina"));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_erase_within_retention_is_refused() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_erase()
+            .times(1)
+            .returning(|_, _, _, _| Err(PrivacyError::RetentionHold("election e1".into())));
+
+        let uc = erase::EraseVoterUseCase::new(Arc::new(repo_mock), roster(), chrono::Duration::days(365));
+        let res = uc.handle(request(Some("rina-token"))).await;
+
+        assert!(matches!(res, Err(PrivacyError::RetentionHold(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_erase_needs_an_acting_staff_member() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_erase().times(0);
+
+        let uc = erase::EraseVoterUseCase::new(Arc::new(repo_mock), roster(), chrono::Duration::days(365));
+
+        assert!(matches!(uc.handle(request(None)).await, Err(PrivacyError::Unauthorized(_))));
+        assert!(matches!(uc.handle(request(Some("budi-token"))).await, Err(PrivacyError::Forbidden(_))));
+    }
+}
@@ -0,0 +1,169 @@
+use crate::privacy::domain::{ExportBundle, PrivacyError, Repository};
+use crate::embargo::domain::StaffRoster;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<ExportBundle, PrivacyError>;
+}
+
+pub struct ExportVoterUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    roster: Arc<StaffRoster>,
+}
+
+pub struct Request {
+    pub voter_id: String,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("voter_id", &self.voter_id)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> ExportVoterUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, roster: Arc<StaffRoster>) -> Self {
+        Self { repository, roster }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for ExportVoterUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<ExportBundle, PrivacyError> {
+        self.roster.acting(req.staff_token.as_deref(), "export a voter's data")?;
+
+        let voter = self.repository.find_voter(req.voter_id.clone()).await?;
+
+        Ok(ExportBundle {
+            roll_entries: self.repository.find_roll_records(req.voter_id.clone()).await?,
+            credentials: self.repository.find_credentials(req.voter_id.clone()).await?,
+            sessions: self.repository.find_sessions(req.voter_id.clone()).await?,
+            participations: self.repository.find_participations(req.voter_id.clone()).await?,
+            late_rejections: self.repository.find_late_rejections(req.voter_id.clone()).await?,
+            groups: self.repository.find_groups(req.voter_id).await?,
+            voter,
+            exported_at: Utc::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::embargo::domain::{StaffRoster, hash_staff_token};
+    use crate::privacy::domain::{self, PrivacyError};
+    use crate::privacy::usecase::export;
+    use crate::privacy::usecase::export::Interactor;
+    use std::sync::Arc;
+
+    fn roster() -> Arc<StaffRoster> {
+        Arc::new(StaffRoster::parse(&format!(
+            "rina:committee:{},budi:observer:{}",
+            hash_staff_token("rina-token"),
+            hash_staff_token("budi-token")
+        )).unwrap())
+    }
+
+    fn request(staff_token: Option<&str>) -> export::Request {
+        export::Request { voter_id: "v1".to_string(), staff_token: staff_token.map(str::to_string) }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_export_gathers_all_records() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_voter().times(1).returning(|id| Ok(domain::VoterRecord {
+            id,
+            nim: "2023010001".to_string(),
+            name: "Alice".to_string(),
+            email: "alice@campus.ac.id".to_string(),
+            faculty: "Engineering".to_string(),
+            cohort: 2023,
+            eligible: true,
+            created_at: chrono::Utc::now(),
+            updated_at: None,
+            erased_at: None,
+        }));
+        repo_mock.expect_find_roll_records().times(1).returning(|_| Ok(vec![domain::RollRecord {
+            election_id: "e1".to_string(),
+            nim: "2023010001".to_string(),
+            name: "Alice".to_string(),
+            email: "alice@campus.ac.id".to_string(),
+            faculty: "Engineering".to_string(),
+            cohort: 2023,
+            frozen_at: chrono::Utc::now(),
+        }]));
+        repo_mock.expect_find_credentials().times(1).returning(|_| Ok(vec![domain::CredentialRecord {
+            election_id: "e1".to_string(),
+            issued_at: chrono::Utc::now(),
+            redeemed_at: None,
+        }]));
+        repo_mock.expect_find_sessions().times(1).returning(|_| Ok(vec![]));
+        repo_mock.expect_find_participations().times(1).returning(|_| Ok(vec![domain::ParticipationRecord {
+            election_id: "e1".to_string(),
+            voted_at: chrono::Utc::now(),
+        }]));
+        repo_mock.expect_find_late_rejections().times(1).returning(|_| Ok(vec![domain::LateRejectionRecord {
+            election_id: "e1".to_string(),
+            session_started_at: chrono::Utc::now() - chrono::Duration::minutes(20),
+            voting_ends_at: Some(chrono::Utc::now() - chrono::Duration::minutes(3)),
+            rejected_at: chrono::Utc::now(),
+        }]));
+        repo_mock.expect_find_groups().times(1).returning(|_| Ok(vec![domain::GroupRecord {
+            group_id: "g1".to_string(),
+            name: "Residence hall A".to_string(),
+            added_at: chrono::Utc::now(),
+        }]));
+
+        let uc = export::ExportVoterUseCase::new(Arc::new(repo_mock), roster());
+        let bundle = uc.handle(request(Some("rina-token"))).await.unwrap();
+
+        assert_eq!(bundle.voter.id, "v1");
+        assert_eq!(bundle.roll_entries.len(), 1);
+        assert_eq!(bundle.credentials.len(), 1);
+        assert!(bundle.sessions.is_empty());
+        assert_eq!(bundle.participations.len(), 1);
+        assert_eq!(bundle.late_rejections[0].election_id, "e1");
+        assert_eq!(bundle.groups[0].name, "Residence hall A");
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_export_unknown_voter() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_voter()
+            .times(1)
+            .returning(|_| Err(PrivacyError::NotFound("voter v1 not found".into())));
+        repo_mock.expect_find_roll_records().times(0);
+
+        let uc = export::ExportVoterUseCase::new(Arc::new(repo_mock), roster());
+        let res = uc.handle(request(Some("rina-token"))).await;
+
+        assert!(matches!(res, Err(PrivacyError::NotFound(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_export_needs_an_acting_staff_member() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_voter().times(0);
+
+        let uc = export::ExportVoterUseCase::new(Arc::new(repo_mock), roster());
+
+        assert!(matches!(uc.handle(request(None)).await, Err(PrivacyError::Unauthorized(_))));
+        assert!(matches!(uc.handle(request(Some("budi-token"))).await, Err(PrivacyError::Forbidden(_))));
+    }
+}
@@ -0,0 +1,30 @@
+use std::sync::Arc;
+use crate::embargo::domain::StaffRoster;
+use crate::privacy::domain::Repository;
+use crate::privacy::usecase::{erase, export};
+use crate::privacy::usecase::erase::EraseVoterUseCase;
+use crate::privacy::usecase::export::ExportVoterUseCase;
+
+
+#[derive(Clone)]
+pub struct UseCase
+{
+    pub export: Arc<dyn export::Interactor>,
+    pub erase: Arc<dyn erase::Interactor>,
+}
+
+impl UseCase {
+
+    pub fn new(
+        privacy_repo: Arc<dyn Repository + Send + Sync>,
+        roster: Arc<StaffRoster>,
+        retention: chrono::Duration,
+    ) -> Self {
+
+        Self {
+            export: Arc::new(ExportVoterUseCase::new(privacy_repo.clone(), roster.clone())),
+            erase: Arc::new(EraseVoterUseCase::new(privacy_repo, roster, retention)),
+        }
+    }
+
+}
@@ -0,0 +1,5 @@
+mod init;
+pub mod erase;
+pub mod export;
+
+pub use init::UseCase;
@@ -0,0 +1,49 @@
+use crate::rla::domain::RlaError;
+use crate::utils::response;
+use actix_web::HttpResponse;
+
+pub fn error_response(e: &RlaError) -> HttpResponse {
+    println!("Error: {}", e);
+    match e {
+        RlaError::NotFound(msg) => HttpResponse::NotFound().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "RLA_NOT_FOUND".into(),
+        )),
+        RlaError::InvalidInput(msg) => HttpResponse::BadRequest().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "RLA_INVALID_INPUT".into(),
+        )),
+        RlaError::Unauthorized(msg) => HttpResponse::Unauthorized().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "RLA_UNAUTHORIZED".into(),
+        )),
+        RlaError::Forbidden(msg) => HttpResponse::Forbidden().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "RLA_FORBIDDEN".into(),
+        )),
+        RlaError::Embargoed(msg) => HttpResponse::Forbidden().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "RESULTS_EMBARGOED".into(),
+        )),
+        RlaError::NotReady(msg) => HttpResponse::Conflict().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "RLA_NOT_READY".into(),
+        )),
+        RlaError::AlreadyInterpreted(msg) => HttpResponse::Conflict().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "RLA_ALREADY_INTERPRETED".into(),
+        )),
+        RlaError::UnknownError(_) => HttpResponse::InternalServerError().json(response::error::<()>(
+            None,
+            "failed process data".into(),
+            "-1".into(),
+        )),
+    }
+}
@@ -0,0 +1,35 @@
+use crate::embargo::delivery::http::staff_token;
+use crate::rla::delivery::http::errors::error_response;
+use crate::rla::usecase::escalate::*;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct EscalateAuditBody {
+    sample_size: i64,
+}
+
+pub async fn escalate_audit(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<EscalateAuditBody>,
+) -> HttpResponse {
+
+    let request = Request {
+        audit_id: path.into_inner(),
+        sample_size: body.sample_size,
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.rla_uc.escalate.handle(request).await {
+        Ok(audit) => HttpResponse::Ok().json(response::success(
+            Some(audit),
+            "Successfully processed escalation".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
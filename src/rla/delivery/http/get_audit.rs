@@ -0,0 +1,27 @@
+use crate::embargo::delivery::http::staff_token;
+use crate::rla::delivery::http::errors::error_response;
+use crate::rla::usecase::get_audit::*;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+
+pub async fn get_audit(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+
+    let request = Request {
+        audit_id: path.into_inner(),
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.rla_uc.get_audit.handle(request).await {
+        Ok(audit) => HttpResponse::Ok().json(response::success(
+            Some(audit),
+            "Successfully processed audit".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
@@ -0,0 +1,13 @@
+use actix_web::web;
+use crate::rla::delivery::http::escalate_audit::escalate_audit;
+use crate::rla::delivery::http::get_audit::get_audit;
+use crate::rla::delivery::http::interpret_ballot::interpret_ballot;
+use crate::rla::delivery::http::start_audit::start_audit;
+
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/elections/{id}/contests/{contest_id}/audits", web::post().to(start_audit))
+        .route("/audits/{id}", web::get().to(get_audit))
+        .route("/audits/{id}/ballots/{station_id}/{position}", web::put().to(interpret_ballot))
+        .route("/audits/{id}/escalate", web::post().to(escalate_audit));
+}
@@ -0,0 +1,41 @@
+use crate::embargo::delivery::http::staff_token;
+use crate::rla::delivery::http::errors::error_response;
+use crate::rla::usecase::interpret::*;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct InterpretBallotBody {
+    mark: String,
+    candidate_id: Option<String>,
+}
+
+pub async fn interpret_ballot(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<(String, String, i64)>,
+    body: web::Json<InterpretBallotBody>,
+) -> HttpResponse {
+
+    let (audit_id, station_id, position) = path.into_inner();
+    let body = body.into_inner();
+    let request = Request {
+        audit_id,
+        station_id,
+        position,
+        mark: body.mark,
+        candidate_id: body.candidate_id,
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.rla_uc.interpret.handle(request).await {
+        Ok(audit) => HttpResponse::Ok().json(response::success(
+            Some(audit),
+            "Successfully processed interpretation".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
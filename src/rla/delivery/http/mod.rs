@@ -0,0 +1,12 @@
+mod handler;
+mod errors;
+mod escalate_audit;
+mod get_audit;
+mod interpret_ballot;
+mod start_audit;
+
+pub use escalate_audit::*;
+pub use get_audit::*;
+pub use interpret_ballot::*;
+pub use start_audit::*;
+pub use handler::*;
@@ -0,0 +1,90 @@
+use crate::embargo::delivery::http::staff_token;
+use crate::rla::delivery::http::errors::error_response;
+use crate::rla::usecase::start_audit::*;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct StartAuditBody {
+    risk_limit: f64,
+    seed: String,
+    sample_size: Option<i64>,
+}
+
+pub async fn start_audit(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<(String, String)>,
+    body: web::Json<StartAuditBody>,
+) -> HttpResponse {
+
+    let (election_id, contest_id) = path.into_inner();
+    let body = body.into_inner();
+    let request = Request {
+        election_id,
+        contest_id,
+        risk_limit: body.risk_limit,
+        seed: body.seed,
+        sample_size: body.sample_size,
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.rla_uc.start_audit.handle(request).await {
+        Ok(audit) => HttpResponse::Created().json(response::success(
+            Some(audit),
+            "Successfully processed audit".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::start_audit;
+    use actix_web::{App, http::StatusCode, test, web};
+    use async_trait::async_trait;
+    use std::sync::Arc;
+    use crate::rla::domain::{AuditView, RlaError};
+    use crate::rla::usecase::start_audit::{Interactor, Request};
+    use crate::utils::app;
+
+    #[actix_rt::test]
+    async fn test_start_audit_maps_unmerged_stations_to_conflict() {
+        struct MockStart;
+
+        #[async_trait]
+        impl Interactor for MockStart {
+            async fn handle(&self, req: Request) -> Result<AuditView, RlaError> {
+                assert_eq!(req.contest_id, "k1");
+                assert_eq!(req.staff_token.as_deref(), Some("secret"));
+                assert_eq!(req.sample_size, None);
+                Err(RlaError::NotReady("stations not merged yet: Library".into()))
+            }
+        }
+
+        let mut rla_uc = app::AppHandlerData::mocked().rla_uc;
+        rla_uc.start_audit = Arc::new(MockStart);
+        let app_data = web::Data::new(app::AppHandlerData { rla_uc, ..app::AppHandlerData::mocked() });
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_data)
+                .route("/elections/{id}/contests/{contest_id}/audits", web::post().to(start_audit)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/elections/e1/contests/k1/audits")
+            .insert_header(("Authorization", "Bearer secret"))
+            .set_json(serde_json::json!({"risk_limit": 0.05, "seed": "0451 2209"}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error_code"], "RLA_NOT_READY");
+    }
+}
@@ -0,0 +1,246 @@
+use crate::rla::domain::entities::{
+    Audit, AuditReport, AuditStatus, AuditView, Interpretation, Mark, PairTest, PaperContest, ReportedOutcome,
+    SampledBallot,
+};
+use crate::rla::domain::errors::RlaError;
+use crate::rla::domain::sample::draw_sample;
+use std::collections::HashMap;
+
+/// The most ballots an audit draws; past this the contest is better counted by hand.
+pub const MAX_SAMPLE_SIZE: i64 = 100_000;
+
+/// The paper outcome an audit checks. Every station has to be merged, and the contest needs
+/// a single leader: a tie is settled by a full hand count, not by sampling.
+pub fn reported_outcome(contest: &PaperContest) -> Result<ReportedOutcome, RlaError> {
+    if !contest.unmerged.is_empty() {
+        return Err(RlaError::NotReady(format!(
+            "stations not merged yet: {}",
+            contest.unmerged.join(", ")
+        )));
+    }
+    if contest.votes.len() < 2 {
+        return Err(RlaError::InvalidInput(format!(
+            "contest {} has no losing candidate to audit against",
+            contest.contest_id
+        )));
+    }
+
+    let ballots: i64 = contest.stations.iter().map(|s| s.ballots).sum();
+    let top = contest.votes.iter().map(|c| c.votes).max().unwrap_or(0);
+    if ballots <= 0 || top <= 0 {
+        return Err(RlaError::InvalidInput(format!(
+            "contest {} has no paper votes to audit",
+            contest.contest_id
+        )));
+    }
+    let leaders: Vec<_> = contest.votes.iter().filter(|c| c.votes == top).collect();
+    if leaders.len() > 1 {
+        return Err(RlaError::InvalidInput(format!(
+            "contest {} is tied on paper and needs a full hand count",
+            contest.contest_id
+        )));
+    }
+
+    Ok(ReportedOutcome {
+        ballots,
+        stations: contest.stations.clone(),
+        votes: contest.votes.clone(),
+        winner: leaders[0].candidate_id.clone(),
+    })
+}
+
+/// The winner's share of the two-candidate vote against each reported loser.
+fn shares(reported: &ReportedOutcome) -> Vec<(String, f64, i64)> {
+    let winner = reported.votes.iter().find(|c| c.candidate_id == reported.winner).map(|c| c.votes).unwrap_or(0);
+    reported.votes
+        .iter()
+        .filter(|c| c.candidate_id != reported.winner)
+        .map(|c| (c.candidate_id.clone(), winner as f64 / (winner + c.votes) as f64, c.votes))
+        .collect()
+}
+
+/// BRAVO's expected number of draws to confirm the reported outcome, were it right, at risk
+/// limit `risk_limit`: the largest over the reported losers, capped at `MAX_SAMPLE_SIZE`.
+pub fn initial_sample_size(reported: &ReportedOutcome, risk_limit: f64) -> i64 {
+    let winner = reported.votes.iter().find(|c| c.candidate_id == reported.winner).map(|c| c.votes).unwrap_or(0);
+    let p_w = winner as f64 / reported.ballots as f64;
+
+    let size = shares(reported)
+        .into_iter()
+        .map(|(_, s, loser)| {
+            let z_w = (2.0 * s).ln();
+            let p_l = loser as f64 / reported.ballots as f64;
+            let lost = if loser == 0 { 0.0 } else { p_l * (2.0 - 2.0 * s).ln() };
+            ((1.0 / risk_limit).ln() + z_w / 2.0) / (p_w * z_w + lost)
+        })
+        .fold(1.0f64, f64::max);
+
+    if size.is_finite() {
+        (size.ceil() as i64).clamp(1, MAX_SAMPLE_SIZE)
+    } else {
+        MAX_SAMPLE_SIZE
+    }
+}
+
+/// Runs BRAVO over the sample in draw order, up to the first draw not interpreted yet. Each
+/// reported loser is tested against the winner on its own and stays confirmed once its
+/// statistic reaches `1 / risk_limit`; ballots for neither candidate leave that pair alone.
+pub fn assess(audit: &Audit, sample: &[SampledBallot]) -> AuditReport {
+    let threshold = 1.0 / audit.risk_limit;
+    let mut pairs: Vec<(PairTest, f64)> = shares(&audit.reported)
+        .into_iter()
+        .map(|(loser, s, _)| (PairTest { loser, statistic: 1.0, confirmed: false }, s))
+        .collect();
+
+    let mut interpreted = 0;
+    for ballot in sample {
+        let Some(interpretation) = &ballot.interpretation else {
+            break;
+        };
+        interpreted += 1;
+        let Some(candidate) = interpretation.candidate_id.as_deref().filter(|_| interpretation.mark == Mark::Candidate) else {
+            continue;
+        };
+        for (pair, s) in pairs.iter_mut().filter(|(p, _)| !p.confirmed) {
+            if candidate == audit.reported.winner {
+                pair.statistic *= *s / 0.5;
+            } else if candidate == pair.loser {
+                pair.statistic *= (1.0 - *s) / 0.5;
+            }
+            pair.confirmed = pair.statistic >= threshold;
+        }
+    }
+
+    let pairs: Vec<PairTest> = pairs.into_iter().map(|(p, _)| p).collect();
+    // A statistic at zero never recovers: only a hand count can settle that pair.
+    let refuted = pairs.iter().any(|p| !p.confirmed && p.statistic <= 0.0);
+    let (status, suggested_sample_size) = if pairs.iter().all(|p| p.confirmed) {
+        (AuditStatus::Confirmed, None)
+    } else if refuted {
+        (AuditStatus::Escalate, None)
+    } else if interpreted >= audit.sample_size {
+        let grown = (audit.sample_size * 2).min(MAX_SAMPLE_SIZE);
+        (AuditStatus::Escalate, Some(grown).filter(|g| *g > audit.sample_size))
+    } else {
+        (AuditStatus::AwaitingInterpretations, None)
+    };
+
+    AuditReport { status, threshold, interpreted, pairs, suggested_sample_size }
+}
+
+/// The audit with its sample drawn, the interpretations entered so far and where it stands.
+pub fn audit_view(audit: Audit, interpretations: Vec<Interpretation>) -> AuditView {
+    let entered: HashMap<(String, i64), Interpretation> = interpretations
+        .into_iter()
+        .map(|i| ((i.station_id.clone(), i.position), i))
+        .collect();
+
+    let sample: Vec<SampledBallot> = draw_sample(&audit.seed, &audit.reported.stations, audit.sample_size)
+        .into_iter()
+        .map(|mut b| {
+            b.interpretation = entered.get(&(b.station_id.clone(), b.position)).cloned();
+            b
+        })
+        .collect();
+    let report = assess(&audit, &sample);
+
+    AuditView { audit, sample, report }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rla::domain::entities::{CandidateVotes, FrameStation};
+    use chrono::Utc;
+
+    fn votes(id: &str, votes: i64) -> CandidateVotes {
+        CandidateVotes { candidate_id: id.to_string(), votes }
+    }
+
+    fn audit(sample_size: i64) -> Audit {
+        Audit {
+            id: "a1".to_string(),
+            election_id: "e1".to_string(),
+            contest_id: "k1".to_string(),
+            risk_limit: 0.1,
+            seed: "seed".to_string(),
+            sample_size,
+            reported: ReportedOutcome {
+                ballots: 100,
+                stations: vec![FrameStation { station_id: "s1".to_string(), name: "Hall".to_string(), ballots: 100 }],
+                votes: vec![votes("w", 70), votes("l", 30)],
+                winner: "w".to_string(),
+            },
+            created_by: "rina".to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn read(draw: i64, candidate: Option<&str>) -> SampledBallot {
+        SampledBallot {
+            draw,
+            station_id: "s1".to_string(),
+            station_name: "Hall".to_string(),
+            position: draw,
+            interpretation: Some(Interpretation {
+                station_id: "s1".to_string(),
+                position: draw,
+                mark: if candidate.is_some() { Mark::Candidate } else { Mark::Blank },
+                candidate_id: candidate.map(str::to_string),
+                interpreted_by: "rina".to_string(),
+                interpreted_at: Utc::now(),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_reported_outcome_needs_merged_stations_and_a_single_leader() {
+        let mut contest = PaperContest {
+            contest_id: "k1".to_string(),
+            stations: vec![FrameStation { station_id: "s1".to_string(), name: "Hall".to_string(), ballots: 10 }],
+            unmerged: vec!["Library".to_string()],
+            votes: vec![votes("w", 6), votes("l", 4)],
+        };
+        assert!(matches!(reported_outcome(&contest), Err(RlaError::NotReady(_))));
+
+        contest.unmerged.clear();
+        assert_eq!(reported_outcome(&contest).unwrap().winner, "w");
+
+        contest.votes = vec![votes("w", 5), votes("l", 5)];
+        assert!(matches!(reported_outcome(&contest), Err(RlaError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_initial_sample_size_grows_as_the_margin_narrows() {
+        let wide = audit(1);
+        let mut narrow = audit(1);
+        narrow.reported.votes = vec![votes("w", 55), votes("l", 45)];
+
+        let wide_size = initial_sample_size(&wide.reported, 0.1);
+        let narrow_size = initial_sample_size(&narrow.reported, 0.1);
+
+        // ln(10) + ln(1.4)/2 over 0.7 ln(1.4) + 0.3 ln(0.6) is just over 30 draws.
+        assert_eq!(wide_size, 31);
+        assert!(narrow_size > 10 * wide_size);
+    }
+
+    #[test]
+    fn test_assess_confirms_escalates_and_waits() {
+        // Each vote for w multiplies the statistic by 1.4, so seven in a row pass 1/0.1.
+        let winning: Vec<_> = (1..=7).map(|n| read(n, Some("w"))).collect();
+        let report = assess(&audit(7), &winning);
+        assert_eq!(report.status, AuditStatus::Confirmed);
+        assert!(report.pairs[0].confirmed);
+
+        let mixed: Vec<_> = (1..=7).map(|n| read(n, Some(if n % 2 == 0 { "l" } else { "w" }))).collect();
+        let report = assess(&audit(7), &mixed);
+        assert_eq!(report.status, AuditStatus::Escalate);
+        assert_eq!(report.suggested_sample_size, Some(14));
+
+        let mut partial = winning.clone();
+        partial[3].interpretation = None;
+        let report = assess(&audit(7), &partial);
+        assert_eq!(report.status, AuditStatus::AwaitingInterpretations);
+        assert_eq!(report.interpreted, 3);
+    }
+}
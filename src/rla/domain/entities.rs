@@ -0,0 +1,163 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+
+/// A merged station's ballot box, as the audit samples it.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FrameStation {
+    pub station_id: String,
+    pub name: String,
+    pub ballots: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CandidateVotes {
+    pub candidate_id: String,
+    pub votes: i64,
+}
+
+/// A contest as the paper stations reported it.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PaperContest {
+    pub contest_id: String,
+    /// Merged stations, in name order.
+    pub stations: Vec<FrameStation>,
+    /// Names of the stations not merged yet.
+    pub unmerged: Vec<String>,
+    /// Every candidate of the contest, in `vote_number` order, with their merged paper votes.
+    pub votes: Vec<CandidateVotes>,
+}
+
+/// The outcome an audit checks and the ballots it draws from, fixed when it starts.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ReportedOutcome {
+    /// Paper ballots in the frame, whether valid, blank or invalid.
+    pub ballots: i64,
+    pub stations: Vec<FrameStation>,
+    pub votes: Vec<CandidateVotes>,
+    pub winner: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct NewAudit {
+    pub election_id: String,
+    pub contest_id: String,
+    pub risk_limit: f64,
+    pub seed: String,
+    pub sample_size: i64,
+    pub reported: ReportedOutcome,
+    pub created_by: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Audit {
+    pub id: String,
+    pub election_id: String,
+    pub contest_id: String,
+    pub risk_limit: f64,
+    pub seed: String,
+    pub sample_size: i64,
+    pub reported: ReportedOutcome,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// What the committee reads on a pulled ballot for the audited contest.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Mark {
+    Candidate,
+    Abstain,
+    Blank,
+    Invalid,
+}
+
+impl Mark {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Mark::Candidate => "candidate",
+            Mark::Abstain => "abstain",
+            Mark::Blank => "blank",
+            Mark::Invalid => "invalid",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Mark> {
+        match value {
+            "candidate" => Some(Mark::Candidate),
+            "abstain" => Some(Mark::Abstain),
+            "blank" => Some(Mark::Blank),
+            "invalid" => Some(Mark::Invalid),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct NewInterpretation {
+    pub audit_id: String,
+    pub station_id: String,
+    pub position: i64,
+    pub mark: Mark,
+    pub candidate_id: Option<String>,
+    pub interpreted_by: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Interpretation {
+    pub station_id: String,
+    pub position: i64,
+    pub mark: Mark,
+    pub candidate_id: Option<String>,
+    pub interpreted_by: String,
+    pub interpreted_at: DateTime<Utc>,
+}
+
+/// One draw of the sample: the `position`th ballot in a station's box. Drawn with
+/// replacement, so a ballot can come up more than once.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SampledBallot {
+    /// 1-based.
+    pub draw: i64,
+    pub station_id: String,
+    pub station_name: String,
+    pub position: i64,
+    pub interpretation: Option<Interpretation>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditStatus {
+    /// Draws still need interpreting before the audit can stop.
+    AwaitingInterpretations,
+    /// The sample confirms the reported winner within the risk limit.
+    Confirmed,
+    /// The sample is interpreted and does not confirm the winner: grow it or count by hand.
+    Escalate,
+}
+
+/// The test of the reported winner against one reported loser.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PairTest {
+    pub loser: String,
+    pub statistic: f64,
+    pub confirmed: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AuditReport {
+    pub status: AuditStatus,
+    /// `1 / risk_limit`; a pair is confirmed once its statistic reaches it.
+    pub threshold: f64,
+    /// Draws taken into account, in draw order up to the first one not yet interpreted.
+    pub interpreted: i64,
+    pub pairs: Vec<PairTest>,
+    pub suggested_sample_size: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AuditView {
+    pub audit: Audit,
+    pub sample: Vec<SampledBallot>,
+    pub report: AuditReport,
+}
@@ -0,0 +1,37 @@
+use crate::embargo::domain::EmbargoError;
+use thiserror::Error;
+
+
+#[derive(Debug, Error)]
+#[derive(Clone)]
+pub enum RlaError {
+    #[error("RlaError::NotFound: {0}")]
+    NotFound(String),
+    #[error("RlaError::InvalidInput: {0}")]
+    InvalidInput(String),
+    #[error("RlaError::Unauthorized: {0}")]
+    Unauthorized(String),
+    #[error("RlaError::Forbidden: {0}")]
+    Forbidden(String),
+    #[error("RlaError::Embargoed: {0}")]
+    Embargoed(String),
+    /// Some of the election's paper stations are not merged yet.
+    #[error("RlaError::NotReady: {0}")]
+    NotReady(String),
+    #[error("RlaError::AlreadyInterpreted: {0}")]
+    AlreadyInterpreted(String),
+    #[error("RlaError::UnknownError: {0}")]
+    UnknownError(String),
+}
+
+impl From<EmbargoError> for RlaError {
+    fn from(e: EmbargoError) -> Self {
+        match e {
+            EmbargoError::NotFound(msg) => RlaError::NotFound(msg),
+            EmbargoError::Unauthorized(msg) => RlaError::Unauthorized(msg),
+            EmbargoError::Forbidden(msg) => RlaError::Forbidden(msg),
+            EmbargoError::Embargoed(msg) => RlaError::Embargoed(msg),
+            other => RlaError::UnknownError(other.to_string()),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+mod bravo;
+mod entities;
+mod errors;
+mod repository;
+mod sample;
+
+pub use bravo::*;
+pub use entities::*;
+pub use errors::*;
+pub use repository::*;
+pub use sample::*;
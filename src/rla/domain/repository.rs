@@ -0,0 +1,23 @@
+use crate::rla::domain::entities::{Audit, Interpretation, NewAudit, NewInterpretation, PaperContest};
+use crate::rla::domain::errors::RlaError;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait Repository: Send + Sync {
+    /// `NotFound` when the election has no such contest.
+    async fn find_paper_contest(&self, election_id: String, contest_id: String) -> Result<PaperContest, RlaError>;
+
+    async fn create_audit(&self, audit: NewAudit) -> Result<Audit, RlaError>;
+
+    async fn find_audit(&self, id: String) -> Result<Audit, RlaError>;
+
+    /// Fails with `InvalidInput` unless `sample_size` is larger than the audit's.
+    async fn grow_sample(&self, id: String, sample_size: i64) -> Result<Audit, RlaError>;
+
+    /// Fails with `AlreadyInterpreted` when the ballot has an interpretation already.
+    async fn save_interpretation(&self, interpretation: NewInterpretation) -> Result<Interpretation, RlaError>;
+
+    async fn find_interpretations(&self, audit_id: String) -> Result<Vec<Interpretation>, RlaError>;
+}
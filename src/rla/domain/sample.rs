@@ -0,0 +1,73 @@
+use crate::rla::domain::entities::{FrameStation, SampledBallot};
+use sha2::{Digest, Sha256};
+
+/// The `n`th draw (1-based) from a frame of `ballots` ballots, as a 0-based index: SHA-256 of
+/// `"{seed},{n}"` read as a big-endian integer, modulo `ballots`. It depends on nothing but the
+/// published seed and the station counts, so anyone can redraw the sample.
+pub fn draw_index(seed: &str, n: i64, ballots: i64) -> i64 {
+    let digest = Sha256::digest(format!("{},{}", seed, n).as_bytes());
+    let modulus = ballots as u128;
+    let index = digest.iter().fold(0u128, |acc, b| (acc * 256 + *b as u128) % modulus);
+    index as i64
+}
+
+/// The first `size` draws, with replacement, each mapped to a station and a 1-based place in
+/// its box. The frame lays the stations' boxes end to end in the order given.
+pub fn draw_sample(seed: &str, stations: &[FrameStation], size: i64) -> Vec<SampledBallot> {
+    let ballots: i64 = stations.iter().map(|s| s.ballots).sum();
+    if ballots <= 0 {
+        return vec![];
+    }
+
+    (1..=size)
+        .filter_map(|n| {
+            let mut index = draw_index(seed, n, ballots);
+            stations.iter().find_map(|s| {
+                if index < s.ballots {
+                    return Some(SampledBallot {
+                        draw: n,
+                        station_id: s.station_id.clone(),
+                        station_name: s.name.clone(),
+                        position: index + 1,
+                        interpretation: None,
+                    });
+                }
+                index -= s.ballots;
+                None
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn station(id: &str, ballots: i64) -> FrameStation {
+        FrameStation { station_id: id.to_string(), name: format!("Station {}", id), ballots }
+    }
+
+    #[test]
+    fn test_sample_is_reproducible_from_the_seed_and_stays_in_the_frame() {
+        let stations = vec![station("a", 3), station("b", 0), station("c", 5)];
+
+        let sample = draw_sample("0451 2209 7713", &stations, 50);
+        let again = draw_sample("0451 2209 7713", &stations, 50);
+        let other = draw_sample("0451 2209 7714", &stations, 50);
+
+        assert_eq!(sample.len(), 50);
+        assert_eq!(sample, again);
+        assert_ne!(sample, other);
+        assert!(sample.iter().all(|b| match b.station_id.as_str() {
+            "a" => (1..=3).contains(&b.position),
+            "c" => (1..=5).contains(&b.position),
+            _ => false,
+        }));
+        assert_eq!(sample.iter().map(|b| b.draw).collect::<Vec<_>>(), (1..=50).collect::<Vec<_>>());
+        // The same as `int(sha256("0451 2209 7713,1").hexdigest(), 16) % 8` and so on.
+        let first: Vec<_> = sample[..3].iter().map(|b| (b.station_id.as_str(), b.position)).collect();
+        assert_eq!(first, vec![("c", 1), ("c", 1), ("a", 3)]);
+        // A larger sample only adds draws.
+        assert_eq!(draw_sample("0451 2209 7713", &stations, 20)[..], sample[..20]);
+    }
+}
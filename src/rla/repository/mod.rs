@@ -0,0 +1,4 @@
+mod postgres;
+mod model;
+
+pub use postgres::PostgresRepo;
@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct Station {
+    pub id: Uuid,
+    pub name: String,
+    pub merged: bool,
+    pub ballots: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct CandidateVotes {
+    pub candidate_id: Uuid,
+    pub votes: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct Audit {
+    pub id: Uuid,
+    pub election_id: Uuid,
+    pub contest_id: Uuid,
+    pub risk_limit: f64,
+    pub seed: String,
+    pub sample_size: i64,
+    pub reported: String,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct Interpretation {
+    pub station_id: Uuid,
+    pub position: i64,
+    pub mark: String,
+    pub candidate_id: Option<Uuid>,
+    pub interpreted_by: String,
+    pub interpreted_at: DateTime<Utc>,
+}
@@ -0,0 +1,239 @@
+use crate::rla::domain;
+use crate::rla::domain::RlaError;
+use crate::rla::domain::Repository;
+use crate::infrastructure::database::postgres::Postgres;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::rla::repository::model::{Audit, CandidateVotes, Interpretation, Station};
+
+const AUDIT_COLUMNS: &str = "id, election_id, contest_id, risk_limit, seed, sample_size, reported, created_by, created_at";
+
+pub struct PostgresRepo {
+    postgres: sqlx::PgPool,
+}
+impl PostgresRepo {
+    pub async fn new(postgres: Arc<Mutex<Postgres>>) -> anyhow::Result<Self> {
+        let guard = postgres.lock().await;
+        let pool = guard
+            .pool()
+            .ok_or_else(|| anyhow!("DB pool is not initialized"))?;
+        Ok(PostgresRepo { postgres: pool })
+    }
+
+    fn transform_audit(&self, a: Audit) -> Result<domain::Audit, RlaError> {
+        let reported: domain::ReportedOutcome = serde_json::from_str(&a.reported)
+            .map_err(|e| RlaError::UnknownError(format!("audit {} has an unreadable outcome: {}", a.id, e)))?;
+        Ok(domain::Audit {
+            id: a.id.to_string(),
+            election_id: a.election_id.to_string(),
+            contest_id: a.contest_id.to_string(),
+            risk_limit: a.risk_limit,
+            seed: a.seed,
+            sample_size: a.sample_size,
+            reported,
+            created_by: a.created_by,
+            created_at: a.created_at,
+        })
+    }
+
+    fn transform_interpretation(&self, i: Interpretation) -> Result<domain::Interpretation, RlaError> {
+        let mark = domain::Mark::parse(&i.mark)
+            .ok_or_else(|| RlaError::UnknownError(format!("unknown mark {}", i.mark)))?;
+        Ok(domain::Interpretation {
+            station_id: i.station_id.to_string(),
+            position: i.position,
+            mark,
+            candidate_id: i.candidate_id.map(|id| id.to_string()),
+            interpreted_by: i.interpreted_by,
+            interpreted_at: i.interpreted_at,
+        })
+    }
+
+    fn parse_id(id: &str) -> Result<Uuid, RlaError> {
+        Uuid::parse_str(id).map_err(|_| RlaError::NotFound(format!("{} not found", id)))
+    }
+}
+
+#[async_trait]
+impl Repository for PostgresRepo {
+    async fn find_paper_contest(&self, election_id: String, contest_id: String) -> Result<domain::PaperContest, RlaError> {
+        let election_uuid = Self::parse_id(&election_id)?;
+        let contest_uuid = Self::parse_id(&contest_id)?;
+
+        let exists: Option<Uuid> = sqlx::query_scalar(r#"SELECT id FROM contests WHERE id = $1 AND election_id = $2"#)
+            .bind(contest_uuid)
+            .bind(election_uuid)
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| RlaError::UnknownError(e.to_string()))?;
+        if exists.is_none() {
+            return Err(RlaError::NotFound(format!("contest {} not found in election {}", contest_id, election_id)));
+        }
+
+        // Both entries of a merged station agree, so the first stands for the station.
+        let stations = sqlx::query_as::<_, Station>(
+            r#"
+        SELECT s.id, s.name, s.merged_at IS NOT NULL AS merged, COALESCE(e.ballots, 0) AS ballots
+        FROM polling_stations s
+        LEFT JOIN paper_entries e ON e.station_id = s.id AND e.slot = 1
+        WHERE s.election_id = $1
+        ORDER BY s.name
+        "#,
+        )
+            .bind(election_uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| RlaError::UnknownError(e.to_string()))?;
+
+        let votes = sqlx::query_as::<_, CandidateVotes>(
+            r#"
+        SELECT k.id AS candidate_id, COALESCE(SUM(pc.votes), 0)::BIGINT AS votes
+        FROM candidates k
+        LEFT JOIN paper_entry_counts pc ON pc.candidate_id = k.id AND pc.contest_id = k.contest_id AND pc.slot = 1
+            AND EXISTS (SELECT 1 FROM polling_stations s WHERE s.id = pc.station_id AND s.merged_at IS NOT NULL)
+        WHERE k.contest_id = $1 AND k.status
+        GROUP BY k.id, k.vote_number
+        ORDER BY k.vote_number
+        "#,
+        )
+            .bind(contest_uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| RlaError::UnknownError(e.to_string()))?;
+
+        let (merged, unmerged): (Vec<_>, Vec<_>) = stations.into_iter().partition(|s| s.merged);
+        Ok(domain::PaperContest {
+            contest_id,
+            stations: merged
+                .into_iter()
+                .map(|s| domain::FrameStation { station_id: s.id.to_string(), name: s.name, ballots: s.ballots })
+                .collect(),
+            unmerged: unmerged.into_iter().map(|s| s.name).collect(),
+            votes: votes
+                .into_iter()
+                .map(|v| domain::CandidateVotes { candidate_id: v.candidate_id.to_string(), votes: v.votes })
+                .collect(),
+        })
+    }
+
+    async fn create_audit(&self, audit: domain::NewAudit) -> Result<domain::Audit, RlaError> {
+        let election_id = Self::parse_id(&audit.election_id)?;
+        let contest_id = Self::parse_id(&audit.contest_id)?;
+        let reported = serde_json::to_string(&audit.reported).map_err(|e| RlaError::UnknownError(e.to_string()))?;
+
+        let sql = format!(
+            r#"
+            INSERT INTO paper_audits (election_id, contest_id, risk_limit, seed, sample_size, reported, created_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING {}
+            "#,
+            AUDIT_COLUMNS
+        );
+        let created = sqlx::query_as::<_, Audit>(&sql)
+            .bind(election_id)
+            .bind(contest_id)
+            .bind(audit.risk_limit)
+            .bind(&audit.seed)
+            .bind(audit.sample_size)
+            .bind(reported)
+            .bind(&audit.created_by)
+            .fetch_one(&self.postgres)
+            .await
+            .map_err(|e| RlaError::UnknownError(e.to_string()))?;
+
+        self.transform_audit(created)
+    }
+
+    async fn find_audit(&self, id: String) -> Result<domain::Audit, RlaError> {
+        let uuid = Self::parse_id(&id)?;
+        let sql = format!("SELECT {} FROM paper_audits WHERE id = $1", AUDIT_COLUMNS);
+
+        let audit = sqlx::query_as::<_, Audit>(&sql)
+            .bind(uuid)
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| RlaError::UnknownError(e.to_string()))?
+            .ok_or_else(|| RlaError::NotFound(format!("audit {} not found", id)))?;
+
+        self.transform_audit(audit)
+    }
+
+    async fn grow_sample(&self, id: String, sample_size: i64) -> Result<domain::Audit, RlaError> {
+        let uuid = Self::parse_id(&id)?;
+        // The size check sits in the statement, so two escalations cannot shrink the sample.
+        let sql = format!(
+            "UPDATE paper_audits SET sample_size = $2 WHERE id = $1 AND sample_size < $2 RETURNING {}",
+            AUDIT_COLUMNS
+        );
+
+        let grown = sqlx::query_as::<_, Audit>(&sql)
+            .bind(uuid)
+            .bind(sample_size)
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| RlaError::UnknownError(e.to_string()))?;
+
+        match grown {
+            Some(audit) => self.transform_audit(audit),
+            None => {
+                let current = self.find_audit(id).await?;
+                Err(RlaError::InvalidInput(format!(
+                    "sample_size must be larger than the current {}", current.sample_size
+                )))
+            }
+        }
+    }
+
+    async fn save_interpretation(&self, interpretation: domain::NewInterpretation) -> Result<domain::Interpretation, RlaError> {
+        let audit_id = Self::parse_id(&interpretation.audit_id)?;
+        let station_id = Self::parse_id(&interpretation.station_id)?;
+        let candidate_id = interpretation.candidate_id.as_deref().map(Self::parse_id).transpose()?;
+
+        let saved = sqlx::query_as::<_, Interpretation>(
+            r#"
+            INSERT INTO paper_audit_interpretations (audit_id, station_id, position, mark, candidate_id, interpreted_by)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING station_id, position, mark, candidate_id, interpreted_by, interpreted_at
+            "#,
+        )
+            .bind(audit_id)
+            .bind(station_id)
+            .bind(interpretation.position)
+            .bind(interpretation.mark.as_str())
+            .bind(candidate_id)
+            .bind(&interpretation.interpreted_by)
+            .fetch_one(&self.postgres)
+            .await
+            .map_err(|e| match e {
+                sqlx::Error::Database(db) if db.is_unique_violation() => RlaError::AlreadyInterpreted(format!(
+                    "ballot {} of station {} is already interpreted",
+                    interpretation.position, interpretation.station_id
+                )),
+                _ => RlaError::UnknownError(e.to_string()),
+            })?;
+
+        self.transform_interpretation(saved)
+    }
+
+    async fn find_interpretations(&self, audit_id: String) -> Result<Vec<domain::Interpretation>, RlaError> {
+        let uuid = Self::parse_id(&audit_id)?;
+
+        let interpretations = sqlx::query_as::<_, Interpretation>(
+            r#"
+            SELECT station_id, position, mark, candidate_id, interpreted_by, interpreted_at
+            FROM paper_audit_interpretations
+            WHERE audit_id = $1
+            ORDER BY interpreted_at
+            "#,
+        )
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| RlaError::UnknownError(e.to_string()))?;
+
+        interpretations.into_iter().map(|i| self.transform_interpretation(i)).collect()
+    }
+}
@@ -0,0 +1,76 @@
+use crate::embargo::domain::ResultsAction;
+use crate::embargo::usecase::authorize;
+use crate::rla::domain::{self, AuditView, Repository, RlaError, MAX_SAMPLE_SIZE};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<AuditView, RlaError>;
+}
+
+/// Grows an audit's sample. The draws it already has stay as they are, along with their
+/// interpretations; the new ones continue the same sequence from the seed.
+pub struct EscalateUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    gate: Arc<dyn authorize::Interactor>,
+}
+
+pub struct Request {
+    pub audit_id: String,
+    pub sample_size: i64,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("audit_id", &self.audit_id)
+            .field("sample_size", &self.sample_size)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> EscalateUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, gate: Arc<dyn authorize::Interactor>) -> Self {
+        Self { repository, gate }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for EscalateUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<AuditView, RlaError> {
+        if req.sample_size > MAX_SAMPLE_SIZE {
+            return Err(RlaError::InvalidInput(format!(
+                "sample_size may not exceed {}; count the contest by hand instead", MAX_SAMPLE_SIZE
+            )));
+        }
+
+        let audit = self.repository.find_audit(req.audit_id.clone()).await?;
+        self.gate.handle(authorize::Request {
+            election_id: audit.election_id.clone(),
+            staff_token: req.staff_token,
+            action: ResultsAction::Compute,
+        }).await?;
+        if req.sample_size <= audit.sample_size {
+            return Err(RlaError::InvalidInput(format!(
+                "sample_size must be larger than the current {}", audit.sample_size
+            )));
+        }
+
+        let audit = self.repository.grow_sample(req.audit_id.clone(), req.sample_size).await?;
+        let interpretations = self.repository.find_interpretations(req.audit_id).await?;
+        Ok(domain::audit_view(audit, interpretations))
+    }
+}
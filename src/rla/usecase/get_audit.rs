@@ -0,0 +1,62 @@
+use crate::embargo::domain::ResultsAction;
+use crate::embargo::usecase::authorize;
+use crate::rla::domain::{self, AuditView, Repository, RlaError};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<AuditView, RlaError>;
+}
+
+/// An audit's sample and where it stands. The audit reports the paper outcome, so reading it
+/// goes through the results embargo like any other look at the results.
+pub struct GetAuditUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    gate: Arc<dyn authorize::Interactor>,
+}
+
+pub struct Request {
+    pub audit_id: String,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("audit_id", &self.audit_id)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> GetAuditUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, gate: Arc<dyn authorize::Interactor>) -> Self {
+        Self { repository, gate }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for GetAuditUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<AuditView, RlaError> {
+        let audit = self.repository.find_audit(req.audit_id.clone()).await?;
+        self.gate.handle(authorize::Request {
+            election_id: audit.election_id.clone(),
+            staff_token: req.staff_token,
+            action: ResultsAction::Read,
+        }).await?;
+
+        let interpretations = self.repository.find_interpretations(req.audit_id).await?;
+        Ok(domain::audit_view(audit, interpretations))
+    }
+}
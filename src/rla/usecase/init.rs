@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use crate::embargo::usecase::authorize;
+use crate::rla::domain::Repository;
+use crate::rla::usecase::{escalate, get_audit, interpret, start_audit};
+use crate::rla::usecase::escalate::EscalateUseCase;
+use crate::rla::usecase::get_audit::GetAuditUseCase;
+use crate::rla::usecase::interpret::InterpretUseCase;
+use crate::rla::usecase::start_audit::StartAuditUseCase;
+
+
+#[derive(Clone)]
+pub struct UseCase
+{
+    pub start_audit: Arc<dyn start_audit::Interactor>,
+    pub get_audit: Arc<dyn get_audit::Interactor>,
+    pub interpret: Arc<dyn interpret::Interactor>,
+    pub escalate: Arc<dyn escalate::Interactor>,
+}
+
+impl UseCase {
+
+    pub fn new(
+        rla_repo: Arc<dyn Repository + Send + Sync>,
+        gate: Arc<dyn authorize::Interactor>,
+    ) -> Self {
+
+        Self {
+            start_audit: Arc::new(StartAuditUseCase::new(rla_repo.clone(), gate.clone())),
+            get_audit: Arc::new(GetAuditUseCase::new(rla_repo.clone(), gate.clone())),
+            interpret: Arc::new(InterpretUseCase::new(rla_repo.clone(), gate.clone())),
+            escalate: Arc::new(EscalateUseCase::new(rla_repo, gate)),
+        }
+    }
+
+}
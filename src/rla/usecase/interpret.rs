@@ -0,0 +1,185 @@
+use crate::embargo::domain::ResultsAction;
+use crate::embargo::usecase::authorize;
+use crate::rla::domain::{self, AuditView, Mark, NewInterpretation, Repository, RlaError};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<AuditView, RlaError>;
+}
+
+/// Records how the committee read a pulled ballot for the audited contest. Only ballots in
+/// the sample are taken, and each once.
+pub struct InterpretUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    gate: Arc<dyn authorize::Interactor>,
+}
+
+pub struct Request {
+    pub audit_id: String,
+    pub station_id: String,
+    pub position: i64,
+    pub mark: String,
+    pub candidate_id: Option<String>,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("audit_id", &self.audit_id)
+            .field("station_id", &self.station_id)
+            .field("position", &self.position)
+            .field("mark", &self.mark)
+            .field("candidate_id", &self.candidate_id)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> InterpretUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, gate: Arc<dyn authorize::Interactor>) -> Self {
+        Self { repository, gate }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for InterpretUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<AuditView, RlaError> {
+        let mark = Mark::parse(&req.mark).ok_or_else(|| {
+            RlaError::InvalidInput("mark must be one of candidate, abstain, blank, invalid".into())
+        })?;
+
+        let audit = self.repository.find_audit(req.audit_id.clone()).await?;
+        let access = self.gate.handle(authorize::Request {
+            election_id: audit.election_id.clone(),
+            staff_token: req.staff_token,
+            action: ResultsAction::Compute,
+        }).await?;
+        let interpreted_by = access.caller
+            .map(|m| m.name)
+            .ok_or_else(|| RlaError::Unauthorized("auditing needs a staff token".into()))?;
+
+        match (mark, &req.candidate_id) {
+            (Mark::Candidate, Some(candidate)) => {
+                if !audit.reported.votes.iter().any(|c| &c.candidate_id == candidate) {
+                    return Err(RlaError::InvalidInput(format!(
+                        "candidate {} does not stand in contest {}", candidate, audit.contest_id
+                    )));
+                }
+            }
+            (Mark::Candidate, None) => {
+                return Err(RlaError::InvalidInput("a candidate mark needs candidate_id".into()));
+            }
+            (_, Some(_)) => {
+                return Err(RlaError::InvalidInput(format!("a {} mark takes no candidate_id", mark.as_str())));
+            }
+            (_, None) => {}
+        }
+
+        let sampled = domain::draw_sample(&audit.seed, &audit.reported.stations, audit.sample_size)
+            .iter()
+            .any(|b| b.station_id == req.station_id && b.position == req.position);
+        if !sampled {
+            return Err(RlaError::InvalidInput(format!(
+                "ballot {} of station {} is not in the sample", req.position, req.station_id
+            )));
+        }
+
+        self.repository.save_interpretation(NewInterpretation {
+            audit_id: req.audit_id.clone(),
+            station_id: req.station_id,
+            position: req.position,
+            mark,
+            candidate_id: req.candidate_id,
+            interpreted_by,
+        }).await?;
+
+        let interpretations = self.repository.find_interpretations(req.audit_id).await?;
+        Ok(domain::audit_view(audit, interpretations))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::election::domain::ElectionPhase;
+    use crate::embargo::domain::{Access, EmbargoError, Role, StaffMember};
+    use crate::embargo::usecase::authorize;
+    use crate::rla::domain::{self, Audit, CandidateVotes, FrameStation, ReportedOutcome, RlaError};
+    use crate::rla::usecase::interpret;
+    use crate::rla::usecase::interpret::Interactor;
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use std::sync::Arc;
+
+    struct CommitteeGate;
+
+    #[async_trait]
+    impl authorize::Interactor for CommitteeGate {
+        async fn handle(&self, _: authorize::Request) -> Result<Access, EmbargoError> {
+            Ok(Access {
+                phase: ElectionPhase::Closed,
+                caller: Some(StaffMember { name: "rina".to_string(), role: Role::Committee }),
+                early: false,
+            })
+        }
+    }
+
+    fn audit() -> Audit {
+        Audit {
+            id: "a1".to_string(),
+            election_id: "e1".to_string(),
+            contest_id: "k1".to_string(),
+            risk_limit: 0.1,
+            seed: "0451 2209".to_string(),
+            sample_size: 3,
+            reported: ReportedOutcome {
+                ballots: 1000,
+                stations: vec![FrameStation { station_id: "s1".to_string(), name: "Hall".to_string(), ballots: 1000 }],
+                votes: vec![
+                    CandidateVotes { candidate_id: "w".to_string(), votes: 700 },
+                    CandidateVotes { candidate_id: "l".to_string(), votes: 300 },
+                ],
+                winner: "w".to_string(),
+            },
+            created_by: "rina".to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_interpret_refuses_a_ballot_outside_the_sample() {
+        let sampled: Vec<i64> = domain::draw_sample("0451 2209", &audit().reported.stations, 3)
+            .into_iter()
+            .map(|b| b.position)
+            .collect();
+        let outside = (1..=1000).find(|p| !sampled.contains(p)).unwrap();
+
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_audit().returning(|_| Ok(audit()));
+        repo_mock.expect_save_interpretation().times(0);
+
+        let uc = interpret::InterpretUseCase::new(Arc::new(repo_mock), Arc::new(CommitteeGate));
+        let result = uc.handle(interpret::Request {
+            audit_id: "a1".to_string(),
+            station_id: "s1".to_string(),
+            position: outside,
+            mark: "candidate".to_string(),
+            candidate_id: Some("w".to_string()),
+            staff_token: Some("t".to_string()),
+        }).await;
+
+        assert!(matches!(result, Err(RlaError::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,7 @@
+mod init;
+pub mod escalate;
+pub mod get_audit;
+pub mod interpret;
+pub mod start_audit;
+
+pub use init::UseCase;
@@ -0,0 +1,183 @@
+use crate::embargo::domain::ResultsAction;
+use crate::embargo::usecase::authorize;
+use crate::rla::domain::{self, AuditView, NewAudit, Repository, RlaError, MAX_SAMPLE_SIZE};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<AuditView, RlaError>;
+}
+
+/// Fixes a paper contest's reported outcome and draws the first sample from the seed. Without
+/// a sample size, BRAVO's expected number of draws at the risk limit is used.
+pub struct StartAuditUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    gate: Arc<dyn authorize::Interactor>,
+}
+
+pub struct Request {
+    pub election_id: String,
+    pub contest_id: String,
+    pub risk_limit: f64,
+    pub seed: String,
+    pub sample_size: Option<i64>,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("election_id", &self.election_id)
+            .field("contest_id", &self.contest_id)
+            .field("risk_limit", &self.risk_limit)
+            .field("seed", &self.seed)
+            .field("sample_size", &self.sample_size)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> StartAuditUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, gate: Arc<dyn authorize::Interactor>) -> Self {
+        Self { repository, gate }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for StartAuditUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<AuditView, RlaError> {
+        if !(req.risk_limit > 0.0 && req.risk_limit < 1.0) {
+            return Err(RlaError::InvalidInput("risk_limit must be between 0 and 1".into()));
+        }
+        let seed = req.seed.trim().to_string();
+        if seed.is_empty() {
+            return Err(RlaError::InvalidInput("seed is required".into()));
+        }
+        if req.sample_size.is_some_and(|size| !(1..=MAX_SAMPLE_SIZE).contains(&size)) {
+            return Err(RlaError::InvalidInput(format!("sample_size must be between 1 and {}", MAX_SAMPLE_SIZE)));
+        }
+
+        let access = self.gate.handle(authorize::Request {
+            election_id: req.election_id.clone(),
+            staff_token: req.staff_token,
+            action: ResultsAction::Compute,
+        }).await?;
+        let created_by = access.caller
+            .map(|m| m.name)
+            .ok_or_else(|| RlaError::Unauthorized("auditing needs a staff token".into()))?;
+
+        let contest = self.repository.find_paper_contest(req.election_id.clone(), req.contest_id.clone()).await?;
+        let reported = domain::reported_outcome(&contest)?;
+        let sample_size = req.sample_size.unwrap_or_else(|| domain::initial_sample_size(&reported, req.risk_limit));
+
+        let audit = self.repository.create_audit(NewAudit {
+            election_id: req.election_id,
+            contest_id: req.contest_id,
+            risk_limit: req.risk_limit,
+            seed,
+            sample_size,
+            reported,
+            created_by,
+        }).await?;
+
+        Ok(domain::audit_view(audit, vec![]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::election::domain::ElectionPhase;
+    use crate::embargo::domain::{Access, EmbargoError, Role, StaffMember};
+    use crate::embargo::usecase::authorize;
+    use crate::rla::domain::{self, Audit, AuditStatus, CandidateVotes, FrameStation, PaperContest, RlaError};
+    use crate::rla::usecase::start_audit;
+    use crate::rla::usecase::start_audit::Interactor;
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use std::sync::Arc;
+
+    struct CommitteeGate;
+
+    #[async_trait]
+    impl authorize::Interactor for CommitteeGate {
+        async fn handle(&self, _: authorize::Request) -> Result<Access, EmbargoError> {
+            Ok(Access {
+                phase: ElectionPhase::Closed,
+                caller: Some(StaffMember { name: "rina".to_string(), role: Role::Committee }),
+                early: false,
+            })
+        }
+    }
+
+    fn contest(w: i64, l: i64) -> PaperContest {
+        PaperContest {
+            contest_id: "k1".to_string(),
+            stations: vec![FrameStation { station_id: "s1".to_string(), name: "Hall".to_string(), ballots: w + l }],
+            unmerged: vec![],
+            votes: vec![
+                CandidateVotes { candidate_id: "w".to_string(), votes: w },
+                CandidateVotes { candidate_id: "l".to_string(), votes: l },
+            ],
+        }
+    }
+
+    fn request() -> start_audit::Request {
+        start_audit::Request {
+            election_id: "e1".to_string(),
+            contest_id: "k1".to_string(),
+            risk_limit: 0.1,
+            seed: " 0451 2209 ".to_string(),
+            sample_size: None,
+            staff_token: Some("t".to_string()),
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_start_audit_draws_the_expected_sample_size() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_paper_contest().returning(|_, _| Ok(contest(70, 30)));
+        repo_mock.expect_create_audit()
+            .times(1)
+            .withf(|a| a.sample_size == 31 && a.seed == "0451 2209" && a.created_by == "rina" && a.reported.winner == "w")
+            .returning(|a| Ok(Audit {
+                id: "a1".to_string(),
+                election_id: a.election_id,
+                contest_id: a.contest_id,
+                risk_limit: a.risk_limit,
+                seed: a.seed,
+                sample_size: a.sample_size,
+                reported: a.reported,
+                created_by: a.created_by,
+                created_at: Utc::now(),
+            }));
+
+        let uc = start_audit::StartAuditUseCase::new(Arc::new(repo_mock), Arc::new(CommitteeGate));
+        let view = uc.handle(request()).await.unwrap();
+
+        assert_eq!(view.sample.len(), 31);
+        assert_eq!(view.report.status, AuditStatus::AwaitingInterpretations);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_start_audit_rejects_a_tied_contest() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_paper_contest().returning(|_, _| Ok(contest(50, 50)));
+        repo_mock.expect_create_audit().times(0);
+
+        let uc = start_audit::StartAuditUseCase::new(Arc::new(repo_mock), Arc::new(CommitteeGate));
+        let result = uc.handle(request()).await;
+
+        assert!(matches!(result, Err(RlaError::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,78 @@
+use crate::embargo::delivery::http::staff_token;
+use crate::tally::delivery::http::errors::error_response;
+use crate::tally::usecase::{certify, public_keys};
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+
+/// The certificate is served bare rather than in the response envelope: the file downloaded
+/// is the file `verify_results` checks.
+pub async fn get_certificate(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+
+    let election_id = path.into_inner();
+    let request = certify::Request {
+        election_id: election_id.clone(),
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.tally_uc.certify.handle(request).await {
+        Ok(certificate) => HttpResponse::Ok()
+            .insert_header((
+                "Content-Disposition",
+                format!("attachment; filename=\"results-{}.certificate.json\"", election_id),
+            ))
+            .insert_header(("Cache-Control", "no-store"))
+            .json(certificate),
+        Err(e) => error_response(&e),
+    }
+}
+
+pub async fn list_certificate_keys(handler: web::Data<app::AppHandlerData>) -> HttpResponse {
+
+    let request = public_keys::Request;
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.tally_uc.public_keys.handle(request).await {
+        Ok(keys) => HttpResponse::Ok().json(response::success(
+            Some(keys),
+            "Successfully processed certificate keys".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::list_certificate_keys;
+    use actix_web::{App, http::StatusCode, test, web};
+    use crate::utils::app;
+
+    #[actix_rt::test]
+    async fn test_certificate_keys_list_the_public_half_only() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(app::AppHandlerData::mocked()))
+                .route("/certificate-keys", web::get().to(list_certificate_keys)),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/certificate-keys").to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        let key = &body["data"][0];
+        assert_eq!(key["key_id"], "c1");
+        assert_eq!(key["algorithm"], "ed25519");
+        assert_eq!(key["active"], true);
+        // 32 bytes in unpadded base64url, and not the configured seed.
+        assert_eq!(key["public_key"].as_str().unwrap().len(), 43);
+        assert!(!body.to_string().contains("HyAhIiMkJSYnKCkqKywtLi8wMTIzNDU2Nzg5Ojs8PT4"));
+    }
+}
@@ -0,0 +1,73 @@
+use crate::tally::delivery::http::errors::error_response;
+use crate::tally::usecase::compute::*;
+use crate::embargo::delivery::http::staff_token;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+
+pub async fn compute_tally(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+
+    let request = Request {
+        election_id: path.into_inner(),
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.tally_uc.compute.handle(request).await {
+        Ok(snapshot) => HttpResponse::Created().json(response::success(
+            Some(snapshot),
+            "Successfully processed tally".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compute_tally;
+    use actix_web::{App, http::StatusCode, test, web};
+    use async_trait::async_trait;
+    use std::sync::Arc;
+    use crate::tally::domain::{Snapshot, TallyError};
+    use crate::tally::usecase::compute::{Interactor, Request};
+    use crate::utils::app;
+
+    #[actix_rt::test]
+    async fn test_compute_tally_refuses_an_embargoed_election() {
+        struct MockCompute;
+
+        #[async_trait]
+        impl Interactor for MockCompute {
+            async fn handle(&self, req: Request) -> Result<Snapshot, TallyError> {
+                assert_eq!(req.election_id, "e1");
+                assert_eq!(req.staff_token.as_deref(), Some("rina-token"));
+                Err(TallyError::Embargoed("voting has not closed; election is in phase voting".into()))
+            }
+        }
+
+        let mut tally_uc = app::AppHandlerData::mocked().tally_uc;
+        tally_uc.compute = Arc::new(MockCompute);
+        let app_data = web::Data::new(app::AppHandlerData { tally_uc, ..app::AppHandlerData::mocked() });
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_data)
+                .route("/elections/{id}/tally", web::post().to(compute_tally)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/elections/e1/tally")
+            .insert_header(("Authorization", "Bearer rina-token"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["error_code"], "RESULTS_EMBARGOED");
+    }
+}
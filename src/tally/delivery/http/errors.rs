@@ -0,0 +1,44 @@
+use crate::tally::domain::TallyError;
+use crate::utils::response;
+use actix_web::HttpResponse;
+
+pub fn error_response(e: &TallyError) -> HttpResponse {
+    println!("Error: {}", e);
+    match e {
+        TallyError::NotFound(msg) => HttpResponse::NotFound().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "TALLY_NOT_FOUND".into(),
+        )),
+        TallyError::Unauthorized(msg) => HttpResponse::Unauthorized().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "TALLY_UNAUTHORIZED".into(),
+        )),
+        TallyError::Forbidden(msg) => HttpResponse::Forbidden().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "TALLY_FORBIDDEN".into(),
+        )),
+        TallyError::Embargoed(msg) => HttpResponse::Forbidden().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "RESULTS_EMBARGOED".into(),
+        )),
+        TallyError::Inconsistent(msg) => HttpResponse::InternalServerError().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "TALLY_INCONSISTENT".into(),
+        )),
+        TallyError::SignatureMismatch(msg) => HttpResponse::InternalServerError().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "TALLY_SIGNATURE_MISMATCH".into(),
+        )),
+        TallyError::UnknownError(_) => HttpResponse::InternalServerError().json(response::error::<()>(
+            None,
+            "failed process data".into(),
+            "-1".into(),
+        )),
+    }
+}
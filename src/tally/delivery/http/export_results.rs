@@ -0,0 +1,375 @@
+use crate::embargo::delivery::http::staff_token;
+use crate::tally::delivery::http::errors::error_response;
+use crate::tally::domain::{ContestResult, ContestVerdict, ResultsExport, Verdict};
+use crate::tally::usecase::export::*;
+use crate::utils::html::escape;
+use crate::utils::{app, csv};
+use actix_web::{HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    #[default]
+    Json,
+    Csv,
+    Html,
+}
+
+#[derive(Deserialize)]
+pub struct ExportResultsQuery {
+    #[serde(default)]
+    format: ExportFormat,
+}
+
+
+const HTML_STYLE: &str = "\
+body{font-family:Georgia,serif;margin:2rem;color:#111}\
+h1{margin-bottom:.2rem}\
+.status{text-transform:uppercase;letter-spacing:.1em;font-weight:bold}\
+table{border-collapse:collapse;width:100%;margin:1rem 0}\
+th,td{border:1px solid #999;padding:.35rem .5rem;text-align:left;vertical-align:middle}\
+td.n{text-align:right;font-variant-numeric:tabular-nums}\
+img{width:56px;height:56px;object-fit:cover}\
+section{page-break-inside:avoid}\
+footer{font-size:.75rem;word-break:break-all;color:#444}\
+@media print{body{margin:0}a{color:inherit;text-decoration:none}}";
+
+fn share(votes: i64, total: i64) -> String {
+    if total == 0 {
+        return "0.00".to_string();
+    }
+    format!("{:.2}", votes as f64 * 100.0 / total as f64)
+}
+
+/// What a candidate's row says about the contest's outcome; empty for everyone else.
+fn candidate_verdict(contest: &ContestResult, verdict: Option<&ContestVerdict>, candidate_id: &str) -> &'static str {
+    match verdict {
+        Some(v) if v.winner.as_deref() == Some(candidate_id) => v.verdict.as_str(),
+        Some(v) if v.verdict == Verdict::RunoffRequired
+            && contest.tie_break.as_ref().is_some_and(|t| t.tied.iter().any(|id| id == candidate_id)) => "runoff",
+        _ => "",
+    }
+}
+
+/// The origin of a photo the report may load: an `https` address whose host is plain letters,
+/// digits, dots, hyphens and an optional port. Anything else is left out of the report.
+fn photo_origin(url: &str) -> Option<&str> {
+    let rest = url.strip_prefix("https://")?;
+    let host = &rest[..rest.find(['/', '?', '#']).unwrap_or(rest.len())];
+    let plain = |c: char| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | ':');
+    if host.is_empty() || !host.chars().all(plain) {
+        return None;
+    }
+    Some(&url[.."https://".len() + host.len()])
+}
+
+fn photo(url: &str, alt: &str) -> String {
+    match photo_origin(url) {
+        Some(_) => format!(r#"<img src="{}" alt="{}">"#, escape(url), escape(alt)),
+        None => String::new(),
+    }
+}
+
+/// The report's styles are inline and it loads nothing but the candidates' photos, so images
+/// are allowed from exactly the hosts those photos are on, and from nowhere when there are none.
+pub fn html_policy(export: &ResultsExport) -> String {
+    let origins: BTreeSet<&str> = export
+        .snapshot
+        .tally
+        .contests
+        .iter()
+        .flat_map(|contest| &contest.candidates)
+        .flat_map(|c| [&c.candidate.president_photo, &c.candidate.vice_president_photo])
+        .filter_map(|url| photo_origin(url))
+        .collect();
+    let img_src = if origins.is_empty() {
+        "'none'".to_string()
+    } else {
+        origins.into_iter().collect::<Vec<_>>().join(" ")
+    };
+    format!("default-src 'none'; img-src {}; style-src 'unsafe-inline'", img_src)
+}
+
+/// One row per count: election totals first, then each contest's candidates and abstentions.
+pub fn results_csv(export: &ResultsExport) -> String {
+    let tally = &export.snapshot.tally;
+    let turnout = &export.turnout;
+    let mut out = String::new();
+    csv::write_row(&mut out, &[
+        "scope", "item", "vote_number", "president_name", "vice_president_name", "votes", "share_percent", "verdict",
+    ]);
+
+    for (item, value) in [
+        ("ballots", tally.ballots),
+        ("paper_ballots", tally.paper_ballots),
+        ("valid", export.valid),
+        ("blank", tally.blank),
+        ("invalid", tally.invalid),
+        ("roll_size", turnout.roll_size),
+        ("voted", turnout.voted),
+        ("paper_signed_in", turnout.paper_signed_in),
+    ] {
+        csv::write_row(&mut out, &["election", item, "", "", "", &value.to_string(), "", ""]);
+    }
+
+    for contest in &tally.contests {
+        let verdict = export.verdicts.iter().find(|v| v.contest_id == contest.contest_id);
+        let total: i64 = contest.candidates.iter().map(|c| c.votes).sum();
+        for c in &contest.candidates {
+            csv::write_row(&mut out, &[
+                contest.contest_name.as_str(),
+                "candidate",
+                &c.candidate.vote_number.to_string(),
+                &c.candidate.president_name,
+                &c.candidate.vice_president_name,
+                &c.votes.to_string(),
+                &share(c.votes, total),
+                candidate_verdict(contest, verdict, &c.candidate.id),
+            ]);
+        }
+        csv::write_row(&mut out, &[contest.contest_name.as_str(), "abstain", "", "", "", &contest.abstain.to_string(), "", ""]);
+    }
+
+    out
+}
+
+/// A printable results notice. Everything but the candidate photos is inline; the photos are
+/// linked by their stored `https` address and load from wherever they are hosted.
+pub fn results_html(export: &ResultsExport) -> String {
+    let tally = &export.snapshot.tally;
+    let turnout = &export.turnout;
+    let name = escape(&export.election_name);
+    let mut out = String::new();
+
+    let _ = write!(
+        out,
+        r#"<!DOCTYPE html><html lang="en"><head><meta charset="utf-8"><title>Results: {name}</title><style>{HTML_STYLE}</style></head><body>"#,
+    );
+    let _ = write!(
+        out,
+        r#"<header><h1>{name}</h1><p class="status">{} results</p></header>"#,
+        export.status.as_str(),
+    );
+
+    out.push_str("<section><h2>Turnout</h2><table><tbody>");
+    for (label, value) in [
+        ("Registered voters", turnout.roll_size),
+        ("Voted", turnout.voted),
+        ("Signed in at paper stations", turnout.paper_signed_in),
+        ("Ballots counted", tally.ballots),
+        ("Paper ballots", tally.paper_ballots),
+        ("Valid ballots", export.valid),
+        ("Blank ballots", tally.blank),
+        ("Invalid ballots", tally.invalid),
+    ] {
+        let _ = write!(out, r#"<tr><th>{label}</th><td class="n">{value}</td></tr>"#);
+    }
+    out.push_str("</tbody></table></section>");
+
+    for contest in &tally.contests {
+        let verdict = export.verdicts.iter().find(|v| v.contest_id == contest.contest_id);
+        let total: i64 = contest.candidates.iter().map(|c| c.votes).sum();
+        let _ = write!(
+            out,
+            r#"<section><h2>{}</h2><p>Verdict: <strong>{}</strong></p><table><thead><tr><th>No.</th><th colspan="2">Photos</th><th>Candidates</th><th>Votes</th><th>Share</th><th>Verdict</th></tr></thead><tbody>"#,
+            escape(&contest.contest_name),
+            verdict.map(|v| v.verdict.as_str()).unwrap_or(""),
+        );
+        for c in &contest.candidates {
+            let _ = write!(
+                out,
+                r#"<tr><td class="n">{}</td><td>{}</td><td>{}</td><td>{} &amp; {}</td><td class="n">{}</td><td class="n">{}%</td><td>{}</td></tr>"#,
+                c.candidate.vote_number,
+                photo(&c.candidate.president_photo, &c.candidate.president_name),
+                photo(&c.candidate.vice_president_photo, &c.candidate.vice_president_name),
+                escape(&c.candidate.president_name),
+                escape(&c.candidate.vice_president_name),
+                c.votes,
+                share(c.votes, total),
+                candidate_verdict(contest, verdict, &c.candidate.id),
+            );
+        }
+        let _ = write!(
+            out,
+            r#"<tr><td colspan="4">Abstained</td><td class="n">{}</td><td></td><td></td></tr></tbody></table></section>"#,
+            contest.abstain,
+        );
+    }
+
+    let snapshot = &export.snapshot;
+    let _ = write!(
+        out,
+        "<footer><p>Snapshot {} computed at {}.</p><p>Digest {}</p><p>Signed with key {}: {}</p></footer></body></html>",
+        escape(&snapshot.id),
+        snapshot.computed_at.to_rfc3339(),
+        escape(&snapshot.digest),
+        escape(&snapshot.key_id),
+        escape(&snapshot.signature),
+    );
+
+    out
+}
+
+pub async fn export_results(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+    q: web::Query<ExportResultsQuery>,
+) -> HttpResponse {
+
+    let election_id = path.into_inner();
+    let format = q.into_inner().format;
+    let request = Request {
+        election_id: election_id.clone(),
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?} as {:?}", request, format);
+
+    let export = match handler.tally_uc.export.handle(request).await {
+        Ok(export) => export,
+        Err(e) => return error_response(&e),
+    };
+
+    match format {
+        ExportFormat::Json => HttpResponse::Ok()
+            .insert_header((
+                "Content-Disposition",
+                format!("attachment; filename=\"results-{}.json\"", election_id),
+            ))
+            .insert_header(("Cache-Control", "no-store"))
+            .json(export),
+        ExportFormat::Csv => HttpResponse::Ok()
+            .content_type("text/csv; charset=utf-8")
+            .insert_header((
+                "Content-Disposition",
+                format!("attachment; filename=\"results-{}.csv\"", election_id),
+            ))
+            .insert_header(("Cache-Control", "no-store"))
+            .body(results_csv(&export)),
+        ExportFormat::Html => HttpResponse::Ok()
+            .content_type("text/html; charset=utf-8")
+            .insert_header(("Content-Security-Policy", html_policy(&export)))
+            .insert_header(("Cache-Control", "no-store"))
+            .body(results_html(&export)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::export_results;
+    use actix_web::{App, http::StatusCode, test, web};
+    use async_trait::async_trait;
+    use std::sync::Arc;
+    use crate::candidate::domain::Candidate;
+    use crate::election::domain::ElectionPhase;
+    use crate::tally::domain::{self, CandidateResult, ContestResult, ResultsExport, Snapshot, Tally, TallyError, Turnout};
+    use crate::tally::usecase::export::{Interactor, Request};
+    use crate::utils::app;
+
+    struct MockExport;
+
+    #[async_trait]
+    impl Interactor for MockExport {
+        async fn handle(&self, req: Request) -> Result<ResultsExport, TallyError> {
+            let candidate = |id: &str, number: i32, name: &str, votes: i64| CandidateResult {
+                candidate: Candidate {
+                    id: id.to_string(),
+                    vote_number: number,
+                    president_name: name.to_string(),
+                    vice_president_name: if id == "c1" { "Sari" } else { "=HYPERLINK(\"x\")" }.to_string(),
+                    president_nim: String::new(),
+                    vice_president_nim: String::new(),
+                    president_photo: format!("https://cdn.campus.ac.id/{}.jpg", id),
+                    vice_president_photo: if id == "c1" {
+                        "javascript:alert(1)".to_string()
+                    } else {
+                        "http://photos.campus.ac.id/sari.jpg".to_string()
+                    },
+                    status: true,
+                    created_by: "committee".to_string(),
+                    created_at: chrono::Utc::now(),
+                    updated_at: None,
+                },
+                votes,
+            };
+            let tally = Tally {
+                election_id: req.election_id.clone(),
+                ballots: 10,
+                paper_ballots: 2,
+                blank: 1,
+                invalid: 1,
+                contests: vec![ContestResult {
+                    contest_id: "president".to_string(),
+                    contest_name: "President".to_string(),
+                    candidates: vec![candidate("c1", 1, "Budi, Jr.", 6), candidate("c2", 2, "<Ani>", 2)],
+                    abstain: 0,
+                    tie_break: None,
+                }],
+                provisional: false,
+            };
+            Ok(domain::results_export(
+                Snapshot {
+                    id: "s1".to_string(),
+                    election_id: req.election_id.clone(),
+                    tally,
+                    digest: "d".to_string(),
+                    key_id: "t1".to_string(),
+                    signature: "sig".to_string(),
+                    computed_at: chrono::Utc::now(),
+                },
+                Turnout {
+                    election_id: req.election_id,
+                    election_name: "Student Council 2026".to_string(),
+                    phase: ElectionPhase::Published,
+                    voted: 9,
+                    paper_signed_in: 2,
+                    roll_size: 12,
+                },
+            ))
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_export_results_renders_csv_and_html() {
+        let mut tally_uc = app::AppHandlerData::mocked().tally_uc;
+        tally_uc.export = Arc::new(MockExport);
+        let app_data = web::Data::new(app::AppHandlerData { tally_uc, ..app::AppHandlerData::mocked() });
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_data)
+                .route("/elections/{id}/export", web::get().to(export_results)),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/elections/e1/export?format=csv").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers().get("Content-Type").unwrap(), "text/csv; charset=utf-8");
+        let body = test::read_body(resp).await;
+        let csv = std::str::from_utf8(&body).unwrap();
+        assert!(csv.contains("election,valid,,,,8,,\r\n"));
+        assert!(csv.contains("President,candidate,1,\"Budi, Jr.\",Sari,6,75.00,elected\r\n"));
+        assert!(csv.contains("President,abstain,,,,0,,\r\n"));
+        assert!(csv.contains("President,candidate,2,<Ani>,\"'=HYPERLINK(\"\"x\"\")\",2,25.00,\r\n"));
+
+        let req = test::TestRequest::get().uri("/elections/e1/export?format=html").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers().get("Content-Security-Policy").unwrap(),
+            "default-src 'none'; img-src https://cdn.campus.ac.id; style-src 'unsafe-inline'",
+        );
+        let body = test::read_body(resp).await;
+        let html = std::str::from_utf8(&body).unwrap();
+        assert!(html.contains("official results"));
+        assert!(html.contains(r#"<img src="https://cdn.campus.ac.id/c1.jpg" alt="Budi, Jr.">"#));
+        assert!(html.contains("&lt;Ani&gt;"));
+        assert!(!html.contains("javascript:"));
+        assert!(!html.contains("http://photos.campus.ac.id"));
+    }
+}
@@ -0,0 +1,27 @@
+use crate::tally::delivery::http::errors::error_response;
+use crate::tally::usecase::get_snapshot::*;
+use crate::embargo::delivery::http::staff_token;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+
+pub async fn get_tally(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+
+    let request = Request {
+        election_id: path.into_inner(),
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.tally_uc.get_snapshot.handle(request).await {
+        Ok(snapshot) => HttpResponse::Ok().json(response::success(
+            Some(snapshot),
+            "Successfully processed tally".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
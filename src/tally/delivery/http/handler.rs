@@ -0,0 +1,19 @@
+use actix_web::web;
+use crate::tally::delivery::http::certificate::{get_certificate, list_certificate_keys};
+use crate::tally::delivery::http::compute_tally::compute_tally;
+use crate::tally::delivery::http::export_results::export_results;
+use crate::tally::delivery::http::get_tally::get_tally;
+use crate::tally::delivery::http::recount_tally::{get_recount, recount_tally};
+use crate::tally::delivery::http::stream_live::stream_live;
+
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/elections/{id}/tally", web::get().to(get_tally))
+        .route("/elections/{id}/tally", web::post().to(compute_tally))
+        .route("/elections/{id}/recounts", web::post().to(recount_tally))
+        .route("/elections/{id}/recounts/{recount_id}", web::get().to(get_recount))
+        .route("/elections/{id}/export", web::get().to(export_results))
+        .route("/elections/{id}/certificate", web::get().to(get_certificate))
+        .route("/certificate-keys", web::get().to(list_certificate_keys))
+        .route("/elections/{id}/live", web::get().to(stream_live));
+}
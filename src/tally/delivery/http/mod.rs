@@ -0,0 +1,16 @@
+mod handler;
+mod errors;
+mod certificate;
+mod compute_tally;
+mod export_results;
+mod get_tally;
+mod recount_tally;
+mod stream_live;
+
+pub use certificate::*;
+pub use compute_tally::*;
+pub use export_results::*;
+pub use get_tally::*;
+pub use recount_tally::*;
+pub use stream_live::*;
+pub use handler::*;
@@ -0,0 +1,51 @@
+use crate::embargo::delivery::http::staff_token;
+use crate::tally::delivery::http::errors::error_response;
+use crate::tally::usecase::{get_recount, recount};
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+
+pub async fn recount_tally(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+
+    let request = recount::Request {
+        election_id: path.into_inner(),
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.tally_uc.recount.handle(request).await {
+        Ok(recount) => HttpResponse::Created().json(response::success(
+            Some(recount),
+            "Successfully processed recount".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
+
+pub async fn get_recount(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+
+    let (election_id, recount_id) = path.into_inner();
+    let request = get_recount::Request {
+        election_id,
+        recount_id,
+        staff_token: staff_token(&http_request),
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.tally_uc.get_recount.handle(request).await {
+        Ok(recount) => HttpResponse::Ok().json(response::success(
+            Some(recount),
+            "Successfully processed recount".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
@@ -0,0 +1,111 @@
+use crate::infrastructure::http::server::Shutdown;
+use crate::tally::delivery::http::errors::error_response;
+use crate::tally::domain::LiveEvent;
+use crate::tally::usecase::watch::*;
+use crate::utils::app;
+use actix_web::{HttpResponse, web};
+use futures_util::StreamExt;
+use serde::Serialize;
+
+fn frame<T: Serialize>(event: &str, data: &T) -> String {
+    match serde_json::to_string(data) {
+        Ok(json) => format!("event: {}\ndata: {}\n\n", event, json),
+        Err(e) => format!(": failed to encode {}: {}\n\n", event, e),
+    }
+}
+
+fn encode(event: &LiveEvent) -> web::Bytes {
+    let text = match event {
+        LiveEvent::Turnout(turnout) => frame("turnout", turnout),
+        LiveEvent::Results(snapshot) => frame("results", snapshot),
+        LiveEvent::KeepAlive => ": keep-alive\n\n".to_string(),
+    };
+    web::Bytes::from(text)
+}
+
+/// Server-Sent Events for a results screen. The stream stays open until the client leaves or
+/// the server shuts down.
+pub async fn stream_live(
+    handler: web::Data<app::AppHandlerData>,
+    shutdown: web::Data<Shutdown>,
+    path: web::Path<String>,
+) -> HttpResponse {
+
+    let request = Request { election_id: path.into_inner() };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.tally_uc.watch.handle(request).await {
+        Ok(events) => {
+            let body = events
+                .map(|event| Ok::<_, actix_web::Error>(encode(&event)))
+                .take_until(shutdown.get_ref().clone().wait());
+
+            HttpResponse::Ok()
+                .content_type("text/event-stream")
+                .insert_header(("Cache-Control", "no-cache"))
+                .streaming(body)
+        }
+        Err(e) => error_response(&e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::stream_live;
+    use actix_web::body::{self, MessageBody};
+    use actix_web::{App, http::StatusCode, test, web};
+    use async_trait::async_trait;
+    use futures_util::StreamExt;
+    use std::sync::Arc;
+    use crate::election::domain::ElectionPhase;
+    use crate::infrastructure::http::server::Shutdown;
+    use crate::tally::domain::{LiveEvent, TallyError, Turnout};
+    use crate::tally::usecase::watch::{Interactor, LiveStream, Request};
+    use crate::utils::app;
+
+    #[actix_rt::test]
+    async fn test_stream_live_ends_when_the_server_stops() {
+        struct MockWatch;
+
+        #[async_trait]
+        impl Interactor for MockWatch {
+            async fn handle(&self, req: Request) -> Result<LiveStream, TallyError> {
+                let turnout = Turnout { election_id: req.election_id, election_name: "Student Council 2026".to_string(), phase: ElectionPhase::Voting, voted: 7, paper_signed_in: 0, roll_size: 10 };
+                // One event, then nothing ever again.
+                Ok(Box::pin(futures_util::stream::iter(vec![LiveEvent::Turnout(turnout)]).chain(futures_util::stream::pending())))
+            }
+        }
+
+        let mut tally_uc = app::AppHandlerData::mocked().tally_uc;
+        tally_uc.watch = Arc::new(MockWatch);
+        let app_data = web::Data::new(app::AppHandlerData { tally_uc, ..app::AppHandlerData::mocked() });
+        let (stop, stopping) = tokio::sync::watch::channel(false);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(app_data)
+                .app_data(web::Data::new(Shutdown::new(stopping)))
+                .route("/elections/{id}/live", web::get().to(stream_live)),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/elections/e1/live").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers().get("content-type").unwrap(), "text/event-stream");
+
+        let mut body = resp.into_body();
+        let first = std::future::poll_fn(|cx| MessageBody::poll_next(std::pin::Pin::new(&mut body), cx))
+            .await
+            .unwrap()
+            .unwrap();
+        let text = String::from_utf8(first.to_vec()).unwrap();
+        assert!(text.starts_with("event: turnout\ndata: {"));
+        assert!(text.contains("\"voted\":7"));
+
+        // Without the signal this would never finish.
+        stop.send_replace(true);
+        assert!(body::to_bytes(body).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,96 @@
+use crate::infrastructure::crypto::{CERTIFICATE_ALGORITHM, Certifier, CryptoError, canonical_json, verify_certificate_signature};
+use crate::tally::domain::entities::{CertificatePayload, ResultsCertificate, ResultsExport};
+use crate::tally::domain::errors::TallyError;
+use serde_json::Value;
+
+pub const CERTIFICATE_KIND: &str = "results-certificate-v1";
+
+/// Signs an export of published results with the active certificate key.
+pub fn certify(certifier: &Certifier, results: ResultsExport) -> Result<ResultsCertificate, TallyError> {
+    let payload = CertificatePayload {
+        kind: CERTIFICATE_KIND.to_string(),
+        election_id: results.election_id.clone(),
+        algorithm: CERTIFICATE_ALGORITHM.to_string(),
+        key_id: certifier.active_key_id().to_string(),
+        results,
+    };
+    let value = serde_json::to_value(&payload).map_err(|e| TallyError::UnknownError(e.to_string()))?;
+    let signature = certifier.sign(canonical_json(&value).as_bytes());
+
+    Ok(ResultsCertificate { payload, signature: signature.value })
+}
+
+/// Checks a certificate as read from a file, with nothing but the public key of the key it
+/// names. The payload is taken as found rather than parsed into `CertificatePayload`, so a
+/// member added or removed after signing fails the check instead of being dropped silently.
+pub fn verify_certificate(document: &Value, public_key: &str) -> Result<(), CryptoError> {
+    let payload = document
+        .get("payload")
+        .ok_or_else(|| CryptoError::Malformed("certificate has no payload".into()))?;
+    let signature = document
+        .get("signature")
+        .and_then(Value::as_str)
+        .ok_or_else(|| CryptoError::Malformed("certificate has no signature".into()))?;
+    if payload.get("kind").and_then(Value::as_str) != Some(CERTIFICATE_KIND) {
+        return Err(CryptoError::Malformed(format!("not a {}", CERTIFICATE_KIND)));
+    }
+    if payload.get("algorithm").and_then(Value::as_str) != Some(CERTIFICATE_ALGORITHM) {
+        return Err(CryptoError::Malformed(format!("only {} certificates are supported", CERTIFICATE_ALGORITHM)));
+    }
+
+    verify_certificate_signature(public_key, canonical_json(payload).as_bytes(), signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::election::domain::ElectionPhase;
+    use crate::infrastructure::crypto::CertifierConfig;
+    use crate::tally::domain::entities::{ResultsStatus, Snapshot, Tally, Turnout};
+    use chrono::Utc;
+
+    fn export() -> ResultsExport {
+        ResultsExport {
+            election_id: "e1".to_string(),
+            election_name: "Student Council 2026".to_string(),
+            status: ResultsStatus::Official,
+            valid: 3,
+            verdicts: vec![],
+            turnout: Turnout {
+                election_id: "e1".to_string(),
+                election_name: "Student Council 2026".to_string(),
+                phase: ElectionPhase::Published,
+                voted: 5,
+                paper_signed_in: 0,
+                roll_size: 20,
+            },
+            snapshot: Snapshot {
+                id: "s1".to_string(),
+                election_id: "e1".to_string(),
+                tally: Tally { election_id: "e1".to_string(), ballots: 5, paper_ballots: 0, blank: 1, invalid: 1, contests: vec![], provisional: false },
+                digest: "d".to_string(),
+                key_id: "t1".to_string(),
+                signature: "sig".to_string(),
+                computed_at: Utc::now(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_certificate_survives_a_file_round_trip_and_catches_edits() {
+        let certifier = Certifier::from_config(&CertifierConfig {
+            active_key_id: "c1".to_string(),
+            keys: "c1:AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=".to_string(),
+        }).unwrap();
+        let public_key = certifier.public_keys()[0].public_key.clone();
+
+        let certificate = certify(&certifier, export()).unwrap();
+        let file = serde_json::to_string_pretty(&certificate).unwrap();
+        let mut document: Value = serde_json::from_str(&file).unwrap();
+
+        assert!(verify_certificate(&document, &public_key).is_ok());
+
+        document["payload"]["results"]["turnout"]["voted"] = Value::from(6);
+        assert!(matches!(verify_certificate(&document, &public_key), Err(CryptoError::Failed(_))));
+    }
+}
@@ -0,0 +1,217 @@
+use crate::paper::domain::StationTotals;
+use crate::tally::domain::entities::{CandidateResult, ContestResult, ContestSetup, RecordedBallot, Tally};
+use crate::tally::domain::errors::TallyError;
+use crate::tally::domain::tie_break::break_tie;
+use std::collections::HashMap;
+
+/// Counts an election from its contests, its counted ballots and the totals of its merged
+/// paper stations. It depends on nothing else, and neither the order of the ballots nor of
+/// the contests matters, so anyone holding the same inputs arrives at the same tally. A tie for
+/// the most votes in a contest is settled by the contest's tie-break policy.
+pub fn count(
+    election_id: &str,
+    contests: &[ContestSetup],
+    ballots: &[RecordedBallot],
+    paper: &[StationTotals],
+) -> Result<Tally, TallyError> {
+    let mut contests: Vec<&ContestSetup> = contests.iter().collect();
+    contests.sort_by(|a, b| (&a.name, &a.id).cmp(&(&b.name, &b.id)));
+
+    let mut results: Vec<ContestResult> = contests
+        .iter()
+        .map(|c| {
+            let mut candidates: Vec<CandidateResult> = c.candidates
+                .iter()
+                .map(|candidate| CandidateResult { candidate: candidate.clone(), votes: 0 })
+                .collect();
+            candidates.sort_by(|a, b| {
+                (a.candidate.vote_number, &a.candidate.id).cmp(&(b.candidate.vote_number, &b.candidate.id))
+            });
+            ContestResult { contest_id: c.id.clone(), contest_name: c.name.clone(), candidates, abstain: 0, tie_break: None }
+        })
+        .collect();
+    let index: HashMap<String, usize> = results
+        .iter()
+        .enumerate()
+        .map(|(i, c)| (c.contest_id.clone(), i))
+        .collect();
+
+    let mut tally = Tally {
+        election_id: election_id.to_string(),
+        ballots: 0,
+        paper_ballots: 0,
+        blank: 0,
+        invalid: 0,
+        contests: vec![],
+        provisional: false,
+    };
+
+    for ballot in ballots {
+        tally.ballots += 1;
+        if ballot.blank {
+            tally.blank += 1;
+            continue;
+        }
+        for choice in &ballot.choices {
+            add(&mut results, &index, &choice.contest_id, choice.candidate_id.as_deref(), 1)?;
+        }
+    }
+
+    for station in paper {
+        tally.ballots += station.ballots;
+        tally.paper_ballots += station.ballots;
+        tally.blank += station.blank;
+        tally.invalid += station.invalid;
+        for count in &station.counts {
+            add(&mut results, &index, &count.contest_id, count.candidate_id.as_deref(), count.votes)?;
+        }
+    }
+
+    for (setup, result) in contests.iter().zip(results.iter_mut()) {
+        result.tie_break = break_tie(setup, result)?;
+    }
+
+    tally.contests = results;
+    Ok(tally)
+}
+
+fn add(
+    results: &mut [ContestResult],
+    index: &HashMap<String, usize>,
+    contest_id: &str,
+    candidate_id: Option<&str>,
+    votes: i64,
+) -> Result<(), TallyError> {
+    let contest = match index.get(contest_id) {
+        Some(&i) => &mut results[i],
+        None => {
+            return Err(TallyError::Inconsistent(format!("contest {} is not part of this election", contest_id)));
+        }
+    };
+
+    match candidate_id {
+        None => contest.abstain += votes,
+        Some(candidate_id) => {
+            let candidate = contest.candidates
+                .iter_mut()
+                .find(|c| c.candidate.id == candidate_id)
+                .ok_or_else(|| TallyError::Inconsistent(format!(
+                    "candidate {} did not run in contest {}", candidate_id, contest_id
+                )))?;
+            candidate.votes += votes;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::candidate::domain::Candidate;
+    use crate::election::domain::TieBreak;
+    use crate::paper::domain::ChoiceCount;
+    use crate::vote::domain::Choice;
+
+    fn candidate(id: &str, vote_number: i32) -> Candidate {
+        Candidate {
+            id: id.to_string(),
+            vote_number,
+            president_name: format!("President {}", id),
+            vice_president_name: format!("Vice {}", id),
+            president_nim: "2023010001".to_string(),
+            vice_president_nim: "2023010002".to_string(),
+            president_photo: format!("https://cdn.example/{}-p.jpg", id),
+            vice_president_photo: format!("https://cdn.example/{}-v.jpg", id),
+            status: true,
+            created_by: "committee".to_string(),
+            created_at: chrono::DateTime::UNIX_EPOCH,
+            updated_at: None,
+        }
+    }
+
+    fn contests() -> Vec<ContestSetup> {
+        vec![
+            ContestSetup {
+                id: "senate".to_string(),
+                name: "Senate".to_string(),
+                candidates: vec![candidate("c3", 1)],
+                tie_break: TieBreak::Runoff,
+                tie_break_seed: None,
+            },
+            ContestSetup {
+                id: "president".to_string(),
+                name: "President".to_string(),
+                candidates: vec![candidate("c2", 2), candidate("c1", 1)],
+                tie_break: TieBreak::Runoff,
+                tie_break_seed: None,
+            },
+        ]
+    }
+
+    fn ballot(choices: Vec<Choice>) -> RecordedBallot {
+        RecordedBallot { blank: false, choices }
+    }
+
+    fn ballots() -> Vec<RecordedBallot> {
+        vec![
+            ballot(vec![Choice::candidate("president", "c1"), Choice::candidate("senate", "c3")]),
+            ballot(vec![Choice::candidate("president", "c2"), Choice::abstention("senate")]),
+            ballot(vec![Choice::candidate("president", "c1"), Choice::candidate("senate", "c3")]),
+            RecordedBallot { blank: true, choices: vec![] },
+        ]
+    }
+
+    fn paper() -> Vec<StationTotals> {
+        vec![StationTotals {
+            ballots: 10,
+            blank: 1,
+            invalid: 2,
+            counts: vec![
+                ChoiceCount { contest_id: "president".to_string(), candidate_id: Some("c2".to_string()), votes: 6 },
+                ChoiceCount { contest_id: "president".to_string(), candidate_id: None, votes: 1 },
+                ChoiceCount { contest_id: "senate".to_string(), candidate_id: Some("c3".to_string()), votes: 7 },
+            ],
+        }]
+    }
+
+    #[test]
+    fn test_count_adds_paper_totals_and_keeps_blank_abstain_and_invalid_apart() {
+        let tally = count("e1", &contests(), &ballots(), &paper()).unwrap();
+
+        assert_eq!((tally.ballots, tally.paper_ballots, tally.blank, tally.invalid), (14, 10, 2, 2));
+
+        let names: Vec<&str> = tally.contests.iter().map(|c| c.contest_name.as_str()).collect();
+        assert_eq!(names, vec!["President", "Senate"]);
+
+        let president = &tally.contests[0];
+        let votes: Vec<(i32, i64)> = president.candidates.iter().map(|c| (c.candidate.vote_number, c.votes)).collect();
+        assert_eq!(votes, vec![(1, 2), (2, 7)]);
+        assert_eq!(president.abstain, 1);
+        assert_eq!(president.candidates[0].candidate.president_photo, "https://cdn.example/c1-p.jpg");
+
+        let senate = &tally.contests[1];
+        assert_eq!((senate.candidates[0].votes, senate.abstain), (9, 1));
+    }
+
+    #[test]
+    fn test_count_does_not_depend_on_input_order() {
+        let mut shuffled_ballots = ballots();
+        shuffled_ballots.reverse();
+        let mut shuffled_contests = contests();
+        shuffled_contests.reverse();
+        shuffled_contests[0].candidates.reverse();
+
+        let a = count("e1", &contests(), &ballots(), &paper()).unwrap();
+        let b = count("e1", &shuffled_contests, &shuffled_ballots, &paper()).unwrap();
+
+        assert_eq!(serde_json::to_string(&a).unwrap(), serde_json::to_string(&b).unwrap());
+    }
+
+    #[test]
+    fn test_count_refuses_choices_outside_the_configuration() {
+        let stray = vec![ballot(vec![Choice::candidate("president", "c3"), Choice::abstention("senate")])];
+
+        assert!(matches!(count("e1", &contests(), &stray, &[]), Err(TallyError::Inconsistent(_))));
+    }
+}
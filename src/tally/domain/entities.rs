@@ -0,0 +1,300 @@
+use crate::candidate::domain::Candidate;
+use crate::election::domain::{ElectionPhase, TieBreak};
+use crate::vote::domain::Choice;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+
+/// A contest as configured for counting: every candidate that ran in it, including any
+/// deactivated after ballots were cast for them.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ContestSetup {
+    pub id: String,
+    pub name: String,
+    pub candidates: Vec<Candidate>,
+    pub tie_break: TieBreak,
+    pub tie_break_seed: Option<String>,
+}
+
+/// A counted ballot as stored: what was voted, nothing else.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RecordedBallot {
+    pub blank: bool,
+    pub choices: Vec<Choice>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CandidateResult {
+    pub candidate: Candidate,
+    pub votes: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ContestResult {
+    pub contest_id: String,
+    pub contest_name: String,
+    /// In `vote_number` order.
+    pub candidates: Vec<CandidateResult>,
+    /// Explicit abstentions, electronic and paper.
+    pub abstain: i64,
+    /// Set when two or more candidates share the most votes.
+    #[serde(default)]
+    pub tie_break: Option<TieBreakOutcome>,
+}
+
+/// How a tie for the most votes was settled, with what anyone needs to check it.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TieBreakOutcome {
+    pub policy: TieBreak,
+    /// The tied candidates, in `vote_number` order.
+    pub tied: Vec<String>,
+    pub votes: i64,
+    /// The lottery's published seed.
+    pub seed: Option<String>,
+    /// Unset after a runoff: the tied candidates go to a new vote.
+    pub winner: Option<String>,
+    pub evidence: Vec<TieBreakEvidence>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TieBreakEvidence {
+    pub candidate_id: String,
+    /// The candidate's lottery draw, or when they registered (RFC 3339).
+    pub value: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Tally {
+    pub election_id: String,
+    /// Every ballot counted, electronic and paper, whether valid, blank or invalid.
+    pub ballots: i64,
+    pub paper_ballots: i64,
+    pub blank: i64,
+    /// Spoilt paper ballots; electronic ballots cannot be invalid.
+    pub invalid: i64,
+    /// In contest name order.
+    pub contests: Vec<ContestResult>,
+    /// Counted before voting closed, under an embargo override; never served as the results.
+    #[serde(default)]
+    pub provisional: bool,
+}
+
+/// A tally as it is stored: `results` is the tally's JSON exactly as it was signed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct NewSnapshot {
+    pub election_id: String,
+    pub results: String,
+    /// SHA-256 of `results`, hex.
+    pub digest: String,
+    pub key_id: String,
+    pub signature: String,
+    pub computed_at: DateTime<Utc>,
+    pub provisional: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct StoredSnapshot {
+    pub id: String,
+    pub election_id: String,
+    pub results: String,
+    pub digest: String,
+    pub key_id: String,
+    pub signature: String,
+    pub computed_at: DateTime<Utc>,
+}
+
+/// A stored tally whose signature has been checked.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Snapshot {
+    pub id: String,
+    pub election_id: String,
+    pub tally: Tally,
+    pub digest: String,
+    pub key_id: String,
+    pub signature: String,
+    pub computed_at: DateTime<Utc>,
+}
+
+/// One count the recount does not agree on; a side without the item has no value.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CountDifference {
+    /// `ballots`, `paper_ballots`, `blank`, `invalid`, `abstain` or `candidate <id>`.
+    pub item: String,
+    pub published: Option<i64>,
+    pub recounted: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ContestComparison {
+    pub contest_id: String,
+    pub contest_name: String,
+    pub matches: bool,
+    pub differences: Vec<CountDifference>,
+}
+
+/// A fresh count from the stored ballots, set against the snapshot it checks.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RecountReport {
+    pub election_id: String,
+    pub snapshot_id: String,
+    pub snapshot_digest: String,
+    pub snapshot_computed_at: DateTime<Utc>,
+    /// Staff member who asked for the recount.
+    pub requested_by: Option<String>,
+    pub recounted_at: DateTime<Utc>,
+    /// Every total and every contest agrees.
+    pub matches: bool,
+    pub totals: Vec<CountDifference>,
+    /// In contest name order, every contest on either side.
+    pub contests: Vec<ContestComparison>,
+    pub recount: Tally,
+}
+
+/// A recount report as it is stored: `report` is its JSON exactly as it was signed.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct NewRecount {
+    pub election_id: String,
+    pub snapshot_id: String,
+    pub report: String,
+    pub digest: String,
+    pub key_id: String,
+    pub signature: String,
+    pub matches: bool,
+    pub recounted_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct StoredRecount {
+    pub id: String,
+    pub election_id: String,
+    pub snapshot_id: String,
+    pub report: String,
+    pub digest: String,
+    pub key_id: String,
+    pub signature: String,
+    pub matches: bool,
+    pub recounted_at: DateTime<Utc>,
+}
+
+/// A stored recount report whose signature has been checked.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Recount {
+    pub id: String,
+    pub report: RecountReport,
+    pub digest: String,
+    pub key_id: String,
+    pub signature: String,
+}
+
+/// How many have voted so far. Paper voters are only known by the codes issued to them at
+/// their station until its totals are merged.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Turnout {
+    pub election_id: String,
+    pub election_name: String,
+    pub phase: ElectionPhase,
+    pub voted: i64,
+    pub paper_signed_in: i64,
+    pub roll_size: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Elected,
+    ElectedByTieBreak,
+    RunoffRequired,
+    NoVotes,
+}
+
+impl Verdict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Elected => "elected",
+            Verdict::ElectedByTieBreak => "elected_by_tie_break",
+            Verdict::RunoffRequired => "runoff_required",
+            Verdict::NoVotes => "no_votes",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ContestVerdict {
+    pub contest_id: String,
+    pub verdict: Verdict,
+    pub winner: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ResultsStatus {
+    /// The election is published.
+    Official,
+    /// Voting has closed, but the results are not published yet.
+    Unofficial,
+    /// Counted before voting closed, under an embargo override.
+    Provisional,
+}
+
+impl ResultsStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ResultsStatus::Official => "official",
+            ResultsStatus::Unofficial => "unofficial",
+            ResultsStatus::Provisional => "provisional",
+        }
+    }
+}
+
+/// Everything an official results notice shows, from one checked snapshot.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ResultsExport {
+    pub election_id: String,
+    pub election_name: String,
+    pub status: ResultsStatus,
+    /// Ballots neither blank nor invalid.
+    pub valid: i64,
+    /// In the order of `snapshot.tally.contests`.
+    pub verdicts: Vec<ContestVerdict>,
+    pub turnout: Turnout,
+    pub snapshot: Snapshot,
+}
+
+/// A running counter that no longer agrees with a count of the rows it stands for.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CounterDrift {
+    pub election_id: String,
+    /// `voted`, `ballots`, `blank`, `contest:<id>`, `abstain:<id>` or `candidate:<contest>:<id>`.
+    pub counter: String,
+    pub stored: i64,
+    pub recomputed: i64,
+}
+
+/// What a live results screen is sent.
+#[derive(Debug, Clone)]
+pub enum LiveEvent {
+    Turnout(Turnout),
+    /// Only once the election is published.
+    Results(Snapshot),
+    /// Nothing changed for a while; keeps idle connections from being dropped.
+    KeepAlive,
+}
+
+/// What a results certificate signs, in canonical JSON.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CertificatePayload {
+    /// Always `results-certificate-v1`, so the signature cannot be passed off for another document.
+    pub kind: String,
+    pub election_id: String,
+    pub algorithm: String,
+    pub key_id: String,
+    pub results: ResultsExport,
+}
+
+/// Published results with an Ed25519 signature anyone can check against the service's public key.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ResultsCertificate {
+    pub payload: CertificatePayload,
+    /// Over the canonical JSON of `payload`, unpadded base64url.
+    pub signature: String,
+}
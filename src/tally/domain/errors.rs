@@ -0,0 +1,47 @@
+use crate::election::domain::ElectionError;
+use crate::embargo::domain::EmbargoError;
+use thiserror::Error;
+
+
+#[derive(Debug, Error)]
+#[derive(Clone)]
+pub enum TallyError {
+    #[error("TallyError::NotFound: {0}")]
+    NotFound(String),
+    #[error("TallyError::Unauthorized: {0}")]
+    Unauthorized(String),
+    #[error("TallyError::Forbidden: {0}")]
+    Forbidden(String),
+    /// Voting has not closed, or results are not published, for this caller.
+    #[error("TallyError::Embargoed: {0}")]
+    Embargoed(String),
+    /// The stored ballots name a contest or candidate the election does not have.
+    #[error("TallyError::Inconsistent: {0}")]
+    Inconsistent(String),
+    /// A stored snapshot no longer matches its digest or signature.
+    #[error("TallyError::SignatureMismatch: {0}")]
+    SignatureMismatch(String),
+    #[error("TallyError::UnknownError: {0}")]
+    UnknownError(String),
+}
+
+impl From<ElectionError> for TallyError {
+    fn from(e: ElectionError) -> Self {
+        match e {
+            ElectionError::NotFound(msg) => TallyError::NotFound(msg),
+            other => TallyError::UnknownError(other.to_string()),
+        }
+    }
+}
+
+impl From<EmbargoError> for TallyError {
+    fn from(e: EmbargoError) -> Self {
+        match e {
+            EmbargoError::NotFound(msg) => TallyError::NotFound(msg),
+            EmbargoError::Unauthorized(msg) => TallyError::Unauthorized(msg),
+            EmbargoError::Forbidden(msg) => TallyError::Forbidden(msg),
+            EmbargoError::Embargoed(msg) => TallyError::Embargoed(msg),
+            other => TallyError::UnknownError(other.to_string()),
+        }
+    }
+}
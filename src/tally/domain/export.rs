@@ -0,0 +1,105 @@
+use crate::election::domain::ElectionPhase;
+use crate::tally::domain::entities::{ContestResult, ContestVerdict, ResultsExport, ResultsStatus, Snapshot, Turnout, Verdict};
+
+/// What a contest's count decides: a winner outright, a winner by the tie-break policy, a
+/// runoff between the tied candidates, or nothing when nobody got a vote.
+pub fn contest_verdict(result: &ContestResult) -> ContestVerdict {
+    let leader = result.candidates.iter().max_by_key(|c| c.votes);
+
+    let (verdict, winner) = match (leader, &result.tie_break) {
+        (None, _) => (Verdict::NoVotes, None),
+        (Some(c), _) if c.votes == 0 => (Verdict::NoVotes, None),
+        (Some(_), Some(tie)) => match &tie.winner {
+            Some(winner) => (Verdict::ElectedByTieBreak, Some(winner.clone())),
+            None => (Verdict::RunoffRequired, None),
+        },
+        (Some(c), None) => (Verdict::Elected, Some(c.candidate.id.clone())),
+    };
+
+    ContestVerdict { contest_id: result.contest_id.clone(), verdict, winner }
+}
+
+/// Puts a checked snapshot together with turnout and a verdict per contest, ready to render.
+pub fn results_export(snapshot: Snapshot, turnout: Turnout) -> ResultsExport {
+    let status = if snapshot.tally.provisional {
+        ResultsStatus::Provisional
+    } else if turnout.phase == ElectionPhase::Published {
+        ResultsStatus::Official
+    } else {
+        ResultsStatus::Unofficial
+    };
+    let tally = &snapshot.tally;
+
+    ResultsExport {
+        election_id: snapshot.election_id.clone(),
+        election_name: turnout.election_name.clone(),
+        status,
+        valid: tally.ballots - tally.blank - tally.invalid,
+        verdicts: tally.contests.iter().map(contest_verdict).collect(),
+        turnout,
+        snapshot,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::candidate::domain::Candidate;
+    use crate::election::domain::TieBreak;
+    use crate::tally::domain::entities::{CandidateResult, TieBreakOutcome};
+
+    fn contest(votes: &[i64], tie_break: Option<TieBreakOutcome>) -> ContestResult {
+        ContestResult {
+            contest_id: "president".to_string(),
+            contest_name: "President".to_string(),
+            candidates: votes
+                .iter()
+                .enumerate()
+                .map(|(i, &votes)| CandidateResult {
+                    candidate: Candidate {
+                        id: format!("c{}", i + 1),
+                        vote_number: i as i32 + 1,
+                        president_name: String::new(),
+                        vice_president_name: String::new(),
+                        president_nim: String::new(),
+                        vice_president_nim: String::new(),
+                        president_photo: String::new(),
+                        vice_president_photo: String::new(),
+                        status: true,
+                        created_by: "committee".to_string(),
+                        created_at: chrono::DateTime::UNIX_EPOCH,
+                        updated_at: None,
+                    },
+                    votes,
+                })
+                .collect(),
+            abstain: 0,
+            tie_break,
+        }
+    }
+
+    fn tie(winner: Option<&str>) -> TieBreakOutcome {
+        TieBreakOutcome {
+            policy: if winner.is_some() { TieBreak::Registration } else { TieBreak::Runoff },
+            tied: vec!["c1".to_string(), "c2".to_string()],
+            votes: 4,
+            seed: None,
+            winner: winner.map(str::to_string),
+            evidence: vec![],
+        }
+    }
+
+    #[test]
+    fn test_contest_verdicts() {
+        let verdict = |c: &ContestResult| {
+            let v = contest_verdict(c);
+            (v.verdict, v.winner)
+        };
+
+        assert_eq!(verdict(&contest(&[3, 5], None)), (Verdict::Elected, Some("c2".to_string())));
+        assert_eq!(verdict(&contest(&[4, 4], Some(tie(Some("c1"))))), (Verdict::ElectedByTieBreak, Some("c1".to_string())));
+        assert_eq!(verdict(&contest(&[4, 4], Some(tie(None)))), (Verdict::RunoffRequired, None));
+        assert_eq!(verdict(&contest(&[0, 0], Some(tie(None)))), (Verdict::NoVotes, None));
+        assert_eq!(verdict(&contest(&[], None)), (Verdict::NoVotes, None));
+    }
+}
@@ -0,0 +1,19 @@
+mod certificate;
+mod count;
+mod entities;
+mod errors;
+mod export;
+mod recount;
+mod repository;
+mod snapshot;
+mod tie_break;
+
+pub use certificate::*;
+pub use count::*;
+pub use entities::*;
+pub use errors::*;
+pub use export::*;
+pub use recount::*;
+pub use repository::*;
+pub use snapshot::*;
+pub use tie_break::*;
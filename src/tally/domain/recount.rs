@@ -0,0 +1,239 @@
+use crate::infrastructure::crypto::{Signature, Signer};
+use crate::tally::domain::entities::{
+    ContestComparison, ContestResult, CountDifference, NewRecount, Recount, RecountReport, Snapshot, StoredRecount, Tally,
+};
+use crate::tally::domain::errors::TallyError;
+use crate::tally::domain::snapshot::results_digest;
+use chrono::{DateTime, SubsecRound, Utc};
+use std::collections::BTreeMap;
+
+const PURPOSE: &str = "tally-recount-v1";
+
+/// A contest as published and as recounted; either may be missing.
+type Sides<'a> = (Option<&'a ContestResult>, Option<&'a ContestResult>);
+
+fn signed_message(election_id: &str, snapshot_id: &str, digest: &str) -> String {
+    format!("{}.{}.{}", election_id, snapshot_id, digest)
+}
+
+fn difference(item: &str, published: Option<i64>, recounted: Option<i64>) -> Option<CountDifference> {
+    (published != recounted).then(|| CountDifference { item: item.to_string(), published, recounted })
+}
+
+fn contest_counts(contest: &ContestResult) -> BTreeMap<String, i64> {
+    let mut counts: BTreeMap<String, i64> = contest.candidates
+        .iter()
+        .map(|c| (format!("candidate {}", c.candidate.id), c.votes))
+        .collect();
+    counts.insert("abstain".to_string(), contest.abstain);
+    counts
+}
+
+/// Sets a recount against the snapshot it checks, total by total and contest by contest.
+/// Only counts are compared; tie-breaks follow from them.
+pub fn compare(published: &Snapshot, recount: Tally, requested_by: Option<String>, recounted_at: DateTime<Utc>) -> RecountReport {
+    let before = &published.tally;
+    let totals: Vec<CountDifference> = [
+        ("ballots", before.ballots, recount.ballots),
+        ("paper_ballots", before.paper_ballots, recount.paper_ballots),
+        ("blank", before.blank, recount.blank),
+        ("invalid", before.invalid, recount.invalid),
+    ]
+        .into_iter()
+        .filter_map(|(item, a, b)| difference(item, Some(a), Some(b)))
+        .collect();
+
+    let mut sides: BTreeMap<(&str, &str), Sides> = BTreeMap::new();
+    for c in &before.contests {
+        sides.entry((&c.contest_name, &c.contest_id)).or_default().0 = Some(c);
+    }
+    for c in &recount.contests {
+        sides.entry((&c.contest_name, &c.contest_id)).or_default().1 = Some(c);
+    }
+
+    let contests: Vec<ContestComparison> = sides
+        .into_iter()
+        .map(|((name, id), (a, b))| {
+            let a = a.map(contest_counts).unwrap_or_default();
+            let b = b.map(contest_counts).unwrap_or_default();
+            let mut items: Vec<&String> = a.keys().chain(b.keys()).collect();
+            items.sort();
+            items.dedup();
+
+            let differences: Vec<CountDifference> = items
+                .into_iter()
+                .filter_map(|item| difference(item, a.get(item).copied(), b.get(item).copied()))
+                .collect();
+            ContestComparison {
+                contest_id: id.to_string(),
+                contest_name: name.to_string(),
+                matches: differences.is_empty(),
+                differences,
+            }
+        })
+        .collect();
+
+    RecountReport {
+        election_id: recount.election_id.clone(),
+        snapshot_id: published.id.clone(),
+        snapshot_digest: published.digest.clone(),
+        snapshot_computed_at: published.computed_at,
+        requested_by,
+        // Postgres keeps microseconds; the stored time must match the signed report.
+        recounted_at: recounted_at.trunc_subsecs(6),
+        matches: totals.is_empty() && contests.iter().all(|c| c.matches),
+        totals,
+        contests,
+        recount,
+    }
+}
+
+/// Serialises a recount report and signs its digest together with the election and the
+/// snapshot it checks.
+pub fn seal_recount(signer: &Signer, report: &RecountReport) -> Result<NewRecount, TallyError> {
+    let body = serde_json::to_string(report).map_err(|e| TallyError::UnknownError(e.to_string()))?;
+    let digest = results_digest(&body);
+    let signature = signer.sign(PURPOSE, signed_message(&report.election_id, &report.snapshot_id, &digest).as_bytes());
+
+    Ok(NewRecount {
+        election_id: report.election_id.clone(),
+        snapshot_id: report.snapshot_id.clone(),
+        report: body,
+        digest,
+        key_id: signature.key_id,
+        signature: signature.value,
+        matches: report.matches,
+        recounted_at: report.recounted_at,
+    })
+}
+
+/// Checks a stored recount report against its digest and signature before handing it out.
+pub fn open_recount(signer: &Signer, stored: StoredRecount) -> Result<Recount, TallyError> {
+    if results_digest(&stored.report) != stored.digest {
+        return Err(TallyError::SignatureMismatch(format!("recount {} does not match its digest", stored.id)));
+    }
+
+    let signature = Signature { key_id: stored.key_id.clone(), value: stored.signature.clone() };
+    signer
+        .verify(PURPOSE, signed_message(&stored.election_id, &stored.snapshot_id, &stored.digest).as_bytes(), &signature)
+        .map_err(|e| TallyError::SignatureMismatch(format!("recount {}: {}", stored.id, e)))?;
+
+    let report: RecountReport = serde_json::from_str(&stored.report).map_err(|e| TallyError::UnknownError(e.to_string()))?;
+    if report.election_id != stored.election_id || report.snapshot_id != stored.snapshot_id || report.matches != stored.matches {
+        return Err(TallyError::SignatureMismatch(format!("recount {} does not match its record", stored.id)));
+    }
+
+    Ok(Recount {
+        id: stored.id,
+        report,
+        digest: stored.digest,
+        key_id: stored.key_id,
+        signature: stored.signature,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::candidate::domain::Candidate;
+    use crate::infrastructure::crypto::SignerConfig;
+    use crate::tally::domain::entities::CandidateResult;
+
+    fn signer() -> Signer {
+        Signer::from_config(&SignerConfig {
+            active_key_id: "t1".to_string(),
+            keys: "t1:AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=".to_string(),
+        }).unwrap()
+    }
+
+    fn contest(id: &str, votes: &[(&str, i64)], abstain: i64) -> ContestResult {
+        ContestResult {
+            contest_id: id.to_string(),
+            contest_name: id.to_uppercase(),
+            candidates: votes
+                .iter()
+                .map(|&(candidate, votes)| CandidateResult {
+                    candidate: Candidate {
+                        id: candidate.to_string(),
+                        vote_number: 1,
+                        president_name: String::new(),
+                        vice_president_name: String::new(),
+                        president_nim: String::new(),
+                        vice_president_nim: String::new(),
+                        president_photo: String::new(),
+                        vice_president_photo: String::new(),
+                        status: true,
+                        created_by: "committee".to_string(),
+                        created_at: DateTime::UNIX_EPOCH,
+                        updated_at: None,
+                    },
+                    votes,
+                })
+                .collect(),
+            abstain,
+            tie_break: None,
+        }
+    }
+
+    fn tally(ballots: i64, contests: Vec<ContestResult>) -> Tally {
+        Tally { election_id: "e1".to_string(), ballots, paper_ballots: 0, blank: 0, invalid: 0, contests, provisional: false }
+    }
+
+    fn published(tally: Tally) -> Snapshot {
+        Snapshot {
+            id: "s1".to_string(),
+            election_id: "e1".to_string(),
+            tally,
+            digest: "d".to_string(),
+            key_id: "t1".to_string(),
+            signature: "sig".to_string(),
+            computed_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_recount_names_each_difference() {
+        let before = published(tally(10, vec![contest("president", &[("c1", 6), ("c2", 4)], 0), contest("senate", &[("c3", 9)], 1)]));
+        let after = tally(11, vec![contest("president", &[("c1", 6), ("c2", 5)], 0), contest("senate", &[("c3", 9)], 1)]);
+
+        let report = compare(&before, after, Some("rina".to_string()), Utc::now());
+
+        assert!(!report.matches);
+        assert_eq!(report.totals, vec![CountDifference { item: "ballots".to_string(), published: Some(10), recounted: Some(11) }]);
+        assert_eq!(report.contests[0].differences, vec![CountDifference {
+            item: "candidate c2".to_string(),
+            published: Some(4),
+            recounted: Some(5),
+        }]);
+        assert!(report.contests[1].matches);
+
+        let same = compare(&before, before.tally.clone(), None, Utc::now());
+        assert!(same.matches);
+    }
+
+    #[test]
+    fn test_recount_report_opens_only_as_it_was_signed() {
+        let before = published(tally(1, vec![contest("president", &[("c1", 1)], 0)]));
+        let report = compare(&before, tally(1, vec![]), None, Utc::now());
+        assert_eq!(report.contests[0].differences.len(), 2);
+
+        let sealed = seal_recount(&signer(), &report).unwrap();
+        let stored = StoredRecount {
+            id: "r1".to_string(),
+            election_id: sealed.election_id,
+            snapshot_id: sealed.snapshot_id,
+            report: sealed.report,
+            digest: sealed.digest,
+            key_id: sealed.key_id,
+            signature: sealed.signature,
+            matches: sealed.matches,
+            recounted_at: sealed.recounted_at,
+        };
+        assert!(!open_recount(&signer(), stored.clone()).unwrap().report.matches);
+
+        let mut edited = stored;
+        edited.report = edited.report.replace("\"matches\":false", "\"matches\":true");
+        edited.digest = results_digest(&edited.report);
+        assert!(matches!(open_recount(&signer(), edited), Err(TallyError::SignatureMismatch(_))));
+    }
+}
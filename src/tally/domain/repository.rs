@@ -0,0 +1,35 @@
+use crate::paper::domain::StationTotals;
+use crate::tally::domain::entities::{ContestSetup, CounterDrift, NewRecount, NewSnapshot, RecordedBallot, StoredRecount, StoredSnapshot, Turnout};
+use crate::tally::domain::errors::TallyError;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait Repository: Send + Sync {
+    async fn find_contests(&self, election_id: String) -> Result<Vec<ContestSetup>, TallyError>;
+
+    /// Ballots with `counted` set; ballots replaced by a re-vote are left out.
+    async fn find_counted_ballots(&self, election_id: String) -> Result<Vec<RecordedBallot>, TallyError>;
+
+    /// The agreed totals of every merged paper station.
+    async fn find_paper_totals(&self, election_id: String) -> Result<Vec<StationTotals>, TallyError>;
+
+    async fn save_snapshot(&self, snapshot: NewSnapshot) -> Result<StoredSnapshot, TallyError>;
+
+    async fn find_turnout(&self, election_id: String) -> Result<Turnout, TallyError>;
+
+    /// Provisional snapshots, counted before voting closed, are left out unless asked for.
+    async fn find_latest_snapshot(&self, election_id: String, include_provisional: bool) -> Result<StoredSnapshot, TallyError>;
+
+    async fn save_recount(&self, recount: NewRecount) -> Result<StoredRecount, TallyError>;
+
+    async fn find_recount(&self, id: String) -> Result<StoredRecount, TallyError>;
+
+    /// Counters of elections in voting or closed that differ from a fresh count of their rows,
+    /// both read in one snapshot.
+    async fn find_counter_drift(&self) -> Result<Vec<CounterDrift>, TallyError>;
+
+    /// Moves each counter by its drift, so writes made since it was found are kept.
+    async fn correct_counters(&self, drift: Vec<CounterDrift>) -> Result<(), TallyError>;
+}
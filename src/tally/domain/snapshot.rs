@@ -0,0 +1,105 @@
+use crate::infrastructure::crypto::{Signature, Signer};
+use crate::tally::domain::entities::{NewSnapshot, Snapshot, StoredSnapshot, Tally};
+use crate::tally::domain::errors::TallyError;
+use chrono::{DateTime, SecondsFormat, SubsecRound, Utc};
+use sha2::{Digest, Sha256};
+
+const PURPOSE: &str = "tally-snapshot-v1";
+
+pub fn results_digest(results: &str) -> String {
+    hex::encode(Sha256::digest(results.as_bytes()))
+}
+
+fn signed_message(election_id: &str, computed_at: DateTime<Utc>, digest: &str) -> String {
+    format!("{}.{}.{}", election_id, computed_at.to_rfc3339_opts(SecondsFormat::Micros, true), digest)
+}
+
+/// Serialises a tally and signs its digest together with the election and the time it was
+/// computed, so a stored snapshot cannot be edited or passed off as another one.
+pub fn seal_snapshot(signer: &Signer, tally: &Tally, computed_at: DateTime<Utc>) -> Result<NewSnapshot, TallyError> {
+    // Postgres keeps microseconds; the signed time must survive the round trip.
+    let computed_at = computed_at.trunc_subsecs(6);
+    let results = serde_json::to_string(tally).map_err(|e| TallyError::UnknownError(e.to_string()))?;
+    let digest = results_digest(&results);
+    let signature = signer.sign(PURPOSE, signed_message(&tally.election_id, computed_at, &digest).as_bytes());
+
+    Ok(NewSnapshot {
+        election_id: tally.election_id.clone(),
+        results,
+        digest,
+        key_id: signature.key_id,
+        signature: signature.value,
+        computed_at,
+        provisional: tally.provisional,
+    })
+}
+
+/// Checks a stored snapshot against its digest and signature before handing out its tally.
+pub fn open_snapshot(signer: &Signer, stored: StoredSnapshot) -> Result<Snapshot, TallyError> {
+    if results_digest(&stored.results) != stored.digest {
+        return Err(TallyError::SignatureMismatch(format!("snapshot {} does not match its digest", stored.id)));
+    }
+
+    let signature = Signature { key_id: stored.key_id.clone(), value: stored.signature.clone() };
+    signer
+        .verify(PURPOSE, signed_message(&stored.election_id, stored.computed_at, &stored.digest).as_bytes(), &signature)
+        .map_err(|e| TallyError::SignatureMismatch(format!("snapshot {}: {}", stored.id, e)))?;
+
+    let tally: Tally = serde_json::from_str(&stored.results).map_err(|e| TallyError::UnknownError(e.to_string()))?;
+    if tally.election_id != stored.election_id {
+        return Err(TallyError::SignatureMismatch(format!("snapshot {} belongs to another election", stored.id)));
+    }
+
+    Ok(Snapshot {
+        id: stored.id,
+        election_id: stored.election_id,
+        tally,
+        digest: stored.digest,
+        key_id: stored.key_id,
+        signature: stored.signature,
+        computed_at: stored.computed_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::crypto::SignerConfig;
+
+    fn signer() -> Signer {
+        Signer::from_config(&SignerConfig {
+            active_key_id: "t1".to_string(),
+            keys: "t1:AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=".to_string(),
+        }).unwrap()
+    }
+
+    fn stored(snapshot: NewSnapshot) -> StoredSnapshot {
+        StoredSnapshot {
+            id: "s1".to_string(),
+            election_id: snapshot.election_id,
+            results: snapshot.results,
+            digest: snapshot.digest,
+            key_id: snapshot.key_id,
+            signature: snapshot.signature,
+            computed_at: snapshot.computed_at,
+        }
+    }
+
+    #[test]
+    fn test_snapshot_opens_only_as_it_was_signed() {
+        let tally = Tally { election_id: "e1".to_string(), ballots: 3, paper_ballots: 0, blank: 1, invalid: 0, contests: vec![], provisional: false };
+        let sealed = seal_snapshot(&signer(), &tally, Utc::now()).unwrap();
+
+        let opened = open_snapshot(&signer(), stored(sealed.clone())).unwrap();
+        assert_eq!(opened.tally.ballots, 3);
+
+        let mut edited = stored(sealed.clone());
+        edited.results = edited.results.replace("\"ballots\":3", "\"ballots\":4");
+        edited.digest = results_digest(&edited.results);
+        assert!(matches!(open_snapshot(&signer(), edited), Err(TallyError::SignatureMismatch(_))));
+
+        let mut moved = stored(sealed);
+        moved.computed_at += chrono::Duration::seconds(1);
+        assert!(matches!(open_snapshot(&signer(), moved), Err(TallyError::SignatureMismatch(_))));
+    }
+}
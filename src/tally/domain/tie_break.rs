@@ -0,0 +1,163 @@
+use crate::election::domain::TieBreak;
+use crate::tally::domain::entities::{ContestResult, ContestSetup, TieBreakEvidence, TieBreakOutcome};
+use crate::tally::domain::errors::TallyError;
+use chrono::SecondsFormat;
+use sha2::{Digest, Sha256};
+
+/// A candidate's lottery draw: SHA-256 of `seed`, the contest id and the candidate id, each
+/// followed by a NUL byte but the last, in hex. The lowest draw wins. It depends on nothing
+/// but published values, so anyone can redraw it.
+pub fn lottery_draw(seed: &str, contest_id: &str, candidate_id: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(seed.as_bytes());
+    hasher.update([0]);
+    hasher.update(contest_id.as_bytes());
+    hasher.update([0]);
+    hasher.update(candidate_id.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+/// Settles a tie for the most votes in a counted contest with the contest's policy. Returns
+/// nothing when one candidate leads, or when the contest has fewer than two candidates.
+pub fn break_tie(setup: &ContestSetup, result: &ContestResult) -> Result<Option<TieBreakOutcome>, TallyError> {
+    let Some(votes) = result.candidates.iter().map(|c| c.votes).max() else {
+        return Ok(None);
+    };
+    let tied: Vec<_> = result.candidates.iter().filter(|c| c.votes == votes).collect();
+    if tied.len() < 2 {
+        return Ok(None);
+    }
+
+    let mut outcome = TieBreakOutcome {
+        policy: setup.tie_break,
+        tied: tied.iter().map(|c| c.candidate.id.clone()).collect(),
+        votes,
+        seed: None,
+        winner: None,
+        evidence: vec![],
+    };
+
+    match setup.tie_break {
+        TieBreak::Runoff => {}
+        TieBreak::Lottery => {
+            let seed = setup.tie_break_seed.as_deref().ok_or_else(|| {
+                TallyError::Inconsistent(format!("contest {} has a lottery without a seed", setup.id))
+            })?;
+            outcome.evidence = tied
+                .iter()
+                .map(|c| TieBreakEvidence {
+                    candidate_id: c.candidate.id.clone(),
+                    value: lottery_draw(seed, &setup.id, &c.candidate.id),
+                })
+                .collect();
+            outcome.winner = outcome.evidence
+                .iter()
+                .min_by(|a, b| a.value.cmp(&b.value))
+                .map(|e| e.candidate_id.clone());
+            outcome.seed = Some(seed.to_string());
+        }
+        TieBreak::Registration => {
+            outcome.evidence = tied
+                .iter()
+                .map(|c| TieBreakEvidence {
+                    candidate_id: c.candidate.id.clone(),
+                    value: c.candidate.created_at.to_rfc3339_opts(SecondsFormat::Micros, true),
+                })
+                .collect();
+            // Registered at the same instant: the lower vote number, assigned in order, goes first.
+            outcome.winner = tied
+                .iter()
+                .min_by_key(|c| (c.candidate.created_at, c.candidate.vote_number))
+                .map(|c| c.candidate.id.clone());
+        }
+    }
+
+    Ok(Some(outcome))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::candidate::domain::Candidate;
+    use crate::tally::domain::entities::CandidateResult;
+    use chrono::{DateTime, Duration};
+
+    fn candidate(id: &str, vote_number: i32, registered_days: i64) -> Candidate {
+        Candidate {
+            id: id.to_string(),
+            vote_number,
+            president_name: format!("President {}", id),
+            vice_president_name: format!("Vice {}", id),
+            president_nim: "2023010001".to_string(),
+            vice_president_nim: "2023010002".to_string(),
+            president_photo: format!("https://cdn.example/{}-p.jpg", id),
+            vice_president_photo: format!("https://cdn.example/{}-v.jpg", id),
+            status: true,
+            created_by: "committee".to_string(),
+            created_at: DateTime::UNIX_EPOCH + Duration::days(registered_days),
+            updated_at: None,
+        }
+    }
+
+    fn contest(tie_break: TieBreak, seed: Option<&str>, votes: &[i64]) -> (ContestSetup, ContestResult) {
+        // c1 registered last, c3 first.
+        let candidates: Vec<Candidate> = (1..=votes.len())
+            .map(|i| candidate(&format!("c{}", i), i as i32, (votes.len() - i) as i64))
+            .collect();
+        let setup = ContestSetup {
+            id: "president".to_string(),
+            name: "President".to_string(),
+            candidates: candidates.clone(),
+            tie_break,
+            tie_break_seed: seed.map(str::to_string),
+        };
+        let result = ContestResult {
+            contest_id: "president".to_string(),
+            contest_name: "President".to_string(),
+            candidates: candidates
+                .into_iter()
+                .zip(votes)
+                .map(|(candidate, &votes)| CandidateResult { candidate, votes })
+                .collect(),
+            abstain: 0,
+            tie_break: None,
+        };
+        (setup, result)
+    }
+
+    #[test]
+    fn test_only_a_tie_for_the_lead_is_broken() {
+        let (setup, result) = contest(TieBreak::Runoff, None, &[5, 3, 3]);
+        assert_eq!(break_tie(&setup, &result).unwrap(), None);
+
+        let (setup, result) = contest(TieBreak::Runoff, None, &[5, 5, 3]);
+        let outcome = break_tie(&setup, &result).unwrap().unwrap();
+        assert_eq!((outcome.tied, outcome.votes, outcome.winner), (vec!["c1".to_string(), "c2".to_string()], 5, None));
+    }
+
+    #[test]
+    fn test_lottery_draws_from_the_published_seed() {
+        let (setup, result) = contest(TieBreak::Lottery, Some("council-2026"), &[4, 4, 4]);
+        let outcome = break_tie(&setup, &result).unwrap().unwrap();
+
+        let lowest = ["c1", "c2", "c3"]
+            .into_iter()
+            .min_by_key(|id| lottery_draw("council-2026", "president", id))
+            .unwrap();
+        assert_eq!(outcome.winner.as_deref(), Some(lowest));
+        assert_eq!(outcome.seed.as_deref(), Some("council-2026"));
+        assert_eq!(outcome.evidence[1].value, lottery_draw("council-2026", "president", "c2"));
+
+        let (setup, _) = contest(TieBreak::Lottery, None, &[4, 4]);
+        assert!(matches!(break_tie(&setup, &result), Err(TallyError::Inconsistent(_))));
+    }
+
+    #[test]
+    fn test_earlier_registration_wins() {
+        let (setup, result) = contest(TieBreak::Registration, None, &[2, 7, 7]);
+        let outcome = break_tie(&setup, &result).unwrap().unwrap();
+
+        assert_eq!(outcome.winner.as_deref(), Some("c3"));
+        assert_eq!(outcome.evidence[1].value, "1970-01-01T00:00:00.000000Z");
+    }
+}
@@ -0,0 +1,94 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct Contest {
+    pub id: Uuid,
+    pub name: String,
+    pub tie_break: String,
+    pub tie_break_seed: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct ContestCandidate {
+    pub contest_id: Uuid,
+    pub id: Uuid,
+    pub vote_number: i32,
+    pub president_name: String,
+    pub vice_president_name: String,
+    pub president_nim: String,
+    pub vice_president_nim: String,
+    pub president_photo: String,
+    pub vice_president_photo: String,
+    pub status: bool,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// A ballot with its choices side by side; a NULL candidate is an abstention.
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct Ballot {
+    pub blank: bool,
+    pub contest_ids: Vec<Uuid>,
+    pub candidate_ids: Vec<Option<Uuid>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct PaperTotals {
+    pub station_id: Uuid,
+    pub ballots: i64,
+    pub blank: i64,
+    pub invalid: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct PaperCount {
+    pub station_id: Uuid,
+    pub contest_id: Uuid,
+    pub candidate_id: Option<Uuid>,
+    pub votes: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct Snapshot {
+    pub id: Uuid,
+    pub election_id: Uuid,
+    pub results: String,
+    pub digest: String,
+    pub key_id: String,
+    pub signature: String,
+    pub computed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct Recount {
+    pub id: Uuid,
+    pub election_id: Uuid,
+    pub snapshot_id: Uuid,
+    pub report: String,
+    pub digest: String,
+    pub key_id: String,
+    pub signature: String,
+    pub matches: bool,
+    pub recounted_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct CounterDrift {
+    pub election_id: Uuid,
+    pub counter: String,
+    pub stored: i64,
+    pub recomputed: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct Turnout {
+    pub name: String,
+    pub phase: String,
+    pub voted: i64,
+    pub paper_signed_in: i64,
+    pub roll_size: i64,
+}
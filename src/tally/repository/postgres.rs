@@ -0,0 +1,427 @@
+use crate::candidate::domain::Candidate;
+use crate::election::domain::{ElectionPhase, TieBreak};
+use crate::infrastructure::database::postgres::Postgres;
+use crate::paper::domain::{ChoiceCount, StationTotals};
+use crate::tally::domain;
+use crate::tally::domain::Repository;
+use crate::tally::domain::TallyError;
+use crate::tally::repository::model::{Ballot, Contest, ContestCandidate, CounterDrift, PaperCount, PaperTotals, Recount, Snapshot, Turnout};
+use crate::vote::domain::Choice;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+const SNAPSHOT_COLUMNS: &str = "id, election_id, results, digest, key_id, signature, computed_at";
+// The counters as migrations/0019 defines them, counted again from the rows.
+const COUNTER_DRIFT_SQL: &str = r#"
+    WITH live AS (
+        SELECT id FROM elections WHERE phase IN ('voting', 'closed')
+    ), recomputed AS (
+        SELECT p.election_id, 'voted' AS counter, COUNT(*) AS value
+        FROM participations p JOIN live ON live.id = p.election_id GROUP BY 1
+        UNION ALL
+        SELECT b.election_id, 'ballots', COUNT(*)
+        FROM ballots b JOIN live ON live.id = b.election_id WHERE b.counted GROUP BY 1
+        UNION ALL
+        SELECT b.election_id, 'blank', COUNT(*)
+        FROM ballots b JOIN live ON live.id = b.election_id WHERE b.counted AND b.blank GROUP BY 1
+        UNION ALL
+        SELECT b.election_id, 'contest:' || c.contest_id, COUNT(*)
+        FROM ballot_choices c JOIN ballots b ON b.id = c.ballot_id JOIN live ON live.id = b.election_id
+        WHERE b.counted GROUP BY 1, 2
+        UNION ALL
+        SELECT b.election_id, 'abstain:' || c.contest_id, COUNT(*)
+        FROM ballot_choices c JOIN ballots b ON b.id = c.ballot_id JOIN live ON live.id = b.election_id
+        WHERE b.counted AND c.abstain GROUP BY 1, 2
+        UNION ALL
+        SELECT b.election_id, 'candidate:' || c.contest_id || ':' || c.candidate_id, COUNT(*)
+        FROM ballot_choices c JOIN ballots b ON b.id = c.ballot_id JOIN live ON live.id = b.election_id
+        WHERE b.counted AND NOT c.abstain GROUP BY 1, 2
+    ), stored AS (
+        SELECT k.election_id, k.counter, k.value
+        FROM tally_counters k JOIN live ON live.id = k.election_id
+    )
+    SELECT COALESCE(r.election_id, s.election_id) AS election_id
+        , COALESCE(r.counter, s.counter) AS counter
+        , COALESCE(s.value, 0)::BIGINT AS stored
+        , COALESCE(r.value, 0)::BIGINT AS recomputed
+    FROM recomputed r
+    FULL OUTER JOIN stored s ON s.election_id = r.election_id AND s.counter = r.counter
+    WHERE COALESCE(s.value, 0) <> COALESCE(r.value, 0)
+    ORDER BY 1, 2
+    "#;
+
+const RECOUNT_COLUMNS: &str = "id, election_id, snapshot_id, report, digest, key_id, signature, matches, recounted_at";
+
+pub struct PostgresRepo {
+    postgres: sqlx::PgPool,
+}
+impl PostgresRepo {
+    pub async fn new(postgres: Arc<Mutex<Postgres>>) -> anyhow::Result<Self> {
+        let guard = postgres.lock().await;
+        let pool = guard
+            .pool()
+            .ok_or_else(|| anyhow!("DB pool is not initialized"))?;
+        Ok(PostgresRepo { postgres: pool })
+    }
+
+    fn transform_candidate(&self, c: ContestCandidate) -> Candidate {
+        Candidate {
+            id: c.id.to_string(),
+            vote_number: c.vote_number,
+            president_name: c.president_name,
+            vice_president_name: c.vice_president_name,
+            president_nim: c.president_nim,
+            vice_president_nim: c.vice_president_nim,
+            president_photo: c.president_photo,
+            vice_president_photo: c.vice_president_photo,
+            status: c.status,
+            created_by: c.created_by,
+            created_at: c.created_at,
+            updated_at: c.updated_at,
+        }
+    }
+
+    fn transform_snapshot(&self, s: Snapshot) -> domain::StoredSnapshot {
+        domain::StoredSnapshot {
+            id: s.id.to_string(),
+            election_id: s.election_id.to_string(),
+            results: s.results,
+            digest: s.digest,
+            key_id: s.key_id,
+            signature: s.signature,
+            computed_at: s.computed_at,
+        }
+    }
+
+    fn transform_recount(&self, r: Recount) -> domain::StoredRecount {
+        domain::StoredRecount {
+            id: r.id.to_string(),
+            election_id: r.election_id.to_string(),
+            snapshot_id: r.snapshot_id.to_string(),
+            report: r.report,
+            digest: r.digest,
+            key_id: r.key_id,
+            signature: r.signature,
+            matches: r.matches,
+            recounted_at: r.recounted_at,
+        }
+    }
+
+    fn parse_id(id: &str) -> Result<Uuid, TallyError> {
+        Uuid::parse_str(id).map_err(|_| TallyError::NotFound(format!("{} not found", id)))
+    }
+}
+
+#[async_trait]
+impl Repository for PostgresRepo {
+    async fn find_contests(&self, election_id: String) -> Result<Vec<domain::ContestSetup>, TallyError> {
+        let uuid = Self::parse_id(&election_id)?;
+
+        let contests = sqlx::query_as::<_, Contest>(
+            r#"SELECT id, name, tie_break, tie_break_seed FROM contests WHERE election_id = $1 ORDER BY name"#,
+        )
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| TallyError::UnknownError(e.to_string()))?;
+
+        // Inactive candidates too: a candidate withdrawn after ballots were cast still has votes.
+        let candidates = sqlx::query_as::<_, ContestCandidate>(
+            r#"
+        SELECT k.contest_id
+            , k.id
+            , k.vote_number
+            , k.president_name
+            , k.vice_president_name
+            , k.president_nim
+            , k.vice_president_nim
+            , k.president_photo
+            , k.vice_president_photo
+            , k.status
+            , k.created_by
+            , k.created_at
+            , k.updated_at
+        FROM candidates k
+        JOIN contests c ON c.id = k.contest_id
+        WHERE c.election_id = $1
+        ORDER BY k.vote_number
+        "#,
+        )
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| TallyError::UnknownError(e.to_string()))?;
+
+        contests
+            .into_iter()
+            .map(|c| {
+                Ok(domain::ContestSetup {
+                    id: c.id.to_string(),
+                    name: c.name,
+                    candidates: candidates
+                        .iter()
+                        .filter(|k| k.contest_id == c.id)
+                        .map(|k| self.transform_candidate(k.clone()))
+                        .collect(),
+                    tie_break: TieBreak::parse(&c.tie_break)
+                        .ok_or_else(|| TallyError::UnknownError(format!("unknown tie-break policy {}", c.tie_break)))?,
+                    tie_break_seed: c.tie_break_seed,
+                })
+            })
+            .collect()
+    }
+
+    async fn find_counted_ballots(&self, election_id: String) -> Result<Vec<domain::RecordedBallot>, TallyError> {
+        let uuid = Self::parse_id(&election_id)?;
+
+        let ballots = sqlx::query_as::<_, Ballot>(
+            r#"
+        SELECT b.blank
+            , COALESCE(ARRAY_AGG(ch.contest_id ORDER BY ch.contest_id) FILTER (WHERE ch.contest_id IS NOT NULL), '{}') AS contest_ids
+            , COALESCE(ARRAY_AGG(ch.candidate_id ORDER BY ch.contest_id) FILTER (WHERE ch.contest_id IS NOT NULL), '{}') AS candidate_ids
+        FROM ballots b
+        LEFT JOIN ballot_choices ch ON ch.ballot_id = b.id
+        WHERE b.election_id = $1 AND b.counted
+        GROUP BY b.id, b.blank
+        "#,
+        )
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| TallyError::UnknownError(e.to_string()))?;
+
+        Ok(ballots
+            .into_iter()
+            .map(|b| domain::RecordedBallot {
+                blank: b.blank,
+                choices: b.contest_ids
+                    .iter()
+                    .zip(&b.candidate_ids)
+                    .map(|(contest_id, candidate_id)| match candidate_id {
+                        Some(candidate_id) => Choice::candidate(&contest_id.to_string(), &candidate_id.to_string()),
+                        None => Choice::abstention(&contest_id.to_string()),
+                    })
+                    .collect(),
+            })
+            .collect())
+    }
+
+    async fn find_paper_totals(&self, election_id: String) -> Result<Vec<StationTotals>, TallyError> {
+        let uuid = Self::parse_id(&election_id)?;
+
+        // Both entries of a merged station agree, so the first stands for the station.
+        let totals = sqlx::query_as::<_, PaperTotals>(
+            r#"
+        SELECT e.station_id, e.ballots, e.blank, e.invalid
+        FROM paper_entries e
+        JOIN polling_stations s ON s.id = e.station_id
+        WHERE s.election_id = $1 AND s.merged_at IS NOT NULL AND e.slot = 1
+        ORDER BY s.name
+        "#,
+        )
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| TallyError::UnknownError(e.to_string()))?;
+
+        let counts = sqlx::query_as::<_, PaperCount>(
+            r#"
+        SELECT pc.station_id, pc.contest_id, pc.candidate_id, pc.votes
+        FROM paper_entry_counts pc
+        JOIN polling_stations s ON s.id = pc.station_id
+        WHERE s.election_id = $1 AND s.merged_at IS NOT NULL AND pc.slot = 1
+        ORDER BY pc.contest_id, pc.candidate_id NULLS LAST
+        "#,
+        )
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| TallyError::UnknownError(e.to_string()))?;
+
+        Ok(totals
+            .into_iter()
+            .map(|t| StationTotals {
+                ballots: t.ballots,
+                blank: t.blank,
+                invalid: t.invalid,
+                counts: counts
+                    .iter()
+                    .filter(|c| c.station_id == t.station_id)
+                    .map(|c| ChoiceCount {
+                        contest_id: c.contest_id.to_string(),
+                        candidate_id: c.candidate_id.map(|id| id.to_string()),
+                        votes: c.votes,
+                    })
+                    .collect(),
+            })
+            .collect())
+    }
+
+    async fn save_snapshot(&self, snapshot: domain::NewSnapshot) -> Result<domain::StoredSnapshot, TallyError> {
+        let election_id = Self::parse_id(&snapshot.election_id)?;
+        let sql = format!(
+            r#"
+            INSERT INTO tally_snapshots (election_id, results, digest, key_id, signature, computed_at, provisional)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING {}
+            "#,
+            SNAPSHOT_COLUMNS
+        );
+
+        let saved = sqlx::query_as::<_, Snapshot>(&sql)
+            .bind(election_id)
+            .bind(&snapshot.results)
+            .bind(&snapshot.digest)
+            .bind(&snapshot.key_id)
+            .bind(&snapshot.signature)
+            .bind(snapshot.computed_at)
+            .bind(snapshot.provisional)
+            .fetch_one(&self.postgres)
+            .await
+            .map_err(|e| TallyError::UnknownError(e.to_string()))?;
+
+        Ok(self.transform_snapshot(saved))
+    }
+
+    async fn find_turnout(&self, election_id: String) -> Result<domain::Turnout, TallyError> {
+        let uuid = Self::parse_id(&election_id)?;
+
+        let turnout = sqlx::query_as::<_, Turnout>(
+            r#"
+        SELECT e.name
+            , e.phase
+            , COALESCE((SELECT k.value FROM tally_counters k WHERE k.election_id = e.id AND k.counter = 'voted'), 0) AS voted
+            , (SELECT COUNT(*) FROM voting_credentials c
+                JOIN polling_stations s ON s.id = c.station_id
+                WHERE s.election_id = e.id AND c.redeemed_at IS NOT NULL)::BIGINT AS paper_signed_in
+            , (SELECT COUNT(*) FROM voter_rolls r WHERE r.election_id = e.id)::BIGINT AS roll_size
+        FROM elections e
+        WHERE e.id = $1
+        "#,
+        )
+            .bind(uuid)
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| TallyError::UnknownError(e.to_string()))?
+            .ok_or_else(|| TallyError::NotFound(format!("election {} not found", election_id)))?;
+
+        Ok(domain::Turnout {
+            election_id,
+            election_name: turnout.name,
+            phase: ElectionPhase::parse(&turnout.phase)
+                .ok_or_else(|| TallyError::UnknownError(format!("unknown phase {}", turnout.phase)))?,
+            voted: turnout.voted,
+            paper_signed_in: turnout.paper_signed_in,
+            roll_size: turnout.roll_size,
+        })
+    }
+
+    async fn find_latest_snapshot(&self, election_id: String, include_provisional: bool) -> Result<domain::StoredSnapshot, TallyError> {
+        let uuid = Self::parse_id(&election_id)?;
+        let sql = format!(
+            r#"
+            SELECT {} FROM tally_snapshots
+            WHERE election_id = $1 AND (NOT provisional OR $2)
+            ORDER BY computed_at DESC, id
+            LIMIT 1
+            "#,
+            SNAPSHOT_COLUMNS
+        );
+
+        let snapshot = sqlx::query_as::<_, Snapshot>(&sql)
+            .bind(uuid)
+            .bind(include_provisional)
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| TallyError::UnknownError(e.to_string()))?
+            .ok_or_else(|| TallyError::NotFound(format!("election {} has not been tallied", election_id)))?;
+
+        Ok(self.transform_snapshot(snapshot))
+    }
+
+    async fn save_recount(&self, recount: domain::NewRecount) -> Result<domain::StoredRecount, TallyError> {
+        let election_id = Self::parse_id(&recount.election_id)?;
+        let snapshot_id = Self::parse_id(&recount.snapshot_id)?;
+        let sql = format!(
+            r#"
+            INSERT INTO recount_reports (election_id, snapshot_id, report, digest, key_id, signature, matches, recounted_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING {}
+            "#,
+            RECOUNT_COLUMNS
+        );
+
+        let saved = sqlx::query_as::<_, Recount>(&sql)
+            .bind(election_id)
+            .bind(snapshot_id)
+            .bind(&recount.report)
+            .bind(&recount.digest)
+            .bind(&recount.key_id)
+            .bind(&recount.signature)
+            .bind(recount.matches)
+            .bind(recount.recounted_at)
+            .fetch_one(&self.postgres)
+            .await
+            .map_err(|e| TallyError::UnknownError(e.to_string()))?;
+
+        Ok(self.transform_recount(saved))
+    }
+
+    async fn find_recount(&self, id: String) -> Result<domain::StoredRecount, TallyError> {
+        let uuid = Self::parse_id(&id)?;
+        let sql = format!(r#"SELECT {} FROM recount_reports WHERE id = $1"#, RECOUNT_COLUMNS);
+
+        let recount = sqlx::query_as::<_, Recount>(&sql)
+            .bind(uuid)
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| TallyError::UnknownError(e.to_string()))?
+            .ok_or_else(|| TallyError::NotFound(format!("recount {} not found", id)))?;
+
+        Ok(self.transform_recount(recount))
+    }
+
+    async fn find_counter_drift(&self) -> Result<Vec<domain::CounterDrift>, TallyError> {
+        let drift = sqlx::query_as::<_, CounterDrift>(COUNTER_DRIFT_SQL)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| TallyError::UnknownError(e.to_string()))?;
+
+        Ok(drift
+            .into_iter()
+            .map(|d| domain::CounterDrift {
+                election_id: d.election_id.to_string(),
+                counter: d.counter,
+                stored: d.stored,
+                recomputed: d.recomputed,
+            })
+            .collect())
+    }
+
+    async fn correct_counters(&self, drift: Vec<domain::CounterDrift>) -> Result<(), TallyError> {
+        let mut election_ids = Vec::with_capacity(drift.len());
+        for d in &drift {
+            election_ids.push(Self::parse_id(&d.election_id)?);
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO tally_counters (election_id, counter, value)
+            SELECT * FROM UNNEST($1::UUID[], $2::TEXT[], $3::BIGINT[])
+            ORDER BY 1, 2
+            ON CONFLICT (election_id, counter) DO UPDATE SET value = tally_counters.value + EXCLUDED.value
+            "#,
+        )
+            .bind(&election_ids)
+            .bind(drift.iter().map(|d| d.counter.clone()).collect::<Vec<String>>())
+            .bind(drift.iter().map(|d| d.recomputed - d.stored).collect::<Vec<i64>>())
+            .execute(&self.postgres)
+            .await
+            .map_err(|e| TallyError::UnknownError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use crate::election::domain::ElectionPhase;
+use crate::embargo::domain::ResultsAction;
+use crate::embargo::usecase::authorize;
+use crate::infrastructure::crypto::{Certifier, Signer};
+use crate::tally::domain::{self, Repository, ResultsCertificate, TallyError};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<ResultsCertificate, TallyError>;
+}
+
+/// Signs the official results notice of a published election with the certificate key. Only
+/// published results are certified: staff reading them after the close get no certificate.
+pub struct CertifyResultsUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    gate: Arc<dyn authorize::Interactor>,
+    signer: Arc<Signer>,
+    certifier: Arc<Certifier>,
+}
+
+pub struct Request {
+    pub election_id: String,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("election_id", &self.election_id)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> CertifyResultsUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, gate: Arc<dyn authorize::Interactor>, signer: Arc<Signer>, certifier: Arc<Certifier>) -> Self {
+        Self { repository, gate, signer, certifier }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for CertifyResultsUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<ResultsCertificate, TallyError> {
+        let access = self.gate.handle(authorize::Request {
+            election_id: req.election_id.clone(),
+            staff_token: req.staff_token,
+            action: ResultsAction::Read,
+        }).await?;
+        if access.phase != ElectionPhase::Published {
+            return Err(TallyError::Embargoed("certificates are only issued for published results".into()));
+        }
+
+        let stored = self.repository.find_latest_snapshot(req.election_id.clone(), false).await?;
+        let snapshot = domain::open_snapshot(&self.signer, stored)?;
+        let turnout = self.repository.find_turnout(req.election_id).await?;
+
+        domain::certify(&self.certifier, domain::results_export(snapshot, turnout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::election::domain::ElectionPhase;
+    use crate::embargo::domain::{Access, EmbargoError, Role, StaffMember};
+    use crate::embargo::usecase::authorize;
+    use crate::infrastructure::crypto::{Certifier, CertifierConfig, Signer, SignerConfig};
+    use crate::tally::domain::{self, TallyError};
+    use crate::tally::usecase::certify;
+    use crate::tally::usecase::certify::Interactor;
+    use async_trait::async_trait;
+    use std::sync::Arc;
+
+    struct ClosedGate;
+
+    #[async_trait]
+    impl authorize::Interactor for ClosedGate {
+        async fn handle(&self, _: authorize::Request) -> Result<Access, EmbargoError> {
+            Ok(Access {
+                phase: ElectionPhase::Closed,
+                caller: Some(StaffMember { name: "rina".to_string(), role: Role::Committee }),
+                early: false,
+            })
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_results_are_not_certified_before_publishing() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_latest_snapshot().times(0);
+
+        let signer = Arc::new(Signer::from_config(&SignerConfig {
+            active_key_id: "t1".to_string(),
+            keys: "t1:AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=".to_string(),
+        }).unwrap());
+        let certifier = Arc::new(Certifier::from_config(&CertifierConfig {
+            active_key_id: "c1".to_string(),
+            keys: "c1:HyAhIiMkJSYnKCkqKywtLi8wMTIzNDU2Nzg5Ojs8PT4=".to_string(),
+        }).unwrap());
+
+        let uc = certify::CertifyResultsUseCase::new(Arc::new(repo_mock), Arc::new(ClosedGate), signer, certifier);
+        let result = uc.handle(certify::Request { election_id: "e1".to_string(), staff_token: Some("t".to_string()) }).await;
+
+        assert!(matches!(result, Err(TallyError::Embargoed(_))));
+    }
+}
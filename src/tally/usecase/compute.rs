@@ -0,0 +1,162 @@
+use crate::embargo::domain::ResultsAction;
+use crate::embargo::usecase::authorize;
+use crate::infrastructure::crypto::Signer;
+use crate::tally::domain::{self, Repository, Snapshot, TallyError};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Snapshot, TallyError>;
+}
+
+/// Counts an election from what is stored and keeps the result as a signed snapshot. Counts
+/// made before voting closes, under an embargo override, are kept apart as provisional.
+pub struct ComputeTallyUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    gate: Arc<dyn authorize::Interactor>,
+    signer: Arc<Signer>,
+}
+
+pub struct Request {
+    pub election_id: String,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("election_id", &self.election_id)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> ComputeTallyUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, gate: Arc<dyn authorize::Interactor>, signer: Arc<Signer>) -> Self {
+        Self { repository, gate, signer }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for ComputeTallyUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Snapshot, TallyError> {
+        let access = self.gate.handle(authorize::Request {
+            election_id: req.election_id.clone(),
+            staff_token: req.staff_token,
+            action: ResultsAction::Compute,
+        }).await?;
+
+        let contests = self.repository.find_contests(req.election_id.clone()).await?;
+        let ballots = self.repository.find_counted_ballots(req.election_id.clone()).await?;
+        let paper = self.repository.find_paper_totals(req.election_id.clone()).await?;
+
+        let mut tally = domain::count(&req.election_id, &contests, &ballots, &paper)?;
+        tally.provisional = access.early;
+        let sealed = domain::seal_snapshot(&self.signer, &tally, Utc::now())?;
+        let stored = self.repository.save_snapshot(sealed).await?;
+
+        domain::open_snapshot(&self.signer, stored)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::election::domain::ElectionPhase;
+    use crate::embargo::domain::{Access, EmbargoError, ResultsAction, Role, StaffMember};
+    use crate::embargo::usecase::authorize;
+    use crate::infrastructure::crypto::{Signer, SignerConfig};
+    use crate::tally::domain::{self, RecordedBallot, TallyError};
+    use crate::tally::usecase::compute;
+    use crate::tally::usecase::compute::Interactor;
+    use crate::vote::domain::Choice;
+    use async_trait::async_trait;
+    use std::sync::Arc;
+
+    fn signer() -> Arc<Signer> {
+        Arc::new(Signer::from_config(&SignerConfig {
+            active_key_id: "t1".to_string(),
+            keys: "t1:AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=".to_string(),
+        }).unwrap())
+    }
+
+    struct Gate(Result<Access, EmbargoError>);
+
+    #[async_trait]
+    impl authorize::Interactor for Gate {
+        async fn handle(&self, req: authorize::Request) -> Result<Access, EmbargoError> {
+            assert_eq!(req.action, ResultsAction::Compute);
+            self.0.clone()
+        }
+    }
+
+    fn request() -> compute::Request {
+        compute::Request { election_id: "e1".to_string(), staff_token: Some("rina-token".to_string()) }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_compute_refuses_an_embargoed_election() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_counted_ballots().never();
+        repo_mock.expect_save_snapshot().never();
+
+        let gate = Gate(Err(EmbargoError::Embargoed("voting has not closed; election is in phase voting".into())));
+        let uc = compute::ComputeTallyUseCase::new(Arc::new(repo_mock), Arc::new(gate), signer());
+        let result = uc.handle(request()).await;
+
+        assert!(matches!(result, Err(TallyError::Embargoed(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_compute_stores_a_signed_snapshot() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_contests().returning(|_| Ok(vec![domain::ContestSetup {
+            id: "president".to_string(),
+            name: "President".to_string(),
+            candidates: vec![],
+            tie_break: Default::default(),
+            tie_break_seed: None,
+        }]));
+        repo_mock.expect_find_counted_ballots().returning(|_| Ok(vec![
+            RecordedBallot { blank: false, choices: vec![Choice::abstention("president")] },
+            RecordedBallot { blank: true, choices: vec![] },
+        ]));
+        repo_mock.expect_find_paper_totals().returning(|_| Ok(vec![]));
+        repo_mock.expect_save_snapshot()
+            .times(1)
+            .withf(|s| s.election_id == "e1" && s.key_id == "t1" && s.digest == domain::results_digest(&s.results) && s.provisional)
+            .returning(|s| Ok(domain::StoredSnapshot {
+                id: "s1".to_string(),
+                election_id: s.election_id,
+                results: s.results,
+                digest: s.digest,
+                key_id: s.key_id,
+                signature: s.signature,
+                computed_at: s.computed_at,
+            }));
+
+        let gate = Gate(Ok(Access {
+            phase: ElectionPhase::Voting,
+            caller: Some(StaffMember { name: "rina".to_string(), role: Role::Admin }),
+            early: true,
+        }));
+        let uc = compute::ComputeTallyUseCase::new(Arc::new(repo_mock), Arc::new(gate), signer());
+        let snapshot = uc.handle(request()).await.unwrap();
+
+        assert_eq!(snapshot.id, "s1");
+        assert!(snapshot.tally.provisional);
+        assert_eq!((snapshot.tally.ballots, snapshot.tally.blank), (2, 1));
+        assert_eq!(snapshot.tally.contests[0].abstain, 1);
+    }
+}
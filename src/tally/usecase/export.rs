@@ -0,0 +1,135 @@
+use crate::embargo::domain::ResultsAction;
+use crate::embargo::usecase::authorize;
+use crate::infrastructure::crypto::Signer;
+use crate::tally::domain::{self, Repository, ResultsExport, TallyError};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<ResultsExport, TallyError>;
+}
+
+/// Gathers the latest result snapshot, turnout and verdicts for an official results notice.
+/// Reads like any other look at the results, so the embargo applies.
+pub struct ExportResultsUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    gate: Arc<dyn authorize::Interactor>,
+    signer: Arc<Signer>,
+}
+
+pub struct Request {
+    pub election_id: String,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("election_id", &self.election_id)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> ExportResultsUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, gate: Arc<dyn authorize::Interactor>, signer: Arc<Signer>) -> Self {
+        Self { repository, gate, signer }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for ExportResultsUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<ResultsExport, TallyError> {
+        let access = self.gate.handle(authorize::Request {
+            election_id: req.election_id.clone(),
+            staff_token: req.staff_token,
+            action: ResultsAction::Read,
+        }).await?;
+
+        let stored = self.repository.find_latest_snapshot(req.election_id.clone(), access.early).await?;
+        let snapshot = domain::open_snapshot(&self.signer, stored)?;
+        let turnout = self.repository.find_turnout(req.election_id).await?;
+
+        Ok(domain::results_export(snapshot, turnout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::election::domain::ElectionPhase;
+    use crate::embargo::domain::{Access, EmbargoError, Role, StaffMember};
+    use crate::embargo::usecase::authorize;
+    use crate::infrastructure::crypto::{Signer, SignerConfig};
+    use crate::tally::domain::{self, ResultsStatus, Tally, Turnout};
+    use crate::tally::usecase::export;
+    use crate::tally::usecase::export::Interactor;
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use std::sync::Arc;
+
+    fn signer() -> Arc<Signer> {
+        Arc::new(Signer::from_config(&SignerConfig {
+            active_key_id: "t1".to_string(),
+            keys: "t1:AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=".to_string(),
+        }).unwrap())
+    }
+
+    struct EarlyGate;
+
+    #[async_trait]
+    impl authorize::Interactor for EarlyGate {
+        async fn handle(&self, _: authorize::Request) -> Result<Access, EmbargoError> {
+            Ok(Access {
+                phase: ElectionPhase::Voting,
+                caller: Some(StaffMember { name: "rina".to_string(), role: Role::Committee }),
+                early: true,
+            })
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_export_under_an_override_is_marked_provisional() {
+        let counted = Tally { election_id: "e1".to_string(), ballots: 5, paper_ballots: 1, blank: 1, invalid: 1, contests: vec![], provisional: true };
+        let sealed = domain::seal_snapshot(&signer(), &counted, Utc::now()).unwrap();
+
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_latest_snapshot()
+            .times(1)
+            .withf(|id, include_provisional| id == "e1" && *include_provisional)
+            .returning(move |_, _| Ok(domain::StoredSnapshot {
+                id: "s1".to_string(),
+                election_id: sealed.election_id.clone(),
+                results: sealed.results.clone(),
+                digest: sealed.digest.clone(),
+                key_id: sealed.key_id.clone(),
+                signature: sealed.signature.clone(),
+                computed_at: sealed.computed_at,
+            }));
+        repo_mock.expect_find_turnout().returning(|id| Ok(Turnout {
+            election_id: id,
+            election_name: "Student Council 2026".to_string(),
+            phase: ElectionPhase::Voting,
+            voted: 5,
+            paper_signed_in: 1,
+            roll_size: 20,
+        }));
+
+        let uc = export::ExportResultsUseCase::new(Arc::new(repo_mock), Arc::new(EarlyGate), signer());
+        let export = uc.handle(export::Request { election_id: "e1".to_string(), staff_token: Some("t".to_string()) }).await.unwrap();
+
+        assert_eq!(export.status, ResultsStatus::Provisional);
+        assert_eq!(export.valid, 3);
+        assert_eq!(export.snapshot.id, "s1");
+    }
+}
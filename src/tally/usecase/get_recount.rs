@@ -0,0 +1,67 @@
+use crate::embargo::domain::ResultsAction;
+use crate::embargo::usecase::authorize;
+use crate::infrastructure::crypto::Signer;
+use crate::tally::domain::{self, Recount, Repository, TallyError};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Recount, TallyError>;
+}
+
+pub struct GetRecountUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    gate: Arc<dyn authorize::Interactor>,
+    signer: Arc<Signer>,
+}
+
+pub struct Request {
+    pub election_id: String,
+    pub recount_id: String,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("election_id", &self.election_id)
+            .field("recount_id", &self.recount_id)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> GetRecountUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, gate: Arc<dyn authorize::Interactor>, signer: Arc<Signer>) -> Self {
+        Self { repository, gate, signer }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for GetRecountUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Recount, TallyError> {
+        self.gate.handle(authorize::Request {
+            election_id: req.election_id.clone(),
+            staff_token: req.staff_token,
+            action: ResultsAction::Read,
+        }).await?;
+
+        let stored = self.repository.find_recount(req.recount_id.clone()).await?;
+        if stored.election_id != req.election_id {
+            return Err(TallyError::NotFound(format!("recount {} not found", req.recount_id)));
+        }
+
+        domain::open_recount(&self.signer, stored)
+    }
+}
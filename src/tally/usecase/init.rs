@@ -0,0 +1,56 @@
+use std::sync::Arc;
+use std::time::Duration;
+use crate::embargo::usecase::authorize;
+use crate::infrastructure::crypto::{Certifier, Signer};
+use crate::tally::domain::Repository;
+use crate::tally::usecase::{certify, compute, export, get_recount, get_snapshot, public_keys, reconcile, recount, watch};
+use crate::tally::usecase::certify::CertifyResultsUseCase;
+use crate::tally::usecase::compute::ComputeTallyUseCase;
+use crate::tally::usecase::export::ExportResultsUseCase;
+use crate::tally::usecase::get_recount::GetRecountUseCase;
+use crate::tally::usecase::get_snapshot::GetSnapshotUseCase;
+use crate::tally::usecase::public_keys::PublicKeysUseCase;
+use crate::tally::usecase::reconcile::ReconcileUseCase;
+use crate::tally::usecase::recount::RecountUseCase;
+use crate::tally::usecase::watch::WatchUseCase;
+
+
+#[derive(Clone)]
+pub struct UseCase
+{
+    pub compute: Arc<dyn compute::Interactor>,
+    pub get_snapshot: Arc<dyn get_snapshot::Interactor>,
+    pub watch: Arc<dyn watch::Interactor>,
+    pub recount: Arc<dyn recount::Interactor>,
+    pub get_recount: Arc<dyn get_recount::Interactor>,
+    pub export: Arc<dyn export::Interactor>,
+    pub reconcile: Arc<dyn reconcile::Interactor>,
+    pub certify: Arc<dyn certify::Interactor>,
+    pub public_keys: Arc<dyn public_keys::Interactor>,
+}
+
+impl UseCase {
+
+    pub fn new(
+        tally_repo: Arc<dyn Repository + Send + Sync>,
+        gate: Arc<dyn authorize::Interactor>,
+        signer: Arc<Signer>,
+        certifier: Arc<Certifier>,
+        live_debounce: Duration,
+        live_keepalive: Duration,
+    ) -> Self {
+
+        Self {
+            compute: Arc::new(ComputeTallyUseCase::new(tally_repo.clone(), gate.clone(), signer.clone())),
+            get_snapshot: Arc::new(GetSnapshotUseCase::new(tally_repo.clone(), gate.clone(), signer.clone())),
+            recount: Arc::new(RecountUseCase::new(tally_repo.clone(), gate.clone(), signer.clone())),
+            get_recount: Arc::new(GetRecountUseCase::new(tally_repo.clone(), gate.clone(), signer.clone())),
+            export: Arc::new(ExportResultsUseCase::new(tally_repo.clone(), gate.clone(), signer.clone())),
+            certify: Arc::new(CertifyResultsUseCase::new(tally_repo.clone(), gate, signer.clone(), certifier.clone())),
+            public_keys: Arc::new(PublicKeysUseCase::new(certifier)),
+            reconcile: Arc::new(ReconcileUseCase::new(tally_repo.clone())),
+            watch: Arc::new(WatchUseCase::new(tally_repo, signer, live_debounce, live_keepalive)),
+        }
+    }
+
+}
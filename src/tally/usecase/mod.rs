@@ -0,0 +1,12 @@
+mod init;
+pub mod certify;
+pub mod compute;
+pub mod export;
+pub mod get_recount;
+pub mod get_snapshot;
+pub mod public_keys;
+pub mod reconcile;
+pub mod recount;
+pub mod watch;
+
+pub use init::UseCase;
@@ -0,0 +1,31 @@
+use crate::infrastructure::crypto::{Certifier, PublicKey};
+use crate::tally::domain::TallyError;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Vec<PublicKey>, TallyError>;
+}
+
+/// The public half of every configured certificate key, retired ones included, so older
+/// certificates can still be checked.
+pub struct PublicKeysUseCase {
+    certifier: Arc<Certifier>,
+}
+
+#[derive(Debug)]
+pub struct Request;
+
+impl PublicKeysUseCase {
+    pub fn new(certifier: Arc<Certifier>) -> Self {
+        Self { certifier }
+    }
+}
+
+#[async_trait]
+impl Interactor for PublicKeysUseCase {
+    async fn handle(&self, _: Request) -> Result<Vec<PublicKey>, TallyError> {
+        Ok(self.certifier.public_keys())
+    }
+}
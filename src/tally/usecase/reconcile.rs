@@ -0,0 +1,104 @@
+use crate::tally::domain::{CounterDrift, Repository, TallyError};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::MissedTickBehavior;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Vec<CounterDrift>, TallyError>;
+}
+
+/// Checks the running counters against the ballots and participations they count. Any drift
+/// is raised as an alert and then corrected, so live turnout stays right while it is looked into.
+pub struct ReconcileUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+}
+
+#[derive(Debug)]
+pub struct Request;
+
+impl<R: ?Sized + Send + Sync> ReconcileUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for ReconcileUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, _: Request) -> Result<Vec<CounterDrift>, TallyError> {
+        let drift = self.repository.find_counter_drift().await?;
+        if drift.is_empty() {
+            return Ok(drift);
+        }
+
+        for d in &drift {
+            log::warn!(
+                "tally counter {} of election {} drifted: stored {}, recounted {}",
+                d.counter, d.election_id, d.stored, d.recomputed
+            );
+        }
+        self.repository.correct_counters(drift.clone()).await?;
+
+        Ok(drift)
+    }
+}
+
+/// Runs the reconciler once at start and then every `every`, for as long as the process runs.
+pub fn spawn(reconciler: Arc<dyn Interactor>, every: Duration) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticks = tokio::time::interval(every);
+        ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticks.tick().await;
+            if let Err(e) = reconciler.handle(Request).await {
+                log::error!("counter reconciliation failed: {}", e);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tally::domain::{self, CounterDrift};
+    use crate::tally::usecase::reconcile;
+    use crate::tally::usecase::reconcile::Interactor;
+    use std::sync::Arc;
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_drift_is_reported_and_corrected() {
+        let drift = CounterDrift { election_id: "e1".to_string(), counter: "voted".to_string(), stored: 12, recomputed: 11 };
+
+        let mut repo_mock = domain::MockRepository::new();
+        let found = drift.clone();
+        repo_mock.expect_find_counter_drift().returning(move || Ok(vec![found.clone()]));
+        repo_mock.expect_correct_counters()
+            .times(1)
+            .withf(|d| d.len() == 1 && d[0].counter == "voted" && d[0].recomputed == 11)
+            .returning(|_| Ok(()));
+
+        let uc = reconcile::ReconcileUseCase::new(Arc::new(repo_mock));
+
+        assert_eq!(uc.handle(reconcile::Request).await.unwrap(), vec![drift]);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_counters_in_step_are_left_alone() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_counter_drift().returning(|| Ok(vec![]));
+        repo_mock.expect_correct_counters().never();
+
+        let uc = reconcile::ReconcileUseCase::new(Arc::new(repo_mock));
+
+        assert!(uc.handle(reconcile::Request).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,159 @@
+use crate::embargo::domain::ResultsAction;
+use crate::embargo::usecase::authorize;
+use crate::infrastructure::crypto::Signer;
+use crate::tally::domain::{self, Recount, Repository, TallyError};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Recount, TallyError>;
+}
+
+/// Counts an election again from its stored ballots, compares it with the latest result
+/// snapshot, and keeps the comparison as a signed recount report.
+pub struct RecountUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    gate: Arc<dyn authorize::Interactor>,
+    signer: Arc<Signer>,
+}
+
+pub struct Request {
+    pub election_id: String,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("election_id", &self.election_id)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> RecountUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, gate: Arc<dyn authorize::Interactor>, signer: Arc<Signer>) -> Self {
+        Self { repository, gate, signer }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for RecountUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Recount, TallyError> {
+        let access = self.gate.handle(authorize::Request {
+            election_id: req.election_id.clone(),
+            staff_token: req.staff_token,
+            action: ResultsAction::Compute,
+        }).await?;
+
+        // A snapshot that no longer matches its signature stops the recount here.
+        let stored = self.repository.find_latest_snapshot(req.election_id.clone(), false).await?;
+        let published = domain::open_snapshot(&self.signer, stored)?;
+
+        let contests = self.repository.find_contests(req.election_id.clone()).await?;
+        let ballots = self.repository.find_counted_ballots(req.election_id.clone()).await?;
+        let paper = self.repository.find_paper_totals(req.election_id.clone()).await?;
+        let recount = domain::count(&req.election_id, &contests, &ballots, &paper)?;
+
+        let report = domain::compare(&published, recount, access.caller.map(|m| m.name), Utc::now());
+        let sealed = domain::seal_recount(&self.signer, &report)?;
+        let saved = self.repository.save_recount(sealed).await?;
+
+        domain::open_recount(&self.signer, saved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::election::domain::ElectionPhase;
+    use crate::embargo::domain::{Access, EmbargoError, Role, StaffMember};
+    use crate::embargo::usecase::authorize;
+    use crate::infrastructure::crypto::{Signer, SignerConfig};
+    use crate::tally::domain::{self, RecordedBallot, Tally};
+    use crate::tally::usecase::recount;
+    use crate::tally::usecase::recount::Interactor;
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use std::sync::Arc;
+
+    fn signer() -> Arc<Signer> {
+        Arc::new(Signer::from_config(&SignerConfig {
+            active_key_id: "t1".to_string(),
+            keys: "t1:AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=".to_string(),
+        }).unwrap())
+    }
+
+    struct Gate;
+
+    #[async_trait]
+    impl authorize::Interactor for Gate {
+        async fn handle(&self, _: authorize::Request) -> Result<Access, EmbargoError> {
+            Ok(Access {
+                phase: ElectionPhase::Published,
+                caller: Some(StaffMember { name: "rina".to_string(), role: Role::Committee }),
+                early: false,
+            })
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_recount_reports_a_difference_from_the_snapshot() {
+        let published = Tally { election_id: "e1".to_string(), ballots: 3, paper_ballots: 0, blank: 3, invalid: 0, contests: vec![], provisional: false };
+        let sealed = domain::seal_snapshot(&signer(), &published, Utc::now()).unwrap();
+
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_latest_snapshot()
+            .withf(|_, include_provisional| !include_provisional)
+            .returning(move |_, _| Ok(domain::StoredSnapshot {
+                id: "s1".to_string(),
+                election_id: sealed.election_id.clone(),
+                results: sealed.results.clone(),
+                digest: sealed.digest.clone(),
+                key_id: sealed.key_id.clone(),
+                signature: sealed.signature.clone(),
+                computed_at: sealed.computed_at,
+            }));
+        repo_mock.expect_find_contests().returning(|_| Ok(vec![]));
+        repo_mock.expect_find_counted_ballots().returning(|_| Ok(vec![
+            RecordedBallot { blank: true, choices: vec![] },
+            RecordedBallot { blank: true, choices: vec![] },
+        ]));
+        repo_mock.expect_find_paper_totals().returning(|_| Ok(vec![]));
+        repo_mock.expect_save_recount()
+            .times(1)
+            .withf(|r| r.snapshot_id == "s1" && !r.matches)
+            .returning(|r| Ok(domain::StoredRecount {
+                id: "r1".to_string(),
+                election_id: r.election_id,
+                snapshot_id: r.snapshot_id,
+                report: r.report,
+                digest: r.digest,
+                key_id: r.key_id,
+                signature: r.signature,
+                matches: r.matches,
+                recounted_at: r.recounted_at,
+            }));
+
+        let uc = recount::RecountUseCase::new(Arc::new(repo_mock), Arc::new(Gate), signer());
+        let recount = uc.handle(recount::Request { election_id: "e1".to_string(), staff_token: Some("t".to_string()) }).await.unwrap();
+
+        assert_eq!(recount.report.requested_by.as_deref(), Some("rina"));
+        let items: Vec<(&str, Option<i64>, Option<i64>)> = recount.report.totals
+            .iter()
+            .map(|d| (d.item.as_str(), d.published, d.recounted))
+            .collect();
+        assert_eq!(items, vec![("ballots", Some(3), Some(2)), ("blank", Some(3), Some(2))]);
+    }
+}
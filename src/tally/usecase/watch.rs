@@ -0,0 +1,356 @@
+use crate::election::domain::ElectionPhase;
+use crate::infrastructure::crypto::Signer;
+use crate::tally::domain::{self, LiveEvent, Repository, TallyError, Turnout};
+use async_trait::async_trait;
+use futures_util::Stream;
+use std::collections::{HashMap, VecDeque};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::sync::Mutex;
+
+pub type LiveStream = Pin<Box<dyn Stream<Item = LiveEvent> + Send>>;
+
+/// Changes a slow screen may fall behind by before it is handed the latest state instead.
+const CHANNEL_CAPACITY: usize = 16;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<LiveStream, TallyError>;
+}
+
+/// Follows an election for a results screen: turnout while voting, and the latest signed
+/// snapshot once the election is published.
+pub struct WatchUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    signer: Arc<Signer>,
+    debounce: Duration,
+    keepalive: Duration,
+    /// One channel per election that has a screen open, each fed by a single poller.
+    channels: Arc<Mutex<HashMap<String, Arc<Channel>>>>,
+}
+
+#[derive(Debug)]
+pub struct Request {
+    pub election_id: String,
+}
+
+impl<R: ?Sized + Send + Sync> WatchUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, signer: Arc<Signer>, debounce: Duration, keepalive: Duration) -> Self {
+        Self { repository, signer, debounce, keepalive, channels: Arc::new(Mutex::new(HashMap::new())) }
+    }
+}
+
+/// Looks at one election. Only what changed since the last look is returned, so a burst of
+/// ballots reaches the screens as a single push.
+struct Poller<R: ?Sized> {
+    repository: Arc<R>,
+    signer: Arc<Signer>,
+    election_id: String,
+    turnout: Option<Turnout>,
+    snapshot_id: Option<String>,
+}
+
+impl<R: ?Sized + Repository> Poller<R> {
+    async fn poll(&mut self) -> Result<Vec<LiveEvent>, TallyError> {
+        let mut changes = vec![];
+        let turnout = self.repository.find_turnout(self.election_id.clone()).await?;
+        let published = turnout.phase == ElectionPhase::Published;
+
+        if self.turnout.as_ref() != Some(&turnout) {
+            self.turnout = Some(turnout.clone());
+            changes.push(LiveEvent::Turnout(turnout));
+        }
+
+        if !published {
+            return Ok(changes);
+        }
+
+        match self.repository.find_latest_snapshot(self.election_id.clone(), false).await {
+            Ok(stored) if self.snapshot_id.as_deref() != Some(stored.id.as_str()) => {
+                // Remembered before it is checked, so a snapshot that fails is reported once.
+                self.snapshot_id = Some(stored.id.clone());
+                let snapshot = domain::open_snapshot(&self.signer, stored)?;
+                changes.push(LiveEvent::Results(snapshot));
+                Ok(changes)
+            }
+            Ok(_) | Err(TallyError::NotFound(_)) => Ok(changes),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// What the screens of one election share: the latest state for screens that join or fall
+/// behind, and the channel every change is announced on.
+struct Channel {
+    events: broadcast::Sender<LiveEvent>,
+    latest: std::sync::Mutex<(Option<LiveEvent>, Option<LiveEvent>)>,
+}
+
+impl Channel {
+    fn new() -> Self {
+        Channel { events: broadcast::channel(CHANNEL_CAPACITY).0, latest: std::sync::Mutex::new((None, None)) }
+    }
+
+    fn publish(&self, event: LiveEvent) {
+        if let Ok(mut latest) = self.latest.lock() {
+            match &event {
+                LiveEvent::Turnout(_) => latest.0 = Some(event.clone()),
+                LiveEvent::Results(_) => latest.1 = Some(event.clone()),
+                LiveEvent::KeepAlive => {}
+            }
+        }
+        // No screen listening is fine; the poller notices and stops on its next tick.
+        let _ = self.events.send(event);
+    }
+
+    fn latest(&self) -> Vec<LiveEvent> {
+        match self.latest.lock() {
+            Ok(latest) => latest.0.iter().chain(latest.1.iter()).cloned().collect(),
+            Err(_) => vec![],
+        }
+    }
+}
+
+/// Polls once per `debounce` for as long as any screen is listening, then drops the channel.
+async fn run<R: ?Sized + Repository>(
+    mut poller: Poller<R>,
+    channel: Arc<Channel>,
+    channels: Arc<Mutex<HashMap<String, Arc<Channel>>>>,
+    debounce: Duration,
+) {
+    loop {
+        tokio::time::sleep(debounce).await;
+
+        // Checked under the lock new screens subscribe under, so none can join a channel
+        // that is going away.
+        {
+            let mut channels = channels.lock().await;
+            if channel.events.receiver_count() == 0 {
+                channels.remove(&poller.election_id);
+                return;
+            }
+        }
+
+        match poller.poll().await {
+            Ok(changes) => changes.into_iter().for_each(|event| channel.publish(event)),
+            Err(e) => log::error!("failed to poll live results of election {}: {}", poller.election_id, e),
+        }
+    }
+}
+
+/// State of one stream. Events are deduplicated, since a screen that joins or falls behind
+/// is handed the latest state and may then see the same change announced.
+struct Feed {
+    channel: Arc<Channel>,
+    events: broadcast::Receiver<LiveEvent>,
+    keepalive: Duration,
+    pending: VecDeque<LiveEvent>,
+    turnout: Option<Turnout>,
+    snapshot_id: Option<String>,
+}
+
+impl Feed {
+    fn is_new(&mut self, event: &LiveEvent) -> bool {
+        match event {
+            LiveEvent::Turnout(t) if self.turnout.as_ref() == Some(t) => false,
+            LiveEvent::Turnout(t) => {
+                self.turnout = Some(t.clone());
+                true
+            }
+            LiveEvent::Results(s) if self.snapshot_id.as_deref() == Some(s.id.as_str()) => false,
+            LiveEvent::Results(s) => {
+                self.snapshot_id = Some(s.id.clone());
+                true
+            }
+            LiveEvent::KeepAlive => true,
+        }
+    }
+
+    async fn next(mut self) -> Option<(LiveEvent, Self)> {
+        loop {
+            while let Some(event) = self.pending.pop_front() {
+                if self.is_new(&event) {
+                    return Some((event, self));
+                }
+            }
+
+            match tokio::time::timeout(self.keepalive, self.events.recv()).await {
+                Ok(Ok(event)) => self.pending.push_back(event),
+                Ok(Err(RecvError::Lagged(_))) => self.pending.extend(self.channel.latest()),
+                Ok(Err(RecvError::Closed)) => return None,
+                Err(_) => return Some((LiveEvent::KeepAlive, self)),
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync + 'static> Interactor for WatchUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<LiveStream, TallyError> {
+        let mut channels = self.channels.lock().await;
+
+        let channel = match channels.get(&req.election_id) {
+            Some(channel) => channel.clone(),
+            None => {
+                let mut poller = Poller {
+                    repository: self.repository.clone(),
+                    signer: self.signer.clone(),
+                    election_id: req.election_id.clone(),
+                    turnout: None,
+                    snapshot_id: None,
+                };
+
+                // The first look happens here, so an unknown election fails before the stream opens.
+                let changes = poller.poll().await?;
+                let channel = Arc::new(Channel::new());
+                changes.into_iter().for_each(|event| channel.publish(event));
+
+                channels.insert(req.election_id.clone(), channel.clone());
+                tokio::spawn(run(poller, channel.clone(), self.channels.clone(), self.debounce));
+                channel
+            }
+        };
+
+        let feed = Feed {
+            events: channel.events.subscribe(),
+            pending: channel.latest().into(),
+            channel,
+            keepalive: self.keepalive,
+            turnout: None,
+            snapshot_id: None,
+        };
+        drop(channels);
+
+        Ok(Box::pin(futures_util::stream::unfold(feed, Feed::next)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::election::domain::ElectionPhase;
+    use crate::infrastructure::crypto::{Signer, SignerConfig};
+    use crate::tally::domain::{self, LiveEvent, Tally, TallyError, Turnout};
+    use crate::tally::usecase::watch;
+    use crate::tally::usecase::watch::Interactor;
+    use futures_util::StreamExt;
+    use std::sync::atomic::{AtomicI64, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn signer() -> Arc<Signer> {
+        Arc::new(Signer::from_config(&SignerConfig {
+            active_key_id: "t1".to_string(),
+            keys: "t1:AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=".to_string(),
+        }).unwrap())
+    }
+
+    fn turnout(phase: ElectionPhase, voted: i64) -> Turnout {
+        Turnout { election_id: "e1".to_string(), election_name: "Student Council 2026".to_string(), phase, voted, paper_signed_in: 0, roll_size: 10 }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_watch_pushes_turnout_only_when_it_changes() {
+        // 1, 1, 1, then 2 from the fourth look on.
+        let looks = Arc::new(AtomicI64::new(0));
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_turnout().returning(move |_| {
+            let n = looks.fetch_add(1, Ordering::SeqCst);
+            Ok(turnout(ElectionPhase::Voting, if n < 3 { 1 } else { 2 }))
+        });
+        repo_mock.expect_find_latest_snapshot().never();
+
+        let uc = watch::WatchUseCase::new(Arc::new(repo_mock), signer(), Duration::from_millis(1), Duration::from_secs(3600));
+        let events: Vec<LiveEvent> = uc.handle(watch::Request { election_id: "e1".to_string() }).await.unwrap()
+            .take(2)
+            .collect()
+            .await;
+
+        let voted: Vec<i64> = events.iter().map(|e| match e {
+            LiveEvent::Turnout(t) => t.voted,
+            other => panic!("unexpected {:?}", other),
+        }).collect();
+        assert_eq!(voted, vec![1, 2]);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_watch_sends_results_once_published_then_keeps_alive() {
+        let tally = Tally { election_id: "e1".to_string(), ballots: 4, paper_ballots: 0, blank: 0, invalid: 0, contests: vec![], provisional: false };
+        let sealed = domain::seal_snapshot(&signer(), &tally, chrono::Utc::now()).unwrap();
+
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_turnout().returning(|_| Ok(turnout(ElectionPhase::Published, 4)));
+        repo_mock.expect_find_latest_snapshot().returning(move |_, _| Ok(domain::StoredSnapshot {
+            id: "s1".to_string(),
+            election_id: sealed.election_id.clone(),
+            results: sealed.results.clone(),
+            digest: sealed.digest.clone(),
+            key_id: sealed.key_id.clone(),
+            signature: sealed.signature.clone(),
+            computed_at: sealed.computed_at,
+        }));
+
+        let uc = watch::WatchUseCase::new(Arc::new(repo_mock), signer(), Duration::from_millis(1), Duration::ZERO);
+        let events: Vec<LiveEvent> = uc.handle(watch::Request { election_id: "e1".to_string() }).await.unwrap()
+            .take(3)
+            .collect()
+            .await;
+
+        assert!(matches!(&events[0], LiveEvent::Turnout(t) if t.voted == 4));
+        assert!(matches!(&events[1], LiveEvent::Results(s) if s.id == "s1" && s.tally.ballots == 4));
+        assert!(matches!(&events[2], LiveEvent::KeepAlive));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_watch_fails_before_streaming_for_an_unknown_election() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_turnout().returning(|id| Err(TallyError::NotFound(format!("election {} not found", id))));
+
+        let uc = watch::WatchUseCase::new(Arc::new(repo_mock), signer(), Duration::from_millis(1), Duration::ZERO);
+
+        assert!(matches!(uc.handle(watch::Request { election_id: "e1".to_string() }).await, Err(TallyError::NotFound(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_screens_of_one_election_share_a_poller() {
+        // 1, 1, 1, then 2 from the fourth look on.
+        let looks = Arc::new(AtomicI64::new(0));
+        let counted = looks.clone();
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_turnout().returning(move |_| {
+            let n = counted.fetch_add(1, Ordering::SeqCst);
+            Ok(turnout(ElectionPhase::Voting, if n < 3 { 1 } else { 2 }))
+        });
+
+        let uc = watch::WatchUseCase::new(Arc::new(repo_mock), signer(), Duration::from_millis(20), Duration::from_secs(3600));
+        let first = uc.handle(watch::Request { election_id: "e1".to_string() }).await.unwrap();
+        let second = uc.handle(watch::Request { election_id: "e1".to_string() }).await.unwrap();
+        assert_eq!(looks.load(Ordering::SeqCst), 1);
+
+        let voted = |events: Vec<LiveEvent>| -> Vec<i64> {
+            events.iter().map(|e| match e {
+                LiveEvent::Turnout(t) => t.voted,
+                other => panic!("unexpected {:?}", other),
+            }).collect()
+        };
+        let (first, second) = futures_util::join!(first.take(2).collect::<Vec<_>>(), second.take(2).collect::<Vec<_>>());
+        assert_eq!(voted(first), vec![1, 2]);
+        assert_eq!(voted(second), vec![1, 2]);
+        // One look per tick, however many screens are open.
+        assert_eq!(looks.load(Ordering::SeqCst), 4);
+
+        // With the screens gone, the poller stops and the election is forgotten.
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert!(uc.channels.lock().await.is_empty());
+    }
+}
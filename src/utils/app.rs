@@ -1,9 +1,109 @@
 use serde::{Deserialize, Serialize};
+use crate::analytics;
 use crate::candidate;
+use crate::credential;
+use crate::election;
+use crate::embargo;
+use crate::group;
+use crate::paper;
+use crate::privacy;
+use crate::rla;
+use crate::tally;
+use crate::vote;
+use crate::voter;
 
 #[derive(Clone)]
 pub struct AppHandlerData {
-    pub candidate_uc: candidate::usecase::UseCase
+    pub candidate_uc: candidate::usecase::UseCase,
+    pub election_uc: election::usecase::UseCase,
+    pub voter_uc: voter::usecase::UseCase,
+    pub credential_uc: credential::usecase::UseCase,
+    pub privacy_uc: privacy::usecase::UseCase,
+    pub group_uc: group::usecase::UseCase,
+    pub vote_uc: vote::usecase::UseCase,
+    pub paper_uc: paper::usecase::UseCase,
+    pub tally_uc: tally::usecase::UseCase,
+    pub embargo_uc: embargo::usecase::UseCase,
+    pub analytics_uc: analytics::usecase::UseCase,
+    pub rla_uc: rla::usecase::UseCase,
+}
+
+#[cfg(test)]
+impl AppHandlerData {
+    /// Handler data backed by repository mocks without expectations; tests replace the
+    /// usecase they exercise and any unexpected call into the rest panics.
+    pub fn mocked() -> Self {
+        use crate::infrastructure::crypto;
+        use std::sync::Arc;
+
+        let embargo_uc = embargo::usecase::UseCase::new(
+            Arc::new(embargo::domain::MockRepository::new()),
+            Arc::new(election::domain::MockRepository::new()),
+            Arc::new(embargo::domain::StaffRoster::default()),
+            chrono::Duration::minutes(15),
+        );
+
+        AppHandlerData {
+            candidate_uc: candidate::usecase::UseCase::new(Arc::new(candidate::domain::MockRepository::new())),
+            election_uc: election::usecase::UseCase::new(
+                Arc::new(election::domain::MockRepository::new()),
+                Arc::new(embargo::domain::StaffRoster::default()),
+            ),
+            voter_uc: voter::usecase::UseCase::new(
+                Arc::new(voter::domain::MockRepository::new()),
+                Arc::new(embargo::domain::StaffRoster::default()),
+            ),
+            credential_uc: credential::usecase::UseCase::new(
+                Arc::new(credential::domain::MockRepository::new()),
+                Arc::new(election::domain::MockRepository::new()),
+                Arc::new(embargo::domain::StaffRoster::default()),
+                chrono::Duration::minutes(15),
+            ),
+            privacy_uc: privacy::usecase::UseCase::new(
+                Arc::new(privacy::domain::MockRepository::new()),
+                Arc::new(embargo::domain::StaffRoster::default()),
+                chrono::Duration::days(365),
+            ),
+            group_uc: group::usecase::UseCase::new(
+                Arc::new(group::domain::MockRepository::new()),
+                Arc::new(embargo::domain::StaffRoster::default()),
+            ),
+            vote_uc: vote::usecase::UseCase::new(
+                Arc::new(vote::domain::MockRepository::new()),
+                Arc::new(credential::domain::MockRepository::new()),
+                Arc::new(election::domain::MockRepository::new()),
+                chrono::Duration::minutes(5),
+                chrono::Duration::minutes(2),
+                Arc::new(crypto::Pepper::from_base64("AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=").unwrap()),
+                Arc::new(embargo::domain::StaffRoster::default()),
+            ),
+            paper_uc: paper::usecase::UseCase::new(
+                Arc::new(paper::domain::MockRepository::new()),
+                Arc::new(election::domain::MockRepository::new()),
+                Arc::new(embargo::domain::StaffRoster::default()),
+            ),
+            tally_uc: tally::usecase::UseCase::new(
+                Arc::new(tally::domain::MockRepository::new()),
+                embargo_uc.authorize.clone(),
+                Arc::new(crypto::Signer::from_config(&crypto::SignerConfig {
+                    active_key_id: "t1".to_string(),
+                    keys: "t1:AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=".to_string(),
+                }).unwrap()),
+                Arc::new(crypto::Certifier::from_config(&crypto::CertifierConfig {
+                    active_key_id: "c1".to_string(),
+                    keys: "c1:HyAhIiMkJSYnKCkqKywtLi8wMTIzNDU2Nzg5Ojs8PT4=".to_string(),
+                }).unwrap()),
+                std::time::Duration::from_secs(2),
+                std::time::Duration::from_secs(15),
+            ),
+            rla_uc: rla::usecase::UseCase::new(
+                Arc::new(rla::domain::MockRepository::new()),
+                embargo_uc.authorize.clone(),
+            ),
+            embargo_uc,
+            analytics_uc: analytics::usecase::UseCase::new(Arc::new(analytics::domain::MockRepository::new()), 10),
+        }
+    }
 }
 
 
@@ -13,4 +113,4 @@ pub struct HttpBaseResponse<T> {
     pub message: String,
     pub error_code: String,
     pub data: Option<T>
-}
\ No newline at end of file
+}
@@ -0,0 +1,116 @@
+use std::borrow::Cow;
+
+/// Quotes a field when it contains a separator, quote or line break (RFC 4180).
+pub fn escape_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Prefixes a field that a spreadsheet would read as a formula with `'`, so a name like
+/// `=HYPERLINK(...)` is shown as text instead of being evaluated.
+pub fn neutralize_formula(field: &str) -> Cow<'_, str> {
+    if field.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        Cow::Owned(format!("'{}", field))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+/// Writes one row of exported fields, neutralized and quoted as needed.
+pub fn write_row<S: AsRef<str>>(out: &mut String, fields: &[S]) {
+    let row: Vec<String> = fields
+        .iter()
+        .map(|f| escape_field(&neutralize_formula(f.as_ref())))
+        .collect();
+    out.push_str(&row.join(","));
+    out.push_str("\r\n");
+}
+
+/// Parses CSV text into rows of fields (RFC 4180: quoted fields, doubled quotes,
+/// CRLF or LF line endings). Blank lines are skipped.
+pub fn parse(input: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                row.push(std::mem::take(&mut field));
+                if row.iter().any(|f| !f.is_empty()) {
+                    rows.push(std::mem::take(&mut row));
+                }
+                row.clear();
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err("unterminated quoted field".into());
+    }
+    row.push(field);
+    if row.iter().any(|f| !f.is_empty()) {
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let rows = parse("nim,name\r\n123,\"Doe, \"\"Johnny\"\"\"\n\n456,Ann\n").unwrap();
+
+        assert_eq!(rows, vec![
+            vec!["nim".to_string(), "name".to_string()],
+            vec!["123".to_string(), "Doe, \"Johnny\"".to_string()],
+            vec!["456".to_string(), "Ann".to_string()],
+        ]);
+        assert!(parse("\"open").is_err());
+    }
+
+    #[test]
+    fn test_write_row() {
+        let mut out = String::new();
+        write_row(&mut out, &["nim", "name"]);
+        write_row(&mut out, &["123", "Doe, \"Johnny\""]);
+
+        assert_eq!(out, "nim,name\r\n123,\"Doe, \"\"Johnny\"\"\"\r\n");
+    }
+
+    #[test]
+    fn test_write_row_neutralizes_formulas() {
+        let mut out = String::new();
+        write_row(&mut out, &["=1+1", "+62 811", "-2", "@SUM(A1)", "Budi = Ani", "7K3M-Q9TX"]);
+
+        assert_eq!(out, "'=1+1,'+62 811,'-2,'@SUM(A1),Budi = Ani,7K3M-Q9TX\r\n");
+
+        let mut out = String::new();
+        write_row(&mut out, &["=HYPERLINK(\"http://x\",\"a,b\")"]);
+        assert_eq!(out, "\"'=HYPERLINK(\"\"http://x\"\",\"\"a,b\"\")\"\r\n");
+    }
+}
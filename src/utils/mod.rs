@@ -1,2 +1,4 @@
 pub mod response;
-pub mod app;
\ No newline at end of file
+pub mod app;
+pub mod csv;
+pub mod html;
@@ -0,0 +1,341 @@
+use crate::vote::delivery::http::errors::error_response;
+use crate::vote::domain::{Choice, MIN_IDEMPOTENCY_KEY_LEN, VoteError};
+use crate::vote::usecase::cast::*;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct CastBallotBody {
+    #[serde(default)]
+    pub(crate) blank: bool,
+    #[serde(default)]
+    pub(crate) choices: Vec<Choice>,
+}
+
+/// Extracts the voting session token from `Authorization: Bearer <token>`.
+pub(crate) fn bearer_token(req: &HttpRequest) -> Result<String, VoteError> {
+    req.headers()
+        .get("Authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .ok_or_else(|| VoteError::Unauthorized("missing voting session token".into()))
+}
+
+/// Reads the optional `Idempotency-Key` header: 16 to 255 visible ASCII characters.
+pub(crate) fn idempotency_key(req: &HttpRequest) -> Result<Option<String>, VoteError> {
+    let Some(value) = req.headers().get("Idempotency-Key") else {
+        return Ok(None);
+    };
+
+    match value.to_str() {
+        Ok(key) if (MIN_IDEMPOTENCY_KEY_LEN..=255).contains(&key.len()) && key.bytes().all(|b| b.is_ascii_graphic()) => {
+            Ok(Some(key.to_string()))
+        }
+        _ => Err(VoteError::InvalidBallot(
+            "Idempotency-Key must be 16 to 255 visible ASCII characters, e.g. a random UUID".into(),
+        )),
+    }
+}
+
+pub async fn cast_ballot(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<CastBallotBody>,
+) -> HttpResponse {
+
+    let session_token = match bearer_token(&http_request) {
+        Ok(token) => token,
+        Err(e) => return error_response(&e),
+    };
+
+    let idempotency_key = match idempotency_key(&http_request) {
+        Ok(key) => key,
+        Err(e) => return error_response(&e),
+    };
+
+    let body = body.into_inner();
+    let request = Request {
+        election_id: path.into_inner(),
+        session_token,
+        blank: body.blank,
+        choices: body.choices,
+        idempotency_key,
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.vote_uc.cast.handle(request).await {
+        Ok(result) => HttpResponse::Created()
+            .insert_header(("Idempotent-Replayed", if result.replayed { "true" } else { "false" }))
+            .json(response::success(
+                Some(result.ballot),
+                "Successfully processed ballot".into(),
+            )),
+        Err(e) => error_response(&e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cast_ballot;
+    use actix_web::{App, http::StatusCode, test, web};
+    use async_trait::async_trait;
+    use serde_json::Value;
+    use std::sync::Arc;
+    use crate::vote::domain::{CastBallot, VoteError};
+    use crate::vote::usecase::cast::{Interactor, Request, Response};
+    use crate::utils::app;
+
+    fn init_app_data(cast_impl: Arc<dyn Interactor>) -> web::Data<app::AppHandlerData> {
+        let mut vote_uc = app::AppHandlerData::mocked().vote_uc;
+        vote_uc.cast = cast_impl;
+
+        web::Data::new(app::AppHandlerData { vote_uc, ..app::AppHandlerData::mocked() })
+    }
+
+    fn body() -> Value {
+        serde_json::json!({ "choices": [{ "contest_id": "president", "candidate_id": "c1" }] })
+    }
+
+    #[actix_rt::test]
+    async fn test_cast_ballot_created() {
+        struct MockCast;
+
+        #[async_trait]
+        impl Interactor for MockCast {
+            async fn handle(&self, req: Request) -> Result<Response, VoteError> {
+                assert_eq!(req.session_token, "token");
+                assert_eq!(req.choices.len(), 1);
+                Ok(Response {
+                    ballot: CastBallot {
+                        ballot_id: "b1".to_string(),
+                        election_id: req.election_id,
+                        cast_at: chrono::Utc::now(),
+                        receipt: "3F9K-T2QW-8XNB-M4RD-7HVC".to_string(),
+                    },
+                    replayed: req.idempotency_key.is_some(),
+                })
+            }
+        }
+
+        let app = test::init_service(
+            App::new()
+                .app_data(init_app_data(Arc::new(MockCast)))
+                .route("/elections/{id}/ballots", web::post().to(cast_ballot)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/elections/e1/ballots")
+            .insert_header(("Authorization", "Bearer token"))
+            .set_json(body())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["ballot_id"], "b1");
+    }
+
+    #[actix_rt::test]
+    async fn test_cast_ballot_requires_session_token() {
+        struct MockCast;
+
+        #[async_trait]
+        impl Interactor for MockCast {
+            async fn handle(&self, _: Request) -> Result<Response, VoteError> {
+                panic!("usecase must not be reached without a token");
+            }
+        }
+
+        let app = test::init_service(
+            App::new()
+                .app_data(init_app_data(Arc::new(MockCast)))
+                .route("/elections/{id}/ballots", web::post().to(cast_ballot)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/elections/e1/ballots")
+            .set_json(body())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["error_code"], "VOTE_UNAUTHORIZED");
+    }
+
+    #[actix_rt::test]
+    async fn test_cast_ballot_busy_asks_to_retry() {
+        struct MockCast;
+
+        #[async_trait]
+        impl Interactor for MockCast {
+            async fn handle(&self, _: Request) -> Result<Response, VoteError> {
+                Err(VoteError::Busy("write queue is full".into()))
+            }
+        }
+
+        let app = test::init_service(
+            App::new()
+                .app_data(init_app_data(Arc::new(MockCast)))
+                .route("/elections/{id}/ballots", web::post().to(cast_ballot)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/elections/e1/ballots")
+            .insert_header(("Authorization", "Bearer token"))
+            .set_json(body())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(resp.headers().get("Retry-After").unwrap(), "1");
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["error_code"], "VOTE_BUSY");
+    }
+
+    #[actix_rt::test]
+    async fn test_cast_ballot_tells_not_yet_open_from_closed() {
+        struct MockCast(bool);
+
+        #[async_trait]
+        impl Interactor for MockCast {
+            async fn handle(&self, _: Request) -> Result<Response, VoteError> {
+                if self.0 {
+                    Err(VoteError::VotingClosed("voting closed at 2026-03-04T17:00:00+00:00".into()))
+                } else {
+                    Err(VoteError::NotYetOpen("voting opens at 2026-03-04T08:00:00+00:00".into()))
+                }
+            }
+        }
+
+        for (closed, code) in [(false, "VOTING_NOT_YET_OPEN"), (true, "VOTING_CLOSED")] {
+            let app = test::init_service(
+                App::new()
+                    .app_data(init_app_data(Arc::new(MockCast(closed))))
+                    .route("/elections/{id}/ballots", web::post().to(cast_ballot)),
+            )
+            .await;
+
+            let req = test::TestRequest::post()
+                .uri("/elections/e1/ballots")
+                .insert_header(("Authorization", "Bearer token"))
+                .set_json(body())
+                .to_request();
+            let resp = test::call_service(&app, req).await;
+
+            assert_eq!(resp.status(), StatusCode::CONFLICT);
+            let body: Value = test::read_body_json(resp).await;
+            assert_eq!(body["error_code"], code);
+        }
+    }
+
+    #[actix_rt::test]
+    async fn test_cast_ballot_already_voted() {
+        struct MockCast;
+
+        #[async_trait]
+        impl Interactor for MockCast {
+            async fn handle(&self, _: Request) -> Result<Response, VoteError> {
+                Err(VoteError::AlreadyVoted("a ballot has already been cast by this voter".into()))
+            }
+        }
+
+        let app = test::init_service(
+            App::new()
+                .app_data(init_app_data(Arc::new(MockCast)))
+                .route("/elections/{id}/ballots", web::post().to(cast_ballot)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/elections/e1/ballots")
+            .insert_header(("Authorization", "Bearer token"))
+            .set_json(body())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["error_code"], "VOTE_ALREADY_VOTED");
+    }
+
+    #[actix_rt::test]
+    async fn test_cast_ballot_replays_with_idempotency_key() {
+        struct MockCast;
+
+        #[async_trait]
+        impl Interactor for MockCast {
+            async fn handle(&self, req: Request) -> Result<Response, VoteError> {
+                assert_eq!(req.idempotency_key.as_deref(), Some("8e03978e-40d5-43e8-bc93-6894a57f9324"));
+                Ok(Response {
+                    ballot: CastBallot {
+                        ballot_id: "b1".to_string(),
+                        election_id: req.election_id,
+                        cast_at: chrono::Utc::now(),
+                        receipt: "3F9K-T2QW-8XNB-M4RD-7HVC".to_string(),
+                    },
+                    replayed: true,
+                })
+            }
+        }
+
+        let app = test::init_service(
+            App::new()
+                .app_data(init_app_data(Arc::new(MockCast)))
+                .route("/elections/{id}/ballots", web::post().to(cast_ballot)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/elections/e1/ballots")
+            .insert_header(("Authorization", "Bearer token"))
+            .insert_header(("Idempotency-Key", "8e03978e-40d5-43e8-bc93-6894a57f9324"))
+            .set_json(body())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        assert_eq!(resp.headers().get("Idempotent-Replayed").unwrap(), "true");
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["data"]["ballot_id"], "b1");
+    }
+
+    #[actix_rt::test]
+    async fn test_cast_ballot_idempotency_conflict() {
+        struct MockCast;
+
+        #[async_trait]
+        impl Interactor for MockCast {
+            async fn handle(&self, _: Request) -> Result<Response, VoteError> {
+                Err(VoteError::IdempotencyConflict("idempotency key was already used with a different ballot".into()))
+            }
+        }
+
+        let app = test::init_service(
+            App::new()
+                .app_data(init_app_data(Arc::new(MockCast)))
+                .route("/elections/{id}/ballots", web::post().to(cast_ballot)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/elections/e1/ballots")
+            .insert_header(("Authorization", "Bearer token"))
+            .insert_header(("Idempotency-Key", "8e03978e-40d5-43e8-bc93-6894a57f9324"))
+            .set_json(body())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["error_code"], "VOTE_IDEMPOTENCY_CONFLICT");
+    }
+}
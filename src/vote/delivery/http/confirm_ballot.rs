@@ -0,0 +1,87 @@
+use crate::vote::delivery::http::cast_ballot::{bearer_token, idempotency_key};
+use crate::vote::delivery::http::errors::error_response;
+use crate::vote::usecase::confirm::*;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+
+pub async fn confirm_ballot(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+
+    let session_token = match bearer_token(&http_request) {
+        Ok(token) => token,
+        Err(e) => return error_response(&e),
+    };
+
+    let idempotency_key = match idempotency_key(&http_request) {
+        Ok(key) => key,
+        Err(e) => return error_response(&e),
+    };
+
+    let (election_id, draft_id) = path.into_inner();
+    let request = Request { election_id, draft_id, session_token, idempotency_key };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.vote_uc.confirm.handle(request).await {
+        Ok(result) => HttpResponse::Created()
+            .insert_header(("Idempotent-Replayed", if result.replayed { "true" } else { "false" }))
+            .json(response::success(
+                Some(result.ballot),
+                "Successfully processed ballot".into(),
+            )),
+        Err(e) => error_response(&e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::confirm_ballot;
+    use actix_web::{App, http::StatusCode, test, web};
+    use async_trait::async_trait;
+    use serde_json::Value;
+    use std::sync::Arc;
+    use crate::vote::domain::VoteError;
+    use crate::vote::usecase::cast::Response;
+    use crate::vote::usecase::confirm::{Interactor, Request};
+    use crate::utils::app;
+
+    fn init_app_data(confirm_impl: Arc<dyn Interactor>) -> web::Data<app::AppHandlerData> {
+        let mut vote_uc = app::AppHandlerData::mocked().vote_uc;
+        vote_uc.confirm = confirm_impl;
+
+        web::Data::new(app::AppHandlerData { vote_uc, ..app::AppHandlerData::mocked() })
+    }
+
+    #[actix_rt::test]
+    async fn test_confirm_ballot_expired_draft() {
+        struct MockConfirm;
+
+        #[async_trait]
+        impl Interactor for MockConfirm {
+            async fn handle(&self, req: Request) -> Result<Response, VoteError> {
+                assert_eq!((req.election_id.as_str(), req.draft_id.as_str()), ("e1", "d1"));
+                Err(VoteError::DraftExpired("ballot draft d1 has expired".into()))
+            }
+        }
+
+        let app = test::init_service(
+            App::new()
+                .app_data(init_app_data(Arc::new(MockConfirm)))
+                .route("/elections/{id}/ballot-drafts/{draft_id}/confirm", web::post().to(confirm_ballot)),
+        )
+        .await;
+
+        let req = test::TestRequest::post()
+            .uri("/elections/e1/ballot-drafts/d1/confirm")
+            .insert_header(("Authorization", "Bearer token"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::GONE);
+        let body: Value = test::read_body_json(resp).await;
+        assert_eq!(body["error_code"], "VOTE_DRAFT_EXPIRED");
+    }
+}
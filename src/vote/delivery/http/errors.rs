@@ -0,0 +1,76 @@
+use crate::vote::domain::VoteError;
+use crate::utils::response;
+use actix_web::HttpResponse;
+
+pub fn error_response(e: &VoteError) -> HttpResponse {
+    println!("Error: {}", e);
+    match e {
+        VoteError::Unauthorized(msg) => HttpResponse::Unauthorized().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "VOTE_UNAUTHORIZED".into(),
+        )),
+        VoteError::Forbidden(msg) => HttpResponse::Forbidden().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "VOTE_FORBIDDEN".into(),
+        )),
+        VoteError::NotFound(msg) => HttpResponse::NotFound().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "VOTE_NOT_FOUND".into(),
+        )),
+        VoteError::NotYetOpen(msg) => HttpResponse::Conflict().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "VOTING_NOT_YET_OPEN".into(),
+        )),
+        VoteError::VotingClosed(msg) => HttpResponse::Conflict().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "VOTING_CLOSED".into(),
+        )),
+        VoteError::InvalidBallot(msg) => HttpResponse::BadRequest().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "VOTE_INVALID_BALLOT".into(),
+        )),
+        VoteError::AlreadyVoted(msg) => HttpResponse::Conflict().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "VOTE_ALREADY_VOTED".into(),
+        )),
+        VoteError::InvalidReceipt(msg) => HttpResponse::BadRequest().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "VOTE_INVALID_RECEIPT".into(),
+        )),
+        VoteError::IdempotencyConflict(msg) => HttpResponse::UnprocessableEntity().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "VOTE_IDEMPOTENCY_CONFLICT".into(),
+        )),
+        VoteError::DraftExpired(msg) => HttpResponse::Gone().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "VOTE_DRAFT_EXPIRED".into(),
+        )),
+        VoteError::DraftConfirmed(msg) => HttpResponse::Conflict().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "VOTE_DRAFT_CONFIRMED".into(),
+        )),
+        VoteError::Busy(msg) => HttpResponse::ServiceUnavailable()
+            .insert_header(("Retry-After", "1"))
+            .json(response::error::<()>(
+                None,
+                msg.clone(),
+                "VOTE_BUSY".into(),
+            )),
+        VoteError::UnknownError(_) => HttpResponse::InternalServerError().json(response::error::<()>(
+            None,
+            "failed process data".into(),
+            "-1".into(),
+        )),
+    }
+}
@@ -0,0 +1,16 @@
+use actix_web::web;
+use crate::vote::delivery::http::cast_ballot::cast_ballot;
+use crate::vote::delivery::http::confirm_ballot::confirm_ballot;
+use crate::vote::delivery::http::list_late_rejections::list_late_rejections;
+use crate::vote::delivery::http::review_ballot::{get_ballot_review, review_ballot};
+use crate::vote::delivery::http::verify_receipt::verify_receipt;
+
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/elections/{id}/ballots", web::post().to(cast_ballot))
+        .route("/elections/{id}/ballot-drafts", web::post().to(review_ballot))
+        .route("/elections/{id}/ballot-drafts/{draft_id}", web::get().to(get_ballot_review))
+        .route("/elections/{id}/ballot-drafts/{draft_id}/confirm", web::post().to(confirm_ballot))
+        .route("/elections/{id}/receipts/{receipt}", web::get().to(verify_receipt))
+        .route("/elections/{id}/late-ballots", web::get().to(list_late_rejections));
+}
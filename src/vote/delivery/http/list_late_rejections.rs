@@ -0,0 +1,25 @@
+use crate::vote::delivery::http::errors::error_response;
+use crate::vote::usecase::list_late_rejections::*;
+use crate::embargo::delivery::http::staff_token;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+
+/// For the audit report: every ballot refused because voting had closed.
+pub async fn list_late_rejections(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+
+    let request = Request { election_id: path.into_inner(), staff_token: staff_token(&http_request) };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.vote_uc.list_late_rejections.handle(request).await {
+        Ok(rejections) => HttpResponse::Ok().json(response::success(
+            Some(rejections),
+            "Successfully processed late ballots".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
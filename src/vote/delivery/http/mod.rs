@@ -0,0 +1,14 @@
+mod handler;
+mod errors;
+mod cast_ballot;
+mod confirm_ballot;
+mod list_late_rejections;
+mod review_ballot;
+mod verify_receipt;
+
+pub use cast_ballot::*;
+pub use confirm_ballot::*;
+pub use list_late_rejections::*;
+pub use review_ballot::*;
+pub use verify_receipt::*;
+pub use handler::*;
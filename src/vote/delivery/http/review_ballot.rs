@@ -0,0 +1,62 @@
+use crate::vote::delivery::http::cast_ballot::{CastBallotBody, bearer_token};
+use crate::vote::delivery::http::errors::error_response;
+use crate::vote::usecase::{get_review, review};
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+
+/// Takes the same body as casting, but only holds the ballot for review.
+pub async fn review_ballot(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<String>,
+    body: web::Json<CastBallotBody>,
+) -> HttpResponse {
+
+    let session_token = match bearer_token(&http_request) {
+        Ok(token) => token,
+        Err(e) => return error_response(&e),
+    };
+
+    let body = body.into_inner();
+    let request = review::Request {
+        election_id: path.into_inner(),
+        session_token,
+        blank: body.blank,
+        choices: body.choices,
+    };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.vote_uc.review.handle(request).await {
+        Ok(review) => HttpResponse::Created().json(response::success(
+            Some(review),
+            "Successfully processed ballot review".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
+
+pub async fn get_ballot_review(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+
+    let session_token = match bearer_token(&http_request) {
+        Ok(token) => token,
+        Err(e) => return error_response(&e),
+    };
+
+    let (election_id, draft_id) = path.into_inner();
+    let request = get_review::Request { election_id, draft_id, session_token };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.vote_uc.get_review.handle(request).await {
+        Ok(review) => HttpResponse::Ok().json(response::success(
+            Some(review),
+            "Successfully processed ballot review".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
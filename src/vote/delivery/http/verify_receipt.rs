@@ -0,0 +1,21 @@
+use crate::vote::delivery::http::errors::error_response;
+use crate::vote::usecase::verify_receipt::*;
+use crate::utils::{app, response};
+use actix_web::{HttpResponse, web};
+
+/// Public: anyone holding a receipt may check it, no session needed.
+pub async fn verify_receipt(handler: web::Data<app::AppHandlerData>, path: web::Path<(String, String)>) -> HttpResponse {
+
+    let (election_id, receipt) = path.into_inner();
+    let request = Request { election_id, receipt };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.vote_uc.verify_receipt.handle(request).await {
+        Ok(check) => HttpResponse::Ok().json(response::success(
+            Some(check),
+            "Successfully processed receipt".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
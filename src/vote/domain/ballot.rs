@@ -0,0 +1,69 @@
+use crate::vote::domain::entities::Choice;
+use chrono::{DateTime, DurationRound, TimeDelta, Utc};
+use rand::Rng;
+use uuid::Builder;
+
+/// Ballot ids are random rather than drawn from the database, so the id is known before the
+/// participation is recorded and carries no ordering.
+pub fn generate_ballot_id() -> String {
+    let mut bytes = [0u8; 16];
+    rand::rng().fill(&mut bytes[..]);
+    Builder::from_random_bytes(bytes).into_uuid().to_string()
+}
+
+/// Truncates a ballot's time to the hour, so it cannot be matched to the exact time of a
+/// participation. Every ballot cast within the same hour looks alike.
+pub fn coarsen_cast_time(at: DateTime<Utc>) -> DateTime<Utc> {
+    at.duration_trunc(TimeDelta::hours(1)).unwrap_or(at)
+}
+
+/// What a ballot says, one line per contest in contest order, independent of how the client
+/// ordered its choices. Receipts and idempotency fingerprints are both computed over this.
+pub fn canonical_ballot(blank: bool, choices: &[Choice]) -> Vec<String> {
+    if blank {
+        return vec!["blank".to_string()];
+    }
+
+    let mut lines: Vec<String> = choices
+        .iter()
+        .map(|c| match &c.candidate_id {
+            Some(candidate_id) => format!("{}:{}", c.contest_id, candidate_id),
+            None => format!("{}/abstain", c.contest_id),
+        })
+        .collect();
+    lines.sort();
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Timelike};
+
+    #[test]
+    fn test_cast_time_is_truncated_to_the_hour() {
+        let at = Utc.with_ymd_and_hms(2026, 3, 4, 10, 47, 31).unwrap();
+        let coarse = coarsen_cast_time(at);
+
+        assert_eq!(coarse, Utc.with_ymd_and_hms(2026, 3, 4, 10, 0, 0).unwrap());
+        assert_eq!(coarsen_cast_time(at + TimeDelta::minutes(12)), coarse);
+        assert_eq!(coarse.nanosecond(), 0);
+    }
+
+    #[test]
+    fn test_canonical_ballot_tells_blank_abstain_and_candidates_apart() {
+        let ballot = canonical_ballot(false, &[Choice::candidate("senate", "c3"), Choice::abstention("president")]);
+
+        assert_eq!(ballot, vec!["president/abstain".to_string(), "senate:c3".to_string()]);
+        assert_eq!(canonical_ballot(true, &[]), vec!["blank".to_string()]);
+        assert!(canonical_ballot(false, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_ballot_ids_are_random_uuids() {
+        let id = generate_ballot_id();
+
+        assert_eq!(uuid::Uuid::parse_str(&id).unwrap().get_version_num(), 4);
+        assert_ne!(id, generate_ballot_id());
+    }
+}
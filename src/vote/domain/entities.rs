@@ -0,0 +1,148 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+
+/// One contest on a ballot: either a candidate, or an explicit abstention with no candidate.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Choice {
+    pub contest_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub candidate_id: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub abstain: bool,
+}
+
+impl Choice {
+    pub fn candidate(contest_id: &str, candidate_id: &str) -> Self {
+        Choice { contest_id: contest_id.to_string(), candidate_id: Some(candidate_id.to_string()), abstain: false }
+    }
+
+    pub fn abstention(contest_id: &str) -> Self {
+        Choice { contest_id: contest_id.to_string(), candidate_id: None, abstain: true }
+    }
+}
+
+/// What was voted. Deliberately carries nothing about the voter, and `cast_at` only to
+/// the hour, so it cannot be matched against a `Participation`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct NewBallot {
+    pub id: String,
+    pub election_id: String,
+    /// Counts toward turnout but toward no contest; carries no choices.
+    pub blank: bool,
+    pub choices: Vec<Choice>,
+    pub cast_at: DateTime<Utc>,
+    pub receipt_nonce: String,
+    pub receipt: String,
+    /// Set in elections that allow re-voting: the same for every ballot cast with one
+    /// credential, so a later ballot can retire the earlier one without naming the voter.
+    pub revote_tag: Option<String>,
+}
+
+/// Who voted, and when.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Participation {
+    pub election_id: String,
+    pub voter_id: String,
+    pub voted_at: DateTime<Utc>,
+    pub idempotency: Option<SealedResult>,
+    /// The voter may already have taken part; that is not an error.
+    pub revote: bool,
+}
+
+/// A cast result stored for idempotent retries, encrypted under the client's
+/// `Idempotency-Key`. Only a hash of the key is kept, so the server cannot open it alone.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SealedResult {
+    pub key_hash: String,
+    pub sealed: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CastBallot {
+    pub ballot_id: String,
+    pub election_id: String,
+    pub cast_at: DateTime<Utc>,
+    /// Lets the voter check later that their ballot is among those counted.
+    pub receipt: String,
+}
+
+/// The plaintext inside a `SealedResult`. `request_hash` fingerprints the election and
+/// choices sent, so a key reused for a different ballot can be told apart.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct StoredResult {
+    pub request_hash: String,
+    pub ballot: CastBallot,
+}
+
+/// What a voter picked, held in a draft until they confirm it.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Selection {
+    pub blank: bool,
+    pub choices: Vec<Choice>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct NewDraft {
+    pub election_id: String,
+    pub session_hash: String,
+    /// The `Selection`, sealed under the session token; see `seal_selection`.
+    pub sealed_selection: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Draft {
+    pub id: String,
+    pub election_id: String,
+    pub session_hash: String,
+    pub sealed_selection: String,
+    pub expires_at: DateTime<Utc>,
+    /// Cast already; a draft is confirmed at most once.
+    pub confirmed: bool,
+}
+
+/// The names a voter checks their pick against.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BallotCandidate {
+    pub id: String,
+    pub vote_number: i32,
+    pub president_name: String,
+    pub vice_president_name: String,
+}
+
+/// One contest of the summary shown for review: the candidate picked, or an abstention.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ReviewLine {
+    pub contest_id: String,
+    pub contest_name: String,
+    pub candidate: Option<BallotCandidate>,
+    pub abstain: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BallotReview {
+    pub draft_id: String,
+    pub election_id: String,
+    pub blank: bool,
+    pub lines: Vec<ReviewLine>,
+    pub expires_at: DateTime<Utc>,
+    pub confirmed: bool,
+}
+
+/// A ballot refused because voting had closed. Says who tried and when, never what they chose.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LateRejection {
+    pub election_id: String,
+    pub voter_id: String,
+    pub session_started_at: DateTime<Utc>,
+    pub voting_ends_at: Option<DateTime<Utc>>,
+    pub rejected_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ReceiptCheck {
+    pub election_id: String,
+    pub receipt: String,
+    pub included: bool,
+}
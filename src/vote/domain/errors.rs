@@ -0,0 +1,77 @@
+use crate::credential::domain::CredentialError;
+use crate::election::domain::ElectionError;
+use crate::embargo::domain::StaffDenied;
+use crate::infrastructure::batch::BatchError;
+use thiserror::Error;
+
+
+#[derive(Debug, Error)]
+#[derive(Clone)]
+pub enum VoteError {
+    #[error("VoteError::Unauthorized: {0}")]
+    Unauthorized(String),
+    #[error("VoteError::Forbidden: {0}")]
+    Forbidden(String),
+    #[error("VoteError::NotFound: {0}")]
+    NotFound(String),
+    #[error("VoteError::NotYetOpen: {0}")]
+    NotYetOpen(String),
+    #[error("VoteError::VotingClosed: {0}")]
+    VotingClosed(String),
+    #[error("VoteError::InvalidBallot: {0}")]
+    InvalidBallot(String),
+    #[error("VoteError::AlreadyVoted: {0}")]
+    AlreadyVoted(String),
+    #[error("VoteError::InvalidReceipt: {0}")]
+    InvalidReceipt(String),
+    #[error("VoteError::IdempotencyConflict: {0}")]
+    IdempotencyConflict(String),
+    /// The ballot draft is past its expiry; the voter has to review their ballot again.
+    #[error("VoteError::DraftExpired: {0}")]
+    DraftExpired(String),
+    #[error("VoteError::DraftConfirmed: {0}")]
+    DraftConfirmed(String),
+    /// The write queue is full; the ballot was not taken and can be sent again shortly.
+    #[error("VoteError::Busy: {0}")]
+    Busy(String),
+    #[error("VoteError::UnknownError: {0}")]
+    UnknownError(String),
+}
+
+impl From<ElectionError> for VoteError {
+    fn from(e: ElectionError) -> Self {
+        match e {
+            ElectionError::NotFound(msg) => VoteError::NotFound(msg),
+            other => VoteError::UnknownError(other.to_string()),
+        }
+    }
+}
+
+impl From<CredentialError> for VoteError {
+    fn from(e: CredentialError) -> Self {
+        match e {
+            CredentialError::NotFound(_) | CredentialError::InvalidCode(_) => {
+                VoteError::Unauthorized("voting session is missing, unknown or expired".into())
+            }
+            other => VoteError::UnknownError(other.to_string()),
+        }
+    }
+}
+
+impl From<StaffDenied> for VoteError {
+    fn from(e: StaffDenied) -> Self {
+        match e {
+            StaffDenied::Unauthenticated(msg) => VoteError::Unauthorized(msg),
+            StaffDenied::NotAllowed(msg) => VoteError::Forbidden(msg),
+        }
+    }
+}
+
+impl From<BatchError> for VoteError {
+    fn from(e: BatchError) -> Self {
+        match e {
+            BatchError::Full(msg) => VoteError::Busy(msg),
+            BatchError::Closed(msg) => VoteError::UnknownError(msg),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+mod ballot;
+mod entities;
+mod errors;
+mod receipt;
+mod repository;
+mod review;
+mod seal;
+mod window;
+
+pub use ballot::*;
+pub use entities::*;
+pub use receipt::*;
+pub use repository::*;
+pub use review::*;
+pub use errors::*;
+pub use seal::*;
+pub use window::*;
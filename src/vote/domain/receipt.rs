@@ -0,0 +1,102 @@
+use crate::vote::domain::ballot::canonical_ballot;
+use crate::vote::domain::entities::Choice;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+/// Crockford base32, as for voting codes, so receipts can be read out over the phone.
+const ALPHABET: &[u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const RECEIPT_LEN: usize = 20;
+const GROUP_LEN: usize = 4;
+const NONCE_BYTES: usize = 32;
+
+/// Random salt stored with the ballot. Without it the few possible ballots of an election
+/// could be hashed one by one and matched against a receipt, revealing the choice.
+pub fn generate_receipt_nonce() -> String {
+    let mut bytes = [0u8; NONCE_BYTES];
+    rand::rng().fill(&mut bytes[..]);
+    hex::encode(bytes)
+}
+
+/// Receipt code for a stored ballot: the first 100 bits of a SHA-256 over the nonce, the
+/// election and the canonical ballot, e.g. `3F9K-T2QW-8XNB-M4RD-7HVC`.
+pub fn receipt_code(nonce: &str, election_id: &str, blank: bool, choices: &[Choice]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(b"receipt-v1\n");
+    hasher.update(nonce.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(election_id.as_bytes());
+    for line in canonical_ballot(blank, choices) {
+        hasher.update(b"\n");
+        hasher.update(line.as_bytes());
+    }
+    let digest = hasher.finalize();
+
+    let symbols: Vec<u8> = (0..RECEIPT_LEN)
+        .map(|i| {
+            let bit = i * 5;
+            let window = u16::from_be_bytes([digest[bit / 8], digest[bit / 8 + 1]]);
+            ALPHABET[((window >> (11 - bit % 8)) & 0x1f) as usize]
+        })
+        .collect();
+    group(&symbols)
+}
+
+/// Brings a receipt typed by a voter into its stored form, or `None` if it cannot be one.
+pub fn normalize_receipt(receipt: &str) -> Option<String> {
+    let symbols: String = receipt
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .map(|c| match c.to_ascii_uppercase() {
+            'O' => '0',
+            'I' | 'L' => '1',
+            other => other,
+        })
+        .collect();
+
+    if symbols.len() != RECEIPT_LEN || !symbols.bytes().all(|c| ALPHABET.contains(&c)) {
+        return None;
+    }
+    Some(group(symbols.as_bytes()))
+}
+
+fn group(symbols: &[u8]) -> String {
+    symbols
+        .chunks(GROUP_LEN)
+        .map(|g| String::from_utf8_lossy(g).into_owned())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn choice(contest_id: &str, candidate_id: &str) -> Choice {
+        Choice::candidate(contest_id, candidate_id)
+    }
+
+    #[test]
+    fn test_receipt_depends_on_nonce_and_ballot() {
+        let ballot = [choice("president", "c1"), choice("senate", "c3")];
+        let receipt = receipt_code("n1", "e1", false, &ballot);
+
+        assert_eq!(receipt.len(), 24);
+        assert_eq!(receipt, receipt_code("n1", "e1", false, &[choice("senate", "c3"), choice("president", "c1")]));
+        assert_ne!(receipt, receipt_code("n2", "e1", false, &ballot));
+        assert_ne!(receipt, receipt_code("n1", "e1", false, &[choice("president", "c2"), choice("senate", "c3")]));
+        assert_ne!(receipt, receipt_code("n1", "e1", false, &[Choice::abstention("president"), choice("senate", "c3")]));
+        assert_ne!(receipt_code("n1", "e1", true, &[]), receipt_code("n1", "e1", false, &[]));
+        assert_ne!(generate_receipt_nonce(), generate_receipt_nonce());
+    }
+
+    #[test]
+    fn test_normalize_receipt() {
+        let receipt = receipt_code("n1", "e1", false, &[choice("president", "c1")]);
+
+        assert_eq!(normalize_receipt(&receipt).as_deref(), Some(receipt.as_str()));
+        assert_eq!(normalize_receipt(&receipt.replace('-', " ").to_lowercase()).as_deref(), Some(receipt.as_str()));
+        assert_eq!(normalize_receipt("0O1I-L000-0000-0000-0000").as_deref(), Some("0011-1000-0000-0000-0000"));
+        assert_eq!(normalize_receipt("3F9K-T2QW"), None);
+        assert_eq!(normalize_receipt("3F9K-T2QW-8XNB-M4RD-7HVU"), None);
+    }
+}
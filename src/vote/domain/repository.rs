@@ -0,0 +1,54 @@
+use crate::vote::domain::entities::{BallotCandidate, Draft, LateRejection, NewBallot, NewDraft, Participation};
+use crate::vote::domain::errors::VoteError;
+use async_trait::async_trait;
+use mockall::automock;
+
+/// Participations and ballots are written in separate transactions. Rows written together
+/// share a transaction id, which would be enough to join them. Both are queued and written
+/// in batches; when the queue is full they fail with `Busy` without writing anything.
+#[automock]
+#[async_trait]
+pub trait Repository: Send + Sync {
+    /// Records that the voter has voted, together with the sealed idempotency result if any,
+    /// and returns whether this is their first participation. Unless `revote` is set, fails
+    /// with `AlreadyVoted` when the voter already took part, including when two casts race.
+    async fn record_participation(&self, participation: Participation) -> Result<bool, VoteError>;
+
+    /// Undoes a first `record_participation` when the ballot could not be stored.
+    async fn withdraw_participation(&self, election_id: String, voter_id: String) -> Result<(), VoteError>;
+
+    /// Drops one idempotency record, for a re-vote whose ballot could not be stored.
+    async fn forget_idempotency_key(&self, voter_id: String, key_hash: String) -> Result<(), VoteError>;
+
+    /// Stores the ballot and its choices, in one transaction with the other ballots of its
+    /// batch. A ballot with a `revote_tag` stops earlier ballots with the same tag from
+    /// being counted.
+    async fn store_ballot(&self, ballot: NewBallot) -> Result<(), VoteError>;
+
+    /// The sealed result stored under this voter's idempotency key hash, if any.
+    async fn find_sealed_result(&self, voter_id: String, key_hash: String) -> Result<Option<String>, VoteError>;
+
+    /// Whether a counted ballot of the election carries this receipt.
+    async fn receipt_included(&self, election_id: String, receipt: String) -> Result<bool, VoteError>;
+
+    /// Stores a ballot draft for review, clearing out drafts that have expired.
+    async fn create_draft(&self, draft: NewDraft) -> Result<Draft, VoteError>;
+
+    /// The draft with this id opened under this session, expired or not; `NotFound` otherwise.
+    async fn find_draft(&self, id: String, session_hash: String) -> Result<Draft, VoteError>;
+
+    /// Marks an unconfirmed, unexpired draft as confirmed. Returns false when it was not, so of
+    /// two confirmations racing only one goes on to cast.
+    async fn claim_draft(&self, id: String) -> Result<bool, VoteError>;
+
+    /// Gives a claimed draft back, for a confirmation whose ballot could not be cast.
+    async fn release_draft(&self, id: String) -> Result<(), VoteError>;
+
+    async fn record_late_rejection(&self, rejection: LateRejection) -> Result<(), VoteError>;
+
+    /// Late rejections of the election, oldest first.
+    async fn find_late_rejections(&self, election_id: String) -> Result<Vec<LateRejection>, VoteError>;
+
+    /// Candidates by id, for the review summary. Unknown ids are left out.
+    async fn find_candidates(&self, ids: Vec<String>) -> Result<Vec<BallotCandidate>, VoteError>;
+}
@@ -0,0 +1,70 @@
+use crate::election::domain::Contest;
+use crate::vote::domain::entities::{BallotCandidate, ReviewLine, Selection};
+
+/// The summary a voter checks before confirming: one line per contest, in the election's
+/// contest order. A blank ballot has no lines.
+pub fn review_lines(contests: &[Contest], candidates: &[BallotCandidate], selection: &Selection) -> Vec<ReviewLine> {
+    if selection.blank {
+        return vec![];
+    }
+
+    contests
+        .iter()
+        .filter_map(|contest| {
+            let choice = selection.choices.iter().find(|c| c.contest_id == contest.id)?;
+            let candidate = choice
+                .candidate_id
+                .as_ref()
+                .and_then(|id| candidates.iter().find(|c| &c.id == id))
+                .cloned();
+
+            Some(ReviewLine {
+                contest_id: contest.id.clone(),
+                contest_name: contest.name.clone(),
+                candidate,
+                abstain: choice.abstain,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vote::domain::entities::Choice;
+
+    fn contest(id: &str, name: &str) -> Contest {
+        Contest {
+            id: id.to_string(),
+            election_id: "e1".to_string(),
+            name: name.to_string(),
+            candidate_ids: vec!["c1".to_string()],
+            tie_break: Default::default(),
+            tie_break_seed: None,
+        }
+    }
+
+    #[test]
+    fn test_review_follows_contest_order_and_names_the_candidate() {
+        let contests = vec![contest("president", "President"), contest("senate", "Senate")];
+        let candidates = vec![BallotCandidate {
+            id: "c1".to_string(),
+            vote_number: 1,
+            president_name: "Ayu".to_string(),
+            vice_president_name: "Bima".to_string(),
+        }];
+        let selection = Selection {
+            blank: false,
+            choices: vec![Choice::abstention("senate"), Choice::candidate("president", "c1")],
+        };
+
+        let lines = review_lines(&contests, &candidates, &selection);
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].contest_name, "President");
+        assert_eq!(lines[0].candidate, Some(candidates[0].clone()));
+        assert!(!lines[0].abstain);
+        assert_eq!((lines[1].contest_name.as_str(), lines[1].candidate.is_none(), lines[1].abstain), ("Senate", true, true));
+        assert!(review_lines(&contests, &candidates, &Selection { blank: true, choices: vec![] }).is_empty());
+    }
+}
@@ -0,0 +1,104 @@
+use crate::infrastructure::crypto;
+use crate::infrastructure::crypto::Pepper;
+use crate::vote::domain::entities::{SealedResult, Selection, StoredResult};
+use crate::vote::domain::errors::VoteError;
+
+/// Shortest `Idempotency-Key` accepted; a UUID is 36 characters. The server's pepper is mixed
+/// in as well, so even a guessable key cannot be tried against the database alone.
+pub const MIN_IDEMPOTENCY_KEY_LEN: usize = 16;
+
+const KEY_HASH_PURPOSE: &str = "vote-idempotency-key-v2";
+const SEAL_PURPOSE: &str = "vote-idempotency-seal-v2";
+const SELECTION_PURPOSE: &str = "vote-draft-selection-v1";
+
+/// Scoped to the voter, so two voters picking the same key do not meet.
+fn scoped(voter_id: &str, key: &str) -> String {
+    format!("{}\0{}", voter_id, key)
+}
+
+/// What the idempotency record is looked up by; the key itself is never stored.
+pub fn idempotency_key_hash(pepper: &Pepper, voter_id: &str, key: &str) -> String {
+    pepper.derive(KEY_HASH_PURPOSE, &scoped(voter_id, key))
+}
+
+/// Encrypts a cast result under a key derived from the server's pepper, the voter and their
+/// `Idempotency-Key`.
+pub fn seal_result(pepper: &Pepper, voter_id: &str, key: &str, result: &StoredResult) -> Result<SealedResult, VoteError> {
+    let plaintext = serde_json::to_vec(result).map_err(|e| VoteError::UnknownError(e.to_string()))?;
+    let sealed = crypto::seal_with_secret(SEAL_PURPOSE, &pepper.derive(SEAL_PURPOSE, &scoped(voter_id, key)), &plaintext)
+        .map_err(|e| VoteError::UnknownError(e.to_string()))?;
+
+    Ok(SealedResult {
+        key_hash: idempotency_key_hash(pepper, voter_id, key),
+        sealed,
+    })
+}
+
+pub fn open_result(pepper: &Pepper, voter_id: &str, key: &str, sealed: &str) -> Result<StoredResult, VoteError> {
+    let plaintext = crypto::open_with_secret(SEAL_PURPOSE, &pepper.derive(SEAL_PURPOSE, &scoped(voter_id, key)), sealed)
+        .map_err(|e| VoteError::UnknownError(e.to_string()))?;
+
+    serde_json::from_slice(&plaintext).map_err(|e| VoteError::UnknownError(e.to_string()))
+}
+
+/// Seals a draft's selection under the voting session token, which is only stored hashed, so
+/// a draft waiting for confirmation cannot be read from the database.
+pub fn seal_selection(token: &str, selection: &Selection) -> Result<String, VoteError> {
+    let plaintext = serde_json::to_vec(selection).map_err(|e| VoteError::UnknownError(e.to_string()))?;
+    crypto::seal_with_secret(SELECTION_PURPOSE, token, &plaintext)
+        .map_err(|e| VoteError::UnknownError(e.to_string()))
+}
+
+pub fn open_selection(token: &str, sealed: &str) -> Result<Selection, VoteError> {
+    let plaintext = crypto::open_with_secret(SELECTION_PURPOSE, token, sealed)
+        .map_err(|e| VoteError::UnknownError(e.to_string()))?;
+
+    serde_json::from_slice(&plaintext).map_err(|e| VoteError::UnknownError(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vote::domain::entities::CastBallot;
+
+    fn result() -> StoredResult {
+        StoredResult {
+            request_hash: "h1".to_string(),
+            ballot: CastBallot {
+                ballot_id: "b1".to_string(),
+                election_id: "e1".to_string(),
+                cast_at: chrono::Utc::now(),
+                receipt: "3F9K-T2QW-8XNB-M4RD-7HVC".to_string(),
+            },
+        }
+    }
+
+    fn pepper(key: &str) -> Pepper {
+        Pepper::from_base64(key).unwrap()
+    }
+
+    #[test]
+    fn test_sealed_result_opens_only_with_the_same_key() {
+        let server = pepper("AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=");
+        let key = "8e03978e-40d5-43e8-bc93-6894a57f9324";
+        let stored = result();
+        let sealed = seal_result(&server, "v1", key, &stored).unwrap();
+
+        assert_eq!(sealed.key_hash, idempotency_key_hash(&server, "v1", key));
+        assert_eq!(open_result(&server, "v1", key, &sealed.sealed).unwrap(), stored);
+        assert!(open_result(&server, "v1", "another-key-0000-0000", &sealed.sealed).is_err());
+        assert!(open_result(&server, "v2", key, &sealed.sealed).is_err());
+    }
+
+    #[test]
+    fn test_a_guessable_key_needs_the_pepper_too() {
+        let server = pepper("AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=");
+        let guess = pepper("HyAhIiMkJSYnKCkqKywtLi8wMTIzNDU2Nzg5Ojs8PT4=");
+        let key = "aaaaaaaaaaaaaaaa";
+        let sealed = seal_result(&server, "v1", key, &result()).unwrap();
+
+        // Knowing the voter and the key is not enough to find or open the record.
+        assert_ne!(sealed.key_hash, idempotency_key_hash(&guess, "v1", key));
+        assert!(open_result(&guess, "v1", key, &sealed.sealed).is_err());
+    }
+}
@@ -0,0 +1,94 @@
+use crate::election::domain::{Election, ElectionPhase};
+use crate::vote::domain::errors::VoteError;
+use chrono::{DateTime, Duration, Utc};
+
+/// Whether a ballot may be cast at `now`, by the server's clock. The election has to be in
+/// its voting phase and inside its voting window. After the window closes, a session that
+/// was opened before the close may still cast for `grace`, so a voter who started in time
+/// is not cut off mid-ballot.
+pub fn check_window(
+    election: &Election,
+    session_started_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+    grace: Duration,
+) -> Result<(), VoteError> {
+    match election.phase {
+        ElectionPhase::Draft | ElectionPhase::Registration => {
+            return Err(VoteError::NotYetOpen(format!(
+                "election {} is in phase {}", election.id, election.phase.as_str()
+            )));
+        }
+        ElectionPhase::Closed | ElectionPhase::Published => {
+            return Err(VoteError::VotingClosed(format!(
+                "election {} is in phase {}", election.id, election.phase.as_str()
+            )));
+        }
+        ElectionPhase::Voting => {}
+    }
+
+    if let Some(starts_at) = election.voting_starts_at
+        && now < starts_at
+    {
+        return Err(VoteError::NotYetOpen(format!("voting opens at {}", starts_at.to_rfc3339())));
+    }
+
+    if let Some(ends_at) = election.voting_ends_at
+        && now >= ends_at
+        && !(session_started_at < ends_at && now < ends_at + grace)
+    {
+        return Err(VoteError::VotingClosed(format!("voting closed at {}", ends_at.to_rfc3339())));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 4, hour, minute, 0).unwrap()
+    }
+
+    fn election(phase: ElectionPhase) -> Election {
+        Election {
+            id: "e1".to_string(),
+            name: "Student Council 2026".to_string(),
+            phase,
+            voting_starts_at: Some(at(8, 0)),
+            voting_ends_at: Some(at(17, 0)),
+            allow_revote: false,
+            created_at: at(0, 0),
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_window_tells_not_yet_open_from_closed() {
+        let grace = Duration::minutes(5);
+        let voting = election(ElectionPhase::Voting);
+
+        assert!(check_window(&voting, at(7, 50), at(12, 0), grace).is_ok());
+        assert!(matches!(check_window(&voting, at(7, 50), at(7, 59), grace), Err(VoteError::NotYetOpen(_))));
+        assert!(matches!(check_window(&voting, at(17, 1), at(17, 1), grace), Err(VoteError::VotingClosed(_))));
+        assert!(matches!(
+            check_window(&election(ElectionPhase::Registration), at(12, 0), at(12, 0), grace),
+            Err(VoteError::NotYetOpen(_))
+        ));
+        assert!(matches!(
+            check_window(&election(ElectionPhase::Closed), at(12, 0), at(12, 0), grace),
+            Err(VoteError::VotingClosed(_))
+        ));
+    }
+
+    #[test]
+    fn test_grace_only_covers_sessions_started_before_the_close() {
+        let grace = Duration::minutes(5);
+        let voting = election(ElectionPhase::Voting);
+
+        assert!(check_window(&voting, at(16, 58), at(17, 4), grace).is_ok());
+        assert!(matches!(check_window(&voting, at(16, 58), at(17, 5), grace), Err(VoteError::VotingClosed(_))));
+        assert!(matches!(check_window(&voting, at(17, 0), at(17, 1), grace), Err(VoteError::VotingClosed(_))));
+    }
+}
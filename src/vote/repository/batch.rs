@@ -0,0 +1,368 @@
+use crate::vote::domain::{NewBallot, Participation, VoteError};
+use rand::seq::SliceRandom;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+fn parse_id(id: &str) -> Result<Uuid, VoteError> {
+    Uuid::parse_str(id).map_err(|_| VoteError::InvalidBallot(format!("{} is not a valid id", id)))
+}
+
+fn db_error(e: sqlx::Error) -> VoteError {
+    VoteError::UnknownError(e.to_string())
+}
+
+// Adds what the batch changed to the running counters (see migrations/0019). Rows are
+// updated in key order so two writers cannot deadlock on them.
+const BALLOT_COUNTERS_SQL: &str = r#"
+    WITH changed AS (
+        SELECT id, election_id, blank, CASE WHEN id = ANY($2) THEN -1 ELSE 1 END AS delta
+        FROM ballots WHERE id = ANY($1) OR id = ANY($2)
+    ), deltas AS (
+        SELECT election_id, 'ballots' AS counter, delta FROM changed
+        UNION ALL
+        SELECT election_id, 'blank', delta FROM changed WHERE blank
+        UNION ALL
+        SELECT b.election_id, 'contest:' || c.contest_id, b.delta
+        FROM changed b JOIN ballot_choices c ON c.ballot_id = b.id
+        UNION ALL
+        SELECT b.election_id, 'abstain:' || c.contest_id, b.delta
+        FROM changed b JOIN ballot_choices c ON c.ballot_id = b.id WHERE c.abstain
+        UNION ALL
+        SELECT b.election_id, 'candidate:' || c.contest_id || ':' || c.candidate_id, b.delta
+        FROM changed b JOIN ballot_choices c ON c.ballot_id = b.id WHERE NOT c.abstain
+    )
+    INSERT INTO tally_counters (election_id, counter, value)
+    SELECT election_id, counter, SUM(delta) FROM deltas
+    GROUP BY election_id, counter
+    HAVING SUM(delta) <> 0
+    ORDER BY election_id, counter
+    ON CONFLICT (election_id, counter) DO UPDATE SET value = tally_counters.value + EXCLUDED.value
+    "#;
+
+pub const VOTED_COUNTER_SQL: &str = r#"
+    INSERT INTO tally_counters (election_id, counter, value)
+    SELECT election_id, 'voted', delta FROM UNNEST($1::UUID[], $2::BIGINT[]) AS t (election_id, delta)
+    ORDER BY election_id
+    ON CONFLICT (election_id, counter) DO UPDATE SET value = tally_counters.value + EXCLUDED.value
+    "#;
+
+const INSERT_PARTICIPATIONS_SQL: &str = r#"
+    INSERT INTO participations (election_id, voter_id, voted_at)
+    SELECT * FROM UNNEST($1::UUID[], $2::UUID[], $3::TIMESTAMPTZ[])
+    ON CONFLICT DO NOTHING
+    RETURNING election_id, voter_id
+    "#;
+
+const INSERT_BALLOTS_SQL: &str = r#"
+    INSERT INTO ballots (id, election_id, blank, cast_at, receipt_nonce, receipt, revote_tag, counted)
+    SELECT * FROM UNNEST($1::UUID[], $2::UUID[], $3::BOOL[], $4::TIMESTAMPTZ[], $5::TEXT[], $6::TEXT[], $7::TEXT[], $8::BOOL[])
+    "#;
+
+const INSERT_CHOICES_SQL: &str = r#"
+    INSERT INTO ballot_choices (ballot_id, contest_id, candidate_id, abstain)
+    SELECT ballot_id, contest_id, candidate_id, candidate_id IS NULL
+    FROM UNNEST($1::UUID[], $2::UUID[], $3::UUID[]) AS t (ballot_id, contest_id, candidate_id)
+    "#;
+
+/// The order a batch of ballots is inserted in. Rows land on disk, and in the transaction,
+/// in no relation to the order their voters' participations were written.
+fn mixed_order(len: usize) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..len).collect();
+    order.shuffle(&mut rand::rng());
+    order
+}
+
+/// Writes the whole batch in one transaction. If that fails, every item is written on its
+/// own, so a bad row fails only itself and not the voters queued with it.
+async fn write_each<T, O, F, Fut>(batch: Vec<T>, write: F) -> Vec<Result<O, VoteError>>
+where
+    T: Clone,
+    F: Fn(Vec<T>) -> Fut,
+    Fut: Future<Output = Result<Vec<Result<O, VoteError>>, VoteError>>,
+{
+    match write(batch.clone()).await {
+        Ok(results) => return results,
+        Err(e) if batch.len() == 1 => return vec![Err(e)],
+        Err(_) => {}
+    }
+
+    let mut results = Vec::with_capacity(batch.len());
+    for item in batch {
+        let result = write(vec![item]).await.and_then(|mut r| {
+            r.pop().unwrap_or_else(|| Err(VoteError::UnknownError("batch returned no result".into())))
+        });
+        results.push(result);
+    }
+    results
+}
+
+pub async fn write_participations(pool: sqlx::PgPool, batch: Vec<Participation>) -> Vec<Result<bool, VoteError>> {
+    write_each(batch, |items| insert_participations(pool.clone(), items)).await
+}
+
+pub async fn write_ballots(pool: sqlx::PgPool, batch: Vec<NewBallot>) -> Vec<Result<(), VoteError>> {
+    write_each(batch, |items| insert_ballots(pool.clone(), items)).await
+}
+
+async fn insert_participations(
+    pool: sqlx::PgPool,
+    batch: Vec<Participation>,
+) -> Result<Vec<Result<bool, VoteError>>, VoteError> {
+    let mut election_ids = Vec::with_capacity(batch.len());
+    let mut voter_ids = Vec::with_capacity(batch.len());
+    let mut voted_at = Vec::with_capacity(batch.len());
+    for p in &batch {
+        election_ids.push(parse_id(&p.election_id)?);
+        voter_ids.push(parse_id(&p.voter_id)?);
+        voted_at.push(p.voted_at);
+    }
+
+    let mut tx = pool.begin().await.map_err(db_error)?;
+
+    let inserted: Vec<(Uuid, Uuid)> = sqlx::query_as(INSERT_PARTICIPATIONS_SQL)
+        .bind(&election_ids)
+        .bind(&voter_ids)
+        .bind(&voted_at)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+    // Of two casts by one voter in the same batch, the first one in the queue wins. A re-vote
+    // keeps the first participation as it is, so turnout counts each voter once.
+    let mut fresh: HashSet<(Uuid, Uuid)> = inserted.into_iter().collect();
+    let mut results: Vec<Result<bool, VoteError>> = batch
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            if fresh.remove(&(election_ids[i], voter_ids[i])) {
+                Ok(true)
+            } else if p.revote {
+                Ok(false)
+            } else {
+                Err(VoteError::AlreadyVoted("a ballot has already been cast by this voter".into()))
+            }
+        })
+        .collect();
+
+    let keyed: Vec<usize> = (0..batch.len())
+        .filter(|&i| results[i].is_ok() && batch[i].idempotency.is_some())
+        .collect();
+
+    if !keyed.is_empty() {
+        let mut key_voters = Vec::with_capacity(keyed.len());
+        let mut key_hashes = Vec::with_capacity(keyed.len());
+        let mut key_elections = Vec::with_capacity(keyed.len());
+        let mut sealed = Vec::with_capacity(keyed.len());
+        for &i in &keyed {
+            if let Some(idempotency) = &batch[i].idempotency {
+                key_voters.push(voter_ids[i]);
+                key_hashes.push(idempotency.key_hash.clone());
+                key_elections.push(election_ids[i]);
+                sealed.push(idempotency.sealed.clone());
+            }
+        }
+
+        let stored: Vec<(Uuid, String)> = sqlx::query_as(
+            r#"
+            INSERT INTO ballot_idempotency_keys (voter_id, key_hash, election_id, sealed_result)
+            SELECT * FROM UNNEST($1::UUID[], $2::TEXT[], $3::UUID[], $4::TEXT[])
+            ON CONFLICT DO NOTHING
+            RETURNING voter_id, key_hash
+            "#,
+        )
+            .bind(&key_voters)
+            .bind(&key_hashes)
+            .bind(&key_elections)
+            .bind(&sealed)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(db_error)?;
+
+        // A key already in use refuses the cast, along with the participation it just made.
+        let mut stored: HashSet<(Uuid, String)> = stored.into_iter().collect();
+        let mut undo_elections = vec![];
+        let mut undo_voters = vec![];
+        for (n, &i) in keyed.iter().enumerate() {
+            if stored.remove(&(key_voters[n], key_hashes[n].clone())) {
+                continue;
+            }
+            if matches!(results[i], Ok(true)) {
+                undo_elections.push(election_ids[i]);
+                undo_voters.push(voter_ids[i]);
+            }
+            results[i] = Err(VoteError::IdempotencyConflict("idempotency key was already used for another request".into()));
+        }
+
+        if !undo_voters.is_empty() {
+            sqlx::query(
+                r#"
+                DELETE FROM participations
+                WHERE (election_id, voter_id) IN (SELECT * FROM UNNEST($1::UUID[], $2::UUID[]))
+                "#,
+            )
+                .bind(&undo_elections)
+                .bind(&undo_voters)
+                .execute(&mut *tx)
+                .await
+                .map_err(db_error)?;
+        }
+    }
+
+    let mut voted: HashMap<Uuid, i64> = HashMap::new();
+    for (i, result) in results.iter().enumerate() {
+        if matches!(result, Ok(true)) {
+            *voted.entry(election_ids[i]).or_default() += 1;
+        }
+    }
+    if !voted.is_empty() {
+        let (counter_elections, deltas): (Vec<Uuid>, Vec<i64>) = voted.into_iter().unzip();
+        sqlx::query(VOTED_COUNTER_SQL)
+            .bind(&counter_elections)
+            .bind(&deltas)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+    }
+
+    tx.commit().await.map_err(db_error)?;
+
+    Ok(results)
+}
+
+async fn insert_ballots(pool: sqlx::PgPool, batch: Vec<NewBallot>) -> Result<Vec<Result<(), VoteError>>, VoteError> {
+    let mut ids = Vec::with_capacity(batch.len());
+    let mut election_ids = Vec::with_capacity(batch.len());
+    for ballot in &batch {
+        ids.push(parse_id(&ballot.id)?);
+        election_ids.push(parse_id(&ballot.election_id)?);
+    }
+
+    // Of several ballots cast with one credential in this batch, only the last is counted.
+    let mut last_per_tag: HashMap<(Uuid, &str), usize> = HashMap::new();
+    for (i, ballot) in batch.iter().enumerate() {
+        if let Some(tag) = &ballot.revote_tag {
+            last_per_tag.insert((election_ids[i], tag.as_str()), i);
+        }
+    }
+    let counted: Vec<bool> = batch
+        .iter()
+        .enumerate()
+        .map(|(i, b)| match &b.revote_tag {
+            Some(tag) => last_per_tag.get(&(election_ids[i], tag.as_str())) == Some(&i),
+            None => true,
+        })
+        .collect();
+
+    let order = mixed_order(batch.len());
+    let mut choice_ballots = vec![];
+    let mut contest_ids = vec![];
+    let mut candidate_ids = vec![];
+    for &i in &order {
+        for choice in &batch[i].choices {
+            choice_ballots.push(ids[i]);
+            contest_ids.push(parse_id(&choice.contest_id)?);
+            candidate_ids.push(choice.candidate_id.as_deref().map(parse_id).transpose()?);
+        }
+    }
+
+    let mut tx = pool.begin().await.map_err(db_error)?;
+
+    let mut retired: Vec<Uuid> = vec![];
+    if !last_per_tag.is_empty() {
+        let mut tags: Vec<String> = last_per_tag.keys().map(|(_, tag)| tag.to_string()).collect();
+        tags.sort();
+        tags.dedup();
+        let (tag_elections, tag_values): (Vec<Uuid>, Vec<String>) = last_per_tag
+            .keys()
+            .map(|(election_id, tag)| (*election_id, tag.to_string()))
+            .unzip();
+
+        // Serialises casts with the same credential, so exactly one ballot per tag stays
+        // counted. Locks are taken in sorted order so two batches cannot deadlock.
+        sqlx::query(r#"SELECT pg_advisory_xact_lock(hashtextextended(tag, 0)) FROM UNNEST($1::TEXT[]) AS t (tag)"#)
+            .bind(&tags)
+            .execute(&mut *tx)
+            .await
+            .map_err(db_error)?;
+
+        retired = sqlx::query_scalar(
+            r#"
+            UPDATE ballots SET counted = false
+            WHERE counted AND (election_id, revote_tag) IN (SELECT * FROM UNNEST($1::UUID[], $2::TEXT[]))
+            RETURNING id
+            "#,
+        )
+            .bind(&tag_elections)
+            .bind(&tag_values)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(db_error)?;
+    }
+
+    sqlx::query(INSERT_BALLOTS_SQL)
+        .bind(order.iter().map(|&i| ids[i]).collect::<Vec<Uuid>>())
+        .bind(order.iter().map(|&i| election_ids[i]).collect::<Vec<Uuid>>())
+        .bind(order.iter().map(|&i| batch[i].blank).collect::<Vec<bool>>())
+        .bind(order.iter().map(|&i| batch[i].cast_at).collect::<Vec<_>>())
+        .bind(order.iter().map(|&i| batch[i].receipt_nonce.clone()).collect::<Vec<String>>())
+        .bind(order.iter().map(|&i| batch[i].receipt.clone()).collect::<Vec<String>>())
+        .bind(order.iter().map(|&i| batch[i].revote_tag.clone()).collect::<Vec<Option<String>>>())
+        .bind(order.iter().map(|&i| counted[i]).collect::<Vec<bool>>())
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+    sqlx::query(INSERT_CHOICES_SQL)
+        .bind(&choice_ballots)
+        .bind(&contest_ids)
+        .bind(&candidate_ids)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+    let added: Vec<Uuid> = (0..batch.len()).filter(|&i| counted[i]).map(|i| ids[i]).collect();
+    sqlx::query(BALLOT_COUNTERS_SQL)
+        .bind(&added)
+        .bind(&retired)
+        .execute(&mut *tx)
+        .await
+        .map_err(db_error)?;
+
+    tx.commit().await.map_err(db_error)?;
+
+    Ok(vec![Ok(()); batch.len()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    /// The columns an `INSERT INTO table (...)` statement writes.
+    fn columns(sql: &str) -> BTreeSet<String> {
+        let start = sql.find('(').unwrap() + 1;
+        let end = start + sql[start..].find(')').unwrap();
+        sql[start..end].split(',').map(|c| c.trim().to_string()).collect()
+    }
+
+    #[test]
+    fn test_ballot_rows_share_only_the_election_with_participations() {
+        let participation = columns(INSERT_PARTICIPATIONS_SQL);
+        let mut ballot = columns(INSERT_BALLOTS_SQL);
+        ballot.extend(columns(INSERT_CHOICES_SQL));
+
+        let shared: Vec<&String> = participation.intersection(&ballot).collect();
+        assert_eq!(shared, vec!["election_id"]);
+        assert!(!ballot.iter().any(|c| c.contains("voter")));
+        assert!(!participation.iter().any(|c| c.contains("ballot") || c.contains("receipt")));
+    }
+
+    #[test]
+    fn test_ballots_are_not_written_in_submission_order() {
+        let order = mixed_order(64);
+
+        assert_eq!(order.iter().copied().collect::<BTreeSet<usize>>(), (0..64).collect());
+        // 1 in 64! of batches would come out in order.
+        assert_ne!(order, (0..64).collect::<Vec<usize>>());
+    }
+}
@@ -0,0 +1,5 @@
+mod batch;
+mod postgres;
+mod model;
+
+pub use postgres::PostgresRepo;
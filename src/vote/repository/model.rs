@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct Draft {
+    pub id: Uuid,
+    pub election_id: Uuid,
+    pub session_hash: String,
+    pub sealed_selection: String,
+    pub expires_at: DateTime<Utc>,
+    pub confirmed: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct BallotCandidate {
+    pub id: Uuid,
+    pub vote_number: i32,
+    pub president_name: String,
+    pub vice_president_name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct LateRejection {
+    pub election_id: Uuid,
+    pub voter_id: Uuid,
+    pub session_started_at: DateTime<Utc>,
+    pub voting_ends_at: Option<DateTime<Utc>>,
+    pub rejected_at: DateTime<Utc>,
+}
@@ -0,0 +1,291 @@
+use crate::vote::domain;
+use crate::vote::domain::VoteError;
+use crate::vote::domain::Repository;
+use crate::infrastructure::batch::{BatchConfig, BatchWriter};
+use crate::infrastructure::database::postgres::Postgres;
+use crate::vote::repository::batch;
+use crate::vote::repository::model::{BallotCandidate, Draft, LateRejection};
+use anyhow::anyhow;
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+pub struct PostgresRepo {
+    postgres: sqlx::PgPool,
+    participations: BatchWriter<domain::Participation, Result<bool, VoteError>>,
+    ballots: BatchWriter<domain::NewBallot, Result<(), VoteError>>,
+}
+impl PostgresRepo {
+    /// Starts one batch writer for participations and one for ballots, so each holds at most
+    /// one pooled connection at a time. Ballots are held for `ballot_mix` before they are
+    /// written, so those cast close together share a transaction whatever order their
+    /// participations went in.
+    pub async fn new(postgres: Arc<Mutex<Postgres>>, batch: BatchConfig, ballot_mix: Duration) -> anyhow::Result<Self> {
+        let guard = postgres.lock().await;
+        let pool = guard
+            .pool()
+            .ok_or_else(|| anyhow!("DB pool is not initialized"))?;
+
+        let participation_pool = pool.clone();
+        let participations = BatchWriter::spawn(batch, move |items| {
+            batch::write_participations(participation_pool.clone(), items)
+        });
+        let ballot_pool = pool.clone();
+        let ballots = BatchWriter::spawn(BatchConfig { hold: ballot_mix, ..batch }, move |items| {
+            batch::write_ballots(ballot_pool.clone(), items)
+        });
+
+        Ok(PostgresRepo { postgres: pool, participations, ballots })
+    }
+
+    fn transform_draft(&self, d: Draft) -> domain::Draft {
+        domain::Draft {
+            id: d.id.to_string(),
+            election_id: d.election_id.to_string(),
+            session_hash: d.session_hash,
+            sealed_selection: d.sealed_selection,
+            expires_at: d.expires_at,
+            confirmed: d.confirmed,
+        }
+    }
+
+    fn parse_id(id: &str) -> Result<Uuid, VoteError> {
+        Uuid::parse_str(id).map_err(|_| VoteError::InvalidBallot(format!("{} is not a valid id", id)))
+    }
+}
+
+#[async_trait]
+impl Repository for PostgresRepo {
+    async fn record_participation(&self, participation: domain::Participation) -> Result<bool, VoteError> {
+        self.participations.submit(participation).await?
+    }
+
+    async fn withdraw_participation(&self, election_id: String, voter_id: String) -> Result<(), VoteError> {
+        let election_id = Self::parse_id(&election_id)?;
+        let voter_id = Self::parse_id(&voter_id)?;
+
+        let mut tx = self.postgres.begin().await
+            .map_err(|e| VoteError::UnknownError(e.to_string()))?;
+
+        sqlx::query(r#"DELETE FROM ballot_idempotency_keys WHERE election_id = $1 AND voter_id = $2"#)
+            .bind(election_id)
+            .bind(voter_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| VoteError::UnknownError(e.to_string()))?;
+
+        let withdrawn = sqlx::query(r#"DELETE FROM participations WHERE election_id = $1 AND voter_id = $2"#)
+            .bind(election_id)
+            .bind(voter_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| VoteError::UnknownError(e.to_string()))?;
+
+        if withdrawn.rows_affected() > 0 {
+            sqlx::query(batch::VOTED_COUNTER_SQL)
+                .bind(vec![election_id])
+                .bind(vec![-(withdrawn.rows_affected() as i64)])
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| VoteError::UnknownError(e.to_string()))?;
+        }
+
+        tx.commit().await
+            .map_err(|e| VoteError::UnknownError(e.to_string()))
+    }
+
+    async fn forget_idempotency_key(&self, voter_id: String, key_hash: String) -> Result<(), VoteError> {
+        let voter_id = Self::parse_id(&voter_id)?;
+
+        sqlx::query(r#"DELETE FROM ballot_idempotency_keys WHERE voter_id = $1 AND key_hash = $2"#)
+            .bind(voter_id)
+            .bind(key_hash)
+            .execute(&self.postgres)
+            .await
+            .map_err(|e| VoteError::UnknownError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn store_ballot(&self, ballot: domain::NewBallot) -> Result<(), VoteError> {
+        self.ballots.submit(ballot).await?
+    }
+
+    async fn find_sealed_result(&self, voter_id: String, key_hash: String) -> Result<Option<String>, VoteError> {
+        let voter_id = Self::parse_id(&voter_id)?;
+
+        sqlx::query_scalar::<_, String>(
+            r#"SELECT sealed_result FROM ballot_idempotency_keys WHERE voter_id = $1 AND key_hash = $2"#,
+        )
+            .bind(voter_id)
+            .bind(key_hash)
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| VoteError::UnknownError(e.to_string()))
+    }
+
+    async fn receipt_included(&self, election_id: String, receipt: String) -> Result<bool, VoteError> {
+        // An unknown election simply has no ballots, rather than being a lookup error.
+        let Ok(election_id) = Uuid::parse_str(&election_id) else {
+            return Ok(false);
+        };
+
+        sqlx::query_scalar::<_, bool>(
+            r#"SELECT EXISTS (SELECT 1 FROM ballots WHERE election_id = $1 AND receipt = $2 AND counted)"#,
+        )
+            .bind(election_id)
+            .bind(receipt)
+            .fetch_one(&self.postgres)
+            .await
+            .map_err(|e| VoteError::UnknownError(e.to_string()))
+    }
+
+    async fn create_draft(&self, draft: domain::NewDraft) -> Result<domain::Draft, VoteError> {
+        let election_id = Self::parse_id(&draft.election_id)?;
+
+        sqlx::query(r#"DELETE FROM ballot_drafts WHERE expires_at < now()"#)
+            .execute(&self.postgres)
+            .await
+            .map_err(|e| VoteError::UnknownError(e.to_string()))?;
+
+        let created = sqlx::query_as::<_, Draft>(
+            r#"
+            INSERT INTO ballot_drafts (election_id, session_hash, sealed_selection, expires_at)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, election_id, session_hash, sealed_selection, expires_at, confirmed
+            "#,
+        )
+            .bind(election_id)
+            .bind(&draft.session_hash)
+            .bind(&draft.sealed_selection)
+            .bind(draft.expires_at)
+            .fetch_one(&self.postgres)
+            .await
+            .map_err(|e| VoteError::UnknownError(e.to_string()))?;
+
+        Ok(self.transform_draft(created))
+    }
+
+    async fn find_draft(&self, id: String, session_hash: String) -> Result<domain::Draft, VoteError> {
+        let not_found = || VoteError::NotFound(format!("ballot draft {} not found", id));
+        let uuid = Uuid::parse_str(&id).map_err(|_| not_found())?;
+
+        let draft = sqlx::query_as::<_, Draft>(
+            r#"
+            SELECT id, election_id, session_hash, sealed_selection, expires_at, confirmed
+            FROM ballot_drafts WHERE id = $1 AND session_hash = $2
+            "#,
+        )
+            .bind(uuid)
+            .bind(&session_hash)
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| VoteError::UnknownError(e.to_string()))?
+            .ok_or_else(not_found)?;
+
+        Ok(self.transform_draft(draft))
+    }
+
+    async fn claim_draft(&self, id: String) -> Result<bool, VoteError> {
+        let uuid = Self::parse_id(&id)?;
+
+        let claimed = sqlx::query(
+            r#"UPDATE ballot_drafts SET confirmed = true WHERE id = $1 AND NOT confirmed AND expires_at > now()"#,
+        )
+            .bind(uuid)
+            .execute(&self.postgres)
+            .await
+            .map_err(|e| VoteError::UnknownError(e.to_string()))?;
+
+        Ok(claimed.rows_affected() == 1)
+    }
+
+    async fn release_draft(&self, id: String) -> Result<(), VoteError> {
+        let uuid = Self::parse_id(&id)?;
+
+        sqlx::query(r#"UPDATE ballot_drafts SET confirmed = false WHERE id = $1"#)
+            .bind(uuid)
+            .execute(&self.postgres)
+            .await
+            .map_err(|e| VoteError::UnknownError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn record_late_rejection(&self, rejection: domain::LateRejection) -> Result<(), VoteError> {
+        let election_id = Self::parse_id(&rejection.election_id)?;
+        let voter_id = Self::parse_id(&rejection.voter_id)?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO late_ballot_rejections (election_id, voter_id, session_started_at, voting_ends_at, rejected_at)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+        )
+            .bind(election_id)
+            .bind(voter_id)
+            .bind(rejection.session_started_at)
+            .bind(rejection.voting_ends_at)
+            .bind(rejection.rejected_at)
+            .execute(&self.postgres)
+            .await
+            .map_err(|e| VoteError::UnknownError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_late_rejections(&self, election_id: String) -> Result<Vec<domain::LateRejection>, VoteError> {
+        let uuid = Uuid::parse_str(&election_id)
+            .map_err(|_| VoteError::NotFound(format!("election {} not found", election_id)))?;
+
+        let rejections = sqlx::query_as::<_, LateRejection>(
+            r#"
+            SELECT election_id, voter_id, session_started_at, voting_ends_at, rejected_at
+            FROM late_ballot_rejections WHERE election_id = $1
+            ORDER BY rejected_at
+            "#,
+        )
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| VoteError::UnknownError(e.to_string()))?;
+
+        Ok(rejections
+            .into_iter()
+            .map(|r| domain::LateRejection {
+                election_id: r.election_id.to_string(),
+                voter_id: r.voter_id.to_string(),
+                session_started_at: r.session_started_at,
+                voting_ends_at: r.voting_ends_at,
+                rejected_at: r.rejected_at,
+            })
+            .collect())
+    }
+
+    async fn find_candidates(&self, ids: Vec<String>) -> Result<Vec<domain::BallotCandidate>, VoteError> {
+        let uuids: Vec<Uuid> = ids.iter().filter_map(|id| Uuid::parse_str(id).ok()).collect();
+
+        let candidates = sqlx::query_as::<_, BallotCandidate>(
+            r#"
+            SELECT id, vote_number, president_name, vice_president_name
+            FROM candidates WHERE id = ANY($1)
+            "#,
+        )
+            .bind(&uuids)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| VoteError::UnknownError(e.to_string()))?;
+
+        Ok(candidates
+            .into_iter()
+            .map(|c| domain::BallotCandidate {
+                id: c.id.to_string(),
+                vote_number: c.vote_number,
+                president_name: c.president_name,
+                vice_president_name: c.vice_president_name,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,761 @@
+use crate::credential;
+use crate::election;
+use crate::election::domain::Contest;
+use crate::infrastructure::crypto::Pepper;
+use crate::vote::domain::{self, CastBallot, Choice, LateRejection, NewBallot, Participation, Repository, StoredResult, VoteError};
+use async_trait::async_trait;
+use chrono::Utc;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Response, VoteError>;
+}
+
+pub struct CastBallotUseCase<R: ?Sized + Send + Sync, C: ?Sized + Send + Sync, E: ?Sized + Send + Sync>
+where
+    R: Repository,
+    C: credential::domain::Repository,
+    E: election::domain::Repository,
+{
+    repository: Arc<R>,
+    credential_repository: Arc<C>,
+    election_repository: Arc<E>,
+    /// How long after the close a session opened before it may still cast.
+    close_grace: chrono::Duration,
+    pepper: Arc<Pepper>,
+}
+
+pub struct Request {
+    pub election_id: String,
+    pub session_token: String,
+    pub blank: bool,
+    pub choices: Vec<Choice>,
+    pub idempotency_key: Option<String>,
+}
+
+// Keep the session token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("election_id", &self.election_id)
+            .field("session_token", &"<redacted>")
+            .field("blank", &self.blank)
+            .field("choices", &self.choices)
+            .field("idempotency_key", &self.idempotency_key)
+            .finish()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Response {
+    pub ballot: CastBallot,
+    /// True when the ballot was stored by an earlier request with the same idempotency key.
+    pub replayed: bool,
+}
+
+impl<R: ?Sized + Send + Sync, C: ?Sized + Send + Sync, E: ?Sized + Send + Sync> CastBallotUseCase<R, C, E>
+where
+    R: Repository,
+    C: credential::domain::Repository,
+    E: election::domain::Repository,
+{
+    pub fn new(
+        repository: Arc<R>,
+        credential_repository: Arc<C>,
+        election_repository: Arc<E>,
+        close_grace: chrono::Duration,
+        pepper: Arc<Pepper>,
+    ) -> Self {
+        Self { repository, credential_repository, election_repository, close_grace, pepper }
+    }
+}
+
+/// Answers a retry from the result sealed under its key. A key reused with a different
+/// election or different choices is refused rather than replayed.
+pub async fn replay<R: Repository + ?Sized>(
+    repository: &R,
+    pepper: &Pepper,
+    voter_id: &str,
+    key: &str,
+    request_hash: &str,
+) -> Result<Option<Response>, VoteError> {
+    let Some(sealed) = repository
+        .find_sealed_result(voter_id.to_string(), domain::idempotency_key_hash(pepper, voter_id, key))
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    let stored = domain::open_result(pepper, voter_id, key, &sealed)?;
+    if stored.request_hash != request_hash {
+        return Err(VoteError::IdempotencyConflict(
+            "idempotency key was already used with a different ballot".into(),
+        ));
+    }
+
+    Ok(Some(Response { ballot: stored.ballot, replayed: true }))
+}
+
+/// Fingerprint of what a submission asks for, over the canonical ballot, so a client that
+/// rebuilds the same ballot in another order still matches.
+pub fn request_hash(election_id: &str, blank: bool, choices: &[Choice]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(election_id.as_bytes());
+    for line in domain::canonical_ballot(blank, choices) {
+        hasher.update(b"\n");
+        hasher.update(line.as_bytes());
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// A blank ballot carries no choices. Any other ballot holds exactly one choice per contest,
+/// each naming a candidate of that contest or abstaining explicitly.
+pub fn validate(contests: &[Contest], blank: bool, choices: &[Choice]) -> Result<(), VoteError> {
+    if blank {
+        if !choices.is_empty() {
+            return Err(VoteError::InvalidBallot("a blank ballot carries no choices".into()));
+        }
+        return Ok(());
+    }
+
+    let by_id: HashMap<&str, &Contest> = contests.iter().map(|c| (c.id.as_str(), c)).collect();
+    let mut seen: HashMap<&str, ()> = HashMap::with_capacity(choices.len());
+
+    for choice in choices {
+        let contest = by_id.get(choice.contest_id.as_str()).ok_or_else(|| {
+            VoteError::InvalidBallot(format!("contest {} is not part of this election", choice.contest_id))
+        })?;
+        if seen.insert(choice.contest_id.as_str(), ()).is_some() {
+            return Err(VoteError::InvalidBallot(format!(
+                "contest {} is chosen more than once", choice.contest_id
+            )));
+        }
+        match (&choice.candidate_id, choice.abstain) {
+            (None, true) => {}
+            (Some(candidate_id), false) => {
+                if !contest.candidate_ids.contains(candidate_id) {
+                    return Err(VoteError::InvalidBallot(format!(
+                        "candidate {} is not running in contest {}", candidate_id, choice.contest_id
+                    )));
+                }
+            }
+            _ => {
+                return Err(VoteError::InvalidBallot(format!(
+                    "contest {} needs either a candidate or an abstention", choice.contest_id
+                )));
+            }
+        }
+    }
+
+    if let Some(missing) = contests.iter().find(|c| !seen.contains_key(c.id.as_str())) {
+        return Err(VoteError::InvalidBallot(format!("contest {} has no choice", missing.id)));
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync, C: ?Sized + Send + Sync, E: ?Sized + Send + Sync> Interactor for CastBallotUseCase<R, C, E>
+where
+    R: Repository,
+    C: credential::domain::Repository,
+    E: election::domain::Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Response, VoteError> {
+        let session = self.credential_repository
+            .find_session(credential::domain::hash_session_token(&req.session_token))
+            .await?;
+
+        if session.election_id != req.election_id {
+            return Err(VoteError::Unauthorized("voting session belongs to another election".into()));
+        }
+
+        let request_hash = request_hash(&req.election_id, req.blank, &req.choices);
+
+        // Replayed before the window check, so a retry still gets its answer after voting closes.
+        if let Some(key) = &req.idempotency_key
+            && let Some(response) = replay(self.repository.as_ref(), &self.pepper, &session.voter_id, key, &request_hash).await?
+        {
+            return Ok(response);
+        }
+
+        let election = self.election_repository
+            .find_by_id(req.election_id.clone())
+            .await?;
+
+        let now = Utc::now();
+        if let Err(e) = domain::check_window(&election, session.created_at, now, self.close_grace) {
+            if matches!(e, VoteError::VotingClosed(_)) {
+                let recorded = self.repository.record_late_rejection(LateRejection {
+                    election_id: req.election_id,
+                    voter_id: session.voter_id,
+                    session_started_at: session.created_at,
+                    voting_ends_at: election.voting_ends_at,
+                    rejected_at: now,
+                }).await;
+                if let Err(record) = recorded {
+                    log::error!("failed to record late ballot rejection: {}", record);
+                }
+            }
+            return Err(e);
+        }
+
+        let contests = self.election_repository
+            .find_contests(req.election_id.clone())
+            .await?;
+        validate(&contests, req.blank, &req.choices)?;
+
+        let revote_tag = if election.allow_revote {
+            let sealed = session.revote_tag_sealed.as_deref().ok_or_else(|| {
+                VoteError::UnknownError("session of a re-vote election carries no re-vote tag".into())
+            })?;
+            Some(credential::domain::open_revote_tag(&req.session_token, sealed)?)
+        } else {
+            None
+        };
+
+        // Everything the voter gets back is settled before anything is written, so the
+        // participation and the ballot can be stored apart without either naming the other.
+        let receipt_nonce = domain::generate_receipt_nonce();
+        let ballot = CastBallot {
+            ballot_id: domain::generate_ballot_id(),
+            election_id: req.election_id.clone(),
+            cast_at: domain::coarsen_cast_time(now),
+            receipt: domain::receipt_code(&receipt_nonce, &req.election_id, req.blank, &req.choices),
+        };
+
+        let idempotency = match &req.idempotency_key {
+            Some(key) => Some(domain::seal_result(&self.pepper, &session.voter_id, key, &StoredResult {
+                request_hash: request_hash.clone(),
+                ballot: ballot.clone(),
+            })?),
+            None => None,
+        };
+        let key_hash = idempotency.as_ref().map(|i| i.key_hash.clone());
+
+        let recorded = self.repository.record_participation(Participation {
+            election_id: req.election_id.clone(),
+            voter_id: session.voter_id.clone(),
+            voted_at: now,
+            idempotency,
+            revote: election.allow_revote,
+        }).await;
+
+        let first_participation = match (recorded, &req.idempotency_key) {
+            (Ok(first), _) => first,
+            // Two retries with the same key raced and the other one recorded the vote.
+            (Err(VoteError::AlreadyVoted(msg)), Some(key)) => {
+                return replay(self.repository.as_ref(), &self.pepper, &session.voter_id, key, &request_hash)
+                    .await?
+                    .ok_or(VoteError::AlreadyVoted(msg));
+            }
+            (Err(e), _) => return Err(e),
+        };
+
+        let stored = self.repository.store_ballot(NewBallot {
+            id: ballot.ballot_id.clone(),
+            election_id: ballot.election_id.clone(),
+            blank: req.blank,
+            choices: req.choices,
+            cast_at: ballot.cast_at,
+            receipt_nonce,
+            receipt: ballot.receipt.clone(),
+            revote_tag,
+        }).await;
+
+        if let Err(e) = stored {
+            // Give the voter their vote back; if even that fails, the participation without a
+            // ballot shows up as a difference between turnout and ballot count. A failed
+            // re-vote leaves the earlier ballot counted and only forgets this attempt's key.
+            let undone = match (first_participation, key_hash) {
+                (true, _) => self.repository.withdraw_participation(req.election_id, session.voter_id).await,
+                (false, Some(key_hash)) => self.repository.forget_idempotency_key(session.voter_id, key_hash).await,
+                (false, None) => Ok(()),
+            };
+            if let Err(undo) = undone {
+                log::error!("failed to undo participation after the ballot store failed: {}", undo);
+            }
+            return Err(e);
+        }
+
+        Ok(Response { ballot, replayed: false })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::credential;
+    use crate::election;
+    use crate::election::domain::{Contest, ElectionPhase};
+    use crate::infrastructure::crypto::Pepper;
+    use crate::vote::domain::{self, CastBallot, Choice, VoteError};
+    use crate::vote::usecase::cast;
+    use crate::vote::usecase::cast::Interactor;
+    use chrono::Timelike;
+    use std::sync::{Arc, Mutex};
+
+    const KEY: &str = "8e03978e-40d5-43e8-bc93-6894a57f9324";
+
+    fn pepper() -> Pepper {
+        Pepper::from_base64("AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=").unwrap()
+    }
+
+    fn contests() -> Vec<Contest> {
+        vec![
+            Contest {
+                id: "president".to_string(),
+                election_id: "e1".to_string(),
+                name: "President".to_string(),
+                candidate_ids: vec!["c1".to_string(), "c2".to_string()],
+                tie_break: Default::default(),
+                tie_break_seed: None,
+            },
+            Contest {
+                id: "senate".to_string(),
+                election_id: "e1".to_string(),
+                name: "Senate".to_string(),
+                candidate_ids: vec!["c3".to_string()],
+                tie_break: Default::default(),
+                tie_break_seed: None,
+            },
+        ]
+    }
+
+    fn choice(contest_id: &str, candidate_id: &str) -> Choice {
+        Choice::candidate(contest_id, candidate_id)
+    }
+
+    fn ballot_choices() -> Vec<Choice> {
+        vec![choice("president", "c2"), choice("senate", "c3")]
+    }
+
+    fn credential_repo(election_id: &'static str) -> credential::domain::MockRepository {
+        let mut repo_mock = credential::domain::MockRepository::new();
+        repo_mock.expect_find_session()
+            .withf(|h| h == &credential::domain::hash_session_token("token"))
+            .returning(move |_| Ok(credential::domain::VotingSession {
+                election_id: election_id.to_string(),
+                voter_id: "v1".to_string(),
+                created_at: chrono::Utc::now(),
+                expires_at: chrono::Utc::now() + chrono::Duration::minutes(10),
+                revote_tag_sealed: None,
+            }));
+        repo_mock
+    }
+
+    fn election_repo(phase: ElectionPhase) -> election::domain::MockRepository {
+        election_repo_with_revote(phase, false)
+    }
+
+    fn election_repo_with_revote(phase: ElectionPhase, allow_revote: bool) -> election::domain::MockRepository {
+        let mut repo_mock = election::domain::MockRepository::new();
+        repo_mock.expect_find_by_id().returning(move |id| Ok(election::domain::Election {
+            id,
+            name: "Student Council 2026".to_string(),
+            phase,
+            voting_starts_at: None,
+            voting_ends_at: None,
+            allow_revote,
+            created_at: chrono::Utc::now(),
+            updated_at: None,
+        }));
+        repo_mock.expect_find_contests().returning(|_| Ok(contests()));
+        repo_mock
+    }
+
+    const CODE: &str = "7K3M-Q9TX-2B4R-HW8D";
+
+    /// A session redeemed in a re-vote election, carrying the code's tag sealed under "token".
+    fn revote_credential_repo() -> credential::domain::MockRepository {
+        let sealed = credential::domain::seal_revote_tag("token", &credential::domain::revote_tag("e1", CODE)).unwrap();
+        let mut repo_mock = credential::domain::MockRepository::new();
+        repo_mock.expect_find_session()
+            .returning(move |_| Ok(credential::domain::VotingSession {
+                election_id: "e1".to_string(),
+                voter_id: "v1".to_string(),
+                created_at: chrono::Utc::now(),
+                expires_at: chrono::Utc::now() + chrono::Duration::minutes(10),
+                revote_tag_sealed: Some(sealed.clone()),
+            }));
+        repo_mock
+    }
+
+    fn request(choices: Vec<Choice>) -> cast::Request {
+        cast::Request {
+            election_id: "e1".to_string(),
+            session_token: "token".to_string(),
+            blank: false,
+            choices,
+            idempotency_key: None,
+        }
+    }
+
+    fn keyed_request(choices: Vec<Choice>) -> cast::Request {
+        cast::Request { idempotency_key: Some(KEY.to_string()), ..request(choices) }
+    }
+
+    fn sealed(choices: &[Choice]) -> String {
+        domain::seal_result(&pepper(), "v1", KEY, &domain::StoredResult {
+            request_hash: cast::request_hash("e1", false, choices),
+            ballot: CastBallot {
+                ballot_id: "b1".to_string(),
+                election_id: "e1".to_string(),
+                cast_at: chrono::Utc::now(),
+                receipt: "3F9K-T2QW-8XNB-M4RD-7HVC".to_string(),
+            },
+        }).unwrap().sealed
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_cast_records_participation_and_ballot_apart() {
+        let participations = Arc::new(Mutex::new(vec![]));
+        let ballots = Arc::new(Mutex::new(vec![]));
+        let mut repo_mock = domain::MockRepository::new();
+        let recorded = participations.clone();
+        repo_mock.expect_record_participation()
+            .times(1)
+            .returning(move |p| {
+                recorded.lock().unwrap().push(p);
+                Ok(true)
+            });
+        let stored = ballots.clone();
+        repo_mock.expect_store_ballot()
+            .times(1)
+            .returning(move |b| {
+                stored.lock().unwrap().push(b);
+                Ok(())
+            });
+
+        let uc = cast::CastBallotUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(credential_repo("e1")),
+            Arc::new(election_repo(ElectionPhase::Voting)),
+            chrono::Duration::minutes(2),
+            Arc::new(pepper()),
+        );
+
+        let response = uc.handle(request(ballot_choices())).await.unwrap();
+        assert!(!response.replayed);
+
+        let participation = participations.lock().unwrap().pop().unwrap();
+        let ballot = ballots.lock().unwrap().pop().unwrap();
+        assert_eq!(participation.voter_id, "v1");
+        assert!(participation.idempotency.is_none());
+        assert_eq!(ballot.id, response.ballot.ballot_id);
+        assert_eq!(ballot.receipt, domain::receipt_code(&ballot.receipt_nonce, "e1", false, &ballot.choices));
+        // Only the hour survives on the ballot, while the participation keeps the exact time.
+        assert_eq!((ballot.cast_at.minute(), ballot.cast_at.second()), (0, 0));
+        assert_eq!(ballot.cast_at, domain::coarsen_cast_time(participation.voted_at));
+        assert_eq!(response.ballot.cast_at, ballot.cast_at);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_cast_withdraws_participation_when_ballot_fails() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_record_participation().times(1).returning(|_| Ok(true));
+        repo_mock.expect_store_ballot()
+            .times(1)
+            .returning(|_| Err(VoteError::UnknownError("connection reset".into())));
+        repo_mock.expect_withdraw_participation()
+            .times(1)
+            .withf(|e, v| e == "e1" && v == "v1")
+            .returning(|_, _| Ok(()));
+
+        let uc = cast::CastBallotUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(credential_repo("e1")),
+            Arc::new(election_repo(ElectionPhase::Voting)),
+            chrono::Duration::minutes(2),
+            Arc::new(pepper()),
+        );
+
+        let result = uc.handle(request(ballot_choices())).await;
+        assert!(matches!(result, Err(VoteError::UnknownError(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_revote_tags_ballot_with_credential() {
+        let mut repo_mock = domain::MockRepository::new();
+        // The voter has voted before; in a re-vote election that is not an error.
+        repo_mock.expect_record_participation()
+            .times(1)
+            .withf(|p| p.revote)
+            .returning(|_| Ok(false));
+        repo_mock.expect_store_ballot()
+            .times(1)
+            .withf(|b| b.revote_tag.as_deref() == Some(credential::domain::revote_tag("e1", CODE).as_str()))
+            .returning(|_| Ok(()));
+
+        let uc = cast::CastBallotUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(revote_credential_repo()),
+            Arc::new(election_repo_with_revote(ElectionPhase::Voting, true)),
+            chrono::Duration::minutes(2),
+            Arc::new(pepper()),
+        );
+
+        let response = uc.handle(request(ballot_choices())).await.unwrap();
+        assert!(!response.replayed);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_failed_revote_keeps_participation() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_sealed_result().returning(|_, _| Ok(None));
+        repo_mock.expect_record_participation().times(1).returning(|_| Ok(false));
+        repo_mock.expect_store_ballot()
+            .times(1)
+            .returning(|_| Err(VoteError::UnknownError("connection reset".into())));
+        repo_mock.expect_withdraw_participation().never();
+        repo_mock.expect_forget_idempotency_key()
+            .times(1)
+            .withf(|v, k| v == "v1" && k == &domain::idempotency_key_hash(&pepper(), "v1", KEY))
+            .returning(|_, _| Ok(()));
+
+        let uc = cast::CastBallotUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(revote_credential_repo()),
+            Arc::new(election_repo_with_revote(ElectionPhase::Voting, true)),
+            chrono::Duration::minutes(2),
+            Arc::new(pepper()),
+        );
+
+        let result = uc.handle(keyed_request(ballot_choices())).await;
+        assert!(matches!(result, Err(VoteError::UnknownError(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_cast_passes_through_already_voted() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_record_participation()
+            .times(1)
+            .returning(|_| Err(VoteError::AlreadyVoted("a ballot has already been cast by this voter".into())));
+        repo_mock.expect_store_ballot().never();
+
+        let uc = cast::CastBallotUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(credential_repo("e1")),
+            Arc::new(election_repo(ElectionPhase::Voting)),
+            chrono::Duration::minutes(2),
+            Arc::new(pepper()),
+        );
+
+        let result = uc.handle(request(ballot_choices())).await;
+        assert!(matches!(result, Err(VoteError::AlreadyVoted(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_cast_rejects_session_of_other_election() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_record_participation().never();
+
+        let uc = cast::CastBallotUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(credential_repo("e2")),
+            Arc::new(election_repo(ElectionPhase::Voting)),
+            chrono::Duration::minutes(2),
+            Arc::new(pepper()),
+        );
+
+        let result = uc.handle(request(ballot_choices())).await;
+        assert!(matches!(result, Err(VoteError::Unauthorized(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_cast_rejects_unknown_session() {
+        let mut credential_mock = credential::domain::MockRepository::new();
+        credential_mock.expect_find_session()
+            .returning(|_| Err(credential::domain::CredentialError::NotFound("session".into())));
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_record_participation().never();
+
+        let uc = cast::CastBallotUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(credential_mock),
+            Arc::new(election_repo(ElectionPhase::Voting)),
+            chrono::Duration::minutes(2),
+            Arc::new(pepper()),
+        );
+
+        let result = uc.handle(request(ballot_choices())).await;
+        assert!(matches!(result, Err(VoteError::Unauthorized(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_cast_rejects_when_not_voting() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_record_participation().never();
+        repo_mock.expect_record_late_rejection()
+            .times(1)
+            .withf(|r| r.election_id == "e1" && r.voter_id == "v1")
+            .returning(|_| Ok(()));
+
+        let uc = cast::CastBallotUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(credential_repo("e1")),
+            Arc::new(election_repo(ElectionPhase::Closed)),
+            chrono::Duration::minutes(2),
+            Arc::new(pepper()),
+        );
+
+        let result = uc.handle(request(ballot_choices())).await;
+        assert!(matches!(result, Err(VoteError::VotingClosed(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_cast_seals_result_under_idempotency_key() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_sealed_result().times(1).returning(|_, _| Ok(None));
+        repo_mock.expect_record_participation()
+            .times(1)
+            .withf(|p| {
+                let Some(idempotency) = &p.idempotency else { return false };
+                let opened = domain::open_result(&pepper(), "v1", KEY, &idempotency.sealed).unwrap();
+                idempotency.key_hash == domain::idempotency_key_hash(&pepper(), "v1", KEY)
+                    && !idempotency.sealed.contains(&opened.ballot.receipt)
+            })
+            .returning(|_| Ok(true));
+        repo_mock.expect_store_ballot().times(1).returning(|_| Ok(()));
+
+        let uc = cast::CastBallotUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(credential_repo("e1")),
+            Arc::new(election_repo(ElectionPhase::Voting)),
+            chrono::Duration::minutes(2),
+            Arc::new(pepper()),
+        );
+
+        let response = uc.handle(keyed_request(ballot_choices())).await.unwrap();
+        assert!(!response.replayed);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_cast_retry_with_same_key_replays_stored_ballot() {
+        // Same ballot rebuilt in a different order.
+        let stored = sealed(&[choice("senate", "c3"), choice("president", "c2")]);
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_sealed_result()
+            .times(1)
+            .withf(|voter_id, key_hash| voter_id == "v1" && key_hash == &domain::idempotency_key_hash(&pepper(), "v1", KEY))
+            .returning(move |_, _| Ok(Some(stored.clone())));
+        repo_mock.expect_record_participation().never();
+
+        let uc = cast::CastBallotUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(credential_repo("e1")),
+            // Voting has closed since the first attempt; the retry is still answered.
+            Arc::new(election_repo(ElectionPhase::Closed)),
+            chrono::Duration::minutes(2),
+            Arc::new(pepper()),
+        );
+
+        let response = uc.handle(keyed_request(ballot_choices())).await.unwrap();
+        assert_eq!(response.ballot.ballot_id, "b1");
+        assert!(response.replayed);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_cast_rejects_key_reused_with_different_body() {
+        let stored = sealed(&[choice("president", "c1"), choice("senate", "c3")]);
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_sealed_result()
+            .returning(move |_, _| Ok(Some(stored.clone())));
+        repo_mock.expect_record_participation().never();
+
+        let uc = cast::CastBallotUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(credential_repo("e1")),
+            Arc::new(election_repo(ElectionPhase::Voting)),
+            chrono::Duration::minutes(2),
+            Arc::new(pepper()),
+        );
+
+        let result = uc.handle(keyed_request(ballot_choices())).await;
+        assert!(matches!(result, Err(VoteError::IdempotencyConflict(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_cast_racing_retries_with_same_key_share_one_ballot() {
+        let stored = sealed(&ballot_choices());
+        let mut lookups = 0;
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_sealed_result()
+            .times(2)
+            .returning(move |_, _| {
+                lookups += 1;
+                // Missed before casting; the concurrent request has committed by the second look.
+                if lookups == 1 { Ok(None) } else { Ok(Some(stored.clone())) }
+            });
+        repo_mock.expect_record_participation()
+            .times(1)
+            .returning(|_| Err(VoteError::AlreadyVoted("a ballot has already been cast by this voter".into())));
+        repo_mock.expect_store_ballot().never();
+
+        let uc = cast::CastBallotUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(credential_repo("e1")),
+            Arc::new(election_repo(ElectionPhase::Voting)),
+            chrono::Duration::minutes(2),
+            Arc::new(pepper()),
+        );
+
+        let response = uc.handle(keyed_request(ballot_choices())).await.unwrap();
+        assert!(response.replayed);
+    }
+
+    #[test]
+    fn test_validate_rejects_malformed_ballots() {
+        let contests = contests();
+        assert!(cast::validate(&contests, false, &[choice("president", "c1"), choice("senate", "c3")]).is_ok());
+        // candidate from another contest
+        assert!(matches!(
+            cast::validate(&contests, false, &[choice("president", "c3"), choice("senate", "c3")]),
+            Err(VoteError::InvalidBallot(_))
+        ));
+        // contest chosen twice
+        assert!(matches!(
+            cast::validate(&contests, false, &[choice("president", "c1"), choice("president", "c2"), choice("senate", "c3")]),
+            Err(VoteError::InvalidBallot(_))
+        ));
+        // missing contest
+        assert!(matches!(
+            cast::validate(&contests, false, &[choice("president", "c1")]),
+            Err(VoteError::InvalidBallot(_))
+        ));
+        // unknown contest
+        assert!(matches!(
+            cast::validate(&contests, false, &[choice("president", "c1"), choice("senate", "c3"), choice("dean", "c9")]),
+            Err(VoteError::InvalidBallot(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_blank_and_abstain() {
+        let contests = contests();
+        assert!(cast::validate(&contests, false, &[Choice::abstention("president"), choice("senate", "c3")]).is_ok());
+        assert!(cast::validate(&contests, false, &[Choice::abstention("president"), Choice::abstention("senate")]).is_ok());
+        assert!(cast::validate(&contests, true, &[]).is_ok());
+        // blank with choices
+        assert!(matches!(
+            cast::validate(&contests, true, &[choice("president", "c1")]),
+            Err(VoteError::InvalidBallot(_))
+        ));
+        // abstaining and naming a candidate at once
+        let both = Choice { abstain: true, ..choice("president", "c1") };
+        assert!(matches!(
+            cast::validate(&contests, false, &[both, choice("senate", "c3")]),
+            Err(VoteError::InvalidBallot(_))
+        ));
+        // neither: an omitted candidate is not an abstention
+        let neither = Choice { contest_id: "president".to_string(), candidate_id: None, abstain: false };
+        assert!(matches!(
+            cast::validate(&contests, false, &[neither, choice("senate", "c3")]),
+            Err(VoteError::InvalidBallot(_))
+        ));
+        // an empty ballot is not a blank one
+        assert!(matches!(cast::validate(&contests, false, &[]), Err(VoteError::InvalidBallot(_))));
+    }
+}
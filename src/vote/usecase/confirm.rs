@@ -0,0 +1,296 @@
+use crate::credential;
+use crate::infrastructure::crypto::Pepper;
+use crate::vote::domain::{self, Draft, Repository, Selection, VoteError};
+use crate::vote::usecase::cast;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<cast::Response, VoteError>;
+}
+
+/// Casts a reviewed draft, exactly as it was reviewed, through the cast usecase.
+pub struct ConfirmBallotUseCase<R: ?Sized + Send + Sync, C: ?Sized + Send + Sync>
+where
+    R: Repository,
+    C: credential::domain::Repository,
+{
+    repository: Arc<R>,
+    credential_repository: Arc<C>,
+    cast: Arc<dyn cast::Interactor>,
+    pepper: Arc<Pepper>,
+}
+
+pub struct Request {
+    pub election_id: String,
+    pub draft_id: String,
+    pub session_token: String,
+    pub idempotency_key: Option<String>,
+}
+
+// Keep the session token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("election_id", &self.election_id)
+            .field("draft_id", &self.draft_id)
+            .field("session_token", &"<redacted>")
+            .field("idempotency_key", &self.idempotency_key)
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync, C: ?Sized + Send + Sync> ConfirmBallotUseCase<R, C>
+where
+    R: Repository,
+    C: credential::domain::Repository,
+{
+    pub fn new(repository: Arc<R>, credential_repository: Arc<C>, cast: Arc<dyn cast::Interactor>, pepper: Arc<Pepper>) -> Self {
+        Self { repository, credential_repository, cast, pepper }
+    }
+
+    /// A second confirmation casts nothing. With the idempotency key of the first it gets the
+    /// first one's result back, so a client that lost the response can still show the receipt.
+    async fn already_confirmed(
+        &self,
+        draft: &Draft,
+        voter_id: &str,
+        selection: &Selection,
+        key: Option<&str>,
+    ) -> Result<cast::Response, VoteError> {
+        if let Some(key) = key {
+            let request_hash = cast::request_hash(&draft.election_id, selection.blank, &selection.choices);
+            if let Some(response) = cast::replay(self.repository.as_ref(), &self.pepper, voter_id, key, &request_hash).await? {
+                return Ok(response);
+            }
+        }
+
+        Err(VoteError::DraftConfirmed(format!("ballot draft {} was already confirmed", draft.id)))
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync, C: ?Sized + Send + Sync> Interactor for ConfirmBallotUseCase<R, C>
+where
+    R: Repository,
+    C: credential::domain::Repository,
+{
+    async fn handle(&self, req: Request) -> Result<cast::Response, VoteError> {
+        let session_hash = credential::domain::hash_session_token(&req.session_token);
+        let session = self.credential_repository.find_session(session_hash.clone()).await?;
+
+        if session.election_id != req.election_id {
+            return Err(VoteError::Unauthorized("voting session belongs to another election".into()));
+        }
+
+        let draft = self.repository.find_draft(req.draft_id.clone(), session_hash).await?;
+        if draft.election_id != req.election_id {
+            return Err(VoteError::NotFound(format!("ballot draft {} not found", req.draft_id)));
+        }
+
+        let selection = domain::open_selection(&req.session_token, &draft.sealed_selection)?;
+        let key = req.idempotency_key.as_deref();
+
+        if draft.confirmed {
+            return self.already_confirmed(&draft, &session.voter_id, &selection, key).await;
+        }
+        if draft.expires_at <= Utc::now() {
+            return Err(VoteError::DraftExpired(format!("ballot draft {} has expired", draft.id)));
+        }
+        if !self.repository.claim_draft(draft.id.clone()).await? {
+            return self.already_confirmed(&draft, &session.voter_id, &selection, key).await;
+        }
+
+        let cast = self.cast.handle(cast::Request {
+            election_id: req.election_id,
+            session_token: req.session_token,
+            blank: selection.blank,
+            choices: selection.choices,
+            idempotency_key: req.idempotency_key,
+        }).await;
+
+        // Nothing was cast, so the voter may confirm the same draft again.
+        if cast.is_err()
+            && let Err(e) = self.repository.release_draft(draft.id).await
+        {
+            log::error!("failed to release ballot draft after a failed cast: {}", e);
+        }
+
+        cast
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::credential;
+    use crate::infrastructure::crypto::Pepper;
+    use crate::vote::domain::{self, CastBallot, Choice, Selection, VoteError};
+    use crate::vote::usecase::{cast, confirm};
+    use crate::vote::usecase::confirm::Interactor;
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+
+    const KEY: &str = "8e03978e-40d5-43e8-bc93-6894a57f9324";
+
+    fn pepper() -> Pepper {
+        Pepper::from_base64("AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=").unwrap()
+    }
+
+    fn choices() -> Vec<Choice> {
+        vec![Choice::candidate("president", "c1")]
+    }
+
+    fn credential_repo() -> credential::domain::MockRepository {
+        let mut repo_mock = credential::domain::MockRepository::new();
+        repo_mock.expect_find_session()
+            .returning(|_| Ok(credential::domain::VotingSession {
+                election_id: "e1".to_string(),
+                voter_id: "v1".to_string(),
+                created_at: chrono::Utc::now(),
+                expires_at: chrono::Utc::now() + chrono::Duration::minutes(10),
+                revote_tag_sealed: None,
+            }));
+        repo_mock
+    }
+
+    fn draft(confirmed: bool, expires_in: chrono::Duration) -> domain::Draft {
+        domain::Draft {
+            id: "d1".to_string(),
+            election_id: "e1".to_string(),
+            session_hash: credential::domain::hash_session_token("token"),
+            sealed_selection: domain::seal_selection("token", &Selection { blank: false, choices: choices() }).unwrap(),
+            expires_at: chrono::Utc::now() + expires_in,
+            confirmed,
+        }
+    }
+
+    fn ballot() -> CastBallot {
+        CastBallot {
+            ballot_id: "b1".to_string(),
+            election_id: "e1".to_string(),
+            cast_at: chrono::Utc::now(),
+            receipt: "3F9K-T2QW-8XNB-M4RD-7HVC".to_string(),
+        }
+    }
+
+    /// Records what it was asked to cast, and fails when `fail` is set.
+    struct MockCast {
+        requests: Arc<Mutex<Vec<cast::Request>>>,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl cast::Interactor for MockCast {
+        async fn handle(&self, req: cast::Request) -> Result<cast::Response, VoteError> {
+            self.requests.lock().unwrap().push(req);
+            if self.fail {
+                return Err(VoteError::Busy("write queue is full".into()));
+            }
+            Ok(cast::Response { ballot: ballot(), replayed: false })
+        }
+    }
+
+    fn request(idempotency_key: Option<&str>) -> confirm::Request {
+        confirm::Request {
+            election_id: "e1".to_string(),
+            draft_id: "d1".to_string(),
+            session_token: "token".to_string(),
+            idempotency_key: idempotency_key.map(str::to_string),
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_confirm_casts_the_reviewed_selection() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_draft()
+            .withf(|id, hash| id == "d1" && hash == &credential::domain::hash_session_token("token"))
+            .returning(|_, _| Ok(draft(false, chrono::Duration::minutes(5))));
+        repo_mock.expect_claim_draft().times(1).returning(|_| Ok(true));
+        repo_mock.expect_release_draft().never();
+
+        let requests = Arc::new(Mutex::new(vec![]));
+        let uc = confirm::ConfirmBallotUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(credential_repo()),
+            Arc::new(MockCast { requests: requests.clone(), fail: false }),
+            Arc::new(pepper()),
+        );
+
+        let response = uc.handle(request(Some(KEY))).await.unwrap();
+        assert_eq!(response.ballot.ballot_id, "b1");
+
+        let cast = requests.lock().unwrap().pop().unwrap();
+        assert_eq!(cast.choices, choices());
+        assert_eq!(cast.idempotency_key.as_deref(), Some(KEY));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_confirm_releases_the_draft_when_the_cast_fails() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_draft().returning(|_, _| Ok(draft(false, chrono::Duration::minutes(5))));
+        repo_mock.expect_claim_draft().times(1).returning(|_| Ok(true));
+        repo_mock.expect_release_draft().times(1).withf(|id| id == "d1").returning(|_| Ok(()));
+
+        let uc = confirm::ConfirmBallotUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(credential_repo()),
+            Arc::new(MockCast { requests: Arc::new(Mutex::new(vec![])), fail: true }),
+            Arc::new(pepper()),
+        );
+
+        let result = uc.handle(request(None)).await;
+        assert!(matches!(result, Err(VoteError::Busy(_))));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_confirm_refuses_an_expired_draft() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_draft().returning(|_, _| Ok(draft(false, -chrono::Duration::seconds(1))));
+        repo_mock.expect_claim_draft().never();
+
+        let requests = Arc::new(Mutex::new(vec![]));
+        let uc = confirm::ConfirmBallotUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(credential_repo()),
+            Arc::new(MockCast { requests: requests.clone(), fail: false }),
+            Arc::new(pepper()),
+        );
+
+        let result = uc.handle(request(None)).await;
+        assert!(matches!(result, Err(VoteError::DraftExpired(_))));
+        assert!(requests.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_second_confirmation_replays_instead_of_casting() {
+        let sealed = domain::seal_result(&pepper(), "v1", KEY, &domain::StoredResult {
+            request_hash: cast::request_hash("e1", false, &choices()),
+            ballot: ballot(),
+        }).unwrap().sealed;
+
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_draft().returning(|_, _| Ok(draft(true, chrono::Duration::minutes(5))));
+        repo_mock.expect_claim_draft().never();
+        repo_mock.expect_find_sealed_result()
+            .withf(|v, h| v == "v1" && h == &domain::idempotency_key_hash(&pepper(), "v1", KEY))
+            .returning(move |_, _| Ok(Some(sealed.clone())));
+
+        let requests = Arc::new(Mutex::new(vec![]));
+        let uc = confirm::ConfirmBallotUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(credential_repo()),
+            Arc::new(MockCast { requests: requests.clone(), fail: false }),
+            Arc::new(pepper()),
+        );
+
+        let response = uc.handle(request(Some(KEY))).await.unwrap();
+        assert!(response.replayed);
+        assert_eq!(response.ballot.receipt, "3F9K-T2QW-8XNB-M4RD-7HVC");
+
+        let result = uc.handle(request(None)).await;
+        assert!(matches!(result, Err(VoteError::DraftConfirmed(_))));
+        assert!(requests.lock().unwrap().is_empty());
+    }
+}
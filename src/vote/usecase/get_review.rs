@@ -0,0 +1,89 @@
+use crate::credential;
+use crate::election;
+use crate::vote::domain::{self, BallotReview, Repository, VoteError};
+use crate::vote::usecase::review;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<BallotReview, VoteError>;
+}
+
+pub struct GetReviewUseCase<R: ?Sized + Send + Sync, C: ?Sized + Send + Sync, E: ?Sized + Send + Sync>
+where
+    R: Repository,
+    C: credential::domain::Repository,
+    E: election::domain::Repository,
+{
+    repository: Arc<R>,
+    credential_repository: Arc<C>,
+    election_repository: Arc<E>,
+}
+
+pub struct Request {
+    pub election_id: String,
+    pub draft_id: String,
+    pub session_token: String,
+}
+
+// Keep the session token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("election_id", &self.election_id)
+            .field("draft_id", &self.draft_id)
+            .field("session_token", &"<redacted>")
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync, C: ?Sized + Send + Sync, E: ?Sized + Send + Sync> GetReviewUseCase<R, C, E>
+where
+    R: Repository,
+    C: credential::domain::Repository,
+    E: election::domain::Repository,
+{
+    pub fn new(repository: Arc<R>, credential_repository: Arc<C>, election_repository: Arc<E>) -> Self {
+        Self { repository, credential_repository, election_repository }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync, C: ?Sized + Send + Sync, E: ?Sized + Send + Sync> Interactor for GetReviewUseCase<R, C, E>
+where
+    R: Repository,
+    C: credential::domain::Repository,
+    E: election::domain::Repository,
+{
+    async fn handle(&self, req: Request) -> Result<BallotReview, VoteError> {
+        let session_hash = credential::domain::hash_session_token(&req.session_token);
+        let session = self.credential_repository.find_session(session_hash.clone()).await?;
+
+        if session.election_id != req.election_id {
+            return Err(VoteError::Unauthorized("voting session belongs to another election".into()));
+        }
+
+        let draft = self.repository.find_draft(req.draft_id.clone(), session_hash).await?;
+        if draft.election_id != req.election_id {
+            return Err(VoteError::NotFound(format!("ballot draft {} not found", req.draft_id)));
+        }
+        if !draft.confirmed && draft.expires_at <= Utc::now() {
+            return Err(VoteError::DraftExpired(format!("ballot draft {} has expired", draft.id)));
+        }
+
+        let selection = domain::open_selection(&req.session_token, &draft.sealed_selection)?;
+        let contests = self.election_repository.find_contests(draft.election_id.clone()).await?;
+        let lines = review::summarize(self.repository.as_ref(), &contests, &selection).await?;
+
+        Ok(BallotReview {
+            draft_id: draft.id,
+            election_id: draft.election_id,
+            blank: selection.blank,
+            lines,
+            expires_at: draft.expires_at,
+            confirmed: draft.confirmed,
+        })
+    }
+}
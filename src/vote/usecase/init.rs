@@ -0,0 +1,56 @@
+use std::sync::Arc;
+use crate::credential;
+use crate::election;
+use crate::embargo::domain::StaffRoster;
+use crate::infrastructure::crypto::Pepper;
+use crate::vote::domain::Repository;
+use crate::vote::usecase::{cast, confirm, get_review, list_late_rejections, review, verify_receipt};
+use crate::vote::usecase::cast::CastBallotUseCase;
+use crate::vote::usecase::confirm::ConfirmBallotUseCase;
+use crate::vote::usecase::get_review::GetReviewUseCase;
+use crate::vote::usecase::list_late_rejections::ListLateRejectionsUseCase;
+use crate::vote::usecase::review::ReviewBallotUseCase;
+use crate::vote::usecase::verify_receipt::VerifyReceiptUseCase;
+
+
+#[derive(Clone)]
+pub struct UseCase
+{
+    pub cast: Arc<dyn cast::Interactor>,
+    pub review: Arc<dyn review::Interactor>,
+    pub get_review: Arc<dyn get_review::Interactor>,
+    pub confirm: Arc<dyn confirm::Interactor>,
+    pub verify_receipt: Arc<dyn verify_receipt::Interactor>,
+    pub list_late_rejections: Arc<dyn list_late_rejections::Interactor>,
+}
+
+impl UseCase {
+
+    pub fn new(
+        vote_repo: Arc<dyn Repository + Send + Sync>,
+        credential_repo: Arc<dyn credential::domain::Repository + Send + Sync>,
+        election_repo: Arc<dyn election::domain::Repository + Send + Sync>,
+        draft_ttl: chrono::Duration,
+        close_grace: chrono::Duration,
+        pepper: Arc<Pepper>,
+        roster: Arc<StaffRoster>,
+    ) -> Self {
+
+        let cast_uc: Arc<dyn cast::Interactor> = Arc::new(CastBallotUseCase::new(vote_repo.clone(), credential_repo.clone(), election_repo.clone(), close_grace, pepper.clone()));
+        let review_uc = ReviewBallotUseCase::new(vote_repo.clone(), credential_repo.clone(), election_repo.clone(), draft_ttl, close_grace);
+        let get_review_uc = GetReviewUseCase::new(vote_repo.clone(), credential_repo.clone(), election_repo);
+        let confirm_uc = ConfirmBallotUseCase::new(vote_repo.clone(), credential_repo, cast_uc.clone(), pepper);
+        let verify_receipt_uc = VerifyReceiptUseCase::new(vote_repo.clone());
+        let list_late_rejections_uc = ListLateRejectionsUseCase::new(vote_repo, roster);
+
+        Self {
+            cast: cast_uc,
+            review: Arc::new(review_uc),
+            get_review: Arc::new(get_review_uc),
+            confirm: Arc::new(confirm_uc),
+            verify_receipt: Arc::new(verify_receipt_uc),
+            list_late_rejections: Arc::new(list_late_rejections_uc),
+        }
+    }
+
+}
@@ -0,0 +1,88 @@
+use crate::vote::domain::{LateRejection, Repository, VoteError};
+use crate::embargo::domain::StaffRoster;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Vec<LateRejection>, VoteError>;
+}
+
+pub struct ListLateRejectionsUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    roster: Arc<StaffRoster>,
+}
+
+pub struct Request {
+    pub election_id: String,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("election_id", &self.election_id)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> ListLateRejectionsUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, roster: Arc<StaffRoster>) -> Self {
+        Self { repository, roster }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for ListLateRejectionsUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Vec<LateRejection>, VoteError> {
+        self.roster.acting(req.staff_token.as_deref(), "list late ballots")?;
+
+        self.repository.find_late_rejections(req.election_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::embargo::domain::{StaffRoster, hash_staff_token};
+    use crate::vote::domain::{self, VoteError};
+    use crate::vote::usecase::list_late_rejections;
+    use crate::vote::usecase::list_late_rejections::Interactor;
+    use std::sync::Arc;
+
+    fn roster() -> Arc<StaffRoster> {
+        Arc::new(StaffRoster::parse(&format!(
+            "rina:committee:{},budi:observer:{}",
+            hash_staff_token("rina-token"),
+            hash_staff_token("budi-token")
+        )).unwrap())
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_list_late_rejections_needs_an_acting_staff_member() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_late_rejections().never();
+        let uc = list_late_rejections::ListLateRejectionsUseCase::new(Arc::new(repo_mock), roster());
+
+        let request = |token: Option<&str>| list_late_rejections::Request {
+            election_id: "e1".to_string(),
+            staff_token: token.map(str::to_string),
+        };
+
+        let anonymous = uc.handle(request(None)).await;
+        assert!(matches!(anonymous, Err(VoteError::Unauthorized(_))));
+
+        let observer = uc.handle(request(Some("budi-token"))).await;
+        assert!(matches!(observer, Err(VoteError::Forbidden(_))));
+    }
+}
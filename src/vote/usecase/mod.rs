@@ -0,0 +1,9 @@
+mod init;
+pub mod cast;
+pub mod confirm;
+pub mod get_review;
+pub mod list_late_rejections;
+pub mod review;
+pub mod verify_receipt;
+
+pub use init::UseCase;
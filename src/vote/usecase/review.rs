@@ -0,0 +1,249 @@
+use crate::credential;
+use crate::election;
+use crate::election::domain::Contest;
+use crate::vote::domain::{self, BallotReview, Choice, NewDraft, Repository, ReviewLine, Selection, VoteError};
+use crate::vote::usecase::cast;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<BallotReview, VoteError>;
+}
+
+/// Holds a voter's selections in a draft and returns the summary they confirm; nothing is
+/// cast until `confirm`.
+pub struct ReviewBallotUseCase<R: ?Sized + Send + Sync, C: ?Sized + Send + Sync, E: ?Sized + Send + Sync>
+where
+    R: Repository,
+    C: credential::domain::Repository,
+    E: election::domain::Repository,
+{
+    repository: Arc<R>,
+    credential_repository: Arc<C>,
+    election_repository: Arc<E>,
+    draft_ttl: chrono::Duration,
+    close_grace: chrono::Duration,
+}
+
+pub struct Request {
+    pub election_id: String,
+    pub session_token: String,
+    pub blank: bool,
+    pub choices: Vec<Choice>,
+}
+
+// Keep the session token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("election_id", &self.election_id)
+            .field("session_token", &"<redacted>")
+            .field("blank", &self.blank)
+            .field("choices", &self.choices)
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync, C: ?Sized + Send + Sync, E: ?Sized + Send + Sync> ReviewBallotUseCase<R, C, E>
+where
+    R: Repository,
+    C: credential::domain::Repository,
+    E: election::domain::Repository,
+{
+    pub fn new(
+        repository: Arc<R>,
+        credential_repository: Arc<C>,
+        election_repository: Arc<E>,
+        draft_ttl: chrono::Duration,
+        close_grace: chrono::Duration,
+    ) -> Self {
+        Self { repository, credential_repository, election_repository, draft_ttl, close_grace }
+    }
+}
+
+/// The review summary of a selection, naming each contest and candidate.
+pub async fn summarize<R: Repository + ?Sized>(
+    repository: &R,
+    contests: &[Contest],
+    selection: &Selection,
+) -> Result<Vec<ReviewLine>, VoteError> {
+    let candidate_ids: Vec<String> = selection.choices.iter().filter_map(|c| c.candidate_id.clone()).collect();
+    let candidates = if candidate_ids.is_empty() {
+        vec![]
+    } else {
+        repository.find_candidates(candidate_ids).await?
+    };
+
+    Ok(domain::review_lines(contests, &candidates, selection))
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync, C: ?Sized + Send + Sync, E: ?Sized + Send + Sync> Interactor for ReviewBallotUseCase<R, C, E>
+where
+    R: Repository,
+    C: credential::domain::Repository,
+    E: election::domain::Repository,
+{
+    async fn handle(&self, req: Request) -> Result<BallotReview, VoteError> {
+        let session_hash = credential::domain::hash_session_token(&req.session_token);
+        let session = self.credential_repository.find_session(session_hash.clone()).await?;
+
+        if session.election_id != req.election_id {
+            return Err(VoteError::Unauthorized("voting session belongs to another election".into()));
+        }
+
+        let election = self.election_repository
+            .find_by_id(req.election_id.clone())
+            .await?;
+
+        // Only refused, not recorded as late: nothing is being cast yet.
+        domain::check_window(&election, session.created_at, Utc::now(), self.close_grace)?;
+
+        // Checked now as well as on confirmation, so the voter never reviews a ballot that
+        // would then be refused.
+        let contests = self.election_repository
+            .find_contests(req.election_id.clone())
+            .await?;
+        cast::validate(&contests, req.blank, &req.choices)?;
+
+        let selection = Selection { blank: req.blank, choices: req.choices };
+        let lines = summarize(self.repository.as_ref(), &contests, &selection).await?;
+
+        // A draft cannot outlive the session that has to confirm it.
+        let expires_at = (Utc::now() + self.draft_ttl).min(session.expires_at);
+        let draft = self.repository.create_draft(NewDraft {
+            election_id: req.election_id,
+            session_hash,
+            sealed_selection: domain::seal_selection(&req.session_token, &selection)?,
+            expires_at,
+        }).await?;
+
+        Ok(BallotReview {
+            draft_id: draft.id,
+            election_id: draft.election_id,
+            blank: selection.blank,
+            lines,
+            expires_at: draft.expires_at,
+            confirmed: draft.confirmed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::credential;
+    use crate::election;
+    use crate::election::domain::{Contest, ElectionPhase};
+    use crate::vote::domain::{self, BallotCandidate, Choice, VoteError};
+    use crate::vote::usecase::review;
+    use crate::vote::usecase::review::Interactor;
+    use std::sync::{Arc, Mutex};
+
+    fn credential_repo(session_expires_in: chrono::Duration) -> credential::domain::MockRepository {
+        let mut repo_mock = credential::domain::MockRepository::new();
+        repo_mock.expect_find_session()
+            .returning(move |_| Ok(credential::domain::VotingSession {
+                election_id: "e1".to_string(),
+                voter_id: "v1".to_string(),
+                created_at: chrono::Utc::now(),
+                expires_at: chrono::Utc::now() + session_expires_in,
+                revote_tag_sealed: None,
+            }));
+        repo_mock
+    }
+
+    fn election_repo(phase: ElectionPhase) -> election::domain::MockRepository {
+        let mut repo_mock = election::domain::MockRepository::new();
+        repo_mock.expect_find_by_id().returning(move |id| Ok(election::domain::Election {
+            id,
+            name: "Student Council 2026".to_string(),
+            phase,
+            voting_starts_at: None,
+            voting_ends_at: None,
+            allow_revote: false,
+            created_at: chrono::Utc::now(),
+            updated_at: None,
+        }));
+        repo_mock.expect_find_contests().returning(|_| Ok(vec![Contest {
+            id: "president".to_string(),
+            election_id: "e1".to_string(),
+            name: "President".to_string(),
+            candidate_ids: vec!["c1".to_string()],
+            tie_break: Default::default(),
+            tie_break_seed: None,
+        }]));
+        repo_mock
+    }
+
+    fn request(choices: Vec<Choice>) -> review::Request {
+        review::Request {
+            election_id: "e1".to_string(),
+            session_token: "token".to_string(),
+            blank: false,
+            choices,
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_review_seals_the_selection_and_summarises_it() {
+        let drafts = Arc::new(Mutex::new(vec![]));
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_candidates()
+            .returning(|_| Ok(vec![BallotCandidate {
+                id: "c1".to_string(),
+                vote_number: 1,
+                president_name: "Ayu".to_string(),
+                vice_president_name: "Bima".to_string(),
+            }]));
+        let created = drafts.clone();
+        repo_mock.expect_create_draft()
+            .times(1)
+            .returning(move |d| {
+                created.lock().unwrap().push(d.clone());
+                Ok(domain::Draft {
+                    id: "d1".to_string(),
+                    election_id: d.election_id,
+                    session_hash: d.session_hash,
+                    sealed_selection: d.sealed_selection,
+                    expires_at: d.expires_at,
+                    confirmed: false,
+                })
+            });
+
+        let uc = review::ReviewBallotUseCase::new(
+            Arc::new(repo_mock),
+            Arc::new(credential_repo(chrono::Duration::minutes(2))),
+            Arc::new(election_repo(ElectionPhase::Voting)),
+            chrono::Duration::minutes(5),
+            chrono::Duration::minutes(2),
+        );
+
+        let review = uc.handle(request(vec![Choice::candidate("president", "c1")])).await.unwrap();
+        assert_eq!(review.draft_id, "d1");
+        assert_eq!(review.lines[0].candidate.as_ref().unwrap().president_name, "Ayu");
+
+        let draft = drafts.lock().unwrap().pop().unwrap();
+        assert_eq!(draft.session_hash, credential::domain::hash_session_token("token"));
+        let selection = domain::open_selection("token", &draft.sealed_selection).unwrap();
+        assert_eq!(selection.choices, vec![Choice::candidate("president", "c1")]);
+        assert!(domain::open_selection("other", &draft.sealed_selection).is_err());
+        // Capped by the session, which runs out before the review would.
+        assert!(draft.expires_at <= chrono::Utc::now() + chrono::Duration::minutes(2));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_review_refuses_an_invalid_ballot() {
+        let uc = review::ReviewBallotUseCase::new(
+            Arc::new(domain::MockRepository::new()),
+            Arc::new(credential_repo(chrono::Duration::minutes(10))),
+            Arc::new(election_repo(ElectionPhase::Voting)),
+            chrono::Duration::minutes(5),
+            chrono::Duration::minutes(2),
+        );
+
+        let result = uc.handle(request(vec![Choice::candidate("president", "c9")])).await;
+        assert!(matches!(result, Err(VoteError::InvalidBallot(_))));
+    }
+}
@@ -0,0 +1,93 @@
+use crate::vote::domain::{self, ReceiptCheck, Repository, VoteError};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<ReceiptCheck, VoteError>;
+}
+
+pub struct VerifyReceiptUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+}
+
+#[derive(Debug)]
+pub struct Request {
+    pub election_id: String,
+    pub receipt: String,
+}
+
+impl<R: ?Sized + Send + Sync> VerifyReceiptUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for VerifyReceiptUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<ReceiptCheck, VoteError> {
+        let receipt = domain::normalize_receipt(&req.receipt).ok_or_else(|| {
+            VoteError::InvalidReceipt("a receipt is 20 characters, e.g. 3F9K-T2QW-8XNB-M4RD-7HVC".into())
+        })?;
+
+        let included = self.repository
+            .receipt_included(req.election_id.clone(), receipt.clone())
+            .await?;
+
+        Ok(ReceiptCheck {
+            election_id: req.election_id,
+            receipt,
+            included,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::vote::domain::{self, VoteError};
+    use crate::vote::usecase::verify_receipt;
+    use crate::vote::usecase::verify_receipt::Interactor;
+    use mockall::predicate::eq;
+    use std::sync::Arc;
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_verify_normalizes_typed_receipt() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_receipt_included()
+            .times(1)
+            .with(eq("e1".to_string()), eq("3F9K-T2QW-8XNB-M4RD-7HVC".to_string()))
+            .returning(|_, _| Ok(true));
+
+        let uc = verify_receipt::VerifyReceiptUseCase::new(Arc::new(repo_mock));
+        let check = uc.handle(verify_receipt::Request {
+            election_id: "e1".to_string(),
+            receipt: "3f9k t2qw 8xnb m4rd 7hvc".to_string(),
+        }).await.unwrap();
+
+        assert!(check.included);
+        assert_eq!(check.receipt, "3F9K-T2QW-8XNB-M4RD-7HVC");
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_verify_rejects_malformed_receipt() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_receipt_included().never();
+
+        let uc = verify_receipt::VerifyReceiptUseCase::new(Arc::new(repo_mock));
+        let result = uc.handle(verify_receipt::Request {
+            election_id: "e1".to_string(),
+            receipt: "3F9K".to_string(),
+        }).await;
+
+        assert!(matches!(result, Err(VoteError::InvalidReceipt(_))));
+    }
+}
@@ -0,0 +1,34 @@
+use crate::voter::domain::VoterError;
+use crate::utils::response;
+use actix_web::HttpResponse;
+
+pub fn error_response(e: &VoterError) -> HttpResponse {
+    println!("Error: {}", e);
+    match e {
+        VoterError::NotFound(msg) => HttpResponse::NotFound().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "VOTER_NOT_FOUND".into(),
+        )),
+        VoterError::Unauthorized(msg) => HttpResponse::Unauthorized().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "VOTER_UNAUTHORIZED".into(),
+        )),
+        VoterError::Forbidden(msg) => HttpResponse::Forbidden().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "VOTER_FORBIDDEN".into(),
+        )),
+        VoterError::InvalidInput(msg) => HttpResponse::BadRequest().json(response::error::<()>(
+            None,
+            msg.clone(),
+            "VOTER_INVALID_INPUT".into(),
+        )),
+        VoterError::UnknownError(_) => HttpResponse::InternalServerError().json(response::error::<()>(
+            None,
+            "failed process data".into(),
+            "-1".into(),
+        )),
+    }
+}
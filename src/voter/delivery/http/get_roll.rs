@@ -0,0 +1,19 @@
+use crate::voter::delivery::http::errors::error_response;
+use crate::voter::usecase::get_roll::*;
+use crate::utils::{app, response};
+use actix_web::{HttpResponse, web};
+
+pub async fn get_roll(handler: web::Data<app::AppHandlerData>, path: web::Path<String>) -> HttpResponse {
+
+    let request = Request { election_id: path.into_inner() };
+
+    println!("-> Received request: {:?}", request);
+
+    match handler.voter_uc.get_roll.handle(request).await {
+        Ok(summary) => HttpResponse::Ok().json(response::success(
+            Some(summary),
+            "Successfully processed voter roll".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
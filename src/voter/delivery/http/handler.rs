@@ -0,0 +1,15 @@
+use actix_web::web;
+use crate::voter::delivery::http::get_roll::get_roll;
+use crate::voter::delivery::http::get_roll_diff::get_roll_diff;
+use crate::voter::delivery::http::lookup_voter::lookup_voter;
+use crate::voter::delivery::http::rotate_pii::rotate_pii;
+use crate::voter::delivery::http::sync_voters::sync_voters;
+
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/voters", web::get().to(lookup_voter))
+        .route("/voters", web::put().to(sync_voters))
+        .route("/voters/pii/rotate", web::post().to(rotate_pii))
+        .route("/elections/{id}/roll", web::get().to(get_roll))
+        .route("/elections/{id}/roll/diff", web::get().to(get_roll_diff));
+}
@@ -0,0 +1,29 @@
+use crate::voter::delivery::http::errors::error_response;
+use crate::voter::usecase::lookup::*;
+use crate::embargo::delivery::http::staff_token;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct VoterLookupQuery {
+    nim: String,
+}
+
+pub async fn lookup_voter(
+    handler: web::Data<app::AppHandlerData>,
+    http_request: HttpRequest,
+    q: web::Query<VoterLookupQuery>,
+) -> HttpResponse {
+
+    let request = Request { nim: q.into_inner().nim, staff_token: staff_token(&http_request) };
+
+    // No request logging here: the NIM is exactly what encryption keeps out of plain sight.
+    match handler.voter_uc.lookup.handle(request).await {
+        Ok(voter) => HttpResponse::Ok().json(response::success(
+            Some(voter),
+            "Successfully processed voter".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
@@ -0,0 +1,14 @@
+mod handler;
+mod errors;
+mod get_roll;
+mod get_roll_diff;
+mod lookup_voter;
+mod rotate_pii;
+mod sync_voters;
+
+pub use get_roll::*;
+pub use get_roll_diff::*;
+pub use lookup_voter::*;
+pub use rotate_pii::*;
+pub use sync_voters::*;
+pub use handler::*;
@@ -0,0 +1,20 @@
+use crate::voter::delivery::http::errors::error_response;
+use crate::voter::usecase::rotate_pii::*;
+use crate::embargo::delivery::http::staff_token;
+use crate::utils::{app, response};
+use actix_web::{HttpRequest, HttpResponse, web};
+
+pub async fn rotate_pii(handler: web::Data<app::AppHandlerData>, http_request: HttpRequest) -> HttpResponse {
+
+    let request = Request { staff_token: staff_token(&http_request) };
+
+    println!("-> Received PII key rotation request");
+
+    match handler.voter_uc.rotate_pii.handle(request).await {
+        Ok(report) => HttpResponse::Ok().json(response::success(
+            Some(report),
+            "Successfully processed key rotation".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
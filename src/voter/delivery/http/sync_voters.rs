@@ -0,0 +1,20 @@
+use crate::voter::delivery::http::errors::error_response;
+use crate::voter::domain::VoterInput;
+use crate::voter::usecase::sync::*;
+use crate::utils::{app, response};
+use actix_web::{HttpResponse, web};
+
+pub async fn sync_voters(handler: web::Data<app::AppHandlerData>, body: web::Json<Vec<VoterInput>>) -> HttpResponse {
+
+    let request = Request { voters: body.into_inner() };
+
+    println!("-> Received voter sync with {} records", request.voters.len());
+
+    match handler.voter_uc.sync.handle(request).await {
+        Ok(response) => HttpResponse::Ok().json(response::success(
+            Some(response),
+            "Successfully processed voters".into(),
+        )),
+        Err(e) => error_response(&e),
+    }
+}
@@ -0,0 +1,80 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+
+/// A voter as currently held in the live registry.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Voter {
+    pub id: String,
+    pub nim: String,
+    pub name: String,
+    pub email: String,
+    pub faculty: String,
+    pub cohort: i32,
+    pub eligible: bool,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// A voter record as delivered by the registrar sync.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct VoterInput {
+    pub nim: String,
+    pub name: String,
+    pub email: String,
+    pub faculty: String,
+    pub cohort: i32,
+    pub eligible: bool,
+}
+
+/// Rows touched by a PII key rotation.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct RotationReport {
+    pub voters: u64,
+    pub roll_entries: u64,
+}
+
+/// A voter as frozen onto an election's roll when voting opened.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RollEntry {
+    pub voter_id: String,
+    pub nim: String,
+    pub name: String,
+    pub email: String,
+    pub faculty: String,
+    pub cohort: i32,
+    pub frozen_at: DateTime<Utc>,
+}
+
+impl RollEntry {
+    pub fn matches(&self, voter: &Voter) -> bool {
+        self.nim == voter.nim
+            && self.name == voter.name
+            && self.email == voter.email
+            && self.faculty == voter.faculty
+            && self.cohort == voter.cohort
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RollSummary {
+    pub election_id: String,
+    pub size: i64,
+    pub frozen_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RollChange {
+    pub frozen: RollEntry,
+    pub live: Voter,
+}
+
+/// Differences between the live registry and a frozen roll, for the committee to resolve by hand.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct RollDiff {
+    /// Eligible in the registry now, but not on the roll.
+    pub added: Vec<Voter>,
+    /// On the roll, but gone from the registry or no longer eligible.
+    pub removed: Vec<RollEntry>,
+    /// On the roll and still eligible, with registry details changed since the freeze.
+    pub changed: Vec<RollChange>,
+}
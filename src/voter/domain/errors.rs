@@ -0,0 +1,27 @@
+use crate::embargo::domain::StaffDenied;
+use thiserror::Error;
+
+
+#[derive(Debug, Error)]
+#[derive(Clone)]
+pub enum VoterError {
+    #[error("VoterError::NotFound: {0}")]
+    NotFound(String),
+    #[error("VoterError::Unauthorized: {0}")]
+    Unauthorized(String),
+    #[error("VoterError::Forbidden: {0}")]
+    Forbidden(String),
+    #[error("VoterError::InvalidInput: {0}")]
+    InvalidInput(String),
+    #[error("VoterError::UnknownError: {0}")]
+    UnknownError(String),
+}
+
+impl From<StaffDenied> for VoterError {
+    fn from(e: StaffDenied) -> Self {
+        match e {
+            StaffDenied::Unauthenticated(msg) => VoterError::Unauthorized(msg),
+            StaffDenied::NotAllowed(msg) => VoterError::Forbidden(msg),
+        }
+    }
+}
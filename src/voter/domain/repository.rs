@@ -0,0 +1,26 @@
+use crate::voter::domain::entities::{RollEntry, RollSummary, RotationReport, Voter, VoterInput};
+use crate::voter::domain::errors::VoterError;
+use async_trait::async_trait;
+use mockall::automock;
+
+#[automock]
+#[async_trait]
+pub trait Repository: Send + Sync {
+    /// Voters currently in the registry, eligible or not.
+    async fn find_registry(&self) -> Result<Vec<Voter>, VoterError>;
+
+    /// Exact NIM lookup through the blind index.
+    async fn find_by_nim(&self, nim: String) -> Result<Voter, VoterError>;
+
+    /// Inserts or updates registry records, matched on NIM. Returns the number of rows written.
+    async fn upsert(&self, voters: Vec<VoterInput>) -> Result<u64, VoterError>;
+
+    /// Rewraps every PII column that is not under the active key, encrypts legacy plaintext
+    /// and recomputes blind indexes, across the registry and all frozen rolls.
+    async fn rotate_pii(&self) -> Result<RotationReport, VoterError>;
+
+    async fn find_roll(&self, election_id: String) -> Result<Vec<RollEntry>, VoterError>;
+
+    /// Size of the frozen roll, used as the turnout denominator.
+    async fn roll_summary(&self, election_id: String) -> Result<RollSummary, VoterError>;
+}
@@ -0,0 +1,5 @@
+mod postgres;
+mod model;
+
+pub use postgres::PostgresRepo;
+pub(crate) use postgres::{FIELD_EMAIL, FIELD_NAME, FIELD_NIM};
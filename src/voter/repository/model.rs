@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct Voter {
+    pub id: Uuid,
+    pub nim: String,
+    pub name: String,
+    pub email: String,
+    pub faculty: String,
+    pub cohort: i32,
+    pub eligible: bool,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, FromRow)]
+pub struct RollEntry {
+    pub voter_id: Uuid,
+    pub nim: String,
+    pub name: String,
+    pub email: String,
+    pub faculty: String,
+    pub cohort: i32,
+    pub frozen_at: DateTime<Utc>,
+}
+
+/// The encrypted identity columns shared by `voters` and `voter_rolls`.
+#[derive(Debug, Clone, FromRow)]
+pub struct EncryptedIdentity {
+    pub id: Uuid,
+    pub nim: String,
+    pub nim_bidx: Option<String>,
+    pub name: String,
+    pub email: String,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct RollIdentity {
+    pub election_id: Uuid,
+    #[sqlx(flatten)]
+    pub identity: EncryptedIdentity,
+}
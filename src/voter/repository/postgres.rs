@@ -0,0 +1,266 @@
+use crate::voter::domain;
+use crate::voter::domain::VoterError;
+use crate::voter::domain::Repository;
+use crate::infrastructure::crypto::{CryptoError, PiiCipher};
+use crate::infrastructure::database::postgres::Postgres;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+use crate::voter::repository::model::{EncryptedIdentity, RollEntry, RollIdentity, Voter};
+
+pub(crate) const FIELD_NIM: &str = "nim";
+pub(crate) const FIELD_NAME: &str = "name";
+pub(crate) const FIELD_EMAIL: &str = "email";
+
+pub struct PostgresRepo {
+    postgres: sqlx::PgPool,
+    cipher: Arc<PiiCipher>,
+}
+impl PostgresRepo {
+    pub async fn new(postgres: Arc<Mutex<Postgres>>, cipher: Arc<PiiCipher>) -> anyhow::Result<Self> {
+        let guard = postgres.lock().await;
+        let pool = guard
+            .pool()
+            .ok_or_else(|| anyhow!("DB pool is not initialized"))?;
+        Ok(PostgresRepo { postgres: pool, cipher })
+    }
+
+    fn crypto_error(e: CryptoError) -> VoterError {
+        VoterError::UnknownError(e.to_string())
+    }
+
+    fn transform_voter(&self, v: Voter) -> Result<domain::Voter, VoterError> {
+        Ok(domain::Voter {
+            id: v.id.to_string(),
+            nim: self.cipher.reveal(FIELD_NIM, &v.nim).map_err(Self::crypto_error)?,
+            name: self.cipher.reveal(FIELD_NAME, &v.name).map_err(Self::crypto_error)?,
+            email: self.cipher.reveal(FIELD_EMAIL, &v.email).map_err(Self::crypto_error)?,
+            faculty: v.faculty,
+            cohort: v.cohort,
+            eligible: v.eligible,
+            updated_at: v.updated_at,
+        })
+    }
+
+    fn transform_roll_entry(&self, r: RollEntry) -> Result<domain::RollEntry, VoterError> {
+        Ok(domain::RollEntry {
+            voter_id: r.voter_id.to_string(),
+            nim: self.cipher.reveal(FIELD_NIM, &r.nim).map_err(Self::crypto_error)?,
+            name: self.cipher.reveal(FIELD_NAME, &r.name).map_err(Self::crypto_error)?,
+            email: self.cipher.reveal(FIELD_EMAIL, &r.email).map_err(Self::crypto_error)?,
+            faculty: r.faculty,
+            cohort: r.cohort,
+            frozen_at: r.frozen_at,
+        })
+    }
+
+    fn parse_election_id(id: &str) -> Result<Uuid, VoterError> {
+        Uuid::parse_str(id).map_err(|_| VoterError::NotFound(format!("election {} not found", id)))
+    }
+
+    /// Returns the rewritten identity when any column is off the active key or the
+    /// blind index is stale, `None` when the row is already current.
+    fn rotate_identity(&self, row: &EncryptedIdentity) -> Result<Option<EncryptedIdentity>, VoterError> {
+        let nim = self.cipher.reveal(FIELD_NIM, &row.nim).map_err(Self::crypto_error)?;
+        let nim_bidx = self.cipher.blind_index(FIELD_NIM, &nim);
+
+        let current = !self.cipher.needs_rewrap(&row.nim)
+            && !self.cipher.needs_rewrap(&row.name)
+            && !self.cipher.needs_rewrap(&row.email)
+            && row.nim_bidx.as_deref() == Some(nim_bidx.as_str());
+        if current {
+            return Ok(None);
+        }
+
+        Ok(Some(EncryptedIdentity {
+            id: row.id,
+            nim: self.cipher.rewrap(FIELD_NIM, &row.nim).map_err(Self::crypto_error)?,
+            nim_bidx: Some(nim_bidx),
+            name: self.cipher.rewrap(FIELD_NAME, &row.name).map_err(Self::crypto_error)?,
+            email: self.cipher.rewrap(FIELD_EMAIL, &row.email).map_err(Self::crypto_error)?,
+        }))
+    }
+}
+
+const VOTER_COLUMNS: &str = r#"
+        id
+        , nim
+        , name
+        , email
+        , faculty
+        , cohort
+        , eligible
+        , updated_at
+"#;
+
+#[async_trait]
+impl Repository for PostgresRepo {
+    async fn find_registry(&self) -> Result<Vec<domain::Voter>, VoterError> {
+        let sql = format!("SELECT {} FROM voters", VOTER_COLUMNS);
+        let voters = sqlx::query_as::<_, Voter>(&sql)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| VoterError::UnknownError(e.to_string()))?;
+
+        voters.into_iter().map(|v| self.transform_voter(v)).collect()
+    }
+
+    async fn find_by_nim(&self, nim: String) -> Result<domain::Voter, VoterError> {
+        let sql = format!("SELECT {} FROM voters WHERE nim_bidx = $1", VOTER_COLUMNS);
+        let voter = sqlx::query_as::<_, Voter>(&sql)
+            .bind(self.cipher.blind_index(FIELD_NIM, &nim))
+            .fetch_optional(&self.postgres)
+            .await
+            .map_err(|e| VoterError::UnknownError(e.to_string()))?
+            .ok_or_else(|| VoterError::NotFound("voter not found".into()))?;
+
+        self.transform_voter(voter)
+    }
+
+    async fn upsert(&self, voters: Vec<domain::VoterInput>) -> Result<u64, VoterError> {
+        let mut nims = Vec::with_capacity(voters.len());
+        let mut bidxs = Vec::with_capacity(voters.len());
+        let mut names = Vec::with_capacity(voters.len());
+        let mut emails = Vec::with_capacity(voters.len());
+        let mut faculties = Vec::with_capacity(voters.len());
+        let mut cohorts = Vec::with_capacity(voters.len());
+        let mut eligibles = Vec::with_capacity(voters.len());
+
+        for v in voters {
+            bidxs.push(self.cipher.blind_index(FIELD_NIM, &v.nim));
+            nims.push(self.cipher.encrypt(FIELD_NIM, v.nim.trim()).map_err(Self::crypto_error)?);
+            names.push(self.cipher.encrypt(FIELD_NAME, &v.name).map_err(Self::crypto_error)?);
+            emails.push(self.cipher.encrypt(FIELD_EMAIL, &v.email).map_err(Self::crypto_error)?);
+            faculties.push(v.faculty);
+            cohorts.push(v.cohort);
+            eligibles.push(v.eligible);
+        }
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO voters (nim, nim_bidx, name, email, faculty, cohort, eligible)
+            SELECT * FROM UNNEST($1::TEXT[], $2::TEXT[], $3::TEXT[], $4::TEXT[], $5::TEXT[], $6::INTEGER[], $7::BOOLEAN[])
+            ON CONFLICT (nim_bidx) DO UPDATE SET
+                nim = EXCLUDED.nim
+                , name = EXCLUDED.name
+                , email = EXCLUDED.email
+                , faculty = EXCLUDED.faculty
+                , cohort = EXCLUDED.cohort
+                , eligible = EXCLUDED.eligible
+                , updated_at = now()
+            "#,
+        )
+            .bind(nims)
+            .bind(bidxs)
+            .bind(names)
+            .bind(emails)
+            .bind(faculties)
+            .bind(cohorts)
+            .bind(eligibles)
+            .execute(&self.postgres)
+            .await
+            .map_err(|e| VoterError::UnknownError(e.to_string()))?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn rotate_pii(&self) -> Result<domain::RotationReport, VoterError> {
+        let mut tx = self.postgres.begin().await
+            .map_err(|e| VoterError::UnknownError(e.to_string()))?;
+        let mut report = domain::RotationReport::default();
+
+        let voters = sqlx::query_as::<_, EncryptedIdentity>(
+            r#"SELECT id, nim, nim_bidx, name, email FROM voters FOR UPDATE"#,
+        )
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| VoterError::UnknownError(e.to_string()))?;
+
+        for row in voters {
+            if let Some(next) = self.rotate_identity(&row)? {
+                sqlx::query(r#"UPDATE voters SET nim = $2, nim_bidx = $3, name = $4, email = $5 WHERE id = $1"#)
+                    .bind(next.id)
+                    .bind(next.nim)
+                    .bind(next.nim_bidx)
+                    .bind(next.name)
+                    .bind(next.email)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| VoterError::UnknownError(e.to_string()))?;
+                report.voters += 1;
+            }
+        }
+
+        let roll_entries = sqlx::query_as::<_, RollIdentity>(
+            r#"SELECT election_id, voter_id AS id, nim, nim_bidx, name, email FROM voter_rolls FOR UPDATE"#,
+        )
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| VoterError::UnknownError(e.to_string()))?;
+
+        for row in roll_entries {
+            if let Some(next) = self.rotate_identity(&row.identity)? {
+                sqlx::query(
+                    r#"UPDATE voter_rolls SET nim = $3, nim_bidx = $4, name = $5, email = $6
+                       WHERE election_id = $1 AND voter_id = $2"#,
+                )
+                    .bind(row.election_id)
+                    .bind(next.id)
+                    .bind(next.nim)
+                    .bind(next.nim_bidx)
+                    .bind(next.name)
+                    .bind(next.email)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| VoterError::UnknownError(e.to_string()))?;
+                report.roll_entries += 1;
+            }
+        }
+
+        tx.commit().await
+            .map_err(|e| VoterError::UnknownError(e.to_string()))?;
+
+        Ok(report)
+    }
+
+    async fn find_roll(&self, election_id: String) -> Result<Vec<domain::RollEntry>, VoterError> {
+        let uuid = Self::parse_election_id(&election_id)?;
+
+        let roll = sqlx::query_as::<_, RollEntry>(
+            r#"
+        SELECT voter_id
+            , nim
+            , name
+            , email
+            , faculty
+            , cohort
+            , frozen_at
+        FROM voter_rolls
+        WHERE election_id = $1
+        "#,
+        )
+            .bind(uuid)
+            .fetch_all(&self.postgres)
+            .await
+            .map_err(|e| VoterError::UnknownError(e.to_string()))?;
+
+        roll.into_iter().map(|r| self.transform_roll_entry(r)).collect()
+    }
+
+    async fn roll_summary(&self, election_id: String) -> Result<domain::RollSummary, VoterError> {
+        let uuid = Self::parse_election_id(&election_id)?;
+
+        let (size, frozen_at): (i64, Option<DateTime<Utc>>) = sqlx::query_as(
+            r#"SELECT COUNT(*)::BIGINT, MIN(frozen_at) FROM voter_rolls WHERE election_id = $1"#,
+        )
+            .bind(uuid)
+            .fetch_one(&self.postgres)
+            .await
+            .map_err(|e| VoterError::UnknownError(e.to_string()))?;
+
+        Ok(domain::RollSummary { election_id, size, frozen_at })
+    }
+}
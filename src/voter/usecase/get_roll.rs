@@ -0,0 +1,39 @@
+use crate::voter::domain::{Repository, RollSummary, VoterError};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<RollSummary, VoterError>;
+}
+
+pub struct GetRollUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+}
+
+#[derive(Debug)]
+pub struct Request {
+    pub election_id: String,
+}
+
+impl<R: ?Sized + Send + Sync> GetRollUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for GetRollUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<RollSummary, VoterError> {
+        self.repository.roll_summary(req.election_id).await
+    }
+}
@@ -0,0 +1,35 @@
+use std::sync::Arc;
+use crate::voter::domain::Repository;
+use crate::embargo::domain::StaffRoster;
+use crate::voter::usecase::{get_roll, lookup, roll_diff, rotate_pii, sync};
+use crate::voter::usecase::get_roll::GetRollUseCase;
+use crate::voter::usecase::lookup::LookupVoterUseCase;
+use crate::voter::usecase::roll_diff::RollDiffUseCase;
+use crate::voter::usecase::rotate_pii::RotatePiiUseCase;
+use crate::voter::usecase::sync::SyncVotersUseCase;
+
+
+#[derive(Clone)]
+pub struct UseCase
+{
+    pub get_roll: Arc<dyn get_roll::Interactor>,
+    pub roll_diff: Arc<dyn roll_diff::Interactor>,
+    pub lookup: Arc<dyn lookup::Interactor>,
+    pub sync: Arc<dyn sync::Interactor>,
+    pub rotate_pii: Arc<dyn rotate_pii::Interactor>,
+}
+
+impl UseCase {
+
+    pub fn new(voter_repo: Arc<dyn Repository + Send + Sync>, roster: Arc<StaffRoster>) -> Self {
+
+        Self {
+            get_roll: Arc::new(GetRollUseCase::new(voter_repo.clone())),
+            roll_diff: Arc::new(RollDiffUseCase::new(voter_repo.clone())),
+            lookup: Arc::new(LookupVoterUseCase::new(voter_repo.clone(), roster.clone())),
+            sync: Arc::new(SyncVotersUseCase::new(voter_repo.clone())),
+            rotate_pii: Arc::new(RotatePiiUseCase::new(voter_repo, roster)),
+        }
+    }
+
+}
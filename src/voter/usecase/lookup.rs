@@ -0,0 +1,93 @@
+use crate::voter::domain::{Repository, Voter, VoterError};
+use crate::embargo::domain::StaffRoster;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Voter, VoterError>;
+}
+
+pub struct LookupVoterUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    roster: Arc<StaffRoster>,
+}
+
+pub struct Request {
+    pub nim: String,
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("nim", &self.nim)
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> LookupVoterUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, roster: Arc<StaffRoster>) -> Self {
+        Self { repository, roster }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for LookupVoterUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Voter, VoterError> {
+        self.roster.acting(req.staff_token.as_deref(), "look up voters")?;
+
+        let nim = req.nim.trim().to_string();
+        if nim.is_empty() {
+            return Err(VoterError::InvalidInput("nim is required".into()));
+        }
+
+        self.repository.find_by_nim(nim).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::embargo::domain::{StaffRoster, hash_staff_token};
+    use crate::voter::domain::{self, VoterError};
+    use crate::voter::usecase::lookup;
+    use crate::voter::usecase::lookup::Interactor;
+    use std::sync::Arc;
+
+    fn roster() -> Arc<StaffRoster> {
+        Arc::new(StaffRoster::parse(&format!(
+            "rina:committee:{},budi:observer:{}",
+            hash_staff_token("rina-token"),
+            hash_staff_token("budi-token")
+        )).unwrap())
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_lookup_needs_an_acting_staff_member() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_by_nim().never();
+        let uc = lookup::LookupVoterUseCase::new(Arc::new(repo_mock), roster());
+
+        let request = |token: Option<&str>| lookup::Request {
+            nim: "2023001".to_string(),
+            staff_token: token.map(str::to_string),
+        };
+
+        let anonymous = uc.handle(request(None)).await;
+        assert!(matches!(anonymous, Err(VoterError::Unauthorized(_))));
+
+        let observer = uc.handle(request(Some("budi-token"))).await;
+        assert!(matches!(observer, Err(VoterError::Forbidden(_))));
+    }
+}
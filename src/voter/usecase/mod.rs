@@ -0,0 +1,8 @@
+mod init;
+pub mod get_roll;
+pub mod lookup;
+pub mod roll_diff;
+pub mod rotate_pii;
+pub mod sync;
+
+pub use init::UseCase;
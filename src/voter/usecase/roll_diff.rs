@@ -0,0 +1,143 @@
+use crate::voter::domain::{Repository, RollChange, RollDiff, RollEntry, Voter, VoterError};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<RollDiff, VoterError>;
+}
+
+pub struct RollDiffUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+}
+
+#[derive(Debug)]
+pub struct Request {
+    pub election_id: String,
+}
+
+impl<R: ?Sized + Send + Sync> RollDiffUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+}
+
+pub fn diff(roll: Vec<RollEntry>, registry: Vec<Voter>) -> RollDiff {
+    let mut live: HashMap<String, Voter> = registry
+        .into_iter()
+        .filter(|v| v.eligible)
+        .map(|v| (v.id.clone(), v))
+        .collect();
+
+    let mut result = RollDiff::default();
+
+    for frozen in roll {
+        match live.remove(&frozen.voter_id) {
+            None => result.removed.push(frozen),
+            Some(voter) if !frozen.matches(&voter) => result.changed.push(RollChange { frozen, live: voter }),
+            Some(_) => {}
+        }
+    }
+
+    result.added = live.into_values().collect();
+    result.added.sort_by(|a, b| a.nim.cmp(&b.nim));
+    result.removed.sort_by(|a, b| a.nim.cmp(&b.nim));
+    result.changed.sort_by(|a, b| a.frozen.nim.cmp(&b.frozen.nim));
+
+    result
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for RollDiffUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<RollDiff, VoterError> {
+        let roll = self.repository.find_roll(req.election_id.clone()).await?;
+        if roll.is_empty() {
+            return Err(VoterError::NotFound(format!("election {} has no frozen roll", req.election_id)));
+        }
+
+        let registry = self.repository.find_registry().await?;
+
+        Ok(diff(roll, registry))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::voter::domain::{self, VoterError};
+    use crate::voter::usecase::roll_diff;
+    use crate::voter::usecase::roll_diff::Interactor;
+    use std::sync::Arc;
+
+    fn voter(id: &str, name: &str, eligible: bool) -> domain::Voter {
+        domain::Voter {
+            id: id.to_string(),
+            nim: format!("nim-{}", id),
+            name: name.to_string(),
+            email: format!("{}@campus.ac.id", id),
+            faculty: "Engineering".to_string(),
+            cohort: 2023,
+            eligible,
+            updated_at: None,
+        }
+    }
+
+    fn entry(id: &str, name: &str) -> domain::RollEntry {
+        domain::RollEntry {
+            voter_id: id.to_string(),
+            nim: format!("nim-{}", id),
+            name: name.to_string(),
+            email: format!("{}@campus.ac.id", id),
+            faculty: "Engineering".to_string(),
+            cohort: 2023,
+            frozen_at: chrono::Utc::now(),
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_roll_diff() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_roll()
+            .times(1)
+            .returning(|_| Ok(vec![entry("1", "Alice"), entry("2", "Bob"), entry("3", "Carol"), entry("4", "Dan")]));
+        repo_mock.expect_find_registry()
+            .times(1)
+            .returning(|| Ok(vec![
+                voter("1", "Alice", true),
+                voter("2", "Bobby", true),
+                voter("3", "Carol", false),
+                voter("5", "Eve", true),
+                voter("6", "Frank", false),
+            ]));
+
+        let uc = roll_diff::RollDiffUseCase::new(Arc::new(repo_mock));
+        let diff = uc.handle(roll_diff::Request { election_id: "e1".to_string() }).await.unwrap();
+
+        assert_eq!(diff.added.iter().map(|v| v.id.as_str()).collect::<Vec<_>>(), vec!["5"]);
+        assert_eq!(diff.removed.iter().map(|r| r.voter_id.as_str()).collect::<Vec<_>>(), vec!["3", "4"]);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].frozen.name, "Bob");
+        assert_eq!(diff.changed[0].live.name, "Bobby");
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_roll_diff_without_frozen_roll() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_find_roll().times(1).returning(|_| Ok(vec![]));
+        repo_mock.expect_find_registry().times(0);
+
+        let uc = roll_diff::RollDiffUseCase::new(Arc::new(repo_mock));
+        let res = uc.handle(roll_diff::Request { election_id: "e1".to_string() }).await;
+
+        assert!(matches!(res, Err(VoterError::NotFound(_))));
+    }
+}
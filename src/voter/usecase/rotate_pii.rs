@@ -0,0 +1,81 @@
+use crate::voter::domain::{Repository, RotationReport, VoterError};
+use crate::embargo::domain::StaffRoster;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<RotationReport, VoterError>;
+}
+
+pub struct RotatePiiUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+    roster: Arc<StaffRoster>,
+}
+
+pub struct Request {
+    pub staff_token: Option<String>,
+}
+
+// Keep the staff token out of request logs.
+impl std::fmt::Debug for Request {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Request")
+            .field("staff_token", &self.staff_token.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl<R: ?Sized + Send + Sync> RotatePiiUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>, roster: Arc<StaffRoster>) -> Self {
+        Self { repository, roster }
+    }
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for RotatePiiUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<RotationReport, VoterError> {
+        self.roster.acting(req.staff_token.as_deref(), "rotate PII keys")?;
+
+        self.repository.rotate_pii().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::embargo::domain::{StaffRoster, hash_staff_token};
+    use crate::voter::domain::{self, VoterError};
+    use crate::voter::usecase::rotate_pii;
+    use crate::voter::usecase::rotate_pii::Interactor;
+    use std::sync::Arc;
+
+    fn roster() -> Arc<StaffRoster> {
+        Arc::new(StaffRoster::parse(&format!(
+            "rina:committee:{},budi:observer:{}",
+            hash_staff_token("rina-token"),
+            hash_staff_token("budi-token")
+        )).unwrap())
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_rotate_pii_needs_an_acting_staff_member() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_rotate_pii().never();
+        let uc = rotate_pii::RotatePiiUseCase::new(Arc::new(repo_mock), roster());
+
+        let anonymous = uc.handle(rotate_pii::Request { staff_token: None }).await;
+        assert!(matches!(anonymous, Err(VoterError::Unauthorized(_))));
+
+        let observer = uc.handle(rotate_pii::Request { staff_token: Some("budi-token".to_string()) }).await;
+        assert!(matches!(observer, Err(VoterError::Forbidden(_))));
+    }
+}
@@ -0,0 +1,112 @@
+use crate::voter::domain::{Repository, VoterError, VoterInput};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+#[async_trait]
+pub trait Interactor: Send + Sync {
+    async fn handle(&self, _: Request) -> Result<Response, VoterError>;
+}
+
+pub struct SyncVotersUseCase<R: ?Sized + Send + Sync>
+where
+    R: Repository,
+{
+    repository: Arc<R>,
+}
+
+#[derive(Debug)]
+pub struct Request {
+    pub voters: Vec<VoterInput>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Response {
+    pub written: u64,
+}
+
+impl<R: ?Sized + Send + Sync> SyncVotersUseCase<R>
+where
+    R: Repository,
+{
+    pub fn new(repository: Arc<R>) -> Self {
+        Self { repository }
+    }
+}
+
+fn validate(voters: &[VoterInput]) -> Result<(), VoterError> {
+    let mut seen = HashSet::new();
+
+    for (i, v) in voters.iter().enumerate() {
+        let nim = v.nim.trim();
+        if nim.is_empty() || v.name.trim().is_empty() || v.email.trim().is_empty() || v.faculty.trim().is_empty() {
+            return Err(VoterError::InvalidInput(format!("record {} has an empty field", i)));
+        }
+        if !seen.insert(nim) {
+            return Err(VoterError::InvalidInput(format!("record {} repeats nim {}", i, nim)));
+        }
+    }
+
+    Ok(())
+}
+
+#[async_trait]
+impl<R: ?Sized + Send + Sync> Interactor for SyncVotersUseCase<R>
+where
+    R: Repository,
+{
+    async fn handle(&self, req: Request) -> Result<Response, VoterError> {
+        validate(&req.voters)?;
+        if req.voters.is_empty() {
+            return Ok(Response { written: 0 });
+        }
+
+        let written = self.repository.upsert(req.voters).await?;
+        Ok(Response { written })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::voter::domain::{self, VoterError, VoterInput};
+    use crate::voter::usecase::sync;
+    use crate::voter::usecase::sync::Interactor;
+    use std::sync::Arc;
+
+    fn input(nim: &str) -> VoterInput {
+        VoterInput {
+            nim: nim.to_string(),
+            name: "Alice".to_string(),
+            email: "alice@campus.ac.id".to_string(),
+            faculty: "Engineering".to_string(),
+            cohort: 2023,
+            eligible: true,
+        }
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_sync_upserts_batch() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_upsert()
+            .times(1)
+            .withf(|voters| voters.len() == 2)
+            .returning(|_| Ok(2));
+
+        let uc = sync::SyncVotersUseCase::new(Arc::new(repo_mock));
+        let res = uc.handle(sync::Request { voters: vec![input("1"), input("2")] }).await.unwrap();
+
+        assert_eq!(res.written, 2);
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn test_sync_rejects_duplicate_nim() {
+        let mut repo_mock = domain::MockRepository::new();
+        repo_mock.expect_upsert().times(0);
+
+        let uc = sync::SyncVotersUseCase::new(Arc::new(repo_mock));
+        let res = uc.handle(sync::Request { voters: vec![input("1"), input(" 1 ")] }).await;
+
+        assert!(matches!(res, Err(VoterError::InvalidInput(_))));
+    }
+}